@@ -65,6 +65,9 @@ pub struct CreateMerchantAccount {
     /// An identifier for the vault used to store payment method information.
     #[schema(example = "locker_abc123")]
     pub locker_id: Option<String>,
+
+    /// Expiry and usage limits of the payment tokens used to confirm payments
+    pub payment_token_config: Option<PaymentTokenConfig>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -128,6 +131,10 @@ pub struct MerchantAccountResponse {
     /// An identifier for the vault used to store payment method information.
     #[schema(example = "locker_abc123")]
     pub locker_id: Option<String>,
+
+    /// Expiry and usage limits of the payment tokens used to confirm payments
+    #[schema(value_type = Option<PaymentTokenConfig>)]
+    pub payment_token_config: Option<serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -210,6 +217,20 @@ pub struct WebhookDetails {
     pub payment_failed_enabled: Option<bool>,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentTokenConfig {
+    /// Number of seconds a payment token can be used for after it was issued. Defaults to the
+    /// payment token expiry configured for the server, and is capped at its maximum.
+    #[schema(minimum = 1, example = 900)]
+    pub ttl_in_secs: Option<u32>,
+
+    /// Number of payments a payment token can be used to confirm. Defaults to the number of uses
+    /// configured for the server.
+    #[schema(minimum = 1, example = 1)]
+    pub max_uses: Option<u32>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct DeleteMerchantAccountResponse {
     /// The identifier for the Merchant Account
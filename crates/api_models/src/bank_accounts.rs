@@ -1 +1,155 @@
+use common_utils::pii;
+use masking::{PeekInterface, Secret};
+use utoipa::ToSchema;
 
+use crate::{enums as api_enums, payments::AddressDetails};
+
+/// Bank account details of a direct debit, for each of the supported debit schemes
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BankDebitData {
+    /// ACH Direct Debit, from bank accounts in the US
+    AchBankDebit {
+        /// Billing details of the account holder
+        billing_details: BankDebitBilling,
+        /// The bank account number
+        #[schema(value_type = String, example = "000123456789")]
+        account_number: Secret<String, pii::BankAccountNumber>,
+        /// The ABA routing number of the bank
+        #[schema(value_type = String, example = "110000000")]
+        routing_number: Secret<String>,
+    },
+    /// SEPA Direct Debit, from bank accounts in the SEPA zone
+    SepaBankDebit {
+        /// Billing details of the account holder
+        billing_details: BankDebitBilling,
+        /// The International Bank Account Number (IBAN)
+        #[schema(value_type = String, example = "DE89370400440532013000")]
+        iban: Secret<String, pii::BankAccountNumber>,
+    },
+    /// BACS Direct Debit, from bank accounts in the UK
+    BacsBankDebit {
+        /// Billing details of the account holder
+        billing_details: BankDebitBilling,
+        /// The bank account number
+        #[schema(value_type = String, example = "00012345")]
+        account_number: Secret<String, pii::BankAccountNumber>,
+        /// The sort code of the bank branch
+        #[schema(value_type = String, example = "108800")]
+        sort_code: Secret<String>,
+    },
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BankDebitBilling {
+    /// The name of the account holder
+    #[schema(value_type = String, example = "John Doe")]
+    pub name: Secret<String>,
+
+    /// The email of the account holder
+    #[schema(value_type = String, example = "johndoe@example.com")]
+    pub email: Secret<String, pii::Email>,
+
+    /// The address of the account holder, required by some debit schemes
+    pub address: Option<AddressDetails>,
+}
+
+/// Details of a bank account saved as a payment method, without the full account number
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BankAccountResponse {
+    /// The debit scheme the bank account is debited through
+    #[schema(value_type = PaymentMethodSubType, example = "sepa")]
+    pub bank_debit_type: api_enums::PaymentMethodSubType,
+
+    /// The last four digits of the account number or IBAN
+    #[schema(example = "3000")]
+    pub last4: String,
+}
+
+impl BankDebitData {
+    pub fn get_billing_details(&self) -> &BankDebitBilling {
+        match self {
+            Self::AchBankDebit {
+                billing_details, ..
+            }
+            | Self::SepaBankDebit {
+                billing_details, ..
+            }
+            | Self::BacsBankDebit {
+                billing_details, ..
+            } => billing_details,
+        }
+    }
+
+    /// The payment method type of the debit scheme the bank account is debited through
+    pub fn get_bank_debit_type(&self) -> api_enums::PaymentMethodSubType {
+        match self {
+            Self::AchBankDebit { .. } => api_enums::PaymentMethodSubType::Ach,
+            Self::SepaBankDebit { .. } => api_enums::PaymentMethodSubType::Sepa,
+            Self::BacsBankDebit { .. } => api_enums::PaymentMethodSubType::Bacs,
+        }
+    }
+
+    /// The account number, or the IBAN for SEPA debits
+    pub fn get_account_number(&self) -> &Secret<String, pii::BankAccountNumber> {
+        match self {
+            Self::AchBankDebit { account_number, .. }
+            | Self::BacsBankDebit { account_number, .. } => account_number,
+            Self::SepaBankDebit { iban, .. } => iban,
+        }
+    }
+
+    pub fn get_last4(&self) -> String {
+        let account_number = self.get_account_number().peek();
+        account_number
+            .char_indices()
+            .rev()
+            .nth(3)
+            .map_or(account_number.as_str(), |(index, _)| {
+                &account_number[index..]
+            })
+            .to_string()
+    }
+}
+
+impl From<&BankDebitData> for BankAccountResponse {
+    fn from(bank_debit: &BankDebitData) -> Self {
+        Self {
+            bank_debit_type: bank_debit.get_bank_debit_type(),
+            last4: bank_debit.get_last4(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bank_debit_last4() {
+        let billing_details = BankDebitBilling {
+            name: Secret::new("John Doe".to_string()),
+            email: Secret::new("johndoe@example.com".to_string()),
+            address: None,
+        };
+        let sepa = BankDebitData::SepaBankDebit {
+            billing_details: billing_details.clone(),
+            iban: Secret::new("DE89370400440532013000".to_string()),
+        };
+        assert_eq!(
+            BankAccountResponse::from(&sepa),
+            BankAccountResponse {
+                bank_debit_type: api_enums::PaymentMethodSubType::Sepa,
+                last4: "3000".to_string(),
+            }
+        );
+
+        let bacs = BankDebitData::BacsBankDebit {
+            billing_details,
+            account_number: Secret::new("123".to_string()),
+            sort_code: Secret::new("108800".to_string()),
+        };
+        assert_eq!(bacs.get_last4(), "123");
+    }
+}
@@ -0,0 +1,58 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// The payment methods, currencies, countries, capture methods and mandates supported by a connector
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct ConnectorCapabilities {
+    /// The name of the connector
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector: api_enums::Connector,
+
+    /// The payment methods which can be processed by the connector
+    #[schema(value_type = Vec<PaymentMethodType>, example = json!(["card", "wallet"]))]
+    pub payment_methods: Vec<api_enums::PaymentMethodType>,
+
+    /// The currencies which can be processed by the connector, any currency is supported if not present
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["USD", "EUR"]))]
+    pub currencies: Option<Vec<api_enums::Currency>>,
+
+    /// The two-letter ISO country codes of the billing countries supported by the connector, any country is supported if not present
+    #[schema(example = json!(["US"]))]
+    pub countries: Option<Vec<String>>,
+
+    /// The capture methods supported by the connector
+    #[schema(value_type = Vec<CaptureMethod>, example = json!(["automatic", "manual"]))]
+    pub capture_methods: Vec<api_enums::CaptureMethod>,
+
+    /// Whether payments can be made with a mandate set up with the connector
+    pub supports_mandates: bool,
+
+    /// Whether the connector accepts network tokens, which Apple Pay payments are processed with once the router decrypts their payment token
+    pub supports_network_tokens: bool,
+}
+
+impl ConnectorCapabilities {
+    pub fn supports_payment_method(&self, payment_method: api_enums::PaymentMethodType) -> bool {
+        self.payment_methods.contains(&payment_method)
+    }
+
+    pub fn supports_currency(&self, currency: api_enums::Currency) -> bool {
+        self.currencies
+            .as_ref()
+            .map_or(true, |currencies| currencies.contains(&currency))
+    }
+
+    pub fn supports_country(&self, country: &str) -> bool {
+        self.countries.as_ref().map_or(true, |countries| {
+            countries
+                .iter()
+                .any(|supported_country| supported_country.eq_ignore_ascii_case(country))
+        })
+    }
+
+    pub fn supports_capture_method(&self, capture_method: api_enums::CaptureMethod) -> bool {
+        self.capture_methods.contains(&capture_method)
+    }
+}
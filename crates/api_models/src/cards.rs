@@ -1 +1,23 @@
+use utoipa::ToSchema;
 
+use crate::enums as api_enums;
+
+/// Card details derived from the BIN (the leading digits) of the card number
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct CardInfo {
+    /// The card network
+    #[schema(example = "Visa")]
+    pub card_network: Option<String>,
+
+    /// The bank which issued the card
+    #[schema(example = "JP Morgan Chase")]
+    pub card_issuer: Option<String>,
+
+    /// Whether the card is a credit, debit or prepaid card
+    #[schema(value_type = Option<CardFundingType>, example = "credit")]
+    pub card_funding_type: Option<api_enums::CardFundingType>,
+
+    /// The two-letter ISO country code of the country the card was issued in
+    #[schema(example = "US")]
+    pub card_issuing_country: Option<String>,
+}
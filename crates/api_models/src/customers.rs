@@ -91,6 +91,9 @@ pub struct CustomerResponse {
     /// object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<serde_json::Value>,
+    /// The identifier of the saved payment method to preselect for the customer
+    #[schema(max_length = 64, example = "pm_7ebf443fa0504067")]
+    pub default_payment_method_id: Option<String>,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize)]
@@ -114,6 +117,12 @@ pub struct CustomerDeleteResponse {
     pub payment_methods_deleted: bool,
 }
 
+#[derive(Default, Debug, Deserialize, Serialize)]
+pub struct CustomerPaymentMethodId {
+    pub customer_id: String,
+    pub payment_method_id: String,
+}
+
 pub fn generate_customer_id() -> String {
     common_utils::generate_id(consts::ID_LENGTH, "cus")
 }
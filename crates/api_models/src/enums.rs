@@ -111,6 +111,8 @@ pub enum ConnectorType {
     PaymentProcessor,
     /// Fraud, Currency Conversion, Crypto etc
     PaymentVas,
+    /// 3DS servers and other providers authenticating payments before they are authorized
+    AuthenticationProcessor,
     /// Accounting, Billing, Invoicing, Tax etc
     FinOperations,
     /// Inventory, ERP, CRM, KYC etc
@@ -262,6 +264,8 @@ pub enum Currency {
 #[strum(serialize_all = "snake_case")]
 pub enum EventType {
     PaymentSucceeded,
+    PaymentMethodUpdated,
+    PaymentMethodClosed,
 }
 
 #[derive(
@@ -366,6 +370,38 @@ pub enum PaymentMethodSubType {
     UpiCollect,
     CreditCardInstallments,
     PayLaterInstallments,
+    Ach,
+    Sepa,
+    Bacs,
+    Ideal,
+    Sofort,
+    Giropay,
+    Eps,
+    BancontactCard,
+    Przelewy24,
+    Pix,
+    Boleto,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardFundingType {
+    Credit,
+    Debit,
+    Prepaid,
 }
 
 #[derive(
@@ -391,6 +427,7 @@ pub enum PaymentMethodType {
     #[default]
     BankTransfer,
     BankDebit,
+    BankRedirect,
     PayLater,
     Netbanking,
     Upi,
@@ -519,9 +556,12 @@ pub enum Connector {
     Cybersource,
     #[default]
     Dummy,
+    #[cfg(feature = "dummy_connector")]
+    DummyConnector,
     Fiserv,
     Globalpay,
     Klarna,
+    Paypal,
     Payu,
     Rapyd,
     Shift4,
@@ -532,7 +572,7 @@ pub enum Connector {
 
 impl Connector {
     pub fn supports_access_token(&self) -> bool {
-        matches!(self, Self::Globalpay | Self::Payu)
+        matches!(self, Self::Globalpay | Self::Paypal | Self::Payu)
     }
 }
 
@@ -557,9 +597,12 @@ pub enum RoutableConnectors {
     Braintree,
     Checkout,
     Cybersource,
+    #[cfg(feature = "dummy_connector")]
+    DummyConnector,
     Fiserv,
     Globalpay,
     Klarna,
+    Paypal,
     Payu,
     Rapyd,
     Shift4,
@@ -568,6 +611,92 @@ pub enum RoutableConnectors {
     Worldpay,
 }
 
+/// Fraud and risk management (FRM) connectors, which assess payments instead of processing them
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FrmConnectors {
+    Signifyd,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthenticationConnectors {
+    #[cfg(feature = "dummy_connector")]
+    DummyConnector,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudCheckStage {
+    /// The payment is checked before it is authorized with the processor
+    #[default]
+    PreAuth,
+    /// The payment is checked after it is authorized, and captured only if accepted
+    PostAuth,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudCheckStatus {
+    /// The payment is checked once it is authorized
+    Pending,
+    Accept,
+    Reject,
+    /// The capture of the payment is held until the check is reviewed by the merchant
+    ManualReview,
+}
+
 /// Wallets which support obtaining session object
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 #[serde(rename_all = "snake_case")]
@@ -608,3 +737,58 @@ impl From<AttemptStatus> for IntentStatus {
         }
     }
 }
+
+/// The reason for which a payment was declined, unified across connectors
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    ToSchema,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    frunk::LabelledGeneric,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DeclineCode {
+    /// The card does not have enough funds, or the credit limit is exceeded
+    InsufficientFunds,
+    /// The issuer declined the payment without giving a reason
+    DoNotHonor,
+    /// The card has expired
+    ExpiredCard,
+    /// The expiry date of the card is incorrect
+    InvalidExpiryDate,
+    /// The CVC of the card is incorrect
+    IncorrectCvc,
+    /// The card number is incorrect
+    IncorrectNumber,
+    /// The amount is invalid or exceeds the amount allowed for the card
+    InvalidAmount,
+    /// The card does not support this type of payment
+    CardNotSupported,
+    /// The card has been reported lost
+    LostCard,
+    /// The card has been reported stolen
+    StolenCard,
+    /// The card cannot be used, as it is blocked or restricted
+    RestrictedCard,
+    /// The payment is suspected to be fraudulent
+    FraudSuspected,
+    /// The payment is not allowed for the card or the cardholder
+    TransactionNotAllowed,
+    /// The payment requires the customer to be authenticated
+    AuthenticationRequired,
+    /// The card has exceeded the number or the amount of payments allowed
+    CardVelocityExceeded,
+    /// The issuer could not be reached, the payment can be retried
+    IssuerUnavailable,
+    /// An error occurred while processing the payment
+    ProcessingError,
+    /// The payment was declined for a reason not covered by another code
+    GenericDecline,
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+/// The result of a fraud check made on a payment attempt
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct FraudCheckResponse {
+    /// The identifier for the fraud check
+    #[schema(example = "frm_mbabizu24mvu3mela5njyhpit4")]
+    pub frm_id: String,
+
+    /// The identifier for the payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    /// The identifier for the payment attempt which was checked
+    pub attempt_id: String,
+
+    /// The fraud and risk management connector which made the check
+    #[schema(example = "signifyd")]
+    pub frm_name: String,
+
+    /// The identifier of the check with the fraud and risk management connector
+    pub frm_transaction_id: Option<String>,
+
+    /// Whether the payment was checked before or after it was authorized
+    #[schema(value_type = FraudCheckStage)]
+    pub frm_stage: enums::FraudCheckStage,
+
+    /// The outcome of the check
+    #[schema(value_type = FraudCheckStatus)]
+    pub frm_status: enums::FraudCheckStatus,
+
+    /// The risk score given to the payment by the connector
+    pub frm_score: Option<i32>,
+
+    /// The reason given by the connector for its decision
+    pub frm_reason: Option<String>,
+
+    /// The time at which the check was reviewed by the merchant
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub reviewed_at: Option<PrimitiveDateTime>,
+
+    /// The time at which the check was made
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The decision of the merchant on a payment held for manual review
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FraudCheckReviewRequest {
+    /// Whether the held payment is captured or voided
+    pub decision: FraudCheckReviewDecision,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FraudCheckReviewDecision {
+    /// Capture the payment, if it was to be captured automatically
+    Accept,
+    /// Void the payment
+    Reject,
+}
+
+impl From<FraudCheckReviewDecision> for enums::FraudCheckStatus {
+    fn from(decision: FraudCheckReviewDecision) -> Self {
+        match decision {
+            FraudCheckReviewDecision::Accept => Self::Accept,
+            FraudCheckReviewDecision::Reject => Self::Reject,
+        }
+    }
+}
+
+/// The constraints that are applicable when listing the fraud checks awaiting a review.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ListFraudCheckConstraints {
+    /// The maximum number of fraud checks to include in the response.
+    pub limit: Option<i64>,
+
+    /// The number of fraud checks to skip when retrieving the list.
+    pub offset: Option<i64>,
+}
@@ -2,12 +2,14 @@
 pub mod admin;
 pub mod api_keys;
 pub mod bank_accounts;
+pub mod capabilities;
 pub mod cards;
 pub mod customers;
 pub mod disputes;
 pub mod enums;
 pub mod errors;
 pub mod files;
+pub mod fraud_check;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
@@ -4,7 +4,7 @@ use common_utils::pii;
 use serde::de;
 use utoipa::ToSchema;
 
-use crate::enums as api_enums;
+use crate::{bank_accounts, enums as api_enums};
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 #[serde(deny_unknown_fields)]
@@ -33,6 +33,9 @@ pub struct CreatePaymentMethod {
     "card_holder_name": "John Doe"}))]
     pub card: Option<CardDetail>,
 
+    /// Bank account details, for bank debit payment methods
+    pub bank_account: Option<bank_accounts::BankDebitData>,
+
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<serde_json::Value>,
@@ -53,6 +56,9 @@ pub struct UpdatePaymentMethod {
     "card_holder_name": "John Doe"}))]
     pub card: Option<CardDetail>,
 
+    /// Bank account details, for bank debit payment methods
+    pub bank_account: Option<bank_accounts::BankDebitData>,
+
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<serde_json::Value>,
@@ -78,7 +84,7 @@ pub struct CardDetail {
     pub card_holder_name: Option<masking::Secret<String>>,
 }
 
-#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct PaymentMethodResponse {
     /// Unique identifier for a merchant
     #[schema(example = "merchant_1671528864")]
@@ -112,6 +118,14 @@ pub struct PaymentMethodResponse {
     #[schema(example = json!({"last4": "1142","exp_month": "03","exp_year": "2030"}))]
     pub card: Option<CardDetailFromLocker>,
 
+    /// A fingerprint of the card number, unique to the merchant. The same card saved by different customers of the merchant has the same fingerprint.
+    #[schema(example = "5c6f0d4e2b6f13cf3c0e0b8a4a2d8e9a1b7c3e5f6a8d9b0c1e2f3a4b5c6d7e8f")]
+    pub card_fingerprint: Option<String>,
+
+    /// Details of the saved bank account, for bank debit payment methods
+    #[schema(example = json!({"bank_debit_type": "sepa", "last4": "3000"}))]
+    pub bank_account: Option<bank_accounts::BankAccountResponse>,
+
     /// Indicates whether the payment method is eligible for recurring payments
     #[schema(example = true)]
     pub recurring_enabled: bool,
@@ -138,6 +152,9 @@ pub struct PaymentMethodResponse {
 pub struct CardDetailFromLocker {
     pub scheme: Option<String>,
     pub issuer_country: Option<String>,
+    pub card_issuer: Option<String>,
+    #[schema(value_type = Option<CardFundingType>)]
+    pub card_funding_type: Option<api_enums::CardFundingType>,
     pub last4_digits: Option<String>,
     #[serde(skip)]
     #[schema(value_type=Option<String>)]
@@ -186,6 +203,10 @@ pub struct ListPaymentMethodRequest {
     /// Indicates whether the payment method is eligible for installment payments
     #[schema(example = true)]
     pub installment_payment_enabled: Option<bool>,
+
+    /// The BIN of the card to be used, card payment methods not supporting the network or funding type of the card are filtered out
+    #[schema(example = "424242")]
+    pub card_bin: Option<String>,
 }
 
 impl<'de> serde::Deserialize<'de> for ListPaymentMethodRequest {
@@ -250,6 +271,13 @@ impl<'de> serde::Deserialize<'de> for ListPaymentMethodRequest {
                                 map.next_value()?,
                             )?;
                         }
+                        "card_bin" => {
+                            set_or_reject_duplicate(
+                                &mut output.card_bin,
+                                "card_bin",
+                                map.next_value()?,
+                            )?;
+                        }
                         _ => {}
                     }
                 }
@@ -417,6 +445,10 @@ pub struct CustomerPaymentMethod {
     #[schema(example = "7ebf443f-a050-4067-84e5-e6f6d4800aef")]
     pub payment_token: String,
 
+    /// The unique identifier of the saved payment method, which does not change over time
+    #[schema(example = "pm_7ebf443fa0504067")]
+    pub payment_method_id: String,
+
     /// The unique identifier of the customer.
     #[schema(example = "cus_meowerunwiuwiwqw")]
     pub customer_id: String,
@@ -453,6 +485,10 @@ pub struct CustomerPaymentMethod {
     #[schema(example = json!({"last4": "1142","exp_month": "03","exp_year": "2030"}))]
     pub card: Option<CardDetailFromLocker>,
 
+    /// Details of the saved bank account, for bank debit payment methods
+    #[schema(example = json!({"bank_debit_type": "sepa", "last4": "3000"}))]
+    pub bank_account: Option<bank_accounts::BankAccountResponse>,
+
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<serde_json::Value>,
@@ -461,6 +497,19 @@ pub struct CustomerPaymentMethod {
     #[schema(value_type = Option<PrimitiveDateTime>,example = "2023-01-18T11:04:09.922Z")]
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub created: Option<time::PrimitiveDateTime>,
+
+    /// Whether the payment method is the default payment method of the customer
+    #[schema(example = true)]
+    pub default_payment_method: bool,
+
+    /// A timestamp (ISO 8601 code) that determines when the payment method was last used for a successful payment
+    #[schema(value_type = Option<PrimitiveDateTime>,example = "2023-01-18T11:04:09.922Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_used_at: Option<time::PrimitiveDateTime>,
+
+    /// The number of successful payments made with the payment method
+    #[schema(example = 3)]
+    pub usage_count: i32,
 }
 
 #[derive(Eq, PartialEq, Hash, Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -6,7 +6,7 @@ use router_derive::Setter;
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use crate::{enums as api_enums, refunds};
+use crate::{bank_accounts, cards, enums as api_enums, refunds};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum PaymentOp {
@@ -215,6 +215,9 @@ pub enum MandateTxnType {
 pub struct MandateIds {
     pub mandate_id: String,
     pub connector_mandate_id: Option<String>,
+    /// The payment method the mandate was set up with, which decides how connectors reference
+    /// the mandate
+    pub payment_method: Option<api_enums::PaymentMethodType>,
 }
 
 impl MandateIds {
@@ -222,6 +225,7 @@ impl MandateIds {
         Self {
             mandate_id,
             connector_mandate_id: None,
+            payment_method: None,
         }
     }
 }
@@ -374,15 +378,180 @@ pub enum PayLaterData {
     },
 }
 
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BankRedirectData {
+    /// For iDEAL, from bank accounts in the Netherlands
+    Ideal {
+        /// The billing details of the account holder
+        billing_details: BankRedirectBilling,
+        /// The bank the customer pays from, as identified by the connector
+        #[schema(example = "ing")]
+        bank_name: Option<String>,
+    },
+    /// For Sofort, from bank accounts in Europe
+    Sofort {
+        /// The billing details of the account holder
+        billing_details: BankRedirectBilling,
+        /// The two-letter ISO country code of the customer's bank
+        #[schema(example = "DE")]
+        country: String,
+        /// The language the bank's authorization page is shown in
+        #[schema(example = "en")]
+        preferred_language: Option<String>,
+    },
+    /// For Giropay, from bank accounts in Germany
+    Giropay {
+        /// The billing details of the account holder
+        billing_details: BankRedirectBilling,
+    },
+    /// For EPS, from bank accounts in Austria
+    Eps {
+        /// The billing details of the account holder
+        billing_details: BankRedirectBilling,
+        /// The bank the customer pays from, as identified by the connector
+        bank_name: Option<String>,
+    },
+    /// For Bancontact, from bank cards in Belgium
+    BancontactCard {
+        /// The billing details of the account holder
+        billing_details: BankRedirectBilling,
+    },
+    /// For Przelewy24, from bank accounts in Poland
+    Przelewy24 {
+        /// The billing details of the account holder
+        billing_details: BankRedirectBilling,
+        /// The bank the customer pays from, as identified by the connector
+        bank_name: Option<String>,
+    },
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BankRedirectBilling {
+    /// The name of the account holder
+    #[schema(value_type = String, example = "John Doe")]
+    pub billing_name: Secret<String>,
+    /// The email of the account holder, required by some banks
+    #[schema(value_type = Option<String>, example = "johndoe@example.com")]
+    pub email: Option<Secret<String, pii::Email>>,
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BankTransferData {
+    /// For PIX instant transfers in Brazil, paid by scanning a QR code
+    Pix {
+        /// The billing details of the payer
+        billing_details: BankTransferBilling,
+    },
+    /// For Boleto Bancário in Brazil, paid with a voucher at a bank or online
+    Boleto {
+        /// The billing details of the payer
+        billing_details: BankTransferBilling,
+    },
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BankTransferBilling {
+    /// The name of the payer
+    #[schema(value_type = String, example = "John Doe")]
+    pub name: Secret<String>,
+    /// The email of the payer
+    #[schema(value_type = String, example = "johndoe@example.com")]
+    pub email: Secret<String, pii::Email>,
+    /// The tax id of the payer, the CPF or CNPJ in Brazil
+    #[schema(value_type = Option<String>, example = "000.000.000-00")]
+    pub tax_id: Option<Secret<String>>,
+    /// The address of the payer, required by some banks
+    pub address: Option<AddressDetails>,
+}
+
+impl BankRedirectData {
+    pub fn get_billing_details(&self) -> &BankRedirectBilling {
+        match self {
+            Self::Ideal {
+                billing_details, ..
+            }
+            | Self::Sofort {
+                billing_details, ..
+            }
+            | Self::Giropay { billing_details }
+            | Self::Eps {
+                billing_details, ..
+            }
+            | Self::BancontactCard { billing_details }
+            | Self::Przelewy24 {
+                billing_details, ..
+            } => billing_details,
+        }
+    }
+
+    pub fn get_bank_redirect_type(&self) -> api_enums::PaymentMethodSubType {
+        match self {
+            Self::Ideal { .. } => api_enums::PaymentMethodSubType::Ideal,
+            Self::Sofort { .. } => api_enums::PaymentMethodSubType::Sofort,
+            Self::Giropay { .. } => api_enums::PaymentMethodSubType::Giropay,
+            Self::Eps { .. } => api_enums::PaymentMethodSubType::Eps,
+            Self::BancontactCard { .. } => api_enums::PaymentMethodSubType::BancontactCard,
+            Self::Przelewy24 { .. } => api_enums::PaymentMethodSubType::Przelewy24,
+        }
+    }
+}
+
+impl BankTransferData {
+    pub fn get_billing_details(&self) -> &BankTransferBilling {
+        match self {
+            Self::Pix { billing_details } | Self::Boleto { billing_details } => billing_details,
+        }
+    }
+
+    pub fn get_bank_transfer_type(&self) -> api_enums::PaymentMethodSubType {
+        match self {
+            Self::Pix { .. } => api_enums::PaymentMethodSubType::Pix,
+            Self::Boleto { .. } => api_enums::PaymentMethodSubType::Boleto,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum UpiData {
+    /// For UPI collect, where the customer approves a collect request sent to their UPI app
+    UpiCollect {
+        /// The Virtual Payment Address of the customer
+        #[schema(value_type = String, example = "someone@okhdfcbank")]
+        vpa_id: Secret<String, pii::UpiVpa>,
+    },
+    /// For UPI intent, where the customer pays by opening a UPI app with a link or QR code
+    UpiIntent {},
+}
+
+impl UpiData {
+    pub fn get_upi_type(&self) -> api_enums::PaymentMethodSubType {
+        match self {
+            Self::UpiCollect { .. } => api_enums::PaymentMethodSubType::UpiCollect,
+            Self::UpiIntent {} => api_enums::PaymentMethodSubType::UpiIntent,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Default, serde::Deserialize, serde::Serialize, ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum PaymentMethod {
     Card(Card),
     #[default]
     BankTransfer,
+    BankDebit(bank_accounts::BankDebitData),
+    BankRedirect(BankRedirectData),
+    LocalBankTransfer(BankTransferData),
+    Upi(UpiData),
     Wallet(WalletData),
     PayLater(PayLaterData),
     Paypal,
+    /// Used internally for recurring payments debited through a mandate held by the connector,
+    /// when no payment method details are stored with us
+    #[serde(skip_deserializing)]
+    MandatePayment,
 }
 
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
@@ -407,9 +576,18 @@ pub enum PaymentMethodDataResponse {
     Card(CardResponse),
     #[serde(rename(deserialize = "bank_transfer"))]
     BankTransfer,
+    #[serde(rename = "bank_debit")]
+    BankDebit(bank_accounts::BankAccountResponse),
+    #[serde(rename = "bank_redirect")]
+    BankRedirect(api_enums::PaymentMethodSubType),
+    #[serde(rename = "local_bank_transfer")]
+    LocalBankTransfer(api_enums::PaymentMethodSubType),
+    #[serde(rename = "upi")]
+    Upi(api_enums::PaymentMethodSubType),
     Wallet(WalletData),
     PayLater(PayLaterData),
     Paypal,
+    MandatePayment,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -561,6 +739,9 @@ pub struct AuthenticationForStartResponse {
 pub enum NextActionType {
     RedirectToUrl,
     DisplayQrCode,
+    DisplayBankTransferInstructions,
+    InvokeUpiApp,
+    WaitForCustomerApproval,
     InvokeSdkClient,
     TriggerApi,
 }
@@ -572,6 +753,45 @@ pub struct NextAction {
     /// Contains the url for redirection flow
     #[schema(example = "https://router.juspay.io/redirect/fakushdfjlksdfasklhdfj")]
     pub redirect_to_url: Option<String>,
+    /// The instructions the customer follows to complete a bank transfer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bank_transfer_instructions: Option<BankTransferInstructions>,
+    /// The link that opens the customer's UPI app, which can also be shown as a QR code
+    #[schema(example = "upi://pay?pa=merchant@bank&am=100.00&cu=INR")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upi_intent_uri: Option<String>,
+    /// How to poll the payment status while the customer completes the payment elsewhere
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll_config: Option<PollConfig>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct PollConfig {
+    /// The interval between two polls of the payment status, in seconds
+    #[schema(example = 5)]
+    pub delay_in_secs: u16,
+    /// The number of times to poll before giving up on the customer
+    #[schema(example = 60)]
+    pub frequency: u16,
+}
+
+/// Instructions for completing a bank transfer outside the checkout, such as a PIX QR code or a
+/// Boleto voucher
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct BankTransferInstructions {
+    /// The reference the customer quotes when paying, such as the Boleto barcode number
+    #[schema(example = "01010101010101010101010101010101010101010101010")]
+    pub reference: Option<String>,
+    /// The data encoded in the QR code the customer scans to pay
+    pub qr_code_data: Option<String>,
+    /// A link to an image of the QR code
+    pub qr_code_url: Option<String>,
+    /// A link to the voucher or the instructions hosted by the connector
+    pub instructions_url: Option<String>,
+    /// The time after which the instructions can no longer be used to pay
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Setter, Clone, Default, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
@@ -649,6 +869,8 @@ pub struct PaymentsResponse {
     #[schema(value_type = Option<PaymentMethod>, example = "bank_transfer")]
     #[auth_based]
     pub payment_method_data: Option<PaymentMethodDataResponse>,
+    /// Details of the card used for the payment, looked up by its BIN
+    pub card_info: Option<cards::CardInfo>,
     /// Provide a reference to a stored payment method
     #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
     pub payment_token: Option<String>,
@@ -690,6 +912,9 @@ pub struct PaymentsResponse {
     /// If there was an error while calling the connector the error message is received here
     #[schema(example = "Failed while verifying the card")]
     pub error_message: Option<String>,
+    /// The reason for which the payment was declined, unified across connectors
+    #[schema(value_type = Option<DeclineCode>, example = "insufficient_funds")]
+    pub decline_code: Option<api_enums::DeclineCode>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema)]
@@ -949,9 +1174,20 @@ impl From<PaymentMethod> for PaymentMethodDataResponse {
         match payment_method_data {
             PaymentMethod::Card(card) => Self::Card(CardResponse::from(card)),
             PaymentMethod::BankTransfer => Self::BankTransfer,
+            PaymentMethod::BankDebit(bank_debit) => {
+                Self::BankDebit(bank_accounts::BankAccountResponse::from(&bank_debit))
+            }
+            PaymentMethod::BankRedirect(bank_redirect) => {
+                Self::BankRedirect(bank_redirect.get_bank_redirect_type())
+            }
+            PaymentMethod::LocalBankTransfer(bank_transfer) => {
+                Self::LocalBankTransfer(bank_transfer.get_bank_transfer_type())
+            }
+            PaymentMethod::Upi(upi_data) => Self::Upi(upi_data.get_upi_type()),
             PaymentMethod::PayLater(pay_later_data) => Self::PayLater(pay_later_data),
             PaymentMethod::Wallet(wallet_data) => Self::Wallet(wallet_data),
             PaymentMethod::Paypal => Self::Paypal,
+            PaymentMethod::MandatePayment => Self::MandatePayment,
         }
     }
 }
@@ -1350,4 +1586,16 @@ mod tests {
             r#"{"multi_use":null}"#
         )
     }
+
+    #[test]
+    fn test_bank_redirect_payment_method() {
+        let payment_method: PaymentMethod = serde_json::from_str(
+            r#"{"bank_redirect":{"ideal":{"billing_details":{"billing_name":"John Doe","email":null},"bank_name":"ing"}}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            PaymentMethodDataResponse::from(payment_method),
+            PaymentMethodDataResponse::BankRedirect(api_enums::PaymentMethodSubType::Ideal)
+        );
+    }
 }
@@ -94,6 +94,34 @@ impl DecodeMessage for NoAlgorithm {
     }
 }
 
+/// Represents the HMAC-SHA-1 algorithm, which is only used to verify legacy signatures
+#[derive(Debug)]
+pub struct HmacSha1;
+
+impl SignMessage for HmacSha1 {
+    fn sign_message(
+        &self,
+        secret: &[u8],
+        msg: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::CryptoError> {
+        let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, secret);
+        Ok(hmac::sign(&key, msg).as_ref().to_vec())
+    }
+}
+
+impl VerifySignature for HmacSha1 {
+    fn verify_signature(
+        &self,
+        secret: &[u8],
+        signature: &[u8],
+        msg: &[u8],
+    ) -> CustomResult<bool, errors::CryptoError> {
+        let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, secret);
+
+        Ok(hmac::verify(&key, msg, signature).is_ok())
+    }
+}
+
 /// Represents the HMAC-SHA-256 algorithm
 #[derive(Debug)]
 pub struct HmacSha256;
@@ -150,12 +178,44 @@ impl VerifySignature for HmacSha512 {
     }
 }
 
+/// Represents the RSASSA-PKCS1-v1_5 signature algorithm with SHA-256, where the secret is the
+/// public key of the signer, as a DER encoded `RSAPublicKey`
+#[derive(Debug)]
+pub struct RsaSha256;
+
+impl VerifySignature for RsaSha256 {
+    fn verify_signature(
+        &self,
+        secret: &[u8],
+        signature: &[u8],
+        msg: &[u8],
+    ) -> CustomResult<bool, errors::CryptoError> {
+        let public_key = ring::signature::UnparsedPublicKey::new(
+            &ring::signature::RSA_PKCS1_2048_8192_SHA256,
+            secret,
+        );
+
+        Ok(public_key.verify(msg, signature).is_ok())
+    }
+}
+
 /// Represents the GCM-AES-256 algorithm
 #[derive(Debug)]
 pub struct GcmAes256 {
     nonce: Vec<u8>,
 }
 
+impl GcmAes256 {
+    /// Length of the nonce, in bytes
+    pub const NONCE_LENGTH: usize = aead::NONCE_LEN;
+
+    /// Create an instance of the algorithm that uses the provided nonce.
+    /// The same nonce must never be used for more than one message encoded with a given key.
+    pub fn new(nonce: Vec<u8>) -> Self {
+        Self { nonce }
+    }
+}
+
 impl EncodeMessage for GcmAes256 {
     fn encode_message(
         &self,
@@ -232,6 +292,17 @@ impl GenerateDigest for Sha512 {
     }
 }
 
+/// Secure Hash Algorithm 1, which is only used to derive keys for legacy signatures
+#[derive(Debug)]
+pub struct Sha1;
+
+impl GenerateDigest for Sha1 {
+    fn generate_digest(&self, message: &[u8]) -> CustomResult<Vec<u8>, errors::CryptoError> {
+        let digest = ring::digest::digest(&ring::digest::SHA1_FOR_LEGACY_USE_ONLY, message);
+        Ok(digest.as_ref().to_vec())
+    }
+}
+
 /// Generate a random string using a cryptographically secure pseudo-random number generator
 /// (CSPRNG). Typically used for generating (readable) keys and passwords.
 #[inline]
@@ -257,6 +328,31 @@ mod crypto_tests {
     #![allow(clippy::expect_used)]
     use super::{DecodeMessage, EncodeMessage, SignMessage, VerifySignature};
 
+    #[test]
+    fn test_hmac_sha1_verify_signature() {
+        let right_signature = hex::decode("9e1ed9945d638e02299d11366a5ecf147ddf5a1b")
+            .expect("Right signature decoding");
+        let wrong_signature = hex::decode("9e1ed9945d638e02299d11366a5ecf147ddf5a1c")
+            .expect("Wrong signature decoding");
+        let secret = "hmac_secret_1234".as_bytes();
+        let data = r#"{"type":"payment_intent"}"#.as_bytes();
+
+        let signature = super::HmacSha1
+            .sign_message(secret, data)
+            .expect("Signature");
+        assert_eq!(signature, right_signature);
+
+        let right_verified = super::HmacSha1
+            .verify_signature(secret, &right_signature, data)
+            .expect("Right signature verification result");
+        assert!(right_verified);
+
+        let wrong_verified = super::HmacSha1
+            .verify_signature(secret, &wrong_signature, data)
+            .expect("Wrong signature verification result");
+        assert!(!wrong_verified);
+    }
+
     #[test]
     fn test_hmac_sha256_sign_message() {
         let message = r#"{"type":"payment_intent"}"#.as_bytes();
@@ -333,6 +429,29 @@ mod crypto_tests {
         assert!(!wrong_verified);
     }
 
+    #[test]
+    fn test_rsa_sha256_verify_signature() {
+        let public_key = hex::decode("3082010a02820101009e77b9d48bdc2a3bb433848cab39eeda6ef27600167b25f5769358fc56b7517dabbe76f28d84907fd72d86ca2a23195d1b32c6f8e294378f53bc31bbaf8f67882b0856d3ebb32270ddaf35197f99c057574ebb745444733cb45f20facdc79740323734d502544a1d06ffc2c4a480651e7b9d5dcda57328ea8d53cf665ff76a9f6d732be4e4fa1fe31ff6145ca6f3708f996adc0d0da74d43555a53c65e09ded824928210561fae31ba051e1a930f39dc4164dd31c54c20d7b7f7d48eb31512f1327aed7dcfa331e9db2c7f1da5b02a1d807d009a43f4a359a17eb5ac87973be7348fe4aeff0deebf9f483dcfd153961180274d5b1a5bf5921249073a6a962a250203010001")
+            .expect("Public key decoding");
+        let right_signature = hex::decode("03448c37306f9a6b19c3674fb3fa15085697e1501499a4b17a23ae3eb79289ac5da380fa6be996ed1021944ee78fa7fbdb0c45c510d801e65e48c9b09aecae1cb8aebc1cfb74f03e75fcd9c0580b44598f7e5e6c551d62e84452ee63e32f1cd7cf52c9d30e46bc2f2a8081098a7d42e6e3c756d4dc2a872c7be1bed53a2bed72892ff288901fd144311041544970cd78fe9d6bde843e92de530c06c492c97aa387c93200c0481bb8eeec0fcd23a68780d8d972eab95bd18458b9e31605bd72979276f44fcac21f1dc5d94c4ba6e01c7b3283cb95c69d6eff44de20780a4797c58cb5f8e3a39dcc9c1941ebbf35cd68bf6baad45a26bccb4cafc5c239213a8524")
+            .expect("Right signature decoding");
+        let mut wrong_signature = right_signature.clone();
+        wrong_signature[0] ^= 1;
+        let data = r#"{"type":"payment_intent"}"#.as_bytes();
+
+        let right_verified = super::RsaSha256
+            .verify_signature(&public_key, &right_signature, data)
+            .expect("Right signature verification result");
+
+        assert!(right_verified);
+
+        let wrong_verified = super::RsaSha256
+            .verify_signature(&public_key, &wrong_signature, data)
+            .expect("Wrong signature verification result");
+
+        assert!(!wrong_verified);
+    }
+
     #[test]
     fn test_gcm_aes_256_encode_message() {
         let message = r#"{"type":"PAYMENT"}"#.as_bytes();
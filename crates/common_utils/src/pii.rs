@@ -25,6 +25,30 @@ where
     }
 }
 
+/// Bank account number or IBAN
+#[derive(Debug)]
+pub struct BankAccountNumber;
+
+impl<T> Strategy<T> for BankAccountNumber
+where
+    T: AsRef<str>,
+{
+    fn fmt(val: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val_str: &str = val.as_ref();
+
+        if val_str.len() < 6 || !val_str.is_ascii() {
+            return WithType::fmt(val, f);
+        }
+
+        write!(
+            f,
+            "{}{}",
+            "*".repeat(val_str.len() - 4),
+            &val_str[(val_str.len() - 4)..]
+        )
+    }
+}
+
 /*
 /// Phone number
 #[derive(Debug)]
@@ -108,6 +132,32 @@ where
     }
 }
 
+/// UPI Virtual Payment Address, of the form `handle@bank`
+#[derive(Debug)]
+pub struct UpiVpa;
+
+impl<T> Strategy<T> for UpiVpa
+where
+    T: AsRef<str>,
+{
+    fn fmt(val: &T, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let val_str: &str = val.as_ref();
+
+        match val_str.split_once('@') {
+            Some((handle, bank)) if handle.len() > 2 && !bank.is_empty() && handle.is_ascii() => {
+                write!(
+                    f,
+                    "{}{}@{}",
+                    &handle[..2],
+                    "*".repeat(handle.len() - 2),
+                    bank
+                )
+            }
+            _ => WithType::fmt(val, f),
+        }
+    }
+}
+
 /// IP address
 #[derive(Debug)]
 pub struct IpAddress;
@@ -138,7 +188,7 @@ where
 mod pii_masking_strategy_tests {
     use masking::Secret;
 
-    use super::{CardNumber, ClientSecret, Email, IpAddress};
+    use super::{BankAccountNumber, CardNumber, ClientSecret, Email, IpAddress, UpiVpa};
 
     #[test]
     fn test_valid_card_number_masking() {
@@ -152,6 +202,19 @@ mod pii_masking_strategy_tests {
         assert_eq!("123456****", format!("{secret:?}"));
     }
 
+    #[test]
+    fn test_valid_bank_account_number_masking() {
+        let secret: Secret<String, BankAccountNumber> =
+            Secret::new("DE89370400440532013000".to_string());
+        assert_eq!("******************3000", format!("{secret:?}"));
+    }
+
+    #[test]
+    fn test_invalid_bank_account_number_masking() {
+        let secret: Secret<String, BankAccountNumber> = Secret::new("1234".to_string());
+        assert_eq!("*** alloc::string::String ***", format!("{secret:?}"));
+    }
+
     /*
     #[test]
     fn test_valid_phone_number_masking() {
@@ -184,6 +247,21 @@ mod pii_masking_strategy_tests {
         assert_eq!("*** alloc::string::String ***", format!("{secret:?}"));
     }
 
+    #[test]
+    fn test_valid_upi_vpa_masking() {
+        let secret: Secret<String, UpiVpa> = Secret::new("someone@okhdfcbank".to_string());
+        assert_eq!("so*****@okhdfcbank", format!("{secret:?}"));
+    }
+
+    #[test]
+    fn test_invalid_upi_vpa_masking() {
+        let secret: Secret<String, UpiVpa> = Secret::new("someone".to_string());
+        assert_eq!("*** alloc::string::String ***", format!("{secret:?}"));
+
+        let secret: Secret<String, UpiVpa> = Secret::new("ab@upi".to_string());
+        assert_eq!("*** alloc::string::String ***", format!("{secret:?}"));
+    }
+
     #[test]
     fn test_valid_ip_addr_masking() {
         let secret: Secret<String, IpAddress> = Secret::new("123.23.1.78".to_string());
@@ -0,0 +1,3 @@
+fn main() {
+    router_env::vergen::generate_cargo_instructions();
+}
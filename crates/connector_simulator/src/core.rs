@@ -0,0 +1,534 @@
+use std::time::Duration;
+
+use actix_web::{rt, web};
+use router_env::logger;
+use time::OffsetDateTime;
+
+use crate::{
+    errors::ApiError,
+    types::{
+        Authentication, AuthenticationOutcome, AuthenticationRequest, CaptureRequest,
+        CardBehaviour, DeclineReason, NextAction, Payment, PaymentRequest, PaymentStatus, Refund,
+        RefundRequest, RefundStatus, TransStatus,
+    },
+    webhooks, AppState,
+};
+
+/// Delays every API call by the configured latency
+pub async fn simulate_latency(state: &AppState) {
+    if state.conf.latency > 0 {
+        rt::time::sleep(Duration::from_millis(state.conf.latency)).await;
+    }
+}
+
+pub async fn create_payment(
+    state: web::Data<AppState>,
+    request: PaymentRequest,
+) -> Result<Payment, ApiError> {
+    if request.amount <= 0 {
+        return Err(ApiError::InvalidRequest(
+            "amount must be greater than zero".to_string(),
+        ));
+    }
+
+    let card_behaviour = match CardBehaviour::from(&request.card) {
+        // Payments authenticated beforehand are not challenged again
+        CardBehaviour::ThreeDsChallenge if request.three_ds.is_some() => CardBehaviour::Succeed,
+        card_behaviour => card_behaviour,
+    };
+    let id = common_utils::generate_id(24, "pay");
+    let mut payment = Payment {
+        id: id.clone(),
+        status: PaymentStatus::Processing,
+        amount: request.amount,
+        amount_captured: 0,
+        amount_refunded: 0,
+        currency: request.currency,
+        reference: request.reference,
+        created: OffsetDateTime::now_utc(),
+        next_action: None,
+        error: None,
+        card_behaviour,
+        capture: request.capture,
+        return_url: request.return_url,
+        merchant_id: request.merchant_id,
+    };
+
+    match card_behaviour {
+        CardBehaviour::Succeed | CardBehaviour::RefundFailure => {
+            complete_authorization(&mut payment)
+        }
+        CardBehaviour::Decline(reason) => decline(&mut payment, reason),
+        CardBehaviour::ProcessingError => return Err(ApiError::ProcessingError),
+        CardBehaviour::ThreeDsChallenge => {
+            payment.status = PaymentStatus::RequiresAction;
+            payment.next_action = Some(NextAction {
+                redirect_to_url: format!("{}/authenticate/{}", state.conf.base_url, id),
+            });
+        }
+        CardBehaviour::AsyncOutcome => {
+            let delay = Duration::from_secs(state.conf.async_outcome_delay);
+            let state = state.clone();
+            let payment_id = id.clone();
+            rt::spawn(async move {
+                rt::time::sleep(delay).await;
+                if let Err(error) = complete_async_payment(&state, &payment_id).await {
+                    logger::error!(?error, %payment_id, "Failed to complete processing payment");
+                }
+            });
+        }
+        CardBehaviour::Timeout => {
+            rt::time::sleep(Duration::from_secs(state.conf.timeout_delay)).await;
+            complete_authorization(&mut payment);
+        }
+    }
+
+    state.payments()?.insert(id, payment.clone());
+    Ok(payment)
+}
+
+pub fn retrieve_payment(state: &AppState, payment_id: &str) -> Result<Payment, ApiError> {
+    state
+        .payments()?
+        .get(payment_id)
+        .cloned()
+        .ok_or(ApiError::PaymentNotFound)
+}
+
+pub fn capture_payment(
+    state: &AppState,
+    payment_id: &str,
+    request: CaptureRequest,
+) -> Result<Payment, ApiError> {
+    let mut payments = state.payments()?;
+    let payment = payments
+        .get_mut(payment_id)
+        .ok_or(ApiError::PaymentNotFound)?;
+    if payment.status != PaymentStatus::Authorized {
+        return Err(ApiError::InvalidPaymentState {
+            action: "captured",
+            status: payment.status.as_str(),
+        });
+    }
+
+    let amount = request.amount.unwrap_or(payment.amount);
+    if amount <= 0 || amount > payment.amount {
+        return Err(ApiError::InvalidRequest(
+            "amount to capture must be greater than zero and at most the authorized amount"
+                .to_string(),
+        ));
+    }
+    payment.amount_captured = amount;
+    payment.status = PaymentStatus::Succeeded;
+    Ok(payment.clone())
+}
+
+pub fn void_payment(state: &AppState, payment_id: &str) -> Result<Payment, ApiError> {
+    let mut payments = state.payments()?;
+    let payment = payments
+        .get_mut(payment_id)
+        .ok_or(ApiError::PaymentNotFound)?;
+    match payment.status {
+        PaymentStatus::Authorized | PaymentStatus::RequiresAction => {
+            payment.status = PaymentStatus::Voided;
+            payment.next_action = None;
+            Ok(payment.clone())
+        }
+        status => Err(ApiError::InvalidPaymentState {
+            action: "voided",
+            status: status.as_str(),
+        }),
+    }
+}
+
+pub fn create_refund(state: &AppState, request: RefundRequest) -> Result<Refund, ApiError> {
+    let mut payments = state.payments()?;
+    let payment = payments
+        .get_mut(&request.payment_id)
+        .ok_or(ApiError::PaymentNotFound)?;
+    if payment.status != PaymentStatus::Succeeded {
+        return Err(ApiError::InvalidPaymentState {
+            action: "refunded",
+            status: payment.status.as_str(),
+        });
+    }
+    if request.amount <= 0 || request.amount > payment.amount_captured - payment.amount_refunded {
+        return Err(ApiError::InvalidRequest(
+            "amount to refund must be greater than zero and at most the amount left to refund"
+                .to_string(),
+        ));
+    }
+
+    let (status, error) = if payment.card_behaviour == CardBehaviour::RefundFailure {
+        (
+            RefundStatus::Failed,
+            Some(DeclineReason::CardDeclined.into()),
+        )
+    } else {
+        payment.amount_refunded += request.amount;
+        (RefundStatus::Succeeded, None)
+    };
+    let refund = Refund {
+        id: common_utils::generate_id(24, "ref"),
+        payment_id: payment.id.clone(),
+        status,
+        amount: request.amount,
+        currency: payment.currency.clone(),
+        reason: request.reason,
+        error,
+        created: OffsetDateTime::now_utc(),
+    };
+    state.refunds()?.insert(refund.id.clone(), refund.clone());
+    Ok(refund)
+}
+
+pub fn retrieve_refund(state: &AppState, refund_id: &str) -> Result<Refund, ApiError> {
+    state
+        .refunds()?
+        .get(refund_id)
+        .cloned()
+        .ok_or(ApiError::RefundNotFound)
+}
+
+/// Authenticates a card the way a 3DS server would. Authentications requiring a challenge are
+/// kept until the customer completes the challenge.
+pub fn create_authentication(
+    state: &AppState,
+    request: AuthenticationRequest,
+) -> Result<Authentication, ApiError> {
+    if request.amount <= 0 {
+        return Err(ApiError::InvalidRequest(
+            "amount must be greater than zero".to_string(),
+        ));
+    }
+
+    let id = common_utils::generate_id(24, "auth");
+    let trans_status = TransStatus::from(&request);
+    let challenge_url = (trans_status == TransStatus::ChallengeRequired).then(|| {
+        format!(
+            "{}/3ds/authentications/{}/challenge",
+            state.conf.base_url, id
+        )
+    });
+    let mut authentication = Authentication {
+        id: id.clone(),
+        trans_status,
+        authentication_value: None,
+        eci: None,
+        ds_trans_id: Some(common_utils::generate_id(24, "ds")),
+        message_version: "2.2.0".to_string(),
+        reason: None,
+        challenge_url,
+        return_url: request.return_url,
+    };
+    set_authentication_result(&mut authentication, trans_status);
+
+    state.authentications()?.insert(id, authentication.clone());
+    Ok(authentication)
+}
+
+pub fn retrieve_authentication(
+    state: &AppState,
+    authentication_id: &str,
+) -> Result<Authentication, ApiError> {
+    state
+        .authentications()?
+        .get(authentication_id)
+        .cloned()
+        .ok_or(ApiError::AuthenticationNotFound)
+}
+
+/// Records the outcome of the challenge of an authentication, and returns the authentication to
+/// send the customer back with
+pub fn complete_challenge(
+    state: &AppState,
+    authentication_id: &str,
+    outcome: AuthenticationOutcome,
+) -> Result<Authentication, ApiError> {
+    let mut authentications = state.authentications()?;
+    let authentication = authentications
+        .get_mut(authentication_id)
+        .ok_or(ApiError::AuthenticationNotFound)?;
+    if authentication.trans_status != TransStatus::ChallengeRequired {
+        return Err(ApiError::InvalidRequest(
+            "the authentication does not require a challenge".to_string(),
+        ));
+    }
+
+    authentication.challenge_url = None;
+    let trans_status = match outcome {
+        AuthenticationOutcome::Success => TransStatus::Authenticated,
+        AuthenticationOutcome::Failure => TransStatus::Failed,
+    };
+    set_authentication_result(authentication, trans_status);
+    Ok(authentication.clone())
+}
+
+fn set_authentication_result(authentication: &mut Authentication, trans_status: TransStatus) {
+    let (authentication_value, eci, reason) = match trans_status {
+        TransStatus::Authenticated => (
+            Some(common_utils::generate_id(28, "cavv")),
+            Some("05".to_string()),
+            None,
+        ),
+        TransStatus::Attempted => (
+            Some(common_utils::generate_id(28, "cavv")),
+            Some("06".to_string()),
+            None,
+        ),
+        TransStatus::ChallengeRequired => (None, None, Some("Challenge required".to_string())),
+        TransStatus::Failed => (
+            None,
+            Some("07".to_string()),
+            Some("Cardholder not authenticated".to_string()),
+        ),
+        TransStatus::Rejected => (
+            None,
+            Some("07".to_string()),
+            Some("Authentication rejected by the issuer".to_string()),
+        ),
+    };
+    authentication.trans_status = trans_status;
+    authentication.authentication_value = authentication_value;
+    authentication.eci = eci;
+    authentication.reason = reason;
+}
+
+/// Records the outcome of the 3DS challenge of a payment, notifies the merchant and returns the
+/// payment to send the customer back with
+pub async fn authenticate_payment(
+    state: &AppState,
+    payment_id: &str,
+    outcome: AuthenticationOutcome,
+) -> Result<Payment, ApiError> {
+    let payment = {
+        let mut payments = state.payments()?;
+        let payment = payments
+            .get_mut(payment_id)
+            .ok_or(ApiError::PaymentNotFound)?;
+        if payment.status != PaymentStatus::RequiresAction {
+            return Err(ApiError::InvalidPaymentState {
+                action: "authenticated",
+                status: payment.status.as_str(),
+            });
+        }
+        payment.next_action = None;
+        match outcome {
+            AuthenticationOutcome::Success => complete_authorization(payment),
+            AuthenticationOutcome::Failure => decline(payment, DeclineReason::AuthenticationFailed),
+        }
+        payment.clone()
+    };
+
+    webhooks::send_payment_webhook(state, &payment).await;
+    Ok(payment)
+}
+
+async fn complete_async_payment(state: &AppState, payment_id: &str) -> Result<(), ApiError> {
+    let payment = {
+        let mut payments = state.payments()?;
+        let payment = payments
+            .get_mut(payment_id)
+            .ok_or(ApiError::PaymentNotFound)?;
+        complete_authorization(payment);
+        payment.clone()
+    };
+
+    webhooks::send_payment_webhook(state, &payment).await;
+    Ok(())
+}
+
+fn complete_authorization(payment: &mut Payment) {
+    if payment.capture {
+        payment.status = PaymentStatus::Succeeded;
+        payment.amount_captured = payment.amount;
+    } else {
+        payment.status = PaymentStatus::Authorized;
+    }
+}
+
+fn decline(payment: &mut Payment, reason: DeclineReason) {
+    payment.status = PaymentStatus::Failed;
+    payment.error = Some(reason.into());
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{settings::SimulatorSettings, types::Card};
+
+    fn mk_state() -> web::Data<AppState> {
+        web::Data::new(AppState::new(SimulatorSettings {
+            timeout_delay: 0,
+            ..SimulatorSettings::default()
+        }))
+    }
+
+    fn mk_payment_request(card_number: &str) -> PaymentRequest {
+        PaymentRequest {
+            amount: 1000,
+            currency: "USD".to_string(),
+            card: Card {
+                number: card_number.to_string(),
+                expiry_month: "10".to_string(),
+                expiry_year: "2030".to_string(),
+                cvc: "123".to_string(),
+                holder_name: None,
+            },
+            capture: true,
+            return_url: None,
+            merchant_id: None,
+            reference: None,
+            three_ds: None,
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_magic_card_outcomes() {
+        let state = mk_state();
+        for (card_number, status, error_code) in [
+            ("4242424242424242", PaymentStatus::Succeeded, None),
+            (
+                "4000000000000002",
+                PaymentStatus::Failed,
+                Some("card_declined"),
+            ),
+            (
+                "4000000000009995",
+                PaymentStatus::Failed,
+                Some("insufficient_funds"),
+            ),
+            (
+                "4000000000000069",
+                PaymentStatus::Failed,
+                Some("expired_card"),
+            ),
+            ("4000000000003220", PaymentStatus::RequiresAction, None),
+            ("4000000000000077", PaymentStatus::Processing, None),
+            ("4000000000000408", PaymentStatus::Succeeded, None),
+            ("4000000000005126", PaymentStatus::Succeeded, None),
+        ] {
+            let payment = create_payment(state.clone(), mk_payment_request(card_number))
+                .await
+                .unwrap();
+            assert_eq!(payment.status, status, "card {card_number}");
+            assert_eq!(
+                payment.error.as_ref().map(|error| error.code.as_str()),
+                error_code,
+                "card {card_number}"
+            );
+            assert_eq!(
+                payment.next_action.is_some(),
+                status == PaymentStatus::RequiresAction,
+                "card {card_number}"
+            );
+        }
+
+        let result = create_payment(state, mk_payment_request("4000000000000119")).await;
+        assert!(matches!(result, Err(ApiError::ProcessingError)));
+    }
+
+    #[actix_web::test]
+    async fn test_authenticated_payments_are_not_challenged() {
+        let mut request = mk_payment_request("4000000000003220");
+        request.three_ds = Some(crate::types::ThreeDsData {
+            authentication_value: "cavv".to_string(),
+            eci: Some("05".to_string()),
+            ds_trans_id: None,
+            version: "2.2.0".to_string(),
+        });
+        let payment = create_payment(mk_state(), request).await.unwrap();
+        assert_eq!(payment.status, PaymentStatus::Succeeded);
+    }
+
+    #[actix_web::test]
+    async fn test_refunds_fail_for_the_refund_failure_card() {
+        let state = mk_state();
+        for (card_number, status) in [
+            ("4242424242424242", RefundStatus::Succeeded),
+            ("4000000000005126", RefundStatus::Failed),
+        ] {
+            let payment = create_payment(state.clone(), mk_payment_request(card_number))
+                .await
+                .unwrap();
+            let refund = create_refund(
+                &state,
+                RefundRequest {
+                    payment_id: payment.id,
+                    amount: 500,
+                    reason: None,
+                },
+            )
+            .unwrap();
+            assert_eq!(refund.status, status, "card {card_number}");
+        }
+    }
+
+    fn mk_authentication_request(card_number: &str) -> AuthenticationRequest {
+        AuthenticationRequest {
+            amount: 1000,
+            currency: "USD".to_string(),
+            card_number: card_number.to_string(),
+            card_expiry_month: "10".to_string(),
+            card_expiry_year: "2030".to_string(),
+            return_url: Some("http://localhost:8080/return".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_magic_card_trans_statuses() {
+        let state = mk_state();
+        for (card_number, trans_status) in [
+            ("4242424242424242", TransStatus::Authenticated),
+            ("4000000000003220", TransStatus::ChallengeRequired),
+            ("4000000000003063", TransStatus::Failed),
+            ("4000000000003279", TransStatus::Rejected),
+            ("4000000000003238", TransStatus::Attempted),
+        ] {
+            let authentication =
+                create_authentication(&state, mk_authentication_request(card_number)).unwrap();
+            assert_eq!(authentication.trans_status, trans_status);
+            assert_eq!(
+                authentication.challenge_url.is_some(),
+                trans_status == TransStatus::ChallengeRequired,
+                "card {card_number}"
+            );
+            assert_eq!(
+                authentication.authentication_value.is_some(),
+                matches!(
+                    trans_status,
+                    TransStatus::Authenticated | TransStatus::Attempted
+                ),
+                "card {card_number}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_complete_challenge() {
+        let state = mk_state();
+        for (outcome, trans_status) in [
+            (AuthenticationOutcome::Success, TransStatus::Authenticated),
+            (AuthenticationOutcome::Failure, TransStatus::Failed),
+        ] {
+            let authentication =
+                create_authentication(&state, mk_authentication_request("4000000000003220"))
+                    .unwrap();
+            complete_challenge(&state, &authentication.id, outcome).unwrap();
+
+            let authentication = retrieve_authentication(&state, &authentication.id).unwrap();
+            assert_eq!(authentication.trans_status, trans_status);
+            assert!(authentication.challenge_url.is_none());
+            assert_eq!(
+                authentication.authentication_value.is_some(),
+                trans_status == TransStatus::Authenticated
+            );
+            assert!(matches!(
+                complete_challenge(&state, &authentication.id, outcome),
+                Err(ApiError::InvalidRequest(_))
+            ));
+        }
+    }
+}
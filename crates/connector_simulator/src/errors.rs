@@ -0,0 +1,88 @@
+use actix_web::{http::StatusCode, HttpResponse, ResponseError};
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::types;
+
+#[derive(Debug, Error)]
+pub enum SimulatorError {
+    #[error("Error in parsing config : {0}")]
+    ConfigParsingError(String),
+    #[error("Application configuration error: {0}")]
+    ConfigurationError(config::ConfigError),
+    #[error("Metrics initialization error")]
+    MetricsError,
+    #[error("Failed to start the server")]
+    ServerError,
+}
+
+pub type SimulatorResult<T> = error_stack::Result<T, SimulatorError>;
+
+impl From<config::ConfigError> for SimulatorError {
+    fn from(err: config::ConfigError) -> Self {
+        Self::ConfigurationError(err)
+    }
+}
+
+/// Errors returned by the simulated API, in the format of the simulated processor
+#[derive(Debug, Clone, Error)]
+pub enum ApiError {
+    #[error("Missing or invalid API key")]
+    Unauthorized,
+    #[error("Payment not found")]
+    PaymentNotFound,
+    #[error("Refund not found")]
+    RefundNotFound,
+    #[error("Authentication not found")]
+    AuthenticationNotFound,
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("The payment cannot be {action} while it is {status}")]
+    InvalidPaymentState {
+        action: &'static str,
+        status: &'static str,
+    },
+    #[error("An error occurred while processing the payment")]
+    ProcessingError,
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: types::ErrorDetails,
+}
+
+impl ApiError {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::Unauthorized => "unauthorized",
+            Self::PaymentNotFound => "payment_not_found",
+            Self::RefundNotFound => "refund_not_found",
+            Self::AuthenticationNotFound => "authentication_not_found",
+            Self::InvalidRequest(_) => "invalid_request",
+            Self::InvalidPaymentState { .. } => "invalid_payment_state",
+            Self::ProcessingError => "processing_error",
+        }
+    }
+}
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::PaymentNotFound | Self::RefundNotFound | Self::AuthenticationNotFound => {
+                StatusCode::NOT_FOUND
+            }
+            Self::InvalidRequest(_) | Self::InvalidPaymentState { .. } => StatusCode::BAD_REQUEST,
+            Self::ProcessingError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(ErrorResponse {
+            error: types::ErrorDetails {
+                code: self.code().to_string(),
+                message: self.to_string(),
+            },
+        })
+    }
+}
@@ -0,0 +1,70 @@
+mod core;
+pub mod env;
+pub mod errors;
+mod routes;
+pub mod settings;
+pub mod types;
+mod webhooks;
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+};
+
+use actix_web::{dev::Server, web, App, HttpServer};
+pub use env as logger;
+use error_stack::{IntoReport, ResultExt};
+
+pub use crate::webhooks::SIGNATURE_HEADER;
+use crate::{
+    errors::{ApiError, SimulatorError, SimulatorResult},
+    settings::SimulatorSettings,
+    types::{Authentication, Payment, Refund},
+};
+
+/// The state of the simulated processor, payments and refunds are only kept in memory
+pub struct AppState {
+    pub conf: SimulatorSettings,
+    payments: Mutex<HashMap<String, Payment>>,
+    refunds: Mutex<HashMap<String, Refund>>,
+    authentications: Mutex<HashMap<String, Authentication>>,
+    client: reqwest::Client,
+}
+
+impl AppState {
+    pub fn new(conf: SimulatorSettings) -> Self {
+        Self {
+            conf,
+            payments: Mutex::default(),
+            refunds: Mutex::default(),
+            authentications: Mutex::default(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    fn payments(&self) -> Result<MutexGuard<'_, HashMap<String, Payment>>, ApiError> {
+        self.payments.lock().map_err(|_| ApiError::ProcessingError)
+    }
+
+    fn refunds(&self) -> Result<MutexGuard<'_, HashMap<String, Refund>>, ApiError> {
+        self.refunds.lock().map_err(|_| ApiError::ProcessingError)
+    }
+
+    fn authentications(&self) -> Result<MutexGuard<'_, HashMap<String, Authentication>>, ApiError> {
+        self.authentications
+            .lock()
+            .map_err(|_| ApiError::ProcessingError)
+    }
+}
+
+pub fn start_server(conf: SimulatorSettings) -> SimulatorResult<Server> {
+    logger::info!("Connector simulator started: {conf:?}");
+    let address = (conf.host.clone(), conf.port);
+    let state = web::Data::new(AppState::new(conf));
+    let server = HttpServer::new(move || App::new().service(routes::server(state.clone())))
+        .bind(address)
+        .into_report()
+        .change_context(SimulatorError::ServerError)?
+        .run();
+    Ok(server)
+}
@@ -0,0 +1,22 @@
+use connector_simulator::{errors, logger::logger, settings, start_server};
+use error_stack::{IntoReport, ResultExt};
+
+#[actix_web::main]
+async fn main() -> errors::SimulatorResult<()> {
+    // Get configuration
+    let cmd_line = <settings::CmdLineConf as clap::Parser>::parse();
+
+    #[allow(clippy::expect_used)]
+    let conf = settings::Settings::with_config_path(cmd_line.config_path)
+        .expect("Unable to construct application configuration");
+    #[allow(clippy::expect_used)]
+    conf.validate()
+        .expect("Failed to validate connector simulator configuration");
+
+    let _guard = logger::setup(&conf.log).change_context(errors::SimulatorError::MetricsError)?;
+
+    start_server(conf.connector_simulator)?
+        .await
+        .into_report()
+        .change_context(errors::SimulatorError::ServerError)
+}
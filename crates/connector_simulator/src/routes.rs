@@ -0,0 +1,232 @@
+use actix_web::{http::header, web, HttpRequest, HttpResponse, Scope};
+use maud::{html, Markup, DOCTYPE};
+
+use crate::{
+    core,
+    errors::ApiError,
+    types::{
+        Authentication, AuthenticationOutcome, AuthenticationRequest, AuthenticationResult,
+        CaptureRequest, Payment, PaymentRequest, Refund, RefundRequest,
+    },
+    AppState,
+};
+
+pub fn server(state: web::Data<AppState>) -> Scope {
+    web::scope("")
+        .app_data(state)
+        .service(web::resource("/health").route(web::get().to(health)))
+        .service(web::resource("/payments").route(web::post().to(payments_create)))
+        .service(web::resource("/payments/{payment_id}").route(web::get().to(payments_retrieve)))
+        .service(
+            web::resource("/payments/{payment_id}/capture").route(web::post().to(payments_capture)),
+        )
+        .service(web::resource("/payments/{payment_id}/void").route(web::post().to(payments_void)))
+        .service(web::resource("/refunds").route(web::post().to(refunds_create)))
+        .service(web::resource("/refunds/{refund_id}").route(web::get().to(refunds_retrieve)))
+        .service(
+            web::resource("/3ds/authentications").route(web::post().to(authentications_create)),
+        )
+        .service(
+            web::resource("/3ds/authentications/{authentication_id}")
+                .route(web::get().to(authentications_retrieve)),
+        )
+        .service(
+            web::resource("/3ds/authentications/{authentication_id}/challenge")
+                .route(web::get().to(challenge_page))
+                .route(web::post().to(challenge_submit)),
+        )
+        .service(
+            web::resource("/authenticate/{payment_id}")
+                .route(web::get().to(authentication_page))
+                .route(web::post().to(authentication_submit)),
+        )
+}
+
+async fn health() -> HttpResponse {
+    HttpResponse::Ok().body("health is good")
+}
+
+/// Any API key is accepted, the simulator only checks that one is sent
+fn authenticate(req: &HttpRequest) -> Result<(), ApiError> {
+    req.headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .filter(|api_key| !api_key.trim().is_empty())
+        .map(|_| ())
+        .ok_or(ApiError::Unauthorized)
+}
+
+async fn payments_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<PaymentRequest>,
+) -> Result<web::Json<Payment>, ApiError> {
+    authenticate(&req)?;
+    core::simulate_latency(&state).await;
+    core::create_payment(state, json_payload.into_inner())
+        .await
+        .map(web::Json)
+}
+
+async fn payments_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<web::Json<Payment>, ApiError> {
+    authenticate(&req)?;
+    core::simulate_latency(&state).await;
+    core::retrieve_payment(&state, &path.into_inner()).map(web::Json)
+}
+
+async fn payments_capture(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<CaptureRequest>,
+) -> Result<web::Json<Payment>, ApiError> {
+    authenticate(&req)?;
+    core::simulate_latency(&state).await;
+    core::capture_payment(&state, &path.into_inner(), json_payload.into_inner()).map(web::Json)
+}
+
+async fn payments_void(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<web::Json<Payment>, ApiError> {
+    authenticate(&req)?;
+    core::simulate_latency(&state).await;
+    core::void_payment(&state, &path.into_inner()).map(web::Json)
+}
+
+async fn refunds_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<RefundRequest>,
+) -> Result<web::Json<Refund>, ApiError> {
+    authenticate(&req)?;
+    core::simulate_latency(&state).await;
+    core::create_refund(&state, json_payload.into_inner()).map(web::Json)
+}
+
+async fn refunds_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<web::Json<Refund>, ApiError> {
+    authenticate(&req)?;
+    core::simulate_latency(&state).await;
+    core::retrieve_refund(&state, &path.into_inner()).map(web::Json)
+}
+
+async fn authentications_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<AuthenticationRequest>,
+) -> Result<web::Json<Authentication>, ApiError> {
+    authenticate(&req)?;
+    core::simulate_latency(&state).await;
+    core::create_authentication(&state, json_payload.into_inner()).map(web::Json)
+}
+
+async fn authentications_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> Result<web::Json<Authentication>, ApiError> {
+    authenticate(&req)?;
+    core::simulate_latency(&state).await;
+    core::retrieve_authentication(&state, &path.into_inner()).map(web::Json)
+}
+
+/// The challenge of a standalone 3DS authentication shown to the customer
+async fn challenge_page(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> Result<Markup, ApiError> {
+    let authentication = core::retrieve_authentication(&state, &path.into_inner())?;
+    Ok(html! {
+        (DOCTYPE)
+        html {
+            head {
+                title { "Connector Simulator - 3DS Challenge" }
+            }
+            body {
+                h1 { "3DS Challenge" }
+                p { "Authentication " (authentication.id) }
+                form method="POST" {
+                    button type="submit" name="result" value="success" { "Complete authentication" }
+                    button type="submit" name="result" value="failure" { "Fail authentication" }
+                }
+            }
+        }
+    })
+}
+
+async fn challenge_submit(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+    form_payload: web::Form<AuthenticationResult>,
+) -> Result<HttpResponse, ApiError> {
+    let outcome = form_payload.into_inner().result;
+    let authentication = core::complete_challenge(&state, &path.into_inner(), outcome)?;
+    Ok(redirect_after_challenge(authentication.return_url, outcome))
+}
+
+/// The 3DS challenge shown to the customer
+async fn authentication_page(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+) -> Result<Markup, ApiError> {
+    let payment = core::retrieve_payment(&state, &path.into_inner())?;
+    Ok(html! {
+        (DOCTYPE)
+        html {
+            head {
+                title { "Connector Simulator - 3DS Challenge" }
+            }
+            body {
+                h1 { "3DS Challenge" }
+                p { "Payment " (payment.id) " of " (payment.amount) " " (payment.currency) }
+                form method="POST" {
+                    button type="submit" name="result" value="success" { "Complete authentication" }
+                    button type="submit" name="result" value="failure" { "Fail authentication" }
+                }
+            }
+        }
+    })
+}
+
+async fn authentication_submit(
+    state: web::Data<AppState>,
+    path: web::Path<String>,
+    form_payload: web::Form<AuthenticationResult>,
+) -> Result<HttpResponse, ApiError> {
+    let outcome = form_payload.into_inner().result;
+    let payment = core::authenticate_payment(&state, &path.into_inner(), outcome).await?;
+    Ok(redirect_after_challenge(payment.return_url, outcome))
+}
+
+/// Sends the customer back to the merchant with the outcome of the challenge
+fn redirect_after_challenge(
+    return_url: Option<String>,
+    outcome: AuthenticationOutcome,
+) -> HttpResponse {
+    let status = match outcome {
+        AuthenticationOutcome::Success => "success",
+        AuthenticationOutcome::Failure => "failure",
+    };
+    match return_url {
+        Some(return_url) => {
+            let separator = if return_url.contains('?') { '&' } else { '?' };
+            HttpResponse::SeeOther()
+                .insert_header((
+                    header::LOCATION,
+                    format!("{return_url}{separator}status={status}"),
+                ))
+                .finish()
+        }
+        None => HttpResponse::Ok().body(format!("Authentication {status}")),
+    }
+}
@@ -0,0 +1,123 @@
+use std::path::PathBuf;
+
+use common_utils::ext_traits::ConfigExt;
+use config::{Environment, File};
+pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
+use router_env::{env, logger};
+use serde::Deserialize;
+
+use crate::errors;
+
+#[derive(clap::Parser, Default)]
+#[command(version = router_env::version!())]
+pub struct CmdLineConf {
+    /// Config file.
+    /// Application will look for "config/config.toml" if this option isn't specified.
+    #[arg(short = 'f', long, value_name = "FILE")]
+    pub config_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Settings {
+    pub log: Log,
+    pub connector_simulator: SimulatorSettings,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SimulatorSettings {
+    pub host: String,
+    pub port: u16,
+    /// Base URL of the simulator as reachable from the customer's browser, used to build the 3DS
+    /// challenge links.
+    pub base_url: String,
+    /// Base URL of the router's incoming webhooks endpoint.
+    pub webhook_base_url: String,
+    pub webhook_secret: String,
+    pub latency: u64,             // in milliseconds
+    pub async_outcome_delay: u64, // in seconds
+    pub timeout_delay: u64,       // in seconds
+}
+
+impl Default for SimulatorSettings {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".into(),
+            port: 8090,
+            base_url: "http://localhost:8090".into(),
+            webhook_base_url: "http://localhost:8080/webhooks".into(),
+            webhook_secret: "simulator_webhook_secret".into(),
+            latency: 0,             // in milliseconds
+            async_outcome_delay: 5, // in seconds
+            timeout_delay: 60,      // in seconds
+        }
+    }
+}
+
+impl SimulatorSettings {
+    fn validate(&self) -> Result<(), errors::SimulatorError> {
+        use common_utils::fp_utils::when;
+
+        when(self.host.is_default_or_empty(), || {
+            Err(errors::SimulatorError::ConfigParsingError(
+                "simulator host must not be empty".into(),
+            ))
+        })?;
+
+        when(self.base_url.is_default_or_empty(), || {
+            Err(errors::SimulatorError::ConfigParsingError(
+                "simulator base URL must not be empty".into(),
+            ))
+        })?;
+
+        when(self.webhook_secret.is_default_or_empty(), || {
+            Err(errors::SimulatorError::ConfigParsingError(
+                "simulator webhook secret must not be empty".into(),
+            ))
+        })
+    }
+}
+
+impl Settings {
+    pub fn new() -> Result<Self, errors::SimulatorError> {
+        Self::with_config_path(None)
+    }
+
+    pub fn with_config_path(config_path: Option<PathBuf>) -> Result<Self, errors::SimulatorError> {
+        // Configuration values are picked up in the following priority order (1 being least
+        // priority):
+        // 1. Defaults from the implementation of the `Default` trait.
+        // 2. Values from config file. The config file accessed depends on the environment
+        //    specified by the `RUN_ENV` environment variable. `RUN_ENV` can be one of
+        //    `Development`, `Sandbox` or `Production`. If nothing is specified for `RUN_ENV`,
+        //    `/config/Development.toml` file is read.
+        // 3. Environment variables prefixed with `CONNECTOR_SIMULATOR` and each level separated
+        //    by double underscores.
+        //
+        // Values in config file override the defaults in `Default` trait, and the values set using
+        // environment variables override both the defaults and the config file values.
+
+        let environment = env::which();
+        let config_path = router_env::Config::config_path(&environment.to_string(), config_path);
+
+        let config = router_env::Config::builder(&environment.to_string())?
+            .add_source(File::from(config_path).required(true))
+            .add_source(
+                Environment::with_prefix("CONNECTOR_SIMULATOR")
+                    .try_parsing(true)
+                    .separator("__"),
+            )
+            .build()?;
+
+        serde_path_to_error::deserialize(config).map_err(|error| {
+            logger::error!(%error, "Unable to deserialize application configuration");
+            eprintln!("Unable to deserialize application configuration: {error}");
+            errors::SimulatorError::from(error.into_inner())
+        })
+    }
+
+    pub fn validate(&self) -> Result<(), errors::SimulatorError> {
+        self.connector_simulator.validate()
+    }
+}
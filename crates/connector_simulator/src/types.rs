@@ -0,0 +1,281 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PaymentRequest {
+    pub amount: i64,
+    pub currency: String,
+    pub card: Card,
+    /// Whether the payment is captured as soon as it is authorized
+    #[serde(default = "default_capture")]
+    pub capture: bool,
+    /// Where the customer is sent back to after a 3DS challenge
+    pub return_url: Option<String>,
+    /// The merchant the webhooks of this payment are sent for
+    pub merchant_id: Option<String>,
+    pub reference: Option<String>,
+    /// Result of an authentication made beforehand, with which no 3DS challenge is required
+    pub three_ds: Option<ThreeDsData>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ThreeDsData {
+    pub authentication_value: String,
+    pub eci: Option<String>,
+    pub ds_trans_id: Option<String>,
+    pub version: String,
+}
+
+fn default_capture() -> bool {
+    true
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Card {
+    pub number: String,
+    pub expiry_month: String,
+    pub expiry_year: String,
+    pub cvc: String,
+    pub holder_name: Option<String>,
+}
+
+/// How the simulator handles a payment made with a card, decided by its card number
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum CardBehaviour {
+    Succeed,
+    Decline(DeclineReason),
+    ProcessingError,
+    ThreeDsChallenge,
+    AsyncOutcome,
+    Timeout,
+    RefundFailure,
+}
+
+impl From<&Card> for CardBehaviour {
+    fn from(card: &Card) -> Self {
+        match card.number.as_str() {
+            "4000000000000002" => Self::Decline(DeclineReason::CardDeclined),
+            "4000000000009995" => Self::Decline(DeclineReason::InsufficientFunds),
+            "4000000000000069" => Self::Decline(DeclineReason::ExpiredCard),
+            "4000000000000119" => Self::ProcessingError,
+            "4000000000003220" => Self::ThreeDsChallenge,
+            "4000000000000077" => Self::AsyncOutcome,
+            "4000000000000408" => Self::Timeout,
+            "4000000000005126" => Self::RefundFailure,
+            _ => Self::Succeed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DeclineReason {
+    CardDeclined,
+    InsufficientFunds,
+    ExpiredCard,
+    AuthenticationFailed,
+}
+
+impl From<DeclineReason> for ErrorDetails {
+    fn from(reason: DeclineReason) -> Self {
+        let (code, message) = match reason {
+            DeclineReason::CardDeclined => ("card_declined", "The card was declined"),
+            DeclineReason::InsufficientFunds => (
+                "insufficient_funds",
+                "The card has insufficient funds to complete the purchase",
+            ),
+            DeclineReason::ExpiredCard => ("expired_card", "The card has expired"),
+            DeclineReason::AuthenticationFailed => (
+                "authentication_failed",
+                "The customer failed the 3DS challenge",
+            ),
+        };
+        Self {
+            code: code.to_string(),
+            message: message.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ErrorDetails {
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentStatus {
+    Succeeded,
+    Authorized,
+    Processing,
+    RequiresAction,
+    Failed,
+    Voided,
+}
+
+impl PaymentStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Succeeded => "succeeded",
+            Self::Authorized => "authorized",
+            Self::Processing => "processing",
+            Self::RequiresAction => "requires_action",
+            Self::Failed => "failed",
+            Self::Voided => "voided",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NextAction {
+    pub redirect_to_url: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Payment {
+    pub id: String,
+    pub status: PaymentStatus,
+    pub amount: i64,
+    pub amount_captured: i64,
+    pub amount_refunded: i64,
+    pub currency: String,
+    pub reference: Option<String>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created: OffsetDateTime,
+    pub next_action: Option<NextAction>,
+    pub error: Option<ErrorDetails>,
+    #[serde(skip)]
+    pub card_behaviour: CardBehaviour,
+    #[serde(skip)]
+    pub capture: bool,
+    #[serde(skip)]
+    pub return_url: Option<String>,
+    #[serde(skip)]
+    pub merchant_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CaptureRequest {
+    /// The amount to capture, the whole authorized amount if not set
+    pub amount: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RefundRequest {
+    pub payment_id: String,
+    pub amount: i64,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundStatus {
+    Succeeded,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Refund {
+    pub id: String,
+    pub payment_id: String,
+    pub status: RefundStatus,
+    pub amount: i64,
+    pub currency: String,
+    pub reason: Option<String>,
+    pub error: Option<ErrorDetails>,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created: OffsetDateTime,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuthenticationResult {
+    pub result: AuthenticationOutcome,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthenticationOutcome {
+    Success,
+    Failure,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuthenticationRequest {
+    pub amount: i64,
+    pub currency: String,
+    pub card_number: String,
+    pub card_expiry_month: String,
+    pub card_expiry_year: String,
+    /// Where the customer is sent back to after a challenge
+    pub return_url: Option<String>,
+}
+
+/// Transaction status of an EMV 3DS authentication
+#[derive(Debug, Clone, Copy, Serialize, Eq, PartialEq)]
+pub enum TransStatus {
+    #[serde(rename = "Y")]
+    Authenticated,
+    #[serde(rename = "A")]
+    Attempted,
+    #[serde(rename = "C")]
+    ChallengeRequired,
+    #[serde(rename = "N")]
+    Failed,
+    #[serde(rename = "R")]
+    Rejected,
+}
+
+impl From<&AuthenticationRequest> for TransStatus {
+    fn from(request: &AuthenticationRequest) -> Self {
+        match request.card_number.as_str() {
+            "4000000000003220" => Self::ChallengeRequired,
+            "4000000000003063" => Self::Failed,
+            "4000000000003279" => Self::Rejected,
+            "4000000000003238" => Self::Attempted,
+            _ => Self::Authenticated,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Authentication {
+    pub id: String,
+    pub trans_status: TransStatus,
+    pub authentication_value: Option<String>,
+    pub eci: Option<String>,
+    pub ds_trans_id: Option<String>,
+    pub message_version: String,
+    pub reason: Option<String>,
+    /// Where the customer completes the challenge, while one is required
+    pub challenge_url: Option<String>,
+    #[serde(skip)]
+    pub return_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventType {
+    PaymentSucceeded,
+    PaymentAuthorized,
+    PaymentFailed,
+}
+
+impl WebhookEventType {
+    /// The event notifying that a payment reached the given status, if it is a final one
+    pub fn from_status(status: PaymentStatus) -> Option<Self> {
+        match status {
+            PaymentStatus::Succeeded => Some(Self::PaymentSucceeded),
+            PaymentStatus::Authorized => Some(Self::PaymentAuthorized),
+            PaymentStatus::Failed => Some(Self::PaymentFailed),
+            PaymentStatus::Processing | PaymentStatus::RequiresAction | PaymentStatus::Voided => {
+                None
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WebhookEvent {
+    pub event_type: WebhookEventType,
+    pub data: Payment,
+}
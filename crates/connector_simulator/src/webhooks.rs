@@ -0,0 +1,100 @@
+use common_utils::{
+    crypto::{self, SignMessage},
+    errors::{CryptoError, CustomResult},
+};
+use router_env::logger;
+
+use crate::{
+    types::{Payment, WebhookEvent, WebhookEventType},
+    AppState,
+};
+
+pub const SIGNATURE_HEADER: &str = "x-simulator-signature";
+
+/// Notifies the merchant of the payment about its final status, failures are only logged as the
+/// merchant can always sync the payment
+pub async fn send_payment_webhook(state: &AppState, payment: &Payment) {
+    let (Some(merchant_id), Some(event_type)) = (
+        payment.merchant_id.as_ref(),
+        WebhookEventType::from_status(payment.status),
+    ) else {
+        return;
+    };
+
+    let event = WebhookEvent {
+        event_type,
+        data: payment.clone(),
+    };
+    let body = match serde_json::to_vec(&event) {
+        Ok(body) => body,
+        Err(error) => {
+            logger::error!(%error, payment_id = %payment.id, "Failed to serialize webhook");
+            return;
+        }
+    };
+    let signature = match sign_webhook(&state.conf.webhook_secret, &body) {
+        Ok(signature) => signature,
+        Err(error) => {
+            logger::error!(?error, payment_id = %payment.id, "Failed to sign webhook");
+            return;
+        }
+    };
+
+    let url = format!(
+        "{}/{}/dummy_connector",
+        state.conf.webhook_base_url, merchant_id
+    );
+    let response = state
+        .client
+        .post(&url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(SIGNATURE_HEADER, signature)
+        .body(body)
+        .send()
+        .await;
+    match response {
+        Ok(response) => {
+            logger::info!(status = %response.status(), %url, payment_id = %payment.id, "Sent webhook")
+        }
+        Err(error) => {
+            logger::error!(%error, %url, payment_id = %payment.id, "Failed to send webhook")
+        }
+    }
+}
+
+/// The hex encoded HMAC-SHA256 signature of the webhook body, sent in the `SIGNATURE_HEADER`
+fn sign_webhook(secret: &str, body: &[u8]) -> CustomResult<String, CryptoError> {
+    crypto::HmacSha256
+        .sign_message(secret.as_bytes(), body)
+        .map(hex::encode)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::crypto::VerifySignature;
+
+    use super::*;
+
+    #[test]
+    fn test_sign_webhook() {
+        let body = b"The quick brown fox jumps over the lazy dog";
+        let signature = sign_webhook("key", body).unwrap();
+        assert_eq!(
+            signature,
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+
+        let signature = hex::decode(signature).unwrap();
+        assert!(crypto::HmacSha256
+            .verify_signature(b"key", &signature, body)
+            .unwrap());
+        assert!(!crypto::HmacSha256
+            .verify_signature(b"another_key", &signature, body)
+            .unwrap());
+        assert!(!crypto::HmacSha256
+            .verify_signature(b"key", &signature, b"The quick brown fox")
+            .unwrap());
+    }
+}
@@ -134,94 +134,25 @@ async fn drainer(
 
     // TODO: Handle errors when deserialization fails and when DB error occurs
     for entry in entries {
-        let typed_sql = entry.1.get("typed_sql").map_or(String::new(), Clone::clone);
-        let result = serde_json::from_str::<kv::DBOperation>(&typed_sql);
-        let db_op = match result {
+        let db_op = match parse_db_operation(&entry.1) {
             Ok(f) => f,
             Err(_err) => continue, // TODO: handle error
         };
 
         let conn = pg_connection(&store.master_pool).await;
-        let insert_op = "insert";
-        let update_op = "update";
-        let payment_intent = "payment_intent";
-        let payment_attempt = "payment_attempt";
-        let refund = "refund";
-        match db_op {
-            // TODO: Handle errors
-            kv::DBOperation::Insert { insertable } => {
-                let (_, execution_time) = common_utils::date_time::time_it(|| async {
-                    match insertable {
-                        kv::Insertable::PaymentIntent(a) => {
-                            macro_util::handle_resp!(
-                                a.insert(&conn).await,
-                                insert_op,
-                                payment_intent
-                            )
-                        }
-                        kv::Insertable::PaymentAttempt(a) => {
-                            macro_util::handle_resp!(
-                                a.insert(&conn).await,
-                                insert_op,
-                                payment_attempt
-                            )
-                        }
-                        kv::Insertable::Refund(a) => {
-                            macro_util::handle_resp!(a.insert(&conn).await, insert_op, refund)
-                        }
-                    }
-                })
-                .await;
-                metrics::QUERY_EXECUTION_TIME.record(
-                    &metrics::CONTEXT,
-                    execution_time,
-                    &[metrics::KeyValue {
-                        key: "operation".into(),
-                        value: insert_op.into(),
-                    }],
-                );
-            }
-            kv::DBOperation::Update { updatable } => {
-                let (_, execution_time) = common_utils::date_time::time_it(|| async {
-                    match updatable {
-                        kv::Updateable::PaymentIntentUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                payment_intent
-                            )
-                        }
-                        kv::Updateable::PaymentAttemptUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                payment_attempt
-                            )
-                        }
-                        kv::Updateable::RefundUpdate(a) => {
-                            macro_util::handle_resp!(
-                                a.orig.update(&conn, a.update_data).await,
-                                update_op,
-                                refund
-                            )
-                        }
-                    }
-                })
-                .await;
-                metrics::QUERY_EXECUTION_TIME.record(
-                    &metrics::CONTEXT,
-                    execution_time,
-                    &[metrics::KeyValue {
-                        key: "operation".into(),
-                        value: update_op.into(),
-                    }],
-                );
-            }
-            kv::DBOperation::Delete => {
-                // [#224]: Implement this
-                logger::error!("Not implemented!");
-            }
-        };
+        let (operation, table) = (db_op.operation(), db_op.table());
+        // TODO: Handle errors
+        let (result, execution_time) =
+            common_utils::date_time::time_it(|| db_op.execute(&conn)).await;
+        macro_util::handle_resp!(result, operation, table);
+        metrics::QUERY_EXECUTION_TIME.record(
+            &metrics::CONTEXT,
+            execution_time,
+            &[metrics::KeyValue {
+                key: "operation".into(),
+                value: operation.into(),
+            }],
+        );
     }
 
     let entries_trimmed =
@@ -239,6 +170,14 @@ async fn drainer(
     Ok(())
 }
 
+/// Deserialize the database operation carried by a stream entry, as pushed by the router.
+fn parse_db_operation(
+    fields: &std::collections::HashMap<String, String>,
+) -> serde_json::Result<kv::DBOperation> {
+    let typed_sql = fields.get("typed_sql").map_or("", String::as_str);
+    serde_json::from_str(typed_sql)
+}
+
 mod macro_util {
 
     macro_rules! handle_resp {
@@ -267,3 +206,76 @@ mod macro_util {
     }
     pub(crate) use handle_resp;
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashMap;
+
+    use storage_models::reverse_lookup::ReverseLookupNew;
+
+    use super::*;
+
+    fn to_stream_entry(typed_sql: kv::TypedSql) -> HashMap<String, String> {
+        typed_sql
+            .to_field_value_pairs()
+            .unwrap()
+            .into_iter()
+            .map(|(field, value)| (field.to_string(), value))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_reverse_lookup_insert() {
+        let entry = to_stream_entry(kv::TypedSql {
+            op: kv::DBOperation::Insert {
+                insertable: kv::Insertable::ReverseLookUp(ReverseLookupNew {
+                    lookup_id: "merchant_123_pay_123".to_string(),
+                    pk_id: "merchant_123_pay_123".to_string(),
+                    sk_id: "pa_pay_123_1".to_string(),
+                    source: "payment_attempt".to_string(),
+                }),
+            },
+        });
+
+        let db_op = parse_db_operation(&entry).unwrap();
+        assert_eq!(
+            (db_op.operation(), db_op.table()),
+            ("insert", "reverse_lookup")
+        );
+        assert!(matches!(
+            db_op,
+            kv::DBOperation::Insert {
+                insertable: kv::Insertable::ReverseLookUp(ref lookup),
+            } if lookup.sk_id == "pa_pay_123_1"
+        ));
+    }
+
+    #[test]
+    fn test_parse_reverse_lookup_delete() {
+        let entry = to_stream_entry(kv::TypedSql {
+            op: kv::DBOperation::Delete {
+                deletable: kv::Deletable::ReverseLookUp {
+                    lookup_id: "merchant_123_txn_123".to_string(),
+                },
+            },
+        });
+
+        let db_op = parse_db_operation(&entry).unwrap();
+        assert_eq!(
+            (db_op.operation(), db_op.table()),
+            ("delete", "reverse_lookup")
+        );
+        assert!(matches!(
+            db_op,
+            kv::DBOperation::Delete {
+                deletable: kv::Deletable::ReverseLookUp { ref lookup_id },
+            } if lookup_id == "merchant_123_txn_123"
+        ));
+    }
+
+    #[test]
+    fn test_parse_invalid_entry() {
+        assert!(parse_db_operation(&HashMap::new()).is_err());
+    }
+}
@@ -15,7 +15,7 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, StreamsInterface},
+    interfaces::{HashesInterface, KeysInterface, PubsubInterface, StreamsInterface},
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
         RedisKey, RedisMap, RedisValue, SetOptions, XCap, XReadResponse,
@@ -594,6 +594,62 @@ impl super::RedisConnectionPool {
             .into_report()
             .change_context(errors::RedisError::ConsumerGroupClaimFailed)
     }
+
+    // Pub/Sub commands
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn subscribe(&self, channel: &str) -> CustomResult<usize, errors::RedisError> {
+        self.subscriber
+            .subscribe(channel)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SubscribeError)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn publish<V>(
+        &self,
+        channel: &str,
+        message: V,
+    ) -> CustomResult<usize, errors::RedisError>
+    where
+        V: TryInto<RedisValue> + Debug + Send,
+        V::Error: Into<fred::error::RedisError> + Send,
+    {
+        self.publisher
+            .publish(channel, message)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::PublishError)
+    }
+
+    /// Returns a stream of `(channel, message)` pairs received on the channels this connection
+    /// pool has subscribed to.
+    pub fn on_message(
+        &self,
+    ) -> impl futures::Stream<Item = (String, RedisValue)> + Send + Unpin + 'static {
+        self.subscriber.on_message()
+    }
+
+    /// Returns a stream yielding each time the pub/sub connection is re-established after being
+    /// lost. Subscriptions do not survive a reconnection, and need to be made again.
+    pub fn on_subscriber_reconnect(
+        &self,
+    ) -> impl futures::Stream<Item = ()> + Send + Unpin + 'static {
+        use fred::interfaces::ClientLike;
+        use futures::StreamExt;
+
+        self.subscriber.on_reconnect().map(|_| ())
+    }
+
+    /// Returns a stream of the connection errors of the pub/sub connection.
+    pub fn on_subscriber_error(
+        &self,
+    ) -> impl futures::Stream<Item = fred::error::RedisError> + Send + Unpin + 'static {
+        use fred::interfaces::ClientLike;
+
+        self.subscriber.on_error()
+    }
 }
 
 #[cfg(test)]
@@ -54,4 +54,8 @@ pub enum RedisError {
     InvalidRedisEntryId,
     #[error("Failed to establish Redis connection")]
     RedisConnectionError,
+    #[error("Failed to subscribe to a channel")]
+    SubscribeError,
+    #[error("Failed to publish to a channel")]
+    PublishError,
 }
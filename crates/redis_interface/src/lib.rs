@@ -23,6 +23,7 @@ pub mod types;
 
 use common_utils::errors::CustomResult;
 use error_stack::{IntoReport, ResultExt};
+use fred::interfaces::ClientLike;
 use router_env::logger;
 
 pub use self::{commands::*, types::*};
@@ -31,6 +32,10 @@ pub struct RedisConnectionPool {
     pub pool: fred::pool::RedisPool,
     config: RedisConfig,
     join_handles: Vec<fred::types::ConnectHandle>,
+    /// Dedicated client for pub/sub subscriptions, since a subscribed connection cannot be used
+    /// for issuing other commands
+    subscriber: fred::clients::RedisClient,
+    publisher: fred::clients::RedisClient,
 }
 
 impl RedisConnectionPool {
@@ -66,27 +71,46 @@ impl RedisConnectionPool {
             conf.reconnect_max_attempts,
             conf.reconnect_delay,
         );
+        let subscriber = fred::clients::RedisClient::new(config.clone());
+        let publisher = fred::clients::RedisClient::new(config.clone());
+
         let pool = fred::pool::RedisPool::new(config, conf.pool_size)
             .into_report()
             .change_context(errors::RedisError::RedisConnectionError)?;
 
-        let join_handles = pool.connect(Some(policy));
+        let mut join_handles = pool.connect(Some(policy.clone()));
         pool.wait_for_connect()
             .await
             .into_report()
             .change_context(errors::RedisError::RedisConnectionError)?;
 
+        for client in [&subscriber, &publisher] {
+            join_handles.push(client.connect(Some(policy.clone())));
+            client
+                .wait_for_connect()
+                .await
+                .into_report()
+                .change_context(errors::RedisError::RedisConnectionError)?;
+        }
+
         let config = RedisConfig::from(conf);
 
         Ok(Self {
             pool,
             config,
             join_handles,
+            subscriber,
+            publisher,
         })
     }
 
     pub async fn close_connections(&mut self) {
         self.pool.quit_pool().await;
+        for client in [&self.subscriber, &self.publisher] {
+            if let Err(error) = client.quit().await {
+                logger::error!(%error);
+            }
+        }
         for handle in self.join_handles.drain(..) {
             match handle.await {
                 Ok(Ok(_)) => (),
@@ -0,0 +1,250 @@
+//! In-process (L1) cache sitting in front of the Redis backed accounts cache.
+//!
+//! Entries are bounded by count and expire after a fixed time to live. Whenever a cached value is
+//! redacted, the key is published on [`PUB_SUB_CHANNEL`] so that every router instance can evict
+//! its local copy. While an instance is not subscribed to the channel, it would miss evictions and
+//! serve stale entries, so the in-memory caches are bypassed until it subscribes again.
+
+use std::{
+    any::Any,
+    borrow::Cow,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use dyn_clone::DynClone;
+use error_stack::Report;
+use moka::future::Cache as MokaCache;
+use once_cell::sync::Lazy;
+use redis_interface::RedisValue;
+
+use crate::core::errors;
+
+/// Redis channel on which cache invalidation messages are published
+pub(crate) const PUB_SUB_CHANNEL: &str = "hyperswitch_invalidate";
+
+/// Prefix for accounts cache keys in invalidation messages
+const ACCOUNTS_CACHE_PREFIX: &str = "accounts";
+
+/// Time to live of a cache entry, in seconds (30 minutes)
+const CACHE_TTL: u64 = 30 * 60;
+
+/// Time to idle of a cache entry, in seconds (10 minutes)
+const CACHE_TTI: u64 = 10 * 60;
+
+/// Maximum number of entries held in the accounts cache
+const ACCOUNTS_CACHE_MAX_CAPACITY: u64 = 10_000;
+
+/// Cache for merchant accounts and merchant connector accounts
+pub static ACCOUNTS_CACHE: Lazy<Cache> =
+    Lazy::new(|| Cache::new(CACHE_TTL, CACHE_TTI, ACCOUNTS_CACHE_MAX_CAPACITY));
+
+/// Whether this instance is subscribed to [`PUB_SUB_CHANNEL`] and receives evictions
+static IS_SUBSCRIBED: AtomicBool = AtomicBool::new(false);
+
+/// Whether the in-memory caches can be used, which is only the case while this instance receives
+/// evictions published by the other instances.
+pub fn is_enabled() -> bool {
+    IS_SUBSCRIBED.load(Ordering::Acquire)
+}
+
+/// Record whether this instance is subscribed to [`PUB_SUB_CHANNEL`]. The in-memory caches are
+/// cleared on subscribing, as evictions published while unsubscribed were missed.
+pub fn set_subscribed(is_subscribed: bool) {
+    if is_subscribed {
+        ACCOUNTS_CACHE.clear();
+    }
+    IS_SUBSCRIBED.store(is_subscribed, Ordering::Release);
+}
+
+/// Identifies the cache a key belongs to, when being sent over the invalidation channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheKind<'a> {
+    Accounts(Cow<'a, str>),
+}
+
+impl<'a> CacheKind<'a> {
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Accounts(key) => key,
+        }
+    }
+}
+
+impl<'a> From<CacheKind<'a>> for RedisValue {
+    fn from(kind: CacheKind<'a>) -> Self {
+        let value = match kind {
+            CacheKind::Accounts(key) => format!("{ACCOUNTS_CACHE_PREFIX},{key}"),
+        };
+        Self::from(value)
+    }
+}
+
+impl TryFrom<RedisValue> for CacheKind<'static> {
+    type Error = Report<errors::ValidationError>;
+
+    fn try_from(value: RedisValue) -> Result<Self, Self::Error> {
+        let validation_err = || {
+            Report::new(errors::ValidationError::InvalidValue {
+                message: "Invalid publish key provided in pubsub".into(),
+            })
+        };
+        let value = value.as_string().ok_or_else(validation_err)?;
+        let (prefix, key) = value.split_once(',').ok_or_else(validation_err)?;
+
+        match prefix {
+            ACCOUNTS_CACHE_PREFIX => Ok(Self::Accounts(Cow::Owned(key.to_string()))),
+            _ => Err(validation_err()),
+        }
+    }
+}
+
+pub trait Cacheable: Any + Send + Sync + DynClone {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T> Cacheable for T
+where
+    T: Any + Clone + Send + Sync,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+dyn_clone::clone_trait_object!(Cacheable);
+
+pub struct Cache {
+    inner: MokaCache<String, Arc<dyn Cacheable>>,
+    /// Incremented before every eviction, so that values fetched before an eviction are not
+    /// cached after it
+    generation: AtomicU64,
+}
+
+impl Cache {
+    /// Create a new cache.
+    ///
+    /// `time_to_live` and `time_to_idle` are in seconds, `max_capacity` is the maximum number of
+    /// entries held in the cache before the least recently used entries are evicted.
+    pub fn new(time_to_live: u64, time_to_idle: u64, max_capacity: u64) -> Self {
+        let inner = MokaCache::builder()
+            .time_to_live(std::time::Duration::from_secs(time_to_live))
+            .time_to_idle(std::time::Duration::from_secs(time_to_idle))
+            .max_capacity(max_capacity)
+            .build();
+        Self {
+            inner,
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    pub async fn push<T: Cacheable>(&self, key: String, val: T) {
+        self.inner.insert(key, Arc::new(val)).await;
+    }
+
+    /// Current generation of the cache, to be read before fetching a value to be cached with
+    /// [`Cache::push_if_current`].
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Cache a value fetched at `generation`, unless an eviction happened since, as the value may
+    /// then be stale. Any eviction drops the value, not only one of `key`.
+    pub async fn push_if_current<T: Cacheable>(&self, key: String, val: T, generation: u64) {
+        if self.generation() != generation {
+            return;
+        }
+        self.inner.insert(key.clone(), Arc::new(val)).await;
+        // An eviction may have happened between the check and the insert
+        if self.generation() != generation {
+            self.inner.invalidate(&key).await;
+        }
+    }
+
+    pub fn get_val<T: Clone + Cacheable>(&self, key: &str) -> Option<T> {
+        let val = self.inner.get(key)?;
+        (*val).as_any().downcast_ref::<T>().cloned()
+    }
+
+    pub async fn remove(&self, key: &str) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        self.inner.invalidate(key).await;
+    }
+
+    pub fn clear(&self) {
+        self.generation.fetch_add(1, Ordering::AcqRel);
+        self.inner.invalidate_all();
+    }
+}
+
+/// Evict the key carried by an invalidation message from the matching in-memory cache.
+pub async fn invalidate(kind: CacheKind<'_>) {
+    match kind {
+        CacheKind::Accounts(key) => ACCOUNTS_CACHE.remove(&key).await,
+    }
+}
+
+#[cfg(test)]
+mod cache_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn construct_and_get_cache() {
+        let cache = Cache::new(1800, 1800, 10);
+        cache.push("key".to_string(), "val".to_string()).await;
+        assert_eq!(cache.get_val::<String>("key"), Some(String::from("val")));
+    }
+
+    #[tokio::test]
+    async fn get_value_of_different_type() {
+        let cache = Cache::new(1800, 1800, 10);
+        cache.push("key".to_string(), "val".to_string()).await;
+        assert_eq!(cache.get_val::<u32>("key"), None);
+    }
+
+    #[tokio::test]
+    async fn remove_value() {
+        let cache = Cache::new(1800, 1800, 10);
+        cache.push("key".to_string(), "val".to_string()).await;
+        cache.remove("key").await;
+        assert_eq!(cache.get_val::<String>("key"), None);
+    }
+
+    #[tokio::test]
+    async fn clear_cache() {
+        let cache = Cache::new(1800, 1800, 10);
+        cache.push("key".to_string(), "val".to_string()).await;
+        cache.clear();
+        assert_eq!(cache.get_val::<String>("key"), None);
+    }
+
+    #[tokio::test]
+    async fn skip_value_fetched_before_eviction() {
+        let cache = Cache::new(1800, 1800, 10);
+        let generation = cache.generation();
+        cache.remove("key").await;
+        cache
+            .push_if_current("key".to_string(), "stale".to_string(), generation)
+            .await;
+        assert_eq!(cache.get_val::<String>("key"), None);
+
+        let generation = cache.generation();
+        cache
+            .push_if_current("key".to_string(), "val".to_string(), generation)
+            .await;
+        assert_eq!(cache.get_val::<String>("key"), Some(String::from("val")));
+    }
+
+    #[test]
+    fn cache_kind_round_trip() {
+        let kind = CacheKind::Accounts(Cow::Borrowed("merchant_123"));
+        let value = RedisValue::from(kind.clone());
+        let parsed = CacheKind::try_from(value).ok();
+        assert_eq!(
+            parsed,
+            Some(CacheKind::Accounts(Cow::Owned("merchant_123".into())))
+        );
+    }
+}
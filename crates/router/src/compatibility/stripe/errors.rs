@@ -97,6 +97,9 @@ pub enum StripeErrorCode {
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such API key")]
     ApiKeyNotFound,
 
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "resource_missing", message = "No such fraud check")]
+    FraudCheckNotFound,
+
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "parameter_missing", message = "Return url is not available")]
     ReturnUrlUnavailable,
 
@@ -168,6 +171,8 @@ pub enum StripeErrorCode {
     PaymentIntentMandateInvalid { message: String },
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "", message = "The payment with the specified payment_id '{payment_id}' already exists in our records.")]
     DuplicatePayment { payment_id: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "token_already_used", message = "The payment token has expired or has already been used.")]
+    PaymentTokenExpired,
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -387,6 +392,7 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             }
             errors::ApiErrorResponse::MandateNotFound => Self::MandateNotFound,
             errors::ApiErrorResponse::ApiKeyNotFound => Self::ApiKeyNotFound,
+            errors::ApiErrorResponse::FraudCheckNotFound => Self::FraudCheckNotFound,
             errors::ApiErrorResponse::MandateValidationFailed { reason } => {
                 Self::PaymentIntentMandateInvalid { message: reason }
             }
@@ -429,6 +435,12 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::DuplicatePayment { payment_id } => {
                 Self::DuplicatePayment { payment_id }
             }
+            errors::ApiErrorResponse::PaymentTokenExpired => Self::PaymentTokenExpired,
+            errors::ApiErrorResponse::NotSupportedByConnector { message, connector } => {
+                Self::InvalidRequestData {
+                    message: format!("{message} is not supported by {connector}"),
+                }
+            }
         }
     }
 }
@@ -458,6 +470,7 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::MerchantConnectorAccountNotFound
             | Self::MandateNotFound
             | Self::ApiKeyNotFound
+            | Self::FraudCheckNotFound
             | Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
@@ -474,7 +487,8 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::ResourceIdNotFound
             | Self::PaymentIntentMandateInvalid { .. }
             | Self::PaymentIntentUnexpectedState { .. }
-            | Self::DuplicatePayment { .. } => StatusCode::BAD_REQUEST,
+            | Self::DuplicatePayment { .. }
+            | Self::PaymentTokenExpired => StatusCode::BAD_REQUEST,
             Self::RefundFailed
             | Self::InternalServerError
             | Self::MandateActive
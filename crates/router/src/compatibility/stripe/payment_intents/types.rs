@@ -278,6 +278,14 @@ pub struct StripePaymentIntentResponse {
     pub refunds: Option<Vec<refunds::RefundResponse>>,
     pub mandate_id: Option<String>,
     pub metadata: Option<Value>,
+    pub last_payment_error: Option<StripePaymentError>,
+}
+
+#[derive(Default, Eq, PartialEq, Serialize)]
+pub struct StripePaymentError {
+    pub code: Option<String>,
+    pub message: Option<String>,
+    pub decline_code: Option<api_enums::DeclineCode>,
 }
 
 impl From<payments::PaymentsResponse> for StripePaymentIntentResponse {
@@ -296,6 +304,11 @@ impl From<payments::PaymentsResponse> for StripePaymentIntentResponse {
             refunds: resp.refunds,
             mandate_id: resp.mandate_id,
             metadata: resp.metadata,
+            last_payment_error: resp.error_code.map(|code| StripePaymentError {
+                code: Some(code),
+                message: resp.error_message,
+                decline_code: resp.decline_code,
+            }),
         }
     }
 }
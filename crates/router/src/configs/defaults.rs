@@ -29,6 +29,7 @@ impl Default for super::settings::Secrets {
         Self {
             jwt_secret: "secret".into(),
             admin_api_key: "test_admin".into(),
+            card_fingerprint_secret: "card_fingerprint_secret".into(),
         }
     }
 }
@@ -43,6 +44,29 @@ impl Default for super::settings::Locker {
     }
 }
 
+impl Default for super::settings::AccountUpdater {
+    fn default() -> Self {
+        Self {
+            provider: super::settings::AccountUpdaterProviderKind::default(),
+            file_path: String::new(),
+            batch_size: 100,
+            lookahead_months: 1,
+            lookback_months: 3,
+        }
+    }
+}
+
+impl Default for super::settings::PaymentToken {
+    fn default() -> Self {
+        Self {
+            default_ttl_in_secs: 900,
+            max_ttl_in_secs: 3600,
+            default_max_uses: 1,
+            purge_batch_size: 100,
+        }
+    }
+}
+
 impl Default for super::settings::SupportedConnectors {
     fn default() -> Self {
         Self {
@@ -43,6 +43,12 @@ pub struct Settings {
     pub log: Log,
     pub secrets: Secrets,
     pub locker: Locker,
+    pub card_vault: CardVault,
+    pub applepay_decryption: ApplepayDecryption,
+    pub network_tokenization: NetworkTokenization,
+    pub account_updater: AccountUpdater,
+    pub bin_lookup: BinLookup,
+    pub payment_token: PaymentToken,
     pub connectors: Connectors,
     pub refund: Refund,
     pub eph_key: EphemeralConfig,
@@ -58,6 +64,8 @@ pub struct Settings {
 pub struct Secrets {
     pub jwt_secret: String,
     pub admin_api_key: String,
+    /// Secret used to derive the merchant specific keys card fingerprints are computed with
+    pub card_fingerprint_secret: String,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -68,6 +76,101 @@ pub struct Locker {
     pub basilisk_host: String,
 }
 
+/// Built-in card vault. When enabled, cards are stored in the vault instead of the external locker
+/// or the locker mock-up.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct CardVault {
+    pub enabled: bool,
+    /// Database holding the encrypted cards and data keys, separate from the main database
+    pub database: Database,
+    /// Path to a file containing the hex encoded key encryption key
+    pub kek_file: String,
+    /// Base64 encoded key encryption key, encrypted with the AWS KMS key configured in `jwekey`.
+    /// Takes precedence over `kek_file` when set.
+    #[cfg(feature = "kms")]
+    pub kms_encrypted_kek: String,
+}
+
+/// Decryption of Apple Pay payment tokens for processors which do not support Apple Pay. The keys
+/// of the merchants' payment processing certificates are stored encrypted with the configured key.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ApplepayDecryption {
+    pub enabled: bool,
+    /// Path to a file containing the hex encoded certificate encryption key
+    pub key_file: String,
+    /// Path to the PEM or DER encoded Apple Root CA - G3 certificate, which the signatures of
+    /// payment tokens are verified against
+    pub root_certificate_file: String,
+    /// Base64 encoded certificate encryption key, encrypted with the AWS KMS key configured in
+    /// `jwekey`. Takes precedence over `key_file` when set.
+    #[cfg(feature = "kms")]
+    pub kms_encrypted_key: String,
+}
+
+/// Provisioning of network tokens for saved cards
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct NetworkTokenization {
+    pub enabled: bool,
+    pub provider: NetworkTokenProviderKind,
+    /// Secret used to verify the token lifecycle webhooks sent by the provider
+    pub webhook_secret: String,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkTokenProviderKind {
+    /// Token service provider emulated locally
+    #[default]
+    Mock,
+}
+
+/// Card account updater, refreshing saved cards that are about to expire or were reissued
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AccountUpdater {
+    pub provider: AccountUpdaterProviderKind,
+    /// Path to the response file read by the `file` provider
+    pub file_path: String,
+    /// Number of cards queried with the provider at once
+    pub batch_size: i64,
+    /// Cards expiring within this many months are checked
+    pub lookahead_months: u8,
+    /// Cards which expired up to this many months ago are checked as well
+    pub lookback_months: u8,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum AccountUpdaterProviderKind {
+    /// Updates read from a local file, in place of a card network updater service
+    #[default]
+    File,
+}
+
+/// Lookup of card metadata (network, issuer, funding type and issuing country) by the card BIN
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct BinLookup {
+    pub enabled: bool,
+    /// Path to the CSV file the BIN table is loaded from
+    pub file_path: String,
+}
+
+/// Expiry and usage limits of payment tokens, which merchants can override within the maximum
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentToken {
+    pub default_ttl_in_secs: u32,
+    /// Longest expiry merchants can configure. Payment tokens are purged once this old
+    pub max_ttl_in_secs: u32,
+    pub default_max_uses: u32,
+    /// Number of expired payment tokens purged at once
+    pub purge_batch_size: i64,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct Refund {
@@ -141,12 +244,15 @@ pub struct Connectors {
     pub braintree: ConnectorParams,
     pub checkout: ConnectorParams,
     pub cybersource: ConnectorParams,
+    pub dummy_connector: ConnectorParams,
     pub fiserv: ConnectorParams,
     pub globalpay: ConnectorParams,
     pub klarna: ConnectorParams,
+    pub paypal: ConnectorParams,
     pub payu: ConnectorParams,
     pub rapyd: ConnectorParams,
     pub shift4: ConnectorParams,
+    pub signifyd: ConnectorParams,
     pub stripe: ConnectorParams,
     pub worldline: ConnectorParams,
     pub worldpay: ConnectorParams,
@@ -269,6 +375,12 @@ impl Settings {
         }
         self.secrets.validate()?;
         self.locker.validate()?;
+        self.card_vault.validate()?;
+        self.applepay_decryption.validate()?;
+        self.network_tokenization.validate()?;
+        self.account_updater.validate()?;
+        self.bin_lookup.validate()?;
+        self.payment_token.validate()?;
         self.connectors.validate()?;
 
         self.scheduler
@@ -16,6 +16,12 @@ impl super::settings::Secrets {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "admin API key must not be empty".into(),
             ))
+        })?;
+
+        when(self.card_fingerprint_secret.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card fingerprint secret must not be empty".into(),
+            ))
         })
     }
 }
@@ -41,6 +47,127 @@ impl super::settings::Locker {
     }
 }
 
+impl super::settings::CardVault {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        self.database.validate()?;
+
+        #[cfg(feature = "kms")]
+        let kek_configured =
+            !self.kek_file.is_default_or_empty() || !self.kms_encrypted_kek.is_default_or_empty();
+        #[cfg(not(feature = "kms"))]
+        let kek_configured = !self.kek_file.is_default_or_empty();
+
+        when(!kek_configured, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card vault key encryption key must be configured when the card vault is enabled"
+                    .into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ApplepayDecryption {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        #[cfg(feature = "kms")]
+        let key_configured =
+            !self.key_file.is_default_or_empty() || !self.kms_encrypted_key.is_default_or_empty();
+        #[cfg(not(feature = "kms"))]
+        let key_configured = !self.key_file.is_default_or_empty();
+
+        when(!key_configured, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "certificate encryption key must be configured when Apple Pay decryption is enabled"
+                    .into(),
+            ))
+        })?;
+
+        when(self.root_certificate_file.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Apple root certificate must be configured when Apple Pay decryption is enabled"
+                    .into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::NetworkTokenization {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.enabled && self.webhook_secret.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                "network token webhook secret must not be empty when network tokenization is enabled"
+                    .into(),
+            ))
+            },
+        )
+    }
+}
+
+impl super::settings::AccountUpdater {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "account updater batch size must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::BinLookup {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.enabled && self.file_path.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "BIN lookup file path must not be empty when BIN lookup is enabled".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::PaymentToken {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.default_ttl_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment token default TTL must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.default_ttl_in_secs > self.max_ttl_in_secs, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment token default TTL must not exceed the maximum TTL".into(),
+            ))
+        })?;
+
+        when(self.default_max_uses == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment token default max uses must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.purge_batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment token purge batch size must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::Jwekey {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         #[cfg(feature = "kms")]
@@ -120,6 +247,8 @@ impl super::settings::Connectors {
         self.braintree.validate()?;
         self.checkout.validate()?;
         self.cybersource.validate()?;
+        #[cfg(feature = "dummy_connector")]
+        self.dummy_connector.validate()?;
         self.globalpay.validate()?;
         self.klarna.validate()?;
         self.shift4.validate()?;
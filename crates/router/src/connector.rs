@@ -5,20 +5,26 @@ pub mod authorizedotnet;
 pub mod braintree;
 pub mod checkout;
 pub mod cybersource;
+#[cfg(feature = "dummy_connector")]
+pub mod dummy_connector;
 pub mod fiserv;
 pub mod globalpay;
 pub mod klarna;
+pub mod paypal;
 pub mod payu;
 pub mod rapyd;
 pub mod shift4;
+pub mod signifyd;
 pub mod stripe;
 pub mod utils;
 pub mod worldline;
 pub mod worldpay;
 
+#[cfg(feature = "dummy_connector")]
+pub use self::dummy_connector::DummyConnector;
 pub use self::{
     aci::Aci, adyen::Adyen, applepay::Applepay, authorizedotnet::Authorizedotnet,
     braintree::Braintree, checkout::Checkout, cybersource::Cybersource, fiserv::Fiserv,
-    globalpay::Globalpay, klarna::Klarna, payu::Payu, rapyd::Rapyd, shift4::Shift4, stripe::Stripe,
-    worldline::Worldline, worldpay::Worldpay,
+    globalpay::Globalpay, klarna::Klarna, paypal::Paypal, payu::Payu, rapyd::Rapyd, shift4::Shift4,
+    signifyd::Signifyd, stripe::Stripe, worldline::Worldline, worldpay::Worldpay,
 };
@@ -7,14 +7,16 @@ use transformers as aci;
 
 use crate::{
     configs::settings,
+    connector::utils as conn_utils,
     core::errors::{self, CustomResult},
+    db::StorageInterface,
     headers,
     services::{self, logger},
     types::{
         self,
         api::{self, ConnectorCommon},
     },
-    utils::{self, BytesExt},
+    utils::{self, crypto, ByteSliceExt, BytesExt},
 };
 
 #[derive(Debug, Clone)]
@@ -42,6 +44,30 @@ impl ConnectorCommon for Aci {
             .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
         Ok(vec![(headers::AUTHORIZATION.to_string(), auth.api_key)])
     }
+
+    fn get_capabilities(
+        &self,
+        capabilities: api::ConnectorCapabilities,
+    ) -> api::ConnectorCapabilities {
+        use api::enums::{CaptureMethod, PaymentMethodType};
+
+        api::ConnectorCapabilities {
+            payment_methods: vec![
+                PaymentMethodType::Card,
+                PaymentMethodType::BankDebit,
+                PaymentMethodType::BankRedirect,
+                PaymentMethodType::PayLater,
+                PaymentMethodType::Wallet,
+                PaymentMethodType::Paypal,
+            ],
+            currencies: None,
+            countries: None,
+            capture_methods: vec![CaptureMethod::Automatic],
+            supports_mandates: false,
+            supports_network_tokens: false,
+            ..capabilities
+        }
+    }
 }
 
 impl api::Payment for Aci {}
@@ -528,25 +554,98 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Aci {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_body_decoding_algorithm(
         &self,
+        headers: &actix_web::http::header::HeaderMap,
         _body: &[u8],
+    ) -> CustomResult<Box<dyn crypto::DecodeMessage + Send>, errors::ConnectorError> {
+        let initialization_vector =
+            conn_utils::get_header_key_value("x-initialization-vector", headers)?;
+        let nonce = hex::decode(initialization_vector)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        Ok(Box::new(crypto::GcmAes256::new(nonce)))
+    }
+
+    async fn get_webhook_body_decoding_merchant_secret(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let key = format!("whsec_decoding_{}_{}", self.id(), merchant_id);
+        let secret = db
+            .get_key(&key)
+            .await
+            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
+
+        // The secret is shared by ACI as a hex string
+        hex::decode(secret)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)
+    }
+
+    fn get_webhook_body_decoding_message(
+        &self,
+        headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let authentication_tag = conn_utils::get_header_key_value("x-authentication-tag", headers)?;
+        let mut message = hex::decode(body)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+        message.extend(
+            hex::decode(authentication_tag)
+                .into_report()
+                .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?,
+        );
+        Ok(message)
+    }
+
+    async fn verify_webhook_source(
+        &self,
+        db: &dyn StorageInterface,
+        headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::ConnectorError> {
+        // ACI encrypts the notifications with AES-GCM, only a notification sent by ACI can be
+        // decrypted with the merchant secret
+        Ok(self
+            .decode_webhook_body(db, headers, body, merchant_id)
+            .await
+            .is_ok())
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        body: &[u8],
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: aci::AciIncomingWebhook = body
+            .parse_struct("AciIncomingWebhook")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        Ok(webhook.payload.id)
     }
 
     fn get_webhook_event_type(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: aci::AciIncomingWebhook = body
+            .parse_struct("AciIncomingWebhook")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        api::IncomingWebhookEvent::try_from(&webhook)
     }
 
     fn get_webhook_resource_object(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: aci::AciIncomingWebhook = body
+            .parse_struct("AciIncomingWebhook")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        let response = aci::AciPaymentsResponse::from(webhook.payload);
+        utils::Encode::<aci::AciPaymentsResponse>::encode_to_value(&response)
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
     }
 }
 
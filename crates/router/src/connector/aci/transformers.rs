@@ -80,7 +80,7 @@ pub struct BankDetails {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize)]
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum AciPaymentType {
     #[serde(rename = "PA")]
     Preauthorization,
@@ -108,7 +108,12 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for AciPaymentsRequest {
                 card_expiry_year: ccard.card_exp_year.peek().clone(),
                 card_cvv: ccard.card_cvc.peek().clone(),
             }),
-            api::PaymentMethod::BankTransfer => PaymentDetails::BankAccount(BankDetails {
+            api::PaymentMethod::BankTransfer
+            | api::PaymentMethod::BankDebit(_)
+            | api::PaymentMethod::BankRedirect(_)
+            | api::PaymentMethod::LocalBankTransfer(_)
+            | api::PaymentMethod::Upi(_)
+            | api::PaymentMethod::MandatePayment => PaymentDetails::BankAccount(BankDetails {
                 account_holder: "xyz".to_string(),
             }),
             api::PaymentMethod::PayLater(_) => PaymentDetails::Klarna,
@@ -175,7 +180,7 @@ impl FromStr for AciPaymentStatus {
     }
 }
 
-#[derive(Default, Clone, Deserialize, PartialEq, Eq)]
+#[derive(Default, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct AciPaymentsResponse {
     id: String,
@@ -186,7 +191,7 @@ pub struct AciPaymentsResponse {
     pub(super) result: ResultCode,
 }
 
-#[derive(Default, Debug, Clone, Deserialize, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct ResultCode {
     pub(super) code: String,
@@ -194,7 +199,7 @@ pub struct ResultCode {
     pub(super) parameter_errors: Option<Vec<ErrorParameters>>,
 }
 
-#[derive(Default, Debug, Clone, Deserialize, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ErrorParameters {
     pub(super) name: String,
     pub(super) value: String,
@@ -316,3 +321,62 @@ impl<F> TryFrom<types::RefundsResponseRouterData<F, AciRefundResponse>>
         })
     }
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AciWebhookType {
+    Payment,
+    Registration,
+    Risk,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AciIncomingWebhook {
+    #[serde(rename = "type")]
+    pub webhook_type: AciWebhookType,
+    pub payload: AciWebhookPayload,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AciWebhookPayload {
+    pub id: String,
+    pub payment_type: Option<AciPaymentType>,
+    pub result: ResultCode,
+    pub ndc: Option<String>,
+    pub timestamp: Option<String>,
+    pub build_number: Option<String>,
+}
+
+impl TryFrom<&AciIncomingWebhook> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(webhook: &AciIncomingWebhook) -> Result<Self, Self::Error> {
+        // Only the outcome of the payment itself updates the payment, captures, reversals and
+        // refunds are notified with their own ids
+        match (&webhook.webhook_type, &webhook.payload.payment_type) {
+            (
+                AciWebhookType::Payment,
+                Some(AciPaymentType::Debit | AciPaymentType::Preauthorization),
+            ) => match AciPaymentStatus::from_str(&webhook.payload.result.code)? {
+                AciPaymentStatus::Succeeded => Ok(Self::PaymentIntentSuccess),
+                AciPaymentStatus::Failed => Ok(Self::PaymentIntentFailure),
+                AciPaymentStatus::Pending => {
+                    Err(report!(errors::ConnectorError::WebhookEventTypeNotFound))
+                }
+            },
+            _ => Err(report!(errors::ConnectorError::WebhookEventTypeNotFound)),
+        }
+    }
+}
+
+impl From<AciWebhookPayload> for AciPaymentsResponse {
+    fn from(payload: AciWebhookPayload) -> Self {
+        Self {
+            id: payload.id,
+            ndc: payload.ndc.unwrap_or_default(),
+            timestamp: payload.timestamp.unwrap_or_default(),
+            build_number: payload.build_number.unwrap_or_default(),
+            result: payload.result,
+        }
+    }
+}
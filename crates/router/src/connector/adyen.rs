@@ -45,6 +45,30 @@ impl ConnectorCommon for Adyen {
     fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
         connectors.adyen.base_url.as_ref()
     }
+
+    fn get_capabilities(
+        &self,
+        capabilities: api::ConnectorCapabilities,
+    ) -> api::ConnectorCapabilities {
+        use api::enums::{CaptureMethod, PaymentMethodType};
+
+        api::ConnectorCapabilities {
+            payment_methods: vec![
+                PaymentMethodType::Card,
+                PaymentMethodType::BankDebit,
+                PaymentMethodType::BankRedirect,
+                PaymentMethodType::PayLater,
+                PaymentMethodType::Wallet,
+                PaymentMethodType::Paypal,
+            ],
+            currencies: None,
+            countries: None,
+            capture_methods: vec![CaptureMethod::Automatic, CaptureMethod::Manual],
+            supports_mandates: true,
+            supports_network_tokens: false,
+            ..capabilities
+        }
+    }
 }
 
 impl api::Payment for Adyen {}
@@ -98,6 +98,7 @@ pub struct AdyenPaymentRequest {
     delivery_address: Option<Address>,
     country_code: Option<String>,
     line_items: Option<Vec<LineItem>>,
+    social_security_number: Option<Secret<String>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -167,10 +168,77 @@ pub struct AdyenThreeDS {
 #[derive(Debug, Clone, Deserialize)]
 #[serde(untagged)]
 pub enum AdyenPaymentResponse {
+    AdyenPresentToShopperResponse(AdyenPresentToShopperResponse),
     AdyenResponse(AdyenResponse),
     AdyenRedirectResponse(AdyenRedirectionResponse),
 }
 
+/// Response of payments the shopper completes outside the checkout, such as by scanning a PIX
+/// QR code or paying a Boleto voucher
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenPresentToShopperResponse {
+    psp_reference: String,
+    action: AdyenPresentToShopperAction,
+    refusal_reason: Option<String>,
+    refusal_reason_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum AdyenPresentToShopperAction {
+    QrCode(AdyenQrCodeAction),
+    Voucher(AdyenVoucherAction),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenQrCodeAction {
+    qr_code_data: String,
+    url: Option<String>,
+    expires_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenVoucherAction {
+    reference: Option<String>,
+    download_url: Option<String>,
+    expires_at: Option<String>,
+}
+
+impl From<AdyenPresentToShopperAction> for api::BankTransferInstructions {
+    fn from(action: AdyenPresentToShopperAction) -> Self {
+        let parse_expires_at = |expires_at: Option<String>| {
+            expires_at.and_then(|expires_at| {
+                time::OffsetDateTime::parse(
+                    &expires_at,
+                    &time::format_description::well_known::Iso8601::DEFAULT,
+                )
+                .ok()
+                .map(|expires_at| {
+                    let expires_at = expires_at.to_offset(time::UtcOffset::UTC);
+                    time::PrimitiveDateTime::new(expires_at.date(), expires_at.time())
+                })
+            })
+        };
+        match action {
+            AdyenPresentToShopperAction::QrCode(qr_code) => Self {
+                qr_code_data: Some(qr_code.qr_code_data),
+                instructions_url: qr_code.url,
+                expires_at: parse_expires_at(qr_code.expires_at),
+                ..Default::default()
+            },
+            AdyenPresentToShopperAction::Voucher(voucher) => Self {
+                reference: voucher.reference,
+                instructions_url: voucher.download_url,
+                expires_at: parse_expires_at(voucher.expires_at),
+                ..Default::default()
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdyenResponse {
@@ -218,6 +286,11 @@ pub enum AdyenPaymentMethod {
     AfterPay(AdyenPayLaterData),
     AdyenKlarna(AdyenPayLaterData),
     AdyenAffirm(AdyenPayLaterData),
+    AchDirectDebit(AdyenAchDirectDebit),
+    SepaDirectDebit(AdyenSepaDirectDebit),
+    BacsDirectDebit(AdyenBacsDirectDebit),
+    BankRedirect(AdyenBankRedirectData),
+    BankTransfer(AdyenBankTransferData),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -281,6 +354,50 @@ pub struct AdyenPayLaterData {
     payment_type: PaymentType,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenAchDirectDebit {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+    bank_account_number: Secret<String, pii::BankAccountNumber>,
+    bank_location_id: Secret<String>,
+    owner_name: Secret<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdyenSepaDirectDebit {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+    #[serde(rename = "sepa.ownerName")]
+    owner_name: Secret<String>,
+    #[serde(rename = "sepa.ibanNumber")]
+    iban_number: Secret<String, pii::BankAccountNumber>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenBacsDirectDebit {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+    bank_account_number: Secret<String, pii::BankAccountNumber>,
+    bank_location_id: Secret<String>,
+    holder_name: Secret<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdyenBankRedirectData {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    issuer: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdyenBankTransferData {
+    #[serde(rename = "type")]
+    payment_type: PaymentType,
+}
+
 // Refunds Request and Response
 #[derive(Default, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -316,6 +433,19 @@ pub enum PaymentType {
     Klarna,
     Affirm,
     Afterpaytouch,
+    Ach,
+    Sepadirectdebit,
+    #[serde(rename = "directdebit_GB")]
+    DirectdebitGb,
+    Ideal,
+    #[serde(rename = "directEbanking")]
+    Sofort,
+    Giropay,
+    Eps,
+    #[serde(rename = "onlineBanking_PL")]
+    OnlineBankingPl,
+    Pix,
+    Boletobancario,
 }
 
 impl TryFrom<&types::ConnectorAuthType> for AdyenAuthType {
@@ -344,6 +474,15 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for AdyenPaymentRequest {
             storage_models::enums::PaymentMethodType::Wallet => {
                 get_wallet_specific_payment_data(item)
             }
+            storage_models::enums::PaymentMethodType::BankDebit => {
+                get_bank_debit_specific_payment_data(item)
+            }
+            storage_models::enums::PaymentMethodType::BankRedirect => {
+                get_bank_redirect_specific_payment_data(item)
+            }
+            storage_models::enums::PaymentMethodType::BankTransfer => {
+                get_bank_transfer_specific_payment_data(item)
+            }
             _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
         }
     }
@@ -407,16 +546,20 @@ fn get_amount_data(item: &types::PaymentsAuthorizeRouterData) -> Amount {
 }
 
 fn get_address_info(address: Option<&api_models::payments::Address>) -> Option<Address> {
-    address.and_then(|add| {
-        add.address.as_ref().map(|a| Address {
-            city: a.city.clone(),
-            country: a.country.clone(),
-            house_number_or_name: a.line1.clone(),
-            postal_code: a.zip.clone(),
-            state_or_province: a.state.clone(),
-            street: a.line2.clone(),
-        })
-    })
+    address.and_then(|add| add.address.as_ref().map(Address::from))
+}
+
+impl From<&api_models::payments::AddressDetails> for Address {
+    fn from(address: &api_models::payments::AddressDetails) -> Self {
+        Self {
+            city: address.city.clone(),
+            country: address.country.clone(),
+            house_number_or_name: address.line1.clone(),
+            postal_code: address.zip.clone(),
+            state_or_province: address.state.clone(),
+            street: address.line2.clone(),
+        }
+    }
 }
 
 fn get_line_items(item: &types::PaymentsAuthorizeRouterData) -> Vec<LineItem> {
@@ -533,7 +676,77 @@ fn get_payment_method_data(
             }
             _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
         },
+        api::PaymentMethod::BankDebit(ref bank_debit_data) => {
+            let owner_name = bank_debit_data.get_billing_details().name.clone();
+            match bank_debit_data {
+                api::BankDebitData::AchBankDebit {
+                    account_number,
+                    routing_number,
+                    ..
+                } => Ok(AdyenPaymentMethod::AchDirectDebit(AdyenAchDirectDebit {
+                    payment_type: PaymentType::Ach,
+                    bank_account_number: account_number.clone(),
+                    bank_location_id: routing_number.clone(),
+                    owner_name,
+                })),
+                api::BankDebitData::SepaBankDebit { iban, .. } => {
+                    Ok(AdyenPaymentMethod::SepaDirectDebit(AdyenSepaDirectDebit {
+                        payment_type: PaymentType::Sepadirectdebit,
+                        owner_name,
+                        iban_number: iban.clone(),
+                    }))
+                }
+                api::BankDebitData::BacsBankDebit {
+                    account_number,
+                    sort_code,
+                    ..
+                } => Ok(AdyenPaymentMethod::BacsDirectDebit(AdyenBacsDirectDebit {
+                    payment_type: PaymentType::DirectdebitGb,
+                    bank_account_number: account_number.clone(),
+                    bank_location_id: sort_code.clone(),
+                    holder_name: owner_name,
+                })),
+            }
+        }
+        api::PaymentMethod::BankRedirect(ref bank_redirect_data) => {
+            let (payment_type, issuer) = match bank_redirect_data {
+                api::BankRedirectData::Ideal { bank_name, .. } => {
+                    (PaymentType::Ideal, bank_name.clone())
+                }
+                api::BankRedirectData::Sofort { .. } => (PaymentType::Sofort, None),
+                api::BankRedirectData::Giropay { .. } => (PaymentType::Giropay, None),
+                api::BankRedirectData::Eps { bank_name, .. } => {
+                    (PaymentType::Eps, bank_name.clone())
+                }
+                api::BankRedirectData::Przelewy24 { bank_name, .. } => {
+                    (PaymentType::OnlineBankingPl, bank_name.clone())
+                }
+                api::BankRedirectData::BancontactCard { .. } => Err(
+                    errors::ConnectorError::NotImplemented("Bancontact through Adyen".to_string()),
+                )?,
+            };
+            Ok(AdyenPaymentMethod::BankRedirect(AdyenBankRedirectData {
+                payment_type,
+                issuer,
+            }))
+        }
+        api::PaymentMethod::LocalBankTransfer(ref bank_transfer_data) => {
+            let payment_type = match bank_transfer_data {
+                api::BankTransferData::Pix { .. } => PaymentType::Pix,
+                api::BankTransferData::Boleto { .. } => PaymentType::Boletobancario,
+            };
+            Ok(AdyenPaymentMethod::BankTransfer(AdyenBankTransferData {
+                payment_type,
+            }))
+        }
+        // Adyen recurring payments need a stored payment method against a shopper reference,
+        // which is not set up for any payment method yet
+        api_models::payments::PaymentMethod::MandatePayment => Err(
+            errors::ConnectorError::NotImplemented("Recurring payments through Adyen".to_string())
+                .into(),
+        ),
         api_models::payments::PaymentMethod::BankTransfer
+        | api_models::payments::PaymentMethod::Upi(_)
         | api_models::payments::PaymentMethod::Paypal => {
             Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into())
         }
@@ -568,6 +781,7 @@ fn get_card_specific_payment_data(
         delivery_address: None,
         country_code: None,
         line_items: None,
+        social_security_number: None,
     })
 }
 
@@ -599,6 +813,7 @@ fn get_wallet_specific_payment_data(
         delivery_address: None,
         country_code: None,
         line_items: None,
+        social_security_number: None,
     })
 }
 
@@ -637,6 +852,147 @@ fn get_paylater_specific_payment_data(
         delivery_address,
         country_code,
         line_items,
+        social_security_number: None,
+    })
+}
+
+fn get_bank_debit_specific_payment_data(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<AdyenPaymentRequest, error_stack::Report<errors::ConnectorError>> {
+    let amount = get_amount_data(item);
+    let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+    let additional_data = get_additional_data(item);
+    let payment_method = get_payment_method_data(item)?;
+    let shopper_interaction = AdyenShopperInteraction::from(item);
+    let recurring_processing_model = get_recurring_processing_model(item);
+    let return_url = item.get_return_url()?;
+    let billing_details = match item.request.payment_method_data {
+        api::PaymentMethod::BankDebit(ref bank_debit_data) => {
+            Some(bank_debit_data.get_billing_details())
+        }
+        _ => None,
+    };
+    let shopper_email = item
+        .request
+        .email
+        .clone()
+        .or_else(|| billing_details.map(|billing| billing.email.clone()));
+    let billing_address = get_address_info(item.address.billing.as_ref()).or_else(|| {
+        billing_details
+            .and_then(|billing| billing.address.as_ref())
+            .map(Address::from)
+    });
+    let country_code = get_country_code(item);
+    Ok(AdyenPaymentRequest {
+        amount,
+        merchant_account: auth_type.merchant_account,
+        payment_method,
+        reference: item.payment_id.to_string(),
+        return_url,
+        shopper_interaction,
+        recurring_processing_model,
+        browser_info: None,
+        additional_data,
+        telephone_number: None,
+        shopper_name: None,
+        shopper_email,
+        billing_address,
+        delivery_address: None,
+        country_code,
+        line_items: None,
+        social_security_number: None,
+    })
+}
+
+fn get_bank_redirect_specific_payment_data(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<AdyenPaymentRequest, error_stack::Report<errors::ConnectorError>> {
+    let amount = get_amount_data(item);
+    let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+    let additional_data = get_additional_data(item);
+    let payment_method = get_payment_method_data(item)?;
+    let shopper_interaction = AdyenShopperInteraction::from(item);
+    let return_url = item.get_return_url()?;
+    let (shopper_email, country_code) = match item.request.payment_method_data {
+        api::PaymentMethod::BankRedirect(ref bank_redirect_data) => {
+            let country_code = match bank_redirect_data {
+                api::BankRedirectData::Sofort { country, .. } => Some(country.clone()),
+                _ => get_country_code(item),
+            };
+            let shopper_email = item
+                .request
+                .email
+                .clone()
+                .or_else(|| bank_redirect_data.get_billing_details().email.clone());
+            (shopper_email, country_code)
+        }
+        _ => (item.request.email.clone(), get_country_code(item)),
+    };
+    Ok(AdyenPaymentRequest {
+        amount,
+        merchant_account: auth_type.merchant_account,
+        payment_method,
+        reference: item.payment_id.to_string(),
+        return_url,
+        shopper_interaction,
+        recurring_processing_model: None,
+        browser_info: None,
+        additional_data,
+        telephone_number: None,
+        shopper_name: None,
+        shopper_email,
+        billing_address: get_address_info(item.address.billing.as_ref()),
+        delivery_address: None,
+        country_code,
+        line_items: None,
+        social_security_number: None,
+    })
+}
+
+fn get_bank_transfer_specific_payment_data(
+    item: &types::PaymentsAuthorizeRouterData,
+) -> Result<AdyenPaymentRequest, error_stack::Report<errors::ConnectorError>> {
+    let amount = get_amount_data(item);
+    let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+    let payment_method = get_payment_method_data(item)?;
+    let shopper_interaction = AdyenShopperInteraction::from(item);
+    let return_url = item.get_return_url()?;
+    let billing_details = match item.request.payment_method_data {
+        api::PaymentMethod::LocalBankTransfer(ref bank_transfer_data) => {
+            bank_transfer_data.get_billing_details()
+        }
+        _ => Err(errors::ConnectorError::NotImplemented(
+            "Payment methods".to_string(),
+        ))?,
+    };
+    let (first_name, last_name) = match billing_details.name.peek().split_once(' ') {
+        Some((first_name, last_name)) => (first_name.to_string(), Some(last_name.to_string())),
+        None => (billing_details.name.peek().to_string(), None),
+    };
+    let shopper_name = Some(ShopperName {
+        first_name: Some(Secret::new(first_name)),
+        last_name: last_name.map(Secret::new),
+    });
+    let billing_address = get_address_info(item.address.billing.as_ref())
+        .or_else(|| billing_details.address.as_ref().map(Address::from));
+    Ok(AdyenPaymentRequest {
+        amount,
+        merchant_account: auth_type.merchant_account,
+        payment_method,
+        reference: item.payment_id.to_string(),
+        return_url,
+        shopper_interaction,
+        recurring_processing_model: None,
+        browser_info: None,
+        additional_data: None,
+        telephone_number: None,
+        shopper_name,
+        shopper_email: Some(billing_details.email.clone()),
+        billing_address,
+        delivery_address: None,
+        country_code: Some("BR".to_string()),
+        line_items: None,
+        social_security_number: billing_details.tax_id.clone(),
     })
 }
 
@@ -729,6 +1085,50 @@ pub fn get_adyen_response(
     Ok((status, error, payments_response_data))
 }
 
+pub fn get_present_to_shopper_response(
+    response: AdyenPresentToShopperResponse,
+    status_code: u16,
+) -> errors::CustomResult<
+    (
+        storage_enums::AttemptStatus,
+        Option<types::ErrorResponse>,
+        types::PaymentsResponseData,
+    ),
+    errors::ConnectorError,
+> {
+    let error = if response.refusal_reason.is_some() || response.refusal_reason_code.is_some() {
+        Some(types::ErrorResponse {
+            code: response
+                .refusal_reason_code
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .refusal_reason
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: None,
+            status_code,
+        })
+    } else {
+        None
+    };
+
+    let connector_metadata =
+        utils::get_bank_transfer_next_steps(api::BankTransferInstructions::from(response.action))?;
+
+    // The payment stays pending on the shopper until they complete it with the instructions
+    let payments_response_data = types::PaymentsResponseData::TransactionResponse {
+        resource_id: types::ResponseId::ConnectorTransactionId(response.psp_reference),
+        redirection_data: None,
+        redirect: false,
+        mandate_reference: None,
+        connector_metadata: Some(connector_metadata),
+    };
+    Ok((
+        storage_enums::AttemptStatus::AuthenticationPending,
+        error,
+        payments_response_data,
+    ))
+}
+
 pub fn get_redirection_response(
     response: AdyenRedirectionResponse,
     status_code: u16,
@@ -806,6 +1206,9 @@ impl<F, Req>
         let item = items.0;
         let is_manual_capture = items.1;
         let (status, error, payment_response_data) = match item.response {
+            AdyenPaymentResponse::AdyenPresentToShopperResponse(response) => {
+                get_present_to_shopper_response(response, item.http_code)?
+            }
             AdyenPaymentResponse::AdyenResponse(response) => {
                 get_adyen_response(response, is_manual_capture, item.http_code)?
             }
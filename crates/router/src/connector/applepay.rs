@@ -28,6 +28,23 @@ impl ConnectorCommon for Applepay {
     fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
         connectors.applepay.base_url.as_ref()
     }
+
+    fn get_capabilities(
+        &self,
+        capabilities: api::ConnectorCapabilities,
+    ) -> api::ConnectorCapabilities {
+        use api::enums::{CaptureMethod, PaymentMethodType};
+
+        api::ConnectorCapabilities {
+            payment_methods: vec![PaymentMethodType::Wallet],
+            currencies: None,
+            countries: None,
+            capture_methods: vec![CaptureMethod::Automatic],
+            supports_mandates: false,
+            supports_network_tokens: false,
+            ..capabilities
+        }
+    }
 }
 
 impl api::Payment for Applepay {}
@@ -8,15 +8,17 @@ use transformers as authorizedotnet;
 
 use crate::{
     configs::settings,
+    connector::utils as conn_utils,
     consts,
     core::errors::{self, CustomResult},
+    db::StorageInterface,
     headers,
     services::{self, logger},
     types::{
         self,
         api::{self, ConnectorCommon},
     },
-    utils::{self, BytesExt},
+    utils::{self, crypto, ByteSliceExt, BytesExt},
 };
 
 #[derive(Debug, Clone)]
@@ -34,6 +36,35 @@ impl ConnectorCommon for Authorizedotnet {
     fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
         connectors.authorizedotnet.base_url.as_ref()
     }
+
+    fn get_capabilities(
+        &self,
+        capabilities: api::ConnectorCapabilities,
+    ) -> api::ConnectorCapabilities {
+        use api::enums::{CaptureMethod, Currency, PaymentMethodType};
+
+        api::ConnectorCapabilities {
+            payment_methods: vec![PaymentMethodType::Card],
+            currencies: Some(vec![
+                Currency::AUD,
+                Currency::CAD,
+                Currency::CHF,
+                Currency::DKK,
+                Currency::EUR,
+                Currency::GBP,
+                Currency::NOK,
+                Currency::NZD,
+                Currency::PLN,
+                Currency::SEK,
+                Currency::USD,
+            ]),
+            countries: None,
+            capture_methods: vec![CaptureMethod::Automatic],
+            supports_mandates: false,
+            supports_network_tokens: false,
+            ..capabilities
+        }
+    }
 }
 
 impl api::Payment for Authorizedotnet {}
@@ -570,25 +601,83 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
 
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Authorizedotnet {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_algorithm(
+        &self,
+        _headers: &actix_web::http::header::HeaderMap,
+        _body: &[u8],
+    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
+        Ok(Box::new(crypto::HmacSha512))
+    }
+
+    fn get_webhook_source_verification_signature(
         &self,
+        headers: &actix_web::http::header::HeaderMap,
         _body: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature = conn_utils::get_header_key_value("X-ANET-Signature", headers)?;
+        let signature = signature
+            .strip_prefix("sha512=")
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)
+            .into_report()?;
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        _headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+        _merchant_id: &str,
+        _secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok(body.to_vec())
+    }
+
+    async fn get_webhook_source_verification_merchant_secret(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let key = format!("whsec_verification_{}_{}", self.id(), merchant_id);
+        let secret = db
+            .get_key(&key)
+            .await
+            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
+
+        Ok(secret)
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        body: &[u8],
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: authorizedotnet::AuthorizedotnetWebhookObject = body
+            .parse_struct("AuthorizedotnetWebhookObject")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        Ok(webhook.payload.id)
     }
 
     fn get_webhook_event_type(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: authorizedotnet::AuthorizedotnetWebhookObject = body
+            .parse_struct("AuthorizedotnetWebhookObject")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        api::IncomingWebhookEvent::try_from(&webhook)
     }
 
     fn get_webhook_resource_object(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let webhook: authorizedotnet::AuthorizedotnetWebhookObject = body
+            .parse_struct("AuthorizedotnetWebhookObject")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        let response = authorizedotnet::AuthorizedotnetSyncResponse::try_from(webhook)?;
+        utils::Encode::<authorizedotnet::AuthorizedotnetSyncResponse>::encode_to_value(&response)
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
     }
 }
 
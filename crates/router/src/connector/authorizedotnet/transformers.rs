@@ -1,5 +1,5 @@
 use common_utils::ext_traits::{Encode, ValueExt};
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -80,7 +80,12 @@ impl From<api_models::payments::PaymentMethod> for PaymentDetails {
                     card_code: Some(ccard.card_cvc.clone()),
                 })
             }
-            api::PaymentMethod::BankTransfer => Self::BankAccount(BankAccountDetails {
+            api::PaymentMethod::BankTransfer
+            | api::PaymentMethod::BankDebit(_)
+            | api::PaymentMethod::BankRedirect(_)
+            | api::PaymentMethod::LocalBankTransfer(_)
+            | api::PaymentMethod::Upi(_)
+            | api::PaymentMethod::MandatePayment => Self::BankAccount(BankAccountDetails {
                 account_number: "XXXXX".to_string().into(),
             }),
             api::PaymentMethod::PayLater(_) => Self::Klarna,
@@ -513,7 +518,7 @@ impl TryFrom<&types::PaymentsSyncRouterData> for AuthorizedotnetCreateSyncReques
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum SyncStatus {
     RefundSettledSuccessfully,
@@ -526,7 +531,7 @@ pub enum SyncStatus {
     CouldNotVoid,
     GeneralError,
 }
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SyncTransactionResponse {
     #[serde(rename = "transId")]
@@ -534,7 +539,7 @@ pub struct SyncTransactionResponse {
     transaction_status: SyncStatus,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct AuthorizedotnetSyncResponse {
     transaction: SyncTransactionResponse,
 }
@@ -637,3 +642,97 @@ fn construct_refund_payment_details(masked_number: String) -> PaymentDetails {
         card_code: None,
     })
 }
+
+#[derive(Debug, Deserialize)]
+pub enum AuthorizedotnetWebhookEvent {
+    #[serde(rename = "net.authorize.payment.authorization.created")]
+    AuthorizationCreated,
+    #[serde(rename = "net.authorize.payment.authcapture.created")]
+    AuthCaptureCreated,
+    #[serde(rename = "net.authorize.payment.capture.created")]
+    CaptureCreated,
+    #[serde(rename = "net.authorize.payment.priorAuthCapture.created")]
+    PriorAuthCaptureCreated,
+    #[serde(rename = "net.authorize.payment.void.created")]
+    VoidCreated,
+    #[serde(rename = "net.authorize.payment.refund.created")]
+    RefundCreated,
+    #[serde(rename = "net.authorize.payment.fraud.declined")]
+    FraudDeclined,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedotnetWebhookPayload {
+    pub id: String,
+    // Unlike the transaction responses, webhooks carry the response code as a number
+    pub response_code: Option<u8>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthorizedotnetWebhookObject {
+    pub event_type: AuthorizedotnetWebhookEvent,
+    pub payload: AuthorizedotnetWebhookPayload,
+}
+
+impl AuthorizedotnetWebhookObject {
+    /// The status the transaction is in after the event, as reported by the transaction details
+    /// API
+    fn get_sync_status(&self) -> Option<SyncStatus> {
+        let payment_status = match self.payload.response_code {
+            Some(1) => Some(AuthorizedotnetPaymentStatus::Approved),
+            Some(2) => Some(AuthorizedotnetPaymentStatus::Declined),
+            Some(3) => Some(AuthorizedotnetPaymentStatus::Error),
+            Some(4) => Some(AuthorizedotnetPaymentStatus::HeldForReview),
+            _ => None,
+        };
+        match (&self.event_type, payment_status) {
+            (AuthorizedotnetWebhookEvent::FraudDeclined, _)
+            | (_, Some(AuthorizedotnetPaymentStatus::Declined)) => Some(SyncStatus::Declined),
+            (_, Some(AuthorizedotnetPaymentStatus::Error)) => Some(SyncStatus::GeneralError),
+            (
+                AuthorizedotnetWebhookEvent::AuthorizationCreated,
+                Some(AuthorizedotnetPaymentStatus::Approved),
+            ) => Some(SyncStatus::AuthorizedPendingCapture),
+            (
+                AuthorizedotnetWebhookEvent::AuthCaptureCreated
+                | AuthorizedotnetWebhookEvent::CaptureCreated
+                | AuthorizedotnetWebhookEvent::PriorAuthCaptureCreated,
+                Some(AuthorizedotnetPaymentStatus::Approved),
+            ) => Some(SyncStatus::CapturedPendingSettlement),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<&AuthorizedotnetWebhookObject> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(webhook: &AuthorizedotnetWebhookObject) -> Result<Self, Self::Error> {
+        match webhook.get_sync_status() {
+            Some(SyncStatus::AuthorizedPendingCapture | SyncStatus::CapturedPendingSettlement) => {
+                Ok(Self::PaymentIntentSuccess)
+            }
+            Some(SyncStatus::Declined | SyncStatus::GeneralError) => Ok(Self::PaymentIntentFailure),
+            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report(),
+        }
+    }
+}
+
+impl TryFrom<AuthorizedotnetWebhookObject> for AuthorizedotnetSyncResponse {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(webhook: AuthorizedotnetWebhookObject) -> Result<Self, Self::Error> {
+        let transaction_status = webhook
+            .get_sync_status()
+            .ok_or(errors::ConnectorError::WebhookResourceObjectNotFound)
+            .into_report()?;
+        Ok(Self {
+            transaction: SyncTransactionResponse {
+                transaction_id: webhook.payload.id,
+                transaction_status,
+            },
+        })
+    }
+}
@@ -1,19 +1,33 @@
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    consts,
     core::errors,
-    pii::PeekInterface,
+    pii::{PeekInterface, Secret},
     types::{self, api, storage::enums},
     utils::OptionExt,
 };
 
+/// Amounts are sent to Braintree in the base unit of the currency, e.g. `10.00` for 1000 cents
+fn get_amount_value(amount: i64, currency: enums::Currency) -> String {
+    match currency {
+        // Currencies which have no minor unit
+        enums::Currency::JPY | enums::Currency::KRW => amount.to_string(),
+        _ => format!("{}.{:02}", amount / 100, amount % 100),
+    }
+}
+
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
 pub struct DeviceData;
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct PaymentOptions {
     submit_for_settlement: bool,
+    /// Vaults the payment method once the transaction succeeds, for the payments of a mandate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store_in_vault_on_success: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Eq, PartialEq)]
@@ -46,6 +60,7 @@ impl TryFrom<&types::PaymentsSessionRouterData> for BraintreeSessionRequest {
 #[serde(rename_all = "camelCase")]
 pub struct TransactionBody {
     amount: String,
+    order_id: String,
     device_data: DeviceData,
     options: PaymentOptions,
     #[serde(flatten)]
@@ -55,18 +70,27 @@ pub struct TransactionBody {
 }
 
 #[derive(Debug, Serialize, Eq, PartialEq)]
-#[serde(rename_all = "camelCase")]
-#[serde(tag = "type")]
+#[serde(untagged)]
 pub enum PaymentMethodType {
     CreditCard(Card),
     PaymentMethodNonce(Nonce),
+    PaymentMethodToken(VaultedToken),
 }
 
+/// The nonce the client SDK of Braintree generated for a wallet, such as PayPal
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct Nonce {
     payment_method_nonce: String,
 }
 
+/// The token of a payment method vaulted with Braintree
+#[derive(Default, Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VaultedToken {
+    payment_method_token: String,
+}
+
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Card {
@@ -90,39 +114,57 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for BraintreePaymentsRequest {
             Some(enums::CaptureMethod::Automatic) | None
         );
 
-        let amount = item.request.amount.to_string();
+        let amount = get_amount_value(item.request.amount, item.request.currency);
         let device_data = DeviceData {};
         let options = PaymentOptions {
             submit_for_settlement,
+            store_in_vault_on_success: item.request.setup_mandate_details.as_ref().map(|_| true),
         };
         let kind = "sale".to_string();
 
-        let payment_method_data_type = match item.request.payment_method_data {
-            api::PaymentMethod::Card(ref ccard) => Ok(PaymentMethodType::CreditCard(Card {
-                credit_card: CardDetails {
-                    number: ccard.card_number.peek().clone(),
-                    expiration_month: ccard.card_exp_month.peek().clone(),
-                    expiration_year: ccard.card_exp_year.peek().clone(),
-                    cvv: ccard.card_cvc.peek().clone(),
-                },
+        let connector_mandate_id = item
+            .request
+            .mandate_id
+            .clone()
+            .and_then(|mandate_ids| mandate_ids.connector_mandate_id);
+        // Payments with a mandate are charged to the payment method vaulted with Braintree
+        let payment_method_data_type = match connector_mandate_id {
+            Some(payment_method_token) => Ok(PaymentMethodType::PaymentMethodToken(VaultedToken {
+                payment_method_token,
             })),
-            api::PaymentMethod::Wallet(ref wallet_data) => {
-                Ok(PaymentMethodType::PaymentMethodNonce(Nonce {
-                    payment_method_nonce: wallet_data
-                        .token
-                        .to_owned()
-                        .get_required_value("token")
-                        .change_context(errors::ConnectorError::RequestEncodingFailed)
-                        .attach_printable("No token passed")?,
-                }))
-            }
-            _ => Err(errors::ConnectorError::NotImplemented(format!(
-                "Current Payment Method - {:?}",
-                item.request.payment_method_data
-            ))),
+            None => match item.request.payment_method_data {
+                api::PaymentMethod::Card(ref ccard) => Ok(PaymentMethodType::CreditCard(Card {
+                    credit_card: CardDetails {
+                        number: ccard.card_number.peek().clone(),
+                        expiration_month: ccard.card_exp_month.peek().clone(),
+                        expiration_year: ccard.card_exp_year.peek().clone(),
+                        cvv: ccard.card_cvc.peek().clone(),
+                    },
+                })),
+                // PayPal is paid through Braintree with the nonce the client SDK generates once the
+                // customer approves the payment, using the client token of the session
+                api::PaymentMethod::Wallet(ref wallet_data) => {
+                    Ok(PaymentMethodType::PaymentMethodNonce(Nonce {
+                        payment_method_nonce: wallet_data
+                            .token
+                            .to_owned()
+                            .get_required_value("token")
+                            .change_context(errors::ConnectorError::RequestEncodingFailed)
+                            .attach_printable("No token passed")?,
+                    }))
+                }
+                api::PaymentMethod::Paypal => Err(errors::ConnectorError::MissingRequiredField {
+                    field_name: "payment_method_data.wallet.token",
+                }),
+                _ => Err(errors::ConnectorError::NotImplemented(format!(
+                    "Current Payment Method - {:?}",
+                    item.request.payment_method_data
+                ))),
+            },
         }?;
         let braintree_transaction_body = TransactionBody {
             amount,
+            order_id: item.payment_id.clone(),
             device_data,
             options,
             payment_method_data_type,
@@ -134,6 +176,26 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for BraintreePaymentsRequest {
     }
 }
 
+#[derive(Default, Debug, Clone, Serialize)]
+pub struct BraintreeCaptureRequest {
+    transaction: Amount,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for BraintreeCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        // The whole authorized amount is captured when no amount is sent
+        Ok(Self {
+            transaction: Amount {
+                amount: item
+                    .request
+                    .amount_to_capture
+                    .map(|amount| get_amount_value(amount, item.request.currency)),
+            },
+        })
+    }
+}
+
 pub struct BraintreeAuthType {
     pub(super) api_key: String,
     pub(super) merchant_account: String,
@@ -153,7 +215,7 @@ impl TryFrom<&types::ConnectorAuthType> for BraintreeAuthType {
     }
 }
 
-#[derive(Debug, Default, Clone, Deserialize, Eq, PartialEq)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "snake_case")]
 pub enum BraintreePaymentStatus {
     Succeeded,
@@ -177,7 +239,9 @@ impl From<BraintreePaymentStatus> for enums::AttemptStatus {
         match item {
             BraintreePaymentStatus::Succeeded
             | BraintreePaymentStatus::SubmittedForSettlement
-            | BraintreePaymentStatus::Settling => Self::Charged,
+            | BraintreePaymentStatus::Settling
+            | BraintreePaymentStatus::Settled
+            | BraintreePaymentStatus::SettlementConfirmed => Self::Charged,
             BraintreePaymentStatus::AuthorizedExpired => Self::AuthorizationFailed,
             BraintreePaymentStatus::Failed
             | BraintreePaymentStatus::GatewayRejected
@@ -185,7 +249,27 @@ impl From<BraintreePaymentStatus> for enums::AttemptStatus {
             | BraintreePaymentStatus::SettlementDeclined => Self::Failure,
             BraintreePaymentStatus::Authorized => Self::Authorized,
             BraintreePaymentStatus::Voided => Self::Voided,
-            _ => Self::Pending,
+            BraintreePaymentStatus::SettlementPending => Self::Pending,
+        }
+    }
+}
+
+impl From<BraintreePaymentStatus> for enums::RefundStatus {
+    fn from(item: BraintreePaymentStatus) -> Self {
+        match item {
+            BraintreePaymentStatus::Succeeded
+            | BraintreePaymentStatus::Settled
+            | BraintreePaymentStatus::SettlementConfirmed => Self::Success,
+            BraintreePaymentStatus::Failed
+            | BraintreePaymentStatus::GatewayRejected
+            | BraintreePaymentStatus::ProcessorDeclined
+            | BraintreePaymentStatus::SettlementDeclined
+            | BraintreePaymentStatus::Voided
+            | BraintreePaymentStatus::AuthorizedExpired => Self::Failure,
+            BraintreePaymentStatus::Authorized
+            | BraintreePaymentStatus::SubmittedForSettlement
+            | BraintreePaymentStatus::Settling
+            | BraintreePaymentStatus::SettlementPending => Self::Pending,
         }
     }
 }
@@ -203,17 +287,30 @@ impl<F, T>
             types::PaymentsResponseData,
         >,
     ) -> Result<Self, Self::Error> {
-        Ok(Self {
-            status: enums::AttemptStatus::from(item.response.transaction.status),
-            response: Ok(types::PaymentsResponseData::TransactionResponse {
-                resource_id: types::ResponseId::ConnectorTransactionId(
-                    item.response.transaction.id,
-                ),
+        let transaction = item.response.transaction;
+        let status = enums::AttemptStatus::from(transaction.status.clone());
+        // Declined transactions are returned along with the response code of the processor
+        let response = match transaction.processor_response_code.clone() {
+            Some(code) if status == enums::AttemptStatus::Failure => Err(types::ErrorResponse {
+                code,
+                message: transaction
+                    .processor_response_text
+                    .clone()
+                    .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+                reason: transaction.processor_response_text.clone(),
+                status_code: item.http_code,
+            }),
+            _ => Ok(types::PaymentsResponseData::TransactionResponse {
+                mandate_reference: transaction.get_payment_method_token(),
+                resource_id: types::ResponseId::ConnectorTransactionId(transaction.id),
                 redirection_data: None,
                 redirect: false,
-                mandate_reference: None,
                 connector_metadata: None,
             }),
+        };
+        Ok(Self {
+            status,
+            response,
             ..item.data
         })
     }
@@ -244,7 +341,7 @@ impl<F, T>
     }
 }
 
-#[derive(Default, Debug, Clone, Deserialize)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BraintreePaymentsResponse {
     transaction: TransactionResponse,
@@ -262,13 +359,33 @@ pub struct BraintreeSessionTokenResponse {
     pub client_token: ClientToken,
 }
 
-#[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionResponse {
     id: String,
     currency_iso_code: String,
     amount: String,
     status: BraintreePaymentStatus,
+    credit_card: Option<PaymentMethodDetails>,
+    paypal: Option<PaymentMethodDetails>,
+    processor_response_code: Option<String>,
+    processor_response_text: Option<String>,
+}
+
+impl TransactionResponse {
+    /// The token of the payment method, when it was vaulted along with the transaction
+    fn get_payment_method_token(&self) -> Option<String> {
+        self.credit_card
+            .as_ref()
+            .or(self.paypal.as_ref())
+            .and_then(|payment_method| payment_method.token.clone())
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentMethodDetails {
+    token: Option<String>,
 }
 
 #[derive(Debug, Default, Deserialize)]
@@ -278,8 +395,26 @@ pub struct ErrorResponse {
 }
 
 #[derive(Default, Debug, Clone, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
 pub struct ApiErrorResponse {
     pub message: String,
+    /// The declined transaction, when the error is a decline of the processor
+    pub transaction: Option<TransactionResponse>,
+}
+
+impl ErrorResponse {
+    pub fn get_processor_response(&self) -> (Option<String>, Option<String>) {
+        self.api_error_response
+            .transaction
+            .as_ref()
+            .map(|transaction| {
+                (
+                    transaction.processor_response_code.clone(),
+                    transaction.processor_response_text.clone(),
+                )
+            })
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Default, Debug, Clone, Serialize)]
@@ -289,73 +424,144 @@ pub struct BraintreeRefundRequest {
 
 #[derive(Default, Debug, Serialize, Clone)]
 pub struct Amount {
+    #[serde(skip_serializing_if = "Option::is_none")]
     amount: Option<String>,
 }
 
 impl<F> TryFrom<&types::RefundsRouterData<F>> for BraintreeRefundRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
-    fn try_from(_item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
         Ok(Self {
-            transaction: Amount { amount: None },
+            transaction: Amount {
+                amount: Some(get_amount_value(
+                    item.request.refund_amount,
+                    item.request.currency,
+                )),
+            },
         })
     }
 }
 
-#[allow(dead_code)]
-#[derive(Debug, Default, Deserialize, Clone)]
-pub enum RefundStatus {
-    Succeeded,
-    Failed,
-    #[default]
-    Processing,
-}
-
-impl From<RefundStatus> for enums::RefundStatus {
-    fn from(item: RefundStatus) -> Self {
-        match item {
-            RefundStatus::Succeeded => Self::Success,
-            RefundStatus::Failed => Self::Failure,
-            RefundStatus::Processing => Self::Pending,
-        }
-    }
-}
-
+/// Refunds are transactions of their own with Braintree, crediting the customer
 #[derive(Default, Debug, Clone, Deserialize)]
 pub struct RefundResponse {
-    pub id: String,
-    pub status: RefundStatus,
+    pub transaction: TransactionResponse,
 }
 
-impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
-    for types::RefundsRouterData<api::Execute>
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RefundResponse>>
+    for types::RefundsRouterData<F>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: types::RefundsResponseRouterData<api::Execute, RefundResponse>,
+        item: types::RefundsResponseRouterData<F, RefundResponse>,
     ) -> Result<Self, Self::Error> {
         Ok(Self {
             response: Ok(types::RefundsResponseData {
-                connector_refund_id: item.response.id,
-                refund_status: enums::RefundStatus::from(item.response.status),
+                refund_status: enums::RefundStatus::from(item.response.transaction.status),
+                connector_refund_id: item.response.transaction.id,
             }),
             ..item.data
         })
     }
 }
 
-impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
-    for types::RefundsRouterData<api::RSync>
-{
-    type Error = error_stack::Report<errors::ConnectorError>;
-    fn try_from(
-        item: types::RefundsResponseRouterData<api::RSync, RefundResponse>,
-    ) -> Result<Self, Self::Error> {
-        Ok(Self {
-            response: Ok(types::RefundsResponseData {
-                connector_refund_id: item.response.id,
-                refund_status: enums::RefundStatus::from(item.response.status),
-            }),
-            ..item.data
+// Webhooks
+/// The form Braintree posts webhooks as, with the base64 encoded XML notification as payload
+#[derive(Debug, Deserialize)]
+pub struct BraintreeWebhookBody {
+    pub bt_signature: String,
+    pub bt_payload: String,
+}
+
+impl BraintreeWebhookBody {
+    /// The signature of the payload for the public key, out of the `public_key|signature` pairs
+    /// separated by `&`
+    pub fn get_signature(&self, public_key: &str) -> Option<&str> {
+        self.bt_signature.split('&').find_map(|pair| {
+            pair.split_once('|')
+                .filter(|(key, _)| *key == public_key)
+                .map(|(_, signature)| signature)
         })
     }
 }
+
+/// The secret a merchant configures to verify the webhooks of Braintree: the API key pair
+/// webhooks are signed with
+#[derive(Debug, Deserialize)]
+pub struct BraintreeWebhookSecret {
+    pub public_key: String,
+    pub private_key: Secret<String>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BraintreeWebhookEventType {
+    TransactionSettled,
+    TransactionSettlementDeclined,
+    #[serde(other)]
+    Unknown,
+}
+
+impl TryFrom<BraintreeWebhookEventType> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(event_type: BraintreeWebhookEventType) -> Result<Self, Self::Error> {
+        match event_type {
+            BraintreeWebhookEventType::TransactionSettled => Ok(Self::PaymentIntentSuccess),
+            BraintreeWebhookEventType::TransactionSettlementDeclined => {
+                Ok(Self::PaymentIntentFailure)
+            }
+            BraintreeWebhookEventType::Unknown => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BraintreeWebhookNotification {
+    pub kind: BraintreeWebhookEventType,
+    pub subject: BraintreeWebhookSubject,
+}
+
+impl BraintreeWebhookNotification {
+    pub fn try_from_xml(payload: &[u8]) -> Result<Self, quick_xml::DeError> {
+        quick_xml::de::from_reader(payload)
+    }
+
+    pub fn get_transaction(
+        self,
+    ) -> Result<BraintreeWebhookTransaction, error_stack::Report<errors::ConnectorError>> {
+        self.subject
+            .transaction
+            .ok_or(errors::ConnectorError::WebhookReferenceIdNotFound)
+            .into_report()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BraintreeWebhookSubject {
+    pub transaction: Option<BraintreeWebhookTransaction>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct BraintreeWebhookTransaction {
+    pub id: String,
+    pub status: BraintreePaymentStatus,
+    pub amount: String,
+    pub currency_iso_code: String,
+}
+
+impl From<BraintreeWebhookTransaction> for BraintreePaymentsResponse {
+    fn from(transaction: BraintreeWebhookTransaction) -> Self {
+        Self {
+            transaction: TransactionResponse {
+                id: transaction.id,
+                currency_iso_code: transaction.currency_iso_code,
+                amount: transaction.amount,
+                status: transaction.status,
+                ..Default::default()
+            },
+        }
+    }
+}
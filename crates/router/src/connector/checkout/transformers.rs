@@ -21,10 +21,23 @@ pub struct CardSource {
     pub expiry_year: Option<pii::Secret<String>>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct NetworkTokenSource {
+    #[serde(rename = "type")]
+    pub source_type: String,
+    pub token: pii::Secret<String, pii::CardNumber>,
+    pub expiry_month: pii::Secret<String>,
+    pub expiry_year: pii::Secret<String>,
+    pub token_type: String,
+    pub cryptogram: pii::Secret<String>,
+    pub eci: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(untagged)]
 pub enum Source {
     Card(CardSource),
+    NetworkToken(NetworkTokenSource),
 }
 
 pub struct CheckoutAuthType {
@@ -55,6 +68,29 @@ pub struct PaymentsRequest {
 pub struct CheckoutThreeDS {
     enabled: bool,
     force_3ds: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    eci: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cryptogram: Option<pii::Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    xid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
+}
+
+impl From<&types::AuthenticationData> for CheckoutThreeDS {
+    fn from(authentication_data: &types::AuthenticationData) -> Self {
+        // The payment was authenticated by an authentication connector, so Checkout authorizes
+        // it with the result of that authentication instead of running 3DS again
+        Self {
+            enabled: true,
+            force_3ds: false,
+            eci: authentication_data.eci.clone(),
+            cryptogram: Some(authentication_data.authentication_value.clone()),
+            xid: authentication_data.ds_transaction_id.clone(),
+            version: Some(authentication_data.message_version.clone()),
+        }
+    }
 }
 
 impl TryFrom<&types::ConnectorAuthType> for CheckoutAuthType {
@@ -76,19 +112,33 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
         let ccard = match item.request.payment_method_data {
             api::PaymentMethod::Card(ref ccard) => Some(ccard),
             api::PaymentMethod::BankTransfer
+            | api::PaymentMethod::BankDebit(_)
+            | api::PaymentMethod::BankRedirect(_)
+            | api::PaymentMethod::LocalBankTransfer(_)
+            | api::PaymentMethod::Upi(_)
+            | api::PaymentMethod::MandatePayment
             | api::PaymentMethod::Wallet(_)
             | api::PaymentMethod::PayLater(_)
             | api::PaymentMethod::Paypal => None,
         };
 
-        let three_ds = match item.auth_type {
-            enums::AuthenticationType::ThreeDs => CheckoutThreeDS {
+        let three_ds = match (&item.request.authentication_data, item.auth_type) {
+            (Some(authentication_data), _) => CheckoutThreeDS::from(authentication_data),
+            (None, enums::AuthenticationType::ThreeDs) => CheckoutThreeDS {
                 enabled: true,
                 force_3ds: true,
+                eci: None,
+                cryptogram: None,
+                xid: None,
+                version: None,
             },
-            enums::AuthenticationType::NoThreeDs => CheckoutThreeDS {
+            (None, enums::AuthenticationType::NoThreeDs) => CheckoutThreeDS {
                 enabled: false,
                 force_3ds: false,
+                eci: None,
+                cryptogram: None,
+                xid: None,
+                version: None,
             },
         };
 
@@ -108,12 +158,27 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
             Some(enums::CaptureMethod::Automatic)
         );
 
-        let source_var = Source::Card(CardSource {
-            source_type: Some("card".to_owned()),
-            number: ccard.map(|x| x.card_number.clone()),
-            expiry_month: ccard.map(|x| x.card_exp_month.clone()),
-            expiry_year: ccard.map(|x| x.card_exp_year.clone()),
-        });
+        let source_var = match item.request.network_token {
+            Some(ref network_token) => Source::NetworkToken(NetworkTokenSource {
+                source_type: "network_token".to_owned(),
+                token: network_token.token_number.clone(),
+                expiry_month: network_token.token_exp_month.clone(),
+                expiry_year: network_token.token_exp_year.clone(),
+                token_type: match network_token.token_service {
+                    types::NetworkTokenService::Vts => "vts",
+                    types::NetworkTokenService::Mdes => "mdes",
+                }
+                .to_owned(),
+                cryptogram: network_token.cryptogram.clone(),
+                eci: network_token.eci.clone(),
+            }),
+            None => Source::Card(CardSource {
+                source_type: Some("card".to_owned()),
+                number: ccard.map(|x| x.card_number.clone()),
+                expiry_month: ccard.map(|x| x.card_exp_month.clone()),
+                expiry_year: ccard.map(|x| x.card_exp_year.clone()),
+            }),
+        };
         let connector_auth = &item.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
         let processing_channel_id = auth_type.processing_channel_id;
@@ -129,7 +194,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentsRequest {
     }
 }
 
-#[derive(Default, Clone, Debug, Eq, PartialEq, Deserialize)]
+#[derive(Default, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum CheckoutPaymentStatus {
     Authorized,
     #[default]
@@ -190,16 +255,16 @@ impl From<transformers::Foreign<(CheckoutPaymentStatus, Balances)>>
     }
 }
 
-#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Href {
     href: String,
 }
 
-#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Links {
     redirect: Option<Href>,
 }
-#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct PaymentsResponse {
     id: String,
     amount: Option<i32>,
@@ -209,7 +274,7 @@ pub struct PaymentsResponse {
     balances: Balances,
 }
 
-#[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct Balances {
     available_to_capture: i32,
 }
@@ -592,3 +657,73 @@ impl From<CheckoutRedirectResponseStatus> for enums::AttemptStatus {
         }
     }
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutWebhookEventType {
+    PaymentApproved,
+    PaymentCaptured,
+    PaymentDeclined,
+    PaymentAuthenticationFailed,
+    PaymentExpired,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutWebhookData {
+    pub id: String,
+    pub amount: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CheckoutWebhookBody {
+    #[serde(rename = "type")]
+    pub event_type: CheckoutWebhookEventType,
+    pub data: CheckoutWebhookData,
+}
+
+impl TryFrom<&CheckoutWebhookEventType> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(event_type: &CheckoutWebhookEventType) -> Result<Self, Self::Error> {
+        match event_type {
+            CheckoutWebhookEventType::PaymentApproved
+            | CheckoutWebhookEventType::PaymentCaptured => Ok(Self::PaymentIntentSuccess),
+            CheckoutWebhookEventType::PaymentDeclined
+            | CheckoutWebhookEventType::PaymentAuthenticationFailed
+            | CheckoutWebhookEventType::PaymentExpired => Ok(Self::PaymentIntentFailure),
+            CheckoutWebhookEventType::Unknown => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()
+            }
+        }
+    }
+}
+
+impl TryFrom<CheckoutWebhookBody> for PaymentsResponse {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(webhook: CheckoutWebhookBody) -> Result<Self, Self::Error> {
+        // An approved payment can still be captured for its full amount
+        let (status, available_to_capture) = match webhook.event_type {
+            CheckoutWebhookEventType::PaymentApproved => (
+                CheckoutPaymentStatus::Authorized,
+                webhook.data.amount.unwrap_or_default(),
+            ),
+            CheckoutWebhookEventType::PaymentCaptured => (CheckoutPaymentStatus::Captured, 0),
+            CheckoutWebhookEventType::PaymentDeclined
+            | CheckoutWebhookEventType::PaymentAuthenticationFailed
+            | CheckoutWebhookEventType::PaymentExpired => (CheckoutPaymentStatus::Declined, 0),
+            CheckoutWebhookEventType::Unknown => {
+                Err(errors::ConnectorError::WebhookResourceObjectNotFound).into_report()?
+            }
+        };
+        Ok(Self {
+            id: webhook.data.id,
+            amount: webhook.data.amount,
+            status,
+            links: Links::default(),
+            balances: Balances {
+                available_to_capture,
+            },
+        })
+    }
+}
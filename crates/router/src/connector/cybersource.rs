@@ -111,6 +111,23 @@ impl ConnectorCommon for Cybersource {
             reason: response.reason,
         })
     }
+
+    fn get_capabilities(
+        &self,
+        capabilities: api::ConnectorCapabilities,
+    ) -> api::ConnectorCapabilities {
+        use api::enums::{CaptureMethod, PaymentMethodType};
+
+        api::ConnectorCapabilities {
+            payment_methods: vec![PaymentMethodType::Card],
+            currencies: None,
+            countries: None,
+            capture_methods: vec![CaptureMethod::Automatic, CaptureMethod::Manual],
+            supports_mandates: false,
+            supports_network_tokens: false,
+            ..capabilities
+        }
+    }
 }
 
 impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Cybersource
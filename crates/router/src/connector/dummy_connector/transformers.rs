@@ -0,0 +1,421 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::errors,
+    pii::PeekInterface,
+    services,
+    types::{self, api, storage::enums},
+};
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct DummyConnectorPaymentsRequest {
+    amount: i64,
+    currency: String,
+    card: DummyConnectorCard,
+    capture: bool,
+    return_url: Option<String>,
+    merchant_id: String,
+    reference: String,
+    three_ds: Option<DummyConnectorThreeDs>,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct DummyConnectorThreeDs {
+    authentication_value: String,
+    eci: Option<String>,
+    ds_trans_id: Option<String>,
+    version: String,
+}
+
+impl From<&types::AuthenticationData> for DummyConnectorThreeDs {
+    fn from(item: &types::AuthenticationData) -> Self {
+        Self {
+            authentication_value: item.authentication_value.peek().clone(),
+            eci: item.eci.clone(),
+            ds_trans_id: item.ds_transaction_id.clone(),
+            version: item.message_version.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct DummyConnectorCard {
+    number: String,
+    expiry_month: String,
+    expiry_year: String,
+    cvc: String,
+    holder_name: Option<String>,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for DummyConnectorPaymentsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data {
+            api::PaymentMethod::Card(ref ccard) => Ok(Self {
+                amount: item.request.amount,
+                currency: item.request.currency.to_string(),
+                card: DummyConnectorCard {
+                    number: ccard.card_number.peek().clone(),
+                    expiry_month: ccard.card_exp_month.peek().clone(),
+                    expiry_year: ccard.card_exp_year.peek().clone(),
+                    cvc: ccard.card_cvc.peek().clone(),
+                    holder_name: Some(ccard.card_holder_name.peek().clone()),
+                },
+                capture: matches!(
+                    item.request.capture_method,
+                    Some(enums::CaptureMethod::Automatic) | None
+                ),
+                return_url: item.router_return_url.clone(),
+                merchant_id: item.merchant_id.clone(),
+                reference: item.payment_id.clone(),
+                three_ds: item
+                    .request
+                    .authentication_data
+                    .as_ref()
+                    .map(DummyConnectorThreeDs::from),
+            }),
+            _ => Err(
+                errors::ConnectorError::NotImplemented("Current Payment Method".to_string()).into(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Eq, PartialEq)]
+pub struct DummyConnectorCaptureRequest {
+    amount: Option<i64>,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for DummyConnectorCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount_to_capture,
+        })
+    }
+}
+
+// Auth Struct
+pub struct DummyConnectorAuthType {
+    pub(super) api_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for DummyConnectorAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        if let types::ConnectorAuthType::HeaderKey { api_key } = auth_type {
+            Ok(Self {
+                api_key: api_key.to_string(),
+            })
+        } else {
+            Err(errors::ConnectorError::FailedToObtainAuthType.into())
+        }
+    }
+}
+
+// PaymentsResponse
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorPaymentStatus {
+    Succeeded,
+    Authorized,
+    Processing,
+    RequiresAction,
+    Failed,
+    Voided,
+}
+
+impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
+    fn from(item: DummyConnectorPaymentStatus) -> Self {
+        match item {
+            DummyConnectorPaymentStatus::Succeeded => Self::Charged,
+            DummyConnectorPaymentStatus::Authorized => Self::Authorized,
+            DummyConnectorPaymentStatus::Processing => Self::Pending,
+            DummyConnectorPaymentStatus::RequiresAction => Self::AuthenticationPending,
+            DummyConnectorPaymentStatus::Failed => Self::Failure,
+            DummyConnectorPaymentStatus::Voided => Self::Voided,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DummyConnectorNextAction {
+    pub redirect_to_url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DummyConnectorPaymentsResponse {
+    pub id: String,
+    pub status: DummyConnectorPaymentStatus,
+    pub amount: i64,
+    pub currency: String,
+    pub next_action: Option<DummyConnectorNextAction>,
+    pub error: Option<ErrorDetails>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            DummyConnectorPaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    > for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            DummyConnectorPaymentsResponse,
+            T,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let response = match item.response.error {
+            Some(error) => Err(types::ErrorResponse {
+                status_code: item.http_code,
+                code: error.code,
+                message: error.message,
+                reason: None,
+            }),
+            None => {
+                let redirection_data =
+                    item.response
+                        .next_action
+                        .map(|next_action| services::RedirectForm {
+                            url: next_action.redirect_to_url,
+                            method: services::Method::Get,
+                            form_fields: std::collections::HashMap::new(),
+                        });
+                Ok(types::PaymentsResponseData::TransactionResponse {
+                    resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
+                    redirect: redirection_data.is_some(),
+                    redirection_data,
+                    mandate_reference: None,
+                    connector_metadata: None,
+                })
+            }
+        };
+        Ok(Self {
+            status: enums::AttemptStatus::from(item.response.status),
+            response,
+            ..item.data
+        })
+    }
+}
+
+// AUTHENTICATION :
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct DummyConnectorAuthenticationRequest {
+    amount: i64,
+    currency: String,
+    card_number: String,
+    card_expiry_month: String,
+    card_expiry_year: String,
+    return_url: Option<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthenticationRouterData> for DummyConnectorAuthenticationRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ConnectorAuthenticationRouterData) -> Result<Self, Self::Error> {
+        match item.request.payment_method_data {
+            api::PaymentMethod::Card(ref ccard) => Ok(Self {
+                amount: item.request.amount,
+                currency: item.request.currency.to_string(),
+                card_number: ccard.card_number.peek().clone(),
+                card_expiry_month: ccard.card_exp_month.peek().clone(),
+                card_expiry_year: ccard.card_exp_year.peek().clone(),
+                return_url: item.router_return_url.clone(),
+            }),
+            _ => Err(
+                errors::ConnectorError::NotImplemented("Current Payment Method".to_string()).into(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub enum DummyConnectorTransStatus {
+    #[serde(rename = "Y")]
+    Authenticated,
+    #[serde(rename = "A")]
+    Attempted,
+    #[serde(rename = "C")]
+    ChallengeRequired,
+    #[serde(rename = "U")]
+    Unavailable,
+    #[serde(rename = "N")]
+    Failed,
+    #[serde(rename = "R")]
+    Rejected,
+}
+
+impl From<DummyConnectorTransStatus> for types::AuthenticationStatus {
+    fn from(item: DummyConnectorTransStatus) -> Self {
+        match item {
+            DummyConnectorTransStatus::Authenticated => Self::Authenticated,
+            DummyConnectorTransStatus::Attempted => Self::Attempted,
+            DummyConnectorTransStatus::ChallengeRequired => Self::ChallengeRequired,
+            DummyConnectorTransStatus::Unavailable => Self::Unavailable,
+            DummyConnectorTransStatus::Failed => Self::Failed,
+            DummyConnectorTransStatus::Rejected => Self::Rejected,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DummyConnectorAuthenticationResponse {
+    pub id: String,
+    pub trans_status: DummyConnectorTransStatus,
+    pub authentication_value: Option<String>,
+    pub eci: Option<String>,
+    pub ds_trans_id: Option<String>,
+    pub message_version: String,
+    pub reason: Option<String>,
+    pub challenge_url: Option<String>,
+}
+
+impl TryFrom<types::ConnectorAuthenticationResponseRouterData<DummyConnectorAuthenticationResponse>>
+    for types::ConnectorAuthenticationRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ConnectorAuthenticationResponseRouterData<
+            DummyConnectorAuthenticationResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let response = item.response;
+        let authentication_data =
+            response
+                .authentication_value
+                .map(|authentication_value| types::AuthenticationData {
+                    authentication_value: authentication_value.into(),
+                    eci: response.eci,
+                    ds_transaction_id: response.ds_trans_id,
+                    message_version: response.message_version,
+                });
+        let redirection_data = response
+            .challenge_url
+            .map(|url| services::RedirectForm::new(url, services::Method::Get, HashMap::new()));
+        Ok(Self {
+            response: Ok(types::AuthenticationResponseData {
+                authentication_id: response.id,
+                status: response.trans_status.into(),
+                authentication_data,
+                reason: response.reason,
+                redirection_data,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// REFUND :
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorRefundRequest {
+    payment_id: String,
+    amount: i64,
+    reason: Option<String>,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for DummyConnectorRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            payment_id: item.request.connector_transaction_id.clone(),
+            amount: item.request.refund_amount,
+            reason: item.request.reason.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorRefundStatus {
+    Succeeded,
+    Failed,
+}
+
+impl From<DummyConnectorRefundStatus> for enums::RefundStatus {
+    fn from(item: DummyConnectorRefundStatus) -> Self {
+        match item {
+            DummyConnectorRefundStatus::Succeeded => Self::Success,
+            DummyConnectorRefundStatus::Failed => Self::Failure,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefundResponse {
+    pub id: String,
+    pub payment_id: String,
+    pub status: DummyConnectorRefundStatus,
+    pub amount: i64,
+    pub currency: String,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, RefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// WEBHOOKS :
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentSucceeded,
+    PaymentAuthorized,
+    PaymentFailed,
+}
+
+impl From<DummyConnectorWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(item: DummyConnectorWebhookEventType) -> Self {
+        match item {
+            DummyConnectorWebhookEventType::PaymentSucceeded
+            | DummyConnectorWebhookEventType::PaymentAuthorized => Self::PaymentIntentSuccess,
+            DummyConnectorWebhookEventType::PaymentFailed => Self::PaymentIntentFailure,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DummyConnectorWebhookEvent {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub data: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DummyConnectorWebhookObjectId {
+    pub data: DummyConnectorWebhookObjectData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DummyConnectorWebhookObjectData {
+    pub id: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ErrorResponse {
+    pub error: ErrorDetails,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ErrorDetails {
+    pub code: String,
+    pub message: String,
+}
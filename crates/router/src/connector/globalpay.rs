@@ -15,10 +15,12 @@ use self::{
 };
 use crate::{
     configs::settings,
+    connector::utils as conn_utils,
     core::{
         errors::{self, CustomResult},
         payments,
     },
+    db::StorageInterface,
     headers, logger,
     services::{self, ConnectorIntegration},
     types::{
@@ -26,7 +28,11 @@ use crate::{
         api::{self, ConnectorCommon, ConnectorCommonExt},
         ErrorResponse,
     },
-    utils::{self, BytesExt, OptionExt},
+    utils::{
+        self,
+        crypto::{self, GenerateDigest},
+        ByteSliceExt, BytesExt, OptionExt,
+    },
 };
 
 #[derive(Debug, Clone)]
@@ -96,6 +102,23 @@ impl ConnectorCommon for Globalpay {
             reason: None,
         })
     }
+
+    fn get_capabilities(
+        &self,
+        capabilities: api::ConnectorCapabilities,
+    ) -> api::ConnectorCapabilities {
+        use api::enums::{CaptureMethod, PaymentMethodType};
+
+        api::ConnectorCapabilities {
+            payment_methods: vec![PaymentMethodType::Card],
+            currencies: None,
+            countries: None,
+            capture_methods: vec![CaptureMethod::Automatic, CaptureMethod::Manual],
+            supports_mandates: false,
+            supports_network_tokens: false,
+            ..capabilities
+        }
+    }
 }
 
 impl api::ConnectorAccessToken for Globalpay {}
@@ -691,25 +714,96 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
 
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Globalpay {
-    fn get_webhook_object_reference_id(
+    fn get_webhook_source_verification_signature(
         &self,
+        headers: &actix_web::http::header::HeaderMap,
         _body: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let signature = conn_utils::get_header_key_value("x-gp-signature", headers)?;
+        hex::decode(signature)
+            .into_report()
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+    }
+
+    fn get_webhook_source_verification_message(
+        &self,
+        _headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+        _merchant_id: &str,
+        secret: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        Ok([body, secret].concat())
+    }
+
+    async fn get_webhook_source_verification_merchant_secret(
+        &self,
+        db: &dyn StorageInterface,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<u8>, errors::ConnectorError> {
+        let key = format!("whsec_verification_{}_{}", self.id(), merchant_id);
+        let secret = db
+            .get_key(&key)
+            .await
+            .change_context(errors::ConnectorError::WebhookVerificationSecretNotFound)?;
+
+        Ok(secret)
+    }
+
+    async fn verify_webhook_source(
+        &self,
+        db: &dyn StorageInterface,
+        headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+        merchant_id: &str,
+    ) -> CustomResult<bool, errors::ConnectorError> {
+        // Global Payments signs a notification with the SHA-512 digest of the body followed by
+        // the app key, rather than with an HMAC
+        let signature = self
+            .get_webhook_source_verification_signature(headers, body)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let secret = self
+            .get_webhook_source_verification_merchant_secret(db, merchant_id)
+            .await
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let message = self
+            .get_webhook_source_verification_message(headers, body, merchant_id, &secret)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        let digest = crypto::Sha512
+            .generate_digest(&message)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
+        Ok(digest == signature)
+    }
+
+    fn get_webhook_object_reference_id(
+        &self,
+        body: &[u8],
     ) -> CustomResult<String, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let transaction: GlobalpayPaymentsResponse = body
+            .parse_struct("GlobalpayPaymentsResponse")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+        Ok(transaction.id)
     }
 
     fn get_webhook_event_type(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<api::IncomingWebhookEvent, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        let transaction: GlobalpayPaymentsResponse = body
+            .parse_struct("GlobalpayPaymentsResponse")
+            .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+        api::IncomingWebhookEvent::try_from(&transaction)
     }
 
     fn get_webhook_resource_object(
         &self,
-        _body: &[u8],
+        body: &[u8],
     ) -> CustomResult<serde_json::Value, errors::ConnectorError> {
-        Err(errors::ConnectorError::WebhooksNotImplemented).into_report()
+        // The notification is the transaction itself, as returned when it is retrieved
+        let transaction: GlobalpayPaymentsResponse = body
+            .parse_struct("GlobalpayPaymentsResponse")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+        utils::Encode::<GlobalpayPaymentsResponse>::encode_to_value(&transaction)
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)
     }
 }
 
@@ -261,3 +261,27 @@ pub struct GlobalpayErrorResponse {
     pub detailed_error_code: String,
     pub detailed_error_description: String,
 }
+
+impl TryFrom<&GlobalpayPaymentsResponse> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(transaction: &GlobalpayPaymentsResponse) -> Result<Self, Self::Error> {
+        if let Some(requests::GlobalpayPaymentsRequestType::Refund) =
+            transaction.globalpay_payments_response_type
+        {
+            Err(errors::ConnectorError::WebhookEventTypeNotFound)?
+        }
+        match transaction.status {
+            GlobalpayPaymentStatus::Captured
+            | GlobalpayPaymentStatus::Funded
+            | GlobalpayPaymentStatus::Preauthorized => Ok(Self::PaymentIntentSuccess),
+            GlobalpayPaymentStatus::Declined | GlobalpayPaymentStatus::Rejected => {
+                Ok(Self::PaymentIntentFailure)
+            }
+            GlobalpayPaymentStatus::Initiated
+            | GlobalpayPaymentStatus::Pending
+            | GlobalpayPaymentStatus::Reversed => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound)?
+            }
+        }
+    }
+}
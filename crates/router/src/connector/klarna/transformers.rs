@@ -1,9 +1,11 @@
-use error_stack::report;
+use api_models::payments::OrderDetails;
+use error_stack::{report, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     core::errors,
-    types::{self, storage::enums},
+    types::{self, api, storage::enums},
+    utils::{Encode, ValueExt},
 };
 
 #[derive(Default, Debug, Serialize)]
@@ -12,9 +14,10 @@ pub struct KlarnaPaymentsRequest {
     order_amount: i64,
     purchase_country: String,
     purchase_currency: enums::Currency,
+    auto_capture: bool,
 }
 
-#[derive(Default, Debug, Deserialize)]
+#[derive(Default, Debug, Serialize, Deserialize)]
 pub struct KlarnaPaymentsResponse {
     order_id: String,
     fraud_status: KlarnaFraudStatus,
@@ -47,12 +50,7 @@ impl TryFrom<&types::PaymentsSessionRouterData> for KlarnaSessionRequest {
                 purchase_currency: request.currency,
                 order_amount: request.amount,
                 locale: "en-US".to_string(),
-                order_lines: vec![OrderLines {
-                    name: order_details.product_name,
-                    quantity: order_details.quantity,
-                    unit_price: request.amount,
-                    total_amount: request.amount,
-                }],
+                order_lines: vec![OrderLines::new(order_details, request.amount)],
             }),
             None => Err(report!(errors::ConnectorError::MissingRequiredField {
                 field_name: "product_name",
@@ -90,12 +88,11 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for KlarnaPaymentsRequest {
                 purchase_country: "US".to_string(),
                 purchase_currency: request.currency,
                 order_amount: request.amount,
-                order_lines: vec![OrderLines {
-                    name: order_details.product_name,
-                    quantity: order_details.quantity,
-                    unit_price: request.amount,
-                    total_amount: request.amount,
-                }],
+                order_lines: vec![OrderLines::new(order_details, request.amount)],
+                auto_capture: matches!(
+                    request.capture_method,
+                    Some(enums::CaptureMethod::Automatic) | None
+                ),
             }),
             None => Err(report!(errors::ConnectorError::MissingRequiredField {
                 field_name: "product_name"
@@ -111,20 +108,37 @@ impl TryFrom<types::PaymentsResponseRouterData<KlarnaPaymentsResponse>>
     fn try_from(
         item: types::PaymentsResponseRouterData<KlarnaPaymentsResponse>,
     ) -> Result<Self, Self::Error> {
+        let is_auto_capture = matches!(
+            item.data.request.capture_method,
+            Some(enums::CaptureMethod::Automatic) | None
+        );
+        let connector_metadata = item
+            .data
+            .request
+            .order_details
+            .clone()
+            .map(|order_details| {
+                Encode::<KlarnaMeta>::encode_to_value(&KlarnaMeta {
+                    order_line: OrderLines::new(order_details, item.data.request.amount),
+                })
+            })
+            .transpose()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
         Ok(Self {
             response: Ok(types::PaymentsResponseData::TransactionResponse {
                 resource_id: types::ResponseId::ConnectorTransactionId(item.response.order_id),
                 redirect: false,
                 redirection_data: None,
                 mandate_reference: None,
-                connector_metadata: None,
+                connector_metadata,
             }),
-            status: item.response.fraud_status.into(),
+            status: get_attempt_status(item.response.fraud_status, is_auto_capture),
             ..item.data
         })
     }
 }
-#[derive(Debug, Serialize)]
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OrderLines {
     name: String,
     quantity: u16,
@@ -132,6 +146,287 @@ pub struct OrderLines {
     total_amount: i64,
 }
 
+impl OrderLines {
+    /// Klarna rejects order lines whose total amount is not the unit price times the quantity, so
+    /// amounts which cannot be split evenly across the quantity are sent as a single unit.
+    fn new(order_details: OrderDetails, amount: i64) -> Self {
+        let quantity = match i64::from(order_details.quantity) {
+            quantity if quantity > 0 && amount % quantity == 0 => order_details.quantity,
+            _ => 1,
+        };
+        Self {
+            name: order_details.product_name,
+            quantity,
+            unit_price: amount / i64::from(quantity),
+            total_amount: amount,
+        }
+    }
+
+    /// Returns the order line covering the given part of the order, when it is a whole number of
+    /// units of the line.
+    fn for_amount(&self, amount: i64) -> Option<Self> {
+        let quantity = match self.unit_price {
+            unit_price if unit_price > 0 && amount % unit_price == 0 => {
+                u16::try_from(amount / unit_price).ok()?
+            }
+            _ => return None,
+        };
+        (quantity > 0 && quantity <= self.quantity).then(|| Self {
+            name: self.name.clone(),
+            quantity,
+            unit_price: self.unit_price,
+            total_amount: amount,
+        })
+    }
+}
+
+/// Stored as the connector metadata of the payment attempt, so that captures and refunds can
+/// report the order lines they cover.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KlarnaMeta {
+    order_line: OrderLines,
+}
+
+fn get_order_lines(
+    connector_meta: Option<&serde_json::Value>,
+    amount: i64,
+) -> Option<Vec<OrderLines>> {
+    let meta: KlarnaMeta = connector_meta?.clone().parse_value("KlarnaMeta").ok()?;
+    meta.order_line.for_amount(amount).map(|line| vec![line])
+}
+
+#[derive(Debug, Serialize)]
+pub struct KlarnaCaptureRequest {
+    captured_amount: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_lines: Option<Vec<OrderLines>>,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for KlarnaCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        let captured_amount = item
+            .request
+            .amount_to_capture
+            .unwrap_or(item.request.amount);
+        Ok(Self {
+            captured_amount,
+            order_lines: get_order_lines(item.request.connector_meta.as_ref(), captured_amount),
+        })
+    }
+}
+
+/// Klarna acknowledges captures, cancellations and refunds of an order without a response body.
+#[derive(Debug)]
+pub struct KlarnaNoContentResponse;
+
+impl TryFrom<types::PaymentsCaptureResponseRouterData<KlarnaNoContentResponse>>
+    for types::PaymentsCaptureRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsCaptureResponseRouterData<KlarnaNoContentResponse>,
+    ) -> Result<Self, Self::Error> {
+        let captured_amount = item
+            .data
+            .request
+            .amount_to_capture
+            .unwrap_or(item.data.request.amount);
+        let status = if captured_amount < item.data.request.amount {
+            enums::AttemptStatus::PartialCharged
+        } else {
+            enums::AttemptStatus::Charged
+        };
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.request.connector_transaction_id.clone(),
+                ),
+                redirect: false,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+            }),
+            status,
+            amount_captured: Some(captured_amount),
+            ..item.data
+        })
+    }
+}
+
+impl TryFrom<types::PaymentsCancelResponseRouterData<KlarnaNoContentResponse>>
+    for types::PaymentsCancelRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsCancelResponseRouterData<KlarnaNoContentResponse>,
+    ) -> Result<Self, Self::Error> {
+        // Releasing the remaining authorization of a partially captured order completes it with
+        // the amount captured so far
+        let status = if is_partially_captured(&item.data) {
+            enums::AttemptStatus::Charged
+        } else {
+            enums::AttemptStatus::Voided
+        };
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.request.connector_transaction_id.clone(),
+                ),
+                redirect: false,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+            }),
+            status,
+            ..item.data
+        })
+    }
+}
+
+pub fn is_partially_captured(item: &types::PaymentsCancelRouterData) -> bool {
+    item.amount_captured.unwrap_or(0) > 0
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum KlarnaOrderStatus {
+    Authorized,
+    PartCaptured,
+    Captured,
+    Cancelled,
+    Expired,
+    Closed,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KlarnaOrderRefund {
+    refund_id: String,
+    reference: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KlarnaOrderResponse {
+    order_id: String,
+    fraud_status: KlarnaFraudStatus,
+    /// Absent when the order is synced from a fraud decision notification
+    status: Option<KlarnaOrderStatus>,
+    captured_amount: Option<i64>,
+    #[serde(default)]
+    refunds: Vec<KlarnaOrderRefund>,
+}
+
+impl TryFrom<types::PaymentsSyncResponseRouterData<KlarnaOrderResponse>>
+    for types::PaymentsSyncRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsSyncResponseRouterData<KlarnaOrderResponse>,
+    ) -> Result<Self, Self::Error> {
+        let is_auto_capture = matches!(
+            item.data.request.capture_method,
+            Some(enums::CaptureMethod::Automatic) | None
+        );
+        let captured_amount = item.response.captured_amount.unwrap_or(0);
+        let status = match (item.response.fraud_status, item.response.status) {
+            (KlarnaFraudStatus::Accepted, Some(status)) => match status {
+                KlarnaOrderStatus::Authorized => enums::AttemptStatus::Authorized,
+                KlarnaOrderStatus::PartCaptured => enums::AttemptStatus::PartialCharged,
+                KlarnaOrderStatus::Captured => enums::AttemptStatus::Charged,
+                KlarnaOrderStatus::Cancelled => enums::AttemptStatus::Voided,
+                KlarnaOrderStatus::Expired => enums::AttemptStatus::AuthorizationFailed,
+                KlarnaOrderStatus::Closed if captured_amount > 0 => enums::AttemptStatus::Charged,
+                KlarnaOrderStatus::Closed => enums::AttemptStatus::Voided,
+            },
+            (fraud_status, _) => get_attempt_status(fraud_status, is_auto_capture),
+        };
+        Ok(Self {
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.order_id),
+                redirect: false,
+                redirection_data: None,
+                mandate_reference: None,
+                connector_metadata: None,
+            }),
+            status,
+            amount_captured: item.response.captured_amount.or(item.data.amount_captured),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct KlarnaRefundRequest {
+    refunded_amount: i64,
+    /// The refund id of the router, which Klarna returns with the refunds of the order
+    reference: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_lines: Option<Vec<OrderLines>>,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for KlarnaRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            refunded_amount: item.request.refund_amount,
+            reference: item.request.refund_id.clone(),
+            order_lines: get_order_lines(
+                item.request.connector_metadata.as_ref(),
+                item.request.refund_amount,
+            ),
+        })
+    }
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::Execute, KlarnaNoContentResponse>>
+    for types::RefundsRouterData<api::Execute>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::Execute, KlarnaNoContentResponse>,
+    ) -> Result<Self, Self::Error> {
+        // The refund id assigned by Klarna is only returned in a header, so the refund is
+        // identified by the reference it was created with
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.data.request.refund_id.clone(),
+                refund_status: enums::RefundStatus::Success,
+            }),
+            ..item.data
+        })
+    }
+}
+
+impl TryFrom<types::RefundsResponseRouterData<api::RSync, KlarnaOrderResponse>>
+    for types::RefundsRouterData<api::RSync>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<api::RSync, KlarnaOrderResponse>,
+    ) -> Result<Self, Self::Error> {
+        let reference = item
+            .data
+            .request
+            .connector_refund_id
+            .clone()
+            .unwrap_or_else(|| item.data.request.refund_id.clone());
+        let refund = item.response.refunds.into_iter().find(|refund| {
+            refund.reference.as_ref() == Some(&reference) || refund.refund_id == reference
+        });
+        let refund_status = match refund {
+            Some(_) => enums::RefundStatus::Success,
+            None => enums::RefundStatus::Pending,
+        };
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: reference,
+                refund_status,
+            }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "snake_case")]
 #[allow(dead_code)]
@@ -164,14 +459,20 @@ pub enum KlarnaFraudStatus {
     Accepted,
     #[default]
     Pending,
+    Rejected,
 }
 
-impl From<KlarnaFraudStatus> for enums::AttemptStatus {
-    fn from(item: KlarnaFraudStatus) -> Self {
-        match item {
-            KlarnaFraudStatus::Accepted => Self::Charged,
-            KlarnaFraudStatus::Pending => Self::Authorizing,
-        }
+/// Orders accepted by Klarna are captured with the authorization when the payment is
+/// automatically captured, and otherwise await a capture through Order Management.
+fn get_attempt_status(
+    fraud_status: KlarnaFraudStatus,
+    is_auto_capture: bool,
+) -> enums::AttemptStatus {
+    match fraud_status {
+        KlarnaFraudStatus::Accepted if is_auto_capture => enums::AttemptStatus::Charged,
+        KlarnaFraudStatus::Accepted => enums::AttemptStatus::Authorized,
+        KlarnaFraudStatus::Pending => enums::AttemptStatus::Authorizing,
+        KlarnaFraudStatus::Rejected => enums::AttemptStatus::Failure,
     }
 }
 
@@ -180,3 +481,40 @@ pub struct KlarnaErrorResponse {
     pub error_code: String,
     pub error_messages: Vec<String>,
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum KlarnaWebhookEventType {
+    FraudRiskAccepted,
+    FraudRiskRejected,
+    FraudRiskStopped,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KlarnaWebhookBody {
+    pub order_id: String,
+    pub event_type: KlarnaWebhookEventType,
+}
+
+impl From<&KlarnaWebhookEventType> for api::IncomingWebhookEvent {
+    fn from(event_type: &KlarnaWebhookEventType) -> Self {
+        match event_type {
+            KlarnaWebhookEventType::FraudRiskAccepted => Self::PaymentIntentSuccess,
+            KlarnaWebhookEventType::FraudRiskRejected
+            | KlarnaWebhookEventType::FraudRiskStopped => Self::PaymentIntentFailure,
+        }
+    }
+}
+
+impl From<KlarnaWebhookBody> for KlarnaPaymentsResponse {
+    fn from(webhook: KlarnaWebhookBody) -> Self {
+        Self {
+            order_id: webhook.order_id,
+            fraud_status: match webhook.event_type {
+                KlarnaWebhookEventType::FraudRiskAccepted => KlarnaFraudStatus::Accepted,
+                KlarnaWebhookEventType::FraudRiskRejected
+                | KlarnaWebhookEventType::FraudRiskStopped => KlarnaFraudStatus::Rejected,
+            },
+        }
+    }
+}
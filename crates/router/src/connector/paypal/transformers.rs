@@ -0,0 +1,943 @@
+use error_stack::{IntoReport, ResultExt};
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::{
+    connector::utils::{self, AccessTokenRequestInfo, PaymentsRequestData},
+    core::errors,
+    pii::Secret,
+    services,
+    types::{self, api, storage::enums},
+};
+
+/// Amounts are sent to PayPal in the base unit of the currency, e.g. `10.00` for 1000 cents
+fn get_amount_value(amount: i64, currency: enums::Currency) -> String {
+    match currency {
+        // Currencies PayPal does not support decimals for
+        enums::Currency::HUF | enums::Currency::JPY | enums::Currency::TWD => amount.to_string(),
+        _ => format!("{}.{:02}", amount / 100, amount % 100),
+    }
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct OrderAmount {
+    currency_code: enums::Currency,
+    value: String,
+}
+
+impl OrderAmount {
+    fn new(amount: i64, currency: enums::Currency) -> Self {
+        Self {
+            currency_code: currency,
+            value: get_amount_value(amount, currency),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalPaymentIntent {
+    Capture,
+    Authorize,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PurchaseUnitRequest {
+    reference_id: String,
+    amount: OrderAmount,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalUserAction {
+    PayNow,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalExperienceContext {
+    return_url: String,
+    cancel_url: String,
+    user_action: PaypalUserAction,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalStoreInVault {
+    OnSuccess,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalVaultUsageType {
+    Merchant,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalVaultRequest {
+    store_in_vault: PaypalStoreInVault,
+    usage_type: PaypalVaultUsageType,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalAttributesRequest {
+    vault: PaypalVaultRequest,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalWalletRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vault_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    experience_context: Option<PaypalExperienceContext>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<PaypalAttributesRequest>,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalTokenType {
+    BillingAgreement,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalTokenSource {
+    id: String,
+    #[serde(rename = "type")]
+    token_type: PaypalTokenType,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaypalPaymentSource {
+    Paypal(PaypalWalletRequest),
+    Token(PaypalTokenSource),
+}
+
+/// The PayPal token the payments of a mandate are debited from. It is recorded as the connector
+/// mandate ID with its kind, which is never guessed from the format of the token.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(tag = "type", content = "id", rename_all = "snake_case")]
+pub enum PaypalMandateReference {
+    /// A PayPal account vaulted once the customer approved the payment setting up the mandate
+    VaultId(String),
+    /// A billing agreement of the older PayPal APIs, which merchants migrating to the Orders API
+    /// hold for their customers
+    BillingAgreement(String),
+}
+
+impl PaypalMandateReference {
+    fn from_connector_mandate_id(
+        connector_mandate_id: &str,
+    ) -> Result<Self, error_stack::Report<errors::ConnectorError>> {
+        serde_json::from_str(connector_mandate_id)
+            .into_report()
+            .change_context(errors::ConnectorError::RequestEncodingFailed)
+            .attach_printable("Invalid PayPal mandate reference")
+    }
+
+    fn to_connector_mandate_id(
+        &self,
+    ) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+        serde_json::to_string(self)
+            .into_report()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalPaymentsRequest {
+    intent: PaypalPaymentIntent,
+    purchase_units: Vec<PurchaseUnitRequest>,
+    payment_source: PaypalPaymentSource,
+}
+
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaypalPaymentsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        let intent = match item.request.capture_method {
+            Some(enums::CaptureMethod::Manual) => PaypalPaymentIntent::Authorize,
+            _ => PaypalPaymentIntent::Capture,
+        };
+        let mandate_reference = item
+            .request
+            .mandate_id
+            .as_ref()
+            .and_then(|mandate_ids| mandate_ids.connector_mandate_id.as_deref())
+            .map(PaypalMandateReference::from_connector_mandate_id)
+            .transpose()?;
+        // Payments with a mandate are debited without the customer approving them with PayPal
+        let payment_source = match mandate_reference {
+            Some(PaypalMandateReference::BillingAgreement(billing_agreement_id)) => {
+                PaypalPaymentSource::Token(PaypalTokenSource {
+                    id: billing_agreement_id,
+                    token_type: PaypalTokenType::BillingAgreement,
+                })
+            }
+            Some(PaypalMandateReference::VaultId(vault_id)) => {
+                PaypalPaymentSource::Paypal(PaypalWalletRequest {
+                    vault_id: Some(vault_id),
+                    experience_context: None,
+                    attributes: None,
+                })
+            }
+            None => match item.request.payment_method_data {
+                api::PaymentMethod::Paypal
+                | api::PaymentMethod::Wallet(api::WalletData {
+                    issuer_name: api_models::enums::WalletIssuer::Paypal,
+                    ..
+                }) => {
+                    let return_url = item.get_return_url()?;
+                    // The PayPal account of the customer is vaulted once they approve the
+                    // payment, to debit the payments of the mandate from it
+                    let attributes = item.request.setup_mandate_details.as_ref().map(|_| {
+                        PaypalAttributesRequest {
+                            vault: PaypalVaultRequest {
+                                store_in_vault: PaypalStoreInVault::OnSuccess,
+                                usage_type: PaypalVaultUsageType::Merchant,
+                            },
+                        }
+                    });
+                    PaypalPaymentSource::Paypal(PaypalWalletRequest {
+                        vault_id: None,
+                        experience_context: Some(PaypalExperienceContext {
+                            cancel_url: return_url.clone(),
+                            return_url,
+                            user_action: PaypalUserAction::PayNow,
+                        }),
+                        attributes,
+                    })
+                }
+                _ => Err(errors::ConnectorError::NotImplemented(
+                    "Payment methods other than PayPal".to_string(),
+                ))?,
+            },
+        };
+
+        Ok(Self {
+            intent,
+            purchase_units: vec![PurchaseUnitRequest {
+                reference_id: item.payment_id.clone(),
+                amount: OrderAmount::new(item.request.amount, item.request.currency),
+            }],
+            payment_source,
+        })
+    }
+}
+
+/// Completes an order the customer approved, capturing or authorizing it depending on its intent
+#[derive(Debug, Default, Serialize, Eq, PartialEq)]
+pub struct PaypalOrderCompleteRequest {}
+
+/// The query parameters PayPal adds to the return URL once the customer approves the order
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PaypalRedirectResponse {
+    pub payer_id: Option<String>,
+}
+
+impl PaypalRedirectResponse {
+    pub fn is_order_approved(encoded_data: &str) -> bool {
+        serde_urlencoded::from_str::<Self>(encoded_data)
+            .map(|redirect_response| redirect_response.payer_id.is_some())
+            .unwrap_or(false)
+    }
+}
+
+// Auth Struct
+pub struct PaypalAuthType {
+    pub(super) client_id: String,
+    pub(super) client_secret: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for PaypalAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::BodyKey { api_key, key1 } => Ok(Self {
+                client_id: key1.to_string(),
+                client_secret: api_key.to_string(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalAuthUpdateRequest {
+    grant_type: String,
+}
+
+impl TryFrom<&types::RefreshTokenRouterData> for PaypalAuthUpdateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefreshTokenRouterData) -> Result<Self, Self::Error> {
+        // The client ID is only sent in the authorization header
+        item.get_request_id()?;
+        Ok(Self {
+            grant_type: "client_credentials".to_string(),
+        })
+    }
+}
+
+#[derive(Default, Debug, Clone, Deserialize, PartialEq)]
+pub struct PaypalAuthUpdateResponse {
+    pub access_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+}
+
+impl<F, T> TryFrom<types::ResponseRouterData<F, PaypalAuthUpdateResponse, T, types::AccessToken>>
+    for types::RouterData<F, T, types::AccessToken>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, PaypalAuthUpdateResponse, T, types::AccessToken>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::AccessToken {
+                token: item.response.access_token,
+                expires: item.response.expires_in,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// PaymentsResponse
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalOrderStatus {
+    Created,
+    Saved,
+    Approved,
+    Voided,
+    Completed,
+    PayerActionRequired,
+}
+
+/// Status of the authorizations and captures of an order
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PaypalPaymentStatus {
+    Created,
+    Captured,
+    PartiallyCaptured,
+    Completed,
+    Declined,
+    Denied,
+    Expired,
+    Failed,
+    Pending,
+    PartiallyRefunded,
+    Refunded,
+    Voided,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalPaymentItem {
+    pub id: String,
+    pub status: PaypalPaymentStatus,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PaypalPaymentCollection {
+    #[serde(default)]
+    pub authorizations: Vec<PaypalPaymentItem>,
+    #[serde(default)]
+    pub captures: Vec<PaypalPaymentItem>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PurchaseUnitResponse {
+    pub payments: Option<PaypalPaymentCollection>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalLink {
+    pub href: String,
+    pub rel: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalVaultResponse {
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalAttributesResponse {
+    pub vault: Option<PaypalVaultResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalWalletResponse {
+    pub attributes: Option<PaypalAttributesResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalPaymentSourceResponse {
+    pub paypal: Option<PaypalWalletResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaypalOrderResponse {
+    pub id: String,
+    pub status: PaypalOrderStatus,
+    #[serde(default)]
+    pub purchase_units: Vec<PurchaseUnitResponse>,
+    #[serde(default)]
+    pub links: Vec<PaypalLink>,
+    pub payment_source: Option<PaypalPaymentSourceResponse>,
+}
+
+/// The authorization and capture of the order, stored with the payment attempt since captures,
+/// voids and refunds are made against them instead of the order
+#[derive(Debug, Default, Serialize, Deserialize, Eq, PartialEq)]
+pub struct PaypalMeta {
+    pub authorize_id: Option<String>,
+    pub capture_id: Option<String>,
+}
+
+impl PaypalMeta {
+    fn from_connector_meta(
+        connector_meta: Option<serde_json::Value>,
+    ) -> Result<Self, error_stack::Report<errors::ConnectorError>> {
+        connector_meta
+            .map(serde_json::from_value::<Self>)
+            .transpose()
+            .into_report()
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)
+            .map(Option::unwrap_or_default)
+    }
+
+    fn to_connector_meta(
+        &self,
+    ) -> Result<Option<serde_json::Value>, error_stack::Report<errors::ConnectorError>> {
+        if self.authorize_id.is_none() && self.capture_id.is_none() {
+            return Ok(None);
+        }
+        serde_json::to_value(self)
+            .into_report()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)
+            .map(Some)
+    }
+}
+
+impl From<PaypalPaymentStatus> for enums::AttemptStatus {
+    fn from(status: PaypalPaymentStatus) -> Self {
+        match status {
+            PaypalPaymentStatus::Created => Self::Authorized,
+            PaypalPaymentStatus::Captured
+            | PaypalPaymentStatus::PartiallyCaptured
+            | PaypalPaymentStatus::Completed
+            | PaypalPaymentStatus::PartiallyRefunded
+            | PaypalPaymentStatus::Refunded => Self::Charged,
+            PaypalPaymentStatus::Declined
+            | PaypalPaymentStatus::Denied
+            | PaypalPaymentStatus::Expired
+            | PaypalPaymentStatus::Failed => Self::Failure,
+            PaypalPaymentStatus::Pending => Self::Pending,
+            PaypalPaymentStatus::Voided => Self::Voided,
+        }
+    }
+}
+
+impl PaypalOrderResponse {
+    fn get_payments(&self) -> Option<&PaypalPaymentCollection> {
+        self.purchase_units
+            .first()
+            .and_then(|purchase_unit| purchase_unit.payments.as_ref())
+    }
+
+    fn get_redirection_data(
+        &self,
+    ) -> Result<Option<services::RedirectForm>, error_stack::Report<errors::ConnectorError>> {
+        self.links
+            .iter()
+            .find(|link| matches!(link.rel.as_str(), "payer-action" | "approve"))
+            .map(|link| {
+                let url = Url::parse(&link.href)
+                    .into_report()
+                    .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+                let mut base_url = url.clone();
+                base_url.set_query(None);
+                Ok(services::RedirectForm {
+                    url: base_url.to_string(),
+                    method: services::Method::Get,
+                    form_fields: std::collections::HashMap::from_iter(
+                        url.query_pairs()
+                            .map(|(key, value)| (key.to_string(), value.to_string())),
+                    ),
+                })
+            })
+            .transpose()
+    }
+
+    fn get_attempt_status(&self) -> enums::AttemptStatus {
+        match self.status {
+            PaypalOrderStatus::Created
+            | PaypalOrderStatus::Saved
+            | PaypalOrderStatus::PayerActionRequired => enums::AttemptStatus::AuthenticationPending,
+            PaypalOrderStatus::Approved => enums::AttemptStatus::AuthenticationSuccessful,
+            PaypalOrderStatus::Voided => enums::AttemptStatus::Voided,
+            // The status of a completed order is the one of its capture, or of its authorization
+            // when it is yet to be captured
+            PaypalOrderStatus::Completed => self
+                .get_payments()
+                .and_then(|payments| {
+                    payments
+                        .captures
+                        .first()
+                        .or_else(|| payments.authorizations.first())
+                })
+                .map_or(enums::AttemptStatus::Pending, |payment| {
+                    enums::AttemptStatus::from(payment.status)
+                }),
+        }
+    }
+
+    fn get_meta(&self) -> PaypalMeta {
+        let payments = self.get_payments();
+        PaypalMeta {
+            authorize_id: payments
+                .and_then(|payments| payments.authorizations.first())
+                .map(|authorization| authorization.id.clone()),
+            capture_id: payments
+                .and_then(|payments| payments.captures.first())
+                .map(|capture| capture.id.clone()),
+        }
+    }
+
+    fn get_mandate_reference(
+        &self,
+    ) -> Result<Option<String>, error_stack::Report<errors::ConnectorError>> {
+        self.payment_source
+            .as_ref()
+            .and_then(|payment_source| payment_source.paypal.as_ref())
+            .and_then(|paypal| paypal.attributes.as_ref())
+            .and_then(|attributes| attributes.vault.as_ref())
+            .and_then(|vault| vault.id.clone())
+            .map(|vault_id| PaypalMandateReference::VaultId(vault_id).to_connector_mandate_id())
+            .transpose()
+    }
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, PaypalOrderResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, PaypalOrderResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let redirection_data = item.response.get_redirection_data()?;
+        Ok(Self {
+            status: item.response.get_attempt_status(),
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(item.response.id.clone()),
+                redirect: redirection_data.is_some(),
+                redirection_data,
+                mandate_reference: item.response.get_mandate_reference()?,
+                connector_metadata: item.response.get_meta().to_connector_meta()?,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Capture
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalPaymentsCaptureRequest {
+    amount: OrderAmount,
+    final_capture: bool,
+}
+
+impl TryFrom<&types::PaymentsCaptureRouterData> for PaypalPaymentsCaptureRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsCaptureRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: OrderAmount::new(
+                item.request
+                    .amount_to_capture
+                    .unwrap_or(item.request.amount),
+                item.request.currency,
+            ),
+            final_capture: true,
+        })
+    }
+}
+
+/// Returns the authorization of the order, which captures and voids are made against
+pub fn get_authorize_id(
+    connector_meta: Option<serde_json::Value>,
+) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+    PaypalMeta::from_connector_meta(connector_meta)?
+        .authorize_id
+        .ok_or_else(utils::missing_field_err("connector_meta.authorize_id"))
+}
+
+/// Returns the capture of the order, which refunds are made against
+pub fn get_capture_id(
+    connector_meta: Option<serde_json::Value>,
+) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+    PaypalMeta::from_connector_meta(connector_meta)?
+        .capture_id
+        .ok_or_else(utils::missing_field_err("connector_meta.capture_id"))
+}
+
+/// A capture or an authorization, as returned when capturing or voiding an authorization
+pub type PaypalPaymentResponse = PaypalPaymentItem;
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Capture,
+            PaypalPaymentResponse,
+            types::PaymentsCaptureData,
+            types::PaymentsResponseData,
+        >,
+    > for types::PaymentsCaptureRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Capture,
+            PaypalPaymentResponse,
+            types::PaymentsCaptureData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status = match item.response.status {
+            PaypalPaymentStatus::Declined | PaypalPaymentStatus::Failed => {
+                enums::AttemptStatus::CaptureFailed
+            }
+            status => enums::AttemptStatus::from(status),
+        };
+        let meta = PaypalMeta {
+            capture_id: Some(item.response.id),
+            ..PaypalMeta::from_connector_meta(item.data.request.connector_meta.clone())?
+        };
+        Ok(Self {
+            status,
+            // The payment stays identified by the order, the capture is kept in the metadata
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.request.connector_transaction_id.clone(),
+                ),
+                redirection_data: None,
+                redirect: false,
+                mandate_reference: None,
+                connector_metadata: meta.to_connector_meta()?,
+            }),
+            amount_captured: Some(
+                item.data
+                    .request
+                    .amount_to_capture
+                    .unwrap_or(item.data.request.amount),
+            ),
+            ..item.data
+        })
+    }
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Void,
+            PaypalPaymentResponse,
+            types::PaymentsCancelData,
+            types::PaymentsResponseData,
+        >,
+    > for types::PaymentsCancelRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Void,
+            PaypalPaymentResponse,
+            types::PaymentsCancelData,
+            types::PaymentsResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let status = match item.response.status {
+            PaypalPaymentStatus::Voided => enums::AttemptStatus::Voided,
+            _ => enums::AttemptStatus::VoidFailed,
+        };
+        Ok(Self {
+            status,
+            response: Ok(types::PaymentsResponseData::TransactionResponse {
+                resource_id: types::ResponseId::ConnectorTransactionId(
+                    item.data.request.connector_transaction_id.clone(),
+                ),
+                redirection_data: None,
+                redirect: false,
+                mandate_reference: None,
+                connector_metadata: None,
+            }),
+            ..item.data
+        })
+    }
+}
+
+// REFUND :
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PaypalRefundRequest {
+    amount: OrderAmount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note_to_payer: Option<String>,
+}
+
+impl<F> TryFrom<&types::RefundsRouterData<F>> for PaypalRefundRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: OrderAmount::new(item.request.refund_amount, item.request.currency),
+            note_to_payer: item.request.reason.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RefundStatus {
+    Cancelled,
+    Completed,
+    Failed,
+    Pending,
+}
+
+impl From<RefundStatus> for enums::RefundStatus {
+    fn from(item: RefundStatus) -> Self {
+        match item {
+            RefundStatus::Completed => Self::Success,
+            RefundStatus::Cancelled | RefundStatus::Failed => Self::Failure,
+            RefundStatus::Pending => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefundResponse {
+    id: String,
+    status: RefundStatus,
+}
+
+impl<F> TryFrom<types::RefundsResponseRouterData<F, RefundResponse>>
+    for types::RefundsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::RefundsResponseRouterData<F, RefundResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::RefundsResponseData {
+                connector_refund_id: item.response.id,
+                refund_status: enums::RefundStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// Error
+#[derive(Debug, Deserialize)]
+pub struct PaypalErrorDetails {
+    pub issue: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalErrorResponse {
+    pub name: String,
+    pub message: String,
+    pub debug_id: Option<String>,
+    pub details: Option<Vec<PaypalErrorDetails>>,
+}
+
+impl PaypalErrorResponse {
+    /// The issue PayPal found with the request, which is more specific than the name of the error
+    pub fn get_reason(&self) -> Option<String> {
+        self.details
+            .as_ref()
+            .and_then(|details| details.first())
+            .map(|detail| {
+                detail
+                    .description
+                    .clone()
+                    .unwrap_or_else(|| detail.issue.clone())
+            })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalAccessTokenErrorResponse {
+    pub error: String,
+    pub error_description: String,
+}
+
+// Webhooks
+#[derive(Debug, Clone, Copy, Deserialize, Eq, PartialEq)]
+pub enum PaypalWebhookEventType {
+    #[serde(rename = "PAYMENT.AUTHORIZATION.CREATED")]
+    PaymentAuthorizationCreated,
+    #[serde(rename = "PAYMENT.AUTHORIZATION.VOIDED")]
+    PaymentAuthorizationVoided,
+    #[serde(rename = "PAYMENT.CAPTURE.COMPLETED")]
+    PaymentCaptureCompleted,
+    #[serde(rename = "PAYMENT.CAPTURE.PENDING")]
+    PaymentCapturePending,
+    #[serde(rename = "PAYMENT.CAPTURE.DECLINED")]
+    PaymentCaptureDeclined,
+    #[serde(rename = "PAYMENT.CAPTURE.DENIED")]
+    PaymentCaptureDenied,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalRelatedIds {
+    pub order_id: String,
+    pub authorization_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalSupplementaryData {
+    pub related_ids: PaypalRelatedIds,
+}
+
+/// The authorization or capture the event is about
+#[derive(Debug, Deserialize)]
+pub struct PaypalWebhookResource {
+    pub id: String,
+    pub status: PaypalPaymentStatus,
+    pub supplementary_data: PaypalSupplementaryData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PaypalWebhookBody {
+    pub event_type: PaypalWebhookEventType,
+    pub resource: PaypalWebhookResource,
+}
+
+impl TryFrom<&PaypalWebhookEventType> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(event_type: &PaypalWebhookEventType) -> Result<Self, Self::Error> {
+        match event_type {
+            PaypalWebhookEventType::PaymentAuthorizationCreated
+            | PaypalWebhookEventType::PaymentCaptureCompleted
+            | PaypalWebhookEventType::PaymentCapturePending => Ok(Self::PaymentIntentSuccess),
+            PaypalWebhookEventType::PaymentAuthorizationVoided
+            | PaypalWebhookEventType::PaymentCaptureDeclined
+            | PaypalWebhookEventType::PaymentCaptureDenied => Ok(Self::PaymentIntentFailure),
+            PaypalWebhookEventType::Unknown => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()
+            }
+        }
+    }
+}
+
+impl TryFrom<PaypalWebhookBody> for PaypalOrderResponse {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(webhook: PaypalWebhookBody) -> Result<Self, Self::Error> {
+        let resource = PaypalPaymentItem {
+            id: webhook.resource.id,
+            status: webhook.resource.status,
+        };
+        let payments = match webhook.event_type {
+            PaypalWebhookEventType::PaymentAuthorizationCreated
+            | PaypalWebhookEventType::PaymentAuthorizationVoided => PaypalPaymentCollection {
+                authorizations: vec![resource],
+                captures: Vec::new(),
+            },
+            PaypalWebhookEventType::PaymentCaptureCompleted
+            | PaypalWebhookEventType::PaymentCapturePending
+            | PaypalWebhookEventType::PaymentCaptureDeclined
+            | PaypalWebhookEventType::PaymentCaptureDenied => {
+                // Captures of authorized orders keep the authorization they were made against,
+                // for voids and refunds to find it
+                let authorizations = webhook
+                    .resource
+                    .supplementary_data
+                    .related_ids
+                    .authorization_id
+                    .map(|authorization_id| PaypalPaymentItem {
+                        id: authorization_id,
+                        status: PaypalPaymentStatus::Captured,
+                    })
+                    .into_iter()
+                    .collect();
+                PaypalPaymentCollection {
+                    authorizations,
+                    captures: vec![resource],
+                }
+            }
+            PaypalWebhookEventType::Unknown => {
+                Err(errors::ConnectorError::WebhookResourceObjectNotFound).into_report()?
+            }
+        };
+        Ok(Self {
+            id: webhook.resource.supplementary_data.related_ids.order_id,
+            status: PaypalOrderStatus::Completed,
+            purchase_units: vec![PurchaseUnitResponse {
+                payments: Some(payments),
+            }],
+            links: Vec::new(),
+            payment_source: None,
+        })
+    }
+}
+
+/// The secret a merchant configures to verify the webhooks of PayPal: the ID PayPal assigned to
+/// the webhook, and the public key of the certificate PayPal signs webhooks with, as a base64
+/// encoded DER `RSAPublicKey`
+#[derive(Debug, Deserialize)]
+pub struct PaypalWebhookSecret {
+    pub webhook_id: String,
+    pub public_key: Secret<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn should_convert_amounts_to_the_base_unit() {
+        assert_eq!(get_amount_value(1000, enums::Currency::USD), "10.00");
+        assert_eq!(get_amount_value(1, enums::Currency::EUR), "0.01");
+        assert_eq!(get_amount_value(12345, enums::Currency::GBP), "123.45");
+        assert_eq!(get_amount_value(500, enums::Currency::JPY), "500");
+    }
+
+    #[test]
+    fn should_detect_approved_orders_from_redirect_query() {
+        assert!(PaypalRedirectResponse::is_order_approved(
+            "token=5O190127TN364715T&PayerID=FSMVU44LF3YUS"
+        ));
+        assert!(!PaypalRedirectResponse::is_order_approved(
+            "token=5O190127TN364715T"
+        ));
+    }
+
+    #[test]
+    fn should_record_the_kind_of_mandate_references() {
+        for (mandate_reference, connector_mandate_id) in [
+            (
+                PaypalMandateReference::VaultId("nkq2y9g".to_string()),
+                r#"{"type":"vault_id","id":"nkq2y9g"}"#,
+            ),
+            (
+                PaypalMandateReference::BillingAgreement("B-7TX81530T8384432T".to_string()),
+                r#"{"type":"billing_agreement","id":"B-7TX81530T8384432T"}"#,
+            ),
+        ] {
+            assert_eq!(
+                mandate_reference.to_connector_mandate_id().unwrap(),
+                connector_mandate_id
+            );
+            assert_eq!(
+                PaypalMandateReference::from_connector_mandate_id(connector_mandate_id).unwrap(),
+                mandate_reference
+            );
+        }
+        assert!(PaypalMandateReference::from_connector_mandate_id("B-7TX81530T8384432T").is_err());
+    }
+}
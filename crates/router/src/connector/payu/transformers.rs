@@ -3,7 +3,7 @@ use error_stack::{IntoReport, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    connector::utils::AccessTokenRequestInfo,
+    connector::utils::{self, AccessTokenRequestInfo},
     consts,
     core::errors,
     pii::{self, Secret},
@@ -12,6 +12,7 @@ use crate::{
 };
 
 const WALLET_IDENTIFIER: &str = "PBL";
+const UPI_INTENT_URI_SCHEME: &str = "upi://";
 
 #[derive(Debug, Serialize, Eq, PartialEq)]
 #[serde(rename_all = "camelCase")]
@@ -36,6 +37,7 @@ pub struct PayuPaymentMethod {
 pub enum PayuPaymentMethodData {
     Card(PayuCard),
     Wallet(PayuWallet),
+    Upi(PayuUpi),
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -65,6 +67,25 @@ pub enum PayuWalletCode {
     Jp,
 }
 
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayuUpi {
+    pub value: PayuUpiCode,
+    #[serde(rename = "type")]
+    pub upi_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vpa: Option<Secret<String, pii::UpiVpa>>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum PayuUpiCode {
+    /// A collect request is sent to the VPA of the customer
+    Upi,
+    /// The customer is given a UPI intent URI to open in their UPI app
+    Intent,
+}
+
 impl TryFrom<&types::PaymentsAuthorizeRouterData> for PayuPaymentsRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
@@ -113,6 +134,20 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PayuPaymentsRequest {
                     "Unknown Wallet in Payment Method".to_string(),
                 )),
             },
+            api::PaymentMethod::Upi(upi_data) => Ok(PayuPaymentMethod {
+                pay_method: PayuPaymentMethodData::Upi(match upi_data {
+                    api::UpiData::UpiCollect { vpa_id } => PayuUpi {
+                        value: PayuUpiCode::Upi,
+                        upi_type: WALLET_IDENTIFIER.to_string(),
+                        vpa: Some(vpa_id),
+                    },
+                    api::UpiData::UpiIntent {} => PayuUpi {
+                        value: PayuUpiCode::Intent,
+                        upi_type: WALLET_IDENTIFIER.to_string(),
+                        vpa: None,
+                    },
+                }),
+            }),
             _ => Err(errors::ConnectorError::NotImplemented(
                 "Unknown payment method".to_string(),
             )),
@@ -195,6 +230,23 @@ pub struct PayuPaymentsResponse {
     pub ext_order_id: Option<String>,
 }
 
+impl PayuPaymentsResponse {
+    /// How the customer approves a UPI payment. PayU returns the intent URI to open in the UPI
+    /// app of the customer as the redirect URI, otherwise a collect request was sent to their VPA
+    fn get_upi_next_steps(&self) -> api::UpiNextSteps {
+        let upi_intent_uri = Some(self.redirect_uri.clone())
+            .filter(|redirect_uri| redirect_uri.starts_with(UPI_INTENT_URI_SCHEME));
+        api::UpiNextSteps {
+            upi_flow: match upi_intent_uri {
+                Some(_) => api_models::enums::PaymentMethodSubType::UpiIntent,
+                None => api_models::enums::PaymentMethodSubType::UpiCollect,
+            },
+            upi_intent_uri,
+            expires_at: None,
+        }
+    }
+}
+
 impl<F, T>
     TryFrom<types::ResponseRouterData<F, PayuPaymentsResponse, T, types::PaymentsResponseData>>
     for types::RouterData<F, T, types::PaymentsResponseData>
@@ -203,14 +255,25 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, PayuPaymentsResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
+        let status = enums::AttemptStatus::from(item.response.status.status_code.clone());
+        // UPI payments wait on the customer to approve them in their UPI app
+        let (status, connector_metadata) = match item.data.payment_method {
+            enums::PaymentMethodType::Upi => (
+                enums::AttemptStatus::AuthenticationPending,
+                Some(utils::get_upi_next_steps(
+                    item.response.get_upi_next_steps(),
+                )?),
+            ),
+            _ => (status, None),
+        };
         Ok(Self {
-            status: enums::AttemptStatus::from(item.response.status.status_code),
+            status,
             response: Ok(types::PaymentsResponseData::TransactionResponse {
                 resource_id: types::ResponseId::ConnectorTransactionId(item.response.order_id),
                 redirect: false,
                 redirection_data: None,
                 mandate_reference: None,
-                connector_metadata: None,
+                connector_metadata,
             }),
             amount_captured: None,
             ..item.data
@@ -390,7 +453,7 @@ pub struct PayuProductData {
     listing_date: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PayuOrderResponseData {
     order_id: String,
@@ -424,7 +487,7 @@ pub struct PayuOrderResponseBuyerData {
     customer_id: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PayuOrderResponsePayMethod {
     CardToken,
@@ -438,7 +501,7 @@ pub struct PayuOrderResponseProperty {
     value: String,
 }
 
-#[derive(Default, Debug, Clone, Deserialize, PartialEq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PayuPaymentsSyncResponse {
     orders: Vec<PayuOrderResponseData>,
     status: PayuPaymentStatusData,
@@ -613,3 +676,72 @@ pub struct PayuAccessTokenErrorResponse {
     pub error: String,
     pub error_description: String,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct PayuWebhookBody {
+    pub order: PayuOrderResponseData,
+    pub properties: Option<Vec<PayuOrderResponseProperty>>,
+}
+
+impl PayuWebhookBody {
+    pub fn get_order_id(&self) -> String {
+        self.order.order_id.clone()
+    }
+}
+
+impl TryFrom<&PayuWebhookBody> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(webhook: &PayuWebhookBody) -> Result<Self, Self::Error> {
+        match webhook.order.status {
+            OrderStatus::Completed | OrderStatus::WaitingForConfirmation => {
+                Ok(Self::PaymentIntentSuccess)
+            }
+            OrderStatus::Canceled => Ok(Self::PaymentIntentFailure),
+            OrderStatus::New | OrderStatus::Pending => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()
+            }
+        }
+    }
+}
+
+impl From<PayuWebhookBody> for PayuPaymentsSyncResponse {
+    fn from(webhook: PayuWebhookBody) -> Self {
+        Self {
+            orders: vec![webhook.order],
+            status: PayuPaymentStatusData {
+                status_code: PayuPaymentStatus::Success,
+                severity: None,
+                status_desc: None,
+            },
+            properties: webhook.properties,
+        }
+    }
+}
+
+/// The parts of the `OpenPayu-Signature` header of a notification
+#[derive(Debug, Default)]
+pub struct PayuWebhookSignature {
+    pub signature: String,
+    pub algorithm: String,
+}
+
+impl std::str::FromStr for PayuWebhookSignature {
+    type Err = error_stack::Report<errors::ConnectorError>;
+    fn from_str(header: &str) -> Result<Self, Self::Err> {
+        let mut webhook_signature = Self::default();
+        for (key, value) in header
+            .split(';')
+            .filter_map(|element| element.split_once('='))
+        {
+            match key {
+                "signature" => webhook_signature.signature = value.to_string(),
+                "algorithm" => webhook_signature.algorithm = value.to_string(),
+                _ => (),
+            }
+        }
+        if webhook_signature.signature.is_empty() {
+            Err(errors::ConnectorError::WebhookSignatureNotFound).into_report()?
+        }
+        Ok(webhook_signature)
+    }
+}
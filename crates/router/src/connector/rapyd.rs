@@ -88,6 +88,27 @@ impl ConnectorCommon for Rapyd {
             reason: response.status.message,
         })
     }
+
+    fn get_capabilities(
+        &self,
+        capabilities: api::ConnectorCapabilities,
+    ) -> api::ConnectorCapabilities {
+        use api::enums::{CaptureMethod, PaymentMethodType};
+
+        api::ConnectorCapabilities {
+            payment_methods: vec![
+                PaymentMethodType::Card,
+                PaymentMethodType::Wallet,
+                PaymentMethodType::Upi,
+            ],
+            currencies: None,
+            countries: None,
+            capture_methods: vec![CaptureMethod::Automatic, CaptureMethod::Manual],
+            supports_mandates: false,
+            supports_network_tokens: false,
+            ..capabilities
+        }
+    }
 }
 
 impl api::ConnectorAccessToken for Rapyd {}
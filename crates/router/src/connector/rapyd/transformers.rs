@@ -1,8 +1,11 @@
+use std::collections::HashMap;
+
 use error_stack::{IntoReport, ResultExt};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::{
+    connector::utils::{self, PaymentsRequestData},
     consts,
     core::errors,
     pii::{self, Secret},
@@ -15,6 +18,9 @@ use crate::{
     utils::OptionExt,
 };
 
+const RAPYD_UPI_COLLECT: &str = "in_upi_collect_bank";
+const RAPYD_UPI_INTENT: &str = "in_upi_intent_bank";
+
 #[derive(Default, Debug, Serialize)]
 pub struct RapydPaymentsRequest {
     pub amount: i64,
@@ -23,6 +29,8 @@ pub struct RapydPaymentsRequest {
     pub payment_method_options: Option<PaymentMethodOptions>,
     pub capture: Option<bool>,
     pub description: Option<String>,
+    pub complete_payment_url: Option<String>,
+    pub error_payment_url: Option<String>,
 }
 
 #[derive(Default, Debug, Serialize)]
@@ -34,11 +42,18 @@ pub struct PaymentMethodOptions {
 pub struct PaymentMethod {
     #[serde(rename = "type")]
     pub pm_type: String,
-    pub fields: Option<PaymentFields>,
+    pub fields: Option<RapydPaymentFields>,
     pub address: Option<Address>,
     pub digital_wallet: Option<RapydWallet>,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum RapydPaymentFields {
+    Card(PaymentFields),
+    Upi(UpiFields),
+}
+
 #[derive(Default, Debug, Serialize)]
 pub struct PaymentFields {
     pub number: Secret<String, pii::CardNumber>,
@@ -48,6 +63,11 @@ pub struct PaymentFields {
     pub cvv: Secret<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct UpiFields {
+    pub vpa: Secret<String, pii::UpiVpa>,
+}
+
 #[derive(Default, Debug, Serialize)]
 pub struct Address {
     name: String,
@@ -92,13 +112,13 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for RapydPaymentsRequest {
             api_models::payments::PaymentMethod::Card(ref ccard) => {
                 Some(PaymentMethod {
                     pm_type: "in_amex_card".to_owned(), //[#369] Map payment method type based on country
-                    fields: Some(PaymentFields {
+                    fields: Some(RapydPaymentFields::Card(PaymentFields {
                         number: ccard.card_number.to_owned(),
                         expiration_month: ccard.card_exp_month.to_owned(),
                         expiration_year: ccard.card_exp_year.to_owned(),
                         name: ccard.card_holder_name.to_owned(),
                         cvv: ccard.card_cvc.to_owned(),
-                    }),
+                    })),
                     address: None,
                     digital_wallet: None,
                 })
@@ -122,12 +142,69 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for RapydPaymentsRequest {
                     digital_wallet,
                 })
             }
+            api_models::payments::PaymentMethod::BankRedirect(ref bank_redirect_data) => {
+                let pm_type = match bank_redirect_data {
+                    api::BankRedirectData::Ideal { .. } => "nl_ideal_bank".to_string(),
+                    api::BankRedirectData::Sofort { country, .. } => {
+                        format!("{}_sofort_bank", country.to_lowercase())
+                    }
+                    api::BankRedirectData::Giropay { .. } => "de_giropay_bank".to_string(),
+                    api::BankRedirectData::Eps { .. } => "at_eps_bank".to_string(),
+                    api::BankRedirectData::BancontactCard { .. } => {
+                        "be_bancontact_card".to_string()
+                    }
+                    api::BankRedirectData::Przelewy24 { .. } => "pl_przelewy24_bank".to_string(),
+                };
+                Some(PaymentMethod {
+                    pm_type,
+                    fields: None,
+                    address: None,
+                    digital_wallet: None,
+                })
+            }
+            api_models::payments::PaymentMethod::LocalBankTransfer(ref bank_transfer_data) => {
+                let pm_type = match bank_transfer_data {
+                    api::BankTransferData::Pix { .. } => "br_pix_bank",
+                    api::BankTransferData::Boleto { .. } => "br_boleto_cash",
+                };
+                Some(PaymentMethod {
+                    pm_type: pm_type.to_string(),
+                    fields: None,
+                    address: None,
+                    digital_wallet: None,
+                })
+            }
+            api_models::payments::PaymentMethod::Upi(ref upi_data) => {
+                let (pm_type, fields) = match upi_data {
+                    api::UpiData::UpiCollect { vpa_id } => (
+                        RAPYD_UPI_COLLECT,
+                        Some(RapydPaymentFields::Upi(UpiFields {
+                            vpa: vpa_id.to_owned(),
+                        })),
+                    ),
+                    api::UpiData::UpiIntent {} => (RAPYD_UPI_INTENT, None),
+                };
+                Some(PaymentMethod {
+                    pm_type: pm_type.to_string(),
+                    fields,
+                    address: None,
+                    digital_wallet: None,
+                })
+            }
             _ => None,
         }
         .get_required_value("payment_method not implemnted")
         .change_context(errors::ConnectorError::NotImplemented(
             "payment_method".to_owned(),
         ))?;
+        // Bank redirects send the customer back to us once they have authorized the payment
+        let (complete_payment_url, error_payment_url) = match item.request.payment_method_data {
+            api_models::payments::PaymentMethod::BankRedirect(_) => {
+                let return_url = item.get_return_url()?;
+                (Some(return_url.clone()), Some(return_url))
+            }
+            _ => (None, None),
+        };
         Ok(Self {
             amount: item.request.amount,
             currency: item.request.currency,
@@ -135,6 +212,8 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for RapydPaymentsRequest {
             capture,
             payment_method_options,
             description: None,
+            complete_payment_url,
+            error_payment_url,
         })
     }
 }
@@ -237,6 +316,61 @@ pub struct ResponseData {
     pub paid: Option<bool>,
     pub failure_code: Option<String>,
     pub failure_message: Option<String>,
+    pub textual_codes: Option<HashMap<String, String>>,
+    pub visual_codes: Option<HashMap<String, String>>,
+    pub expiration: Option<i64>,
+    pub payment_method_type: Option<String>,
+}
+
+impl ResponseData {
+    /// The codes the customer pays with, for payment methods completed outside the checkout
+    /// such as PIX and Boleto
+    fn get_bank_transfer_instructions(&self) -> Option<api::BankTransferInstructions> {
+        if self.textual_codes.is_none() && self.visual_codes.is_none() {
+            return None;
+        }
+        let first_code = |codes: &Option<HashMap<String, String>>| {
+            codes
+                .as_ref()
+                .and_then(|codes| codes.values().next().cloned())
+        };
+        Some(api::BankTransferInstructions {
+            reference: first_code(&self.textual_codes),
+            qr_code_url: first_code(&self.visual_codes),
+            expires_at: self.get_expires_at(),
+            ..Default::default()
+        })
+    }
+
+    /// How the customer approves a UPI payment, either in their UPI app on a collect request sent
+    /// to their VPA, or by opening their UPI app with the intent URI
+    fn get_upi_next_steps(&self) -> Option<api::UpiNextSteps> {
+        let upi_flow = match self.payment_method_type.as_deref() {
+            Some(RAPYD_UPI_COLLECT) => api_models::enums::PaymentMethodSubType::UpiCollect,
+            Some(RAPYD_UPI_INTENT) => api_models::enums::PaymentMethodSubType::UpiIntent,
+            _ => return None,
+        };
+        let upi_intent_uri = self.redirect_url.clone().or_else(|| {
+            self.textual_codes.as_ref().and_then(|codes| {
+                codes
+                    .values()
+                    .find(|code| code.starts_with("upi://"))
+                    .cloned()
+            })
+        });
+        Some(api::UpiNextSteps {
+            upi_flow,
+            upi_intent_uri,
+            expires_at: self.get_expires_at(),
+        })
+    }
+
+    fn get_expires_at(&self) -> Option<time::PrimitiveDateTime> {
+        self.expiration
+            .filter(|expiration| *expiration > 0)
+            .and_then(|expiration| time::OffsetDateTime::from_unix_timestamp(expiration).ok())
+            .map(|expiration| time::PrimitiveDateTime::new(expiration.date(), expiration.time()))
+    }
 }
 
 #[derive(Default, Debug, Serialize)]
@@ -408,6 +542,24 @@ impl<F, T>
                                 }
                                 (_, _) => None,
                             };
+                        let connector_metadata = match attempt_status {
+                            enums::AttemptStatus::Pending => match data.get_upi_next_steps() {
+                                Some(upi_next_steps) => {
+                                    Some(utils::get_upi_next_steps(upi_next_steps)?)
+                                }
+                                None => data
+                                    .get_bank_transfer_instructions()
+                                    .map(utils::get_bank_transfer_next_steps)
+                                    .transpose()?,
+                            },
+                            _ => None,
+                        };
+                        // The payment waits on the customer to pay with the instructions, or to
+                        // approve it in their UPI app
+                        let attempt_status = match connector_metadata {
+                            Some(_) => enums::AttemptStatus::AuthenticationPending,
+                            None => attempt_status,
+                        };
                         (
                             attempt_status,
                             Ok(types::PaymentsResponseData::TransactionResponse {
@@ -417,7 +569,7 @@ impl<F, T>
                                 redirect: redirection_data.is_some(),
                                 redirection_data,
                                 mandate_reference: None,
-                                connector_metadata: None,
+                                connector_metadata,
                             }),
                         )
                     }
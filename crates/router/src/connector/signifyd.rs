@@ -0,0 +1,252 @@
+mod transformers;
+
+use std::fmt::Debug;
+
+use error_stack::ResultExt;
+use transformers as signifyd;
+
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, CustomResult},
+    headers, logger,
+    services::{self, ConnectorIntegration},
+    types::{
+        self,
+        api::{self, ConnectorCommon, ConnectorCommonExt},
+        ErrorResponse,
+    },
+    utils::{self, BytesExt},
+};
+
+#[derive(Debug, Clone)]
+pub struct Signifyd;
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Signifyd
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &types::RouterData<Flow, Request, Response>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let mut headers = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            self.get_content_type().to_string(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        headers.append(&mut api_key);
+        Ok(headers)
+    }
+}
+
+impl ConnectorCommon for Signifyd {
+    fn id(&self) -> &'static str {
+        "signifyd"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, connectors: &'a settings::Connectors) -> &'a str {
+        connectors.signifyd.base_url.as_ref()
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &types::ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        let auth: signifyd::SignifydAuthType = auth_type
+            .try_into()
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        Ok(vec![(
+            headers::AUTHORIZATION.to_string(),
+            format!("Basic {}", auth.api_key),
+        )])
+    }
+
+    fn build_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: signifyd::SignifydErrorResponse = res
+            .response
+            .parse_struct("Signifyd ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        Ok(ErrorResponse {
+            status_code: res.status_code,
+            code: consts::NO_ERROR_CODE.to_string(),
+            message: response
+                .errors
+                .map(|errors| errors.to_string())
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: response.trace_id,
+        })
+    }
+}
+
+impl api::FraudCheck for Signifyd {}
+impl api::FraudCheckPreAuth for Signifyd {}
+
+impl ConnectorIntegration<api::PreAuth, types::FraudCheckPreAuthData, types::FraudCheckResponseData>
+    for Signifyd
+{
+    fn get_headers(
+        &self,
+        req: &types::FrmPreAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::FrmPreAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v3/orders/events/checkouts",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::FrmPreAuthRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let signifyd_req =
+            utils::Encode::<signifyd::SignifydCheckoutRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(signifyd_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FrmPreAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::FrmPreAuthType::get_url(self, req, connectors)?)
+                .headers(types::FrmPreAuthType::get_headers(self, req, connectors)?)
+                .body(types::FrmPreAuthType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FrmPreAuthRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::FrmPreAuthRouterData, errors::ConnectorError> {
+        let response: signifyd::SignifydResponse = res
+            .response
+            .parse_struct("SignifydResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::debug!(signifyd_checkout_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl api::FraudCheckPostAuth for Signifyd {}
+
+impl
+    ConnectorIntegration<
+        api::PostAuth,
+        types::FraudCheckPostAuthData,
+        types::FraudCheckResponseData,
+    > for Signifyd
+{
+    fn get_headers(
+        &self,
+        req: &types::FrmPostAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, String)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::FrmPostAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v3/orders/events/transactions",
+            self.base_url(connectors)
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::FrmPostAuthRouterData,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let signifyd_req =
+            utils::Encode::<signifyd::SignifydTransactionsRequest>::convert_and_encode(req)
+                .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        Ok(Some(signifyd_req))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::FrmPostAuthRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::FrmPostAuthType::get_url(self, req, connectors)?)
+                .headers(types::FrmPostAuthType::get_headers(self, req, connectors)?)
+                .body(types::FrmPostAuthType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::FrmPostAuthRouterData,
+        res: types::Response,
+    ) -> CustomResult<types::FrmPostAuthRouterData, errors::ConnectorError> {
+        let response: signifyd::SignifydResponse = res
+            .response
+            .parse_struct("SignifydResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        logger::debug!(signifyd_transaction_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
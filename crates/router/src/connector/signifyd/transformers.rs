@@ -0,0 +1,296 @@
+use base64::Engine;
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    connector::utils::{self, CardData},
+    consts,
+    core::errors,
+    types::{self, api, storage::enums},
+};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydCheckoutRequest {
+    checkout_id: String,
+    order_id: String,
+    purchase: Purchase,
+    transactions: Vec<CheckoutTransaction>,
+    user_account: Option<UserAccount>,
+    device: Option<Device>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Purchase {
+    order_channel: OrderChannel,
+    total_price: f64,
+    currency: String,
+    products: Vec<Product>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderChannel {
+    Web,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Product {
+    item_name: String,
+    item_quantity: u16,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SignifydPaymentMethod {
+    CreditCard,
+    Unknown,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckoutTransaction {
+    payment_method: SignifydPaymentMethod,
+    amount: f64,
+    currency: String,
+    checkout_payment_details: Option<CheckoutPaymentDetails>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckoutPaymentDetails {
+    holder_name: String,
+    card_bin: Option<String>,
+    card_last4: Option<String>,
+    card_expiry_month: String,
+    card_expiry_year: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserAccount {
+    email: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Device {
+    client_ip_address: String,
+}
+
+/// Signifyd takes amounts in the major unit of the currency
+fn to_major_unit(amount: i64) -> Result<f64, error_stack::Report<errors::ConnectorError>> {
+    format!("{}.{:02}", amount / 100, amount % 100)
+        .parse()
+        .into_report()
+        .change_context(errors::ConnectorError::RequestEncodingFailed)
+}
+
+impl TryFrom<&types::FrmPreAuthRouterData> for SignifydCheckoutRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::FrmPreAuthRouterData) -> Result<Self, Self::Error> {
+        let amount = to_major_unit(item.request.amount)?;
+        let currency = item.request.currency.to_string();
+        let (payment_method, checkout_payment_details) = match item.request.payment_method_data {
+            api::PaymentMethod::Card(ref card) => {
+                let card_number = card.get_card_number();
+                (
+                    SignifydPaymentMethod::CreditCard,
+                    Some(CheckoutPaymentDetails {
+                        holder_name: card.card_holder_name.peek().clone(),
+                        card_bin: card_number.get(..6).map(ToString::to_string),
+                        card_last4: card_number
+                            .get(card_number.len().saturating_sub(4)..)
+                            .map(ToString::to_string),
+                        card_expiry_month: card.get_card_expiry_month(),
+                        card_expiry_year: card.get_card_expiry_year(),
+                    }),
+                )
+            }
+            _ => (SignifydPaymentMethod::Unknown, None),
+        };
+        Ok(Self {
+            checkout_id: item
+                .attempt_id
+                .clone()
+                .ok_or_else(utils::missing_field_err("attempt_id"))?,
+            order_id: item.payment_id.clone(),
+            purchase: Purchase {
+                order_channel: OrderChannel::Web,
+                total_price: amount,
+                currency: currency.clone(),
+                products: item
+                    .request
+                    .order_details
+                    .iter()
+                    .map(|order| Product {
+                        item_name: order.product_name.clone(),
+                        item_quantity: order.quantity,
+                    })
+                    .collect(),
+            },
+            transactions: vec![CheckoutTransaction {
+                payment_method,
+                amount,
+                currency,
+                checkout_payment_details,
+            }],
+            user_account: item.request.email.as_ref().map(|email| UserAccount {
+                email: email.peek().clone(),
+            }),
+            device: item
+                .request
+                .browser_info
+                .as_ref()
+                .and_then(|browser_info| browser_info.ip_address)
+                .map(|ip_address| Device {
+                    client_ip_address: ip_address.to_string(),
+                }),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydTransactionsRequest {
+    checkout_id: String,
+    order_id: String,
+    transactions: Vec<Transaction>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GatewayStatusCode {
+    Success,
+    Failure,
+    Pending,
+}
+
+impl From<enums::AttemptStatus> for GatewayStatusCode {
+    fn from(status: enums::AttemptStatus) -> Self {
+        match status {
+            enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged => Self::Success,
+            enums::AttemptStatus::AuthenticationFailed
+            | enums::AttemptStatus::AuthorizationFailed
+            | enums::AttemptStatus::RouterDeclined
+            | enums::AttemptStatus::Failure => Self::Failure,
+            _ => Self::Pending,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Transaction {
+    transaction_id: Option<String>,
+    gateway_status_code: GatewayStatusCode,
+    gateway: String,
+    amount: f64,
+    currency: String,
+}
+
+impl TryFrom<&types::FrmPostAuthRouterData> for SignifydTransactionsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::FrmPostAuthRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            checkout_id: item
+                .attempt_id
+                .clone()
+                .ok_or_else(utils::missing_field_err("attempt_id"))?,
+            order_id: item.payment_id.clone(),
+            transactions: vec![Transaction {
+                transaction_id: item.request.connector_transaction_id.clone(),
+                gateway_status_code: item.request.attempt_status.into(),
+                gateway: item.request.connector.clone(),
+                amount: to_major_unit(item.request.amount)?,
+                currency: item.request.currency.to_string(),
+            }],
+        })
+    }
+}
+
+// Auth Struct
+pub struct SignifydAuthType {
+    pub(super) api_key: String,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for SignifydAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        if let types::ConnectorAuthType::HeaderKey { api_key } = item {
+            Ok(Self {
+                api_key: consts::BASE64_ENGINE.encode(api_key),
+            })
+        } else {
+            Err(errors::ConnectorError::FailedToObtainAuthType)?
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CheckpointAction {
+    Accept,
+    Reject,
+    Hold,
+}
+
+impl From<CheckpointAction> for enums::FraudCheckStatus {
+    fn from(action: CheckpointAction) -> Self {
+        match action {
+            CheckpointAction::Accept => Self::Accept,
+            CheckpointAction::Reject => Self::Reject,
+            CheckpointAction::Hold => Self::ManualReview,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Decision {
+    checkpoint_action: CheckpointAction,
+    checkpoint_action_reason: Option<String>,
+    score: Option<f64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydResponse {
+    signifyd_id: i64,
+    decision: Decision,
+}
+
+impl<F, T> TryFrom<types::FrmResponseRouterData<F, SignifydResponse, T>>
+    for types::RouterData<F, T, types::FraudCheckResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::FrmResponseRouterData<F, SignifydResponse, T>,
+    ) -> Result<Self, Self::Error> {
+        let decision = item.response.decision;
+        Ok(Self {
+            response: Ok(types::FraudCheckResponseData {
+                frm_transaction_id: Some(item.response.signifyd_id.to_string()),
+                frm_status: decision.checkpoint_action.into(),
+                // Signifyd scores range from 0 to 1000, with higher scores being less risky
+                score: decision
+                    .score
+                    .and_then(|score| format!("{score:.0}").parse().ok()),
+                reason: decision.checkpoint_action_reason,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignifydErrorResponse {
+    pub trace_id: Option<String>,
+    pub errors: Option<serde_json::Value>,
+}
@@ -52,6 +52,29 @@ impl ConnectorCommon for Stripe {
             format!("Bearer {}", auth.api_key),
         )])
     }
+
+    fn get_capabilities(
+        &self,
+        capabilities: api::ConnectorCapabilities,
+    ) -> api::ConnectorCapabilities {
+        use api::enums::{CaptureMethod, PaymentMethodType};
+
+        api::ConnectorCapabilities {
+            payment_methods: vec![
+                PaymentMethodType::Card,
+                PaymentMethodType::BankDebit,
+                PaymentMethodType::BankRedirect,
+                PaymentMethodType::PayLater,
+                PaymentMethodType::Wallet,
+            ],
+            currencies: None,
+            countries: None,
+            capture_methods: vec![CaptureMethod::Automatic, CaptureMethod::Manual],
+            supports_mandates: true,
+            supports_network_tokens: false,
+            ..capabilities
+        }
+    }
 }
 
 impl api::Payment for Stripe {}
@@ -195,7 +218,7 @@ impl
                 .error
                 .message
                 .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
-            reason: None,
+            reason: response.error.decline_code,
         })
     }
 }
@@ -296,7 +319,7 @@ impl
                 .error
                 .message
                 .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
-            reason: None,
+            reason: response.error.decline_code,
         })
     }
 }
@@ -408,7 +431,7 @@ impl
                 .error
                 .message
                 .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
-            reason: None,
+            reason: response.error.decline_code,
         })
     }
 }
@@ -515,7 +538,7 @@ impl
                 .error
                 .message
                 .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
-            reason: None,
+            reason: response.error.decline_code,
         })
     }
 }
@@ -642,7 +665,7 @@ impl
                 .error
                 .message
                 .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
-            reason: None,
+            reason: response.error.decline_code,
         })
     }
 }
@@ -746,7 +769,7 @@ impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::Ref
                 .error
                 .message
                 .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
-            reason: None,
+            reason: response.error.decline_code,
         })
     }
 }
@@ -841,7 +864,7 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
                 .error
                 .message
                 .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
-            reason: None,
+            reason: response.error.decline_code,
         })
     }
 }
@@ -10,6 +10,7 @@ use url::Url;
 use uuid::Uuid;
 
 use crate::{
+    connector::utils,
     core::errors,
     pii::{self, ExposeOptionInterface, Secret},
     services,
@@ -82,11 +83,15 @@ pub struct PaymentIntentRequest {
     pub confirm: bool,
     pub off_session: Option<bool>,
     pub mandate: Option<String>,
+    pub payment_method: Option<String>,
+    pub setup_future_usage: Option<enums::FutureUsage>,
     pub description: Option<String>,
     #[serde(flatten)]
     pub shipping: StripeShippingAddress,
     #[serde(flatten)]
     pub payment_data: Option<StripePaymentMethodData>,
+    #[serde(flatten)]
+    pub mandate_data: Option<StripeMandateRequest>,
     pub capture_method: StripeCaptureMethod,
 }
 
@@ -136,6 +141,132 @@ pub struct StripePayLaterData {
     pub billing_name: Option<String>,
 }
 
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeBankDebitData {
+    #[serde(rename = "payment_method_types[]")]
+    pub payment_method_types: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[type]")]
+    pub payment_method_data_type: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[billing_details][name]")]
+    pub billing_name: Secret<String>,
+    #[serde(rename = "payment_method_data[billing_details][email]")]
+    pub billing_email: Secret<String, pii::Email>,
+    #[serde(rename = "payment_method_data[billing_details][address][line1]")]
+    pub billing_address_line1: Option<Secret<String>>,
+    #[serde(rename = "payment_method_data[billing_details][address][city]")]
+    pub billing_address_city: Option<String>,
+    #[serde(rename = "payment_method_data[billing_details][address][postal_code]")]
+    pub billing_address_postal_code: Option<Secret<String>>,
+    #[serde(rename = "payment_method_data[billing_details][address][country]")]
+    pub billing_address_country: Option<String>,
+    #[serde(flatten)]
+    pub bank_specific_data: StripeBankDebitSpecificData,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum StripeBankDebitSpecificData {
+    Ach {
+        #[serde(rename = "payment_method_data[us_bank_account][account_holder_type]")]
+        account_holder_type: String,
+        #[serde(rename = "payment_method_data[us_bank_account][account_number]")]
+        account_number: Secret<String, pii::BankAccountNumber>,
+        #[serde(rename = "payment_method_data[us_bank_account][routing_number]")]
+        routing_number: Secret<String>,
+    },
+    Sepa {
+        #[serde(rename = "payment_method_data[sepa_debit][iban]")]
+        iban: Secret<String, pii::BankAccountNumber>,
+    },
+    Bacs {
+        #[serde(rename = "payment_method_data[bacs_debit][account_number]")]
+        account_number: Secret<String, pii::BankAccountNumber>,
+        #[serde(rename = "payment_method_data[bacs_debit][sort_code]")]
+        sort_code: Secret<String>,
+    },
+}
+
+/// The customer's authorization to debit their bank account, required by Stripe for bank debits
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeMandateRequest {
+    #[serde(rename = "mandate_data[customer_acceptance][type]")]
+    pub acceptance_type: StripeMandateAcceptanceType,
+    #[serde(rename = "mandate_data[customer_acceptance][accepted_at]")]
+    pub accepted_at: Option<i64>,
+    #[serde(rename = "mandate_data[customer_acceptance][online][ip_address]")]
+    pub ip_address: Option<Secret<String, pii::IpAddress>>,
+    #[serde(rename = "mandate_data[customer_acceptance][online][user_agent]")]
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StripeMandateAcceptanceType {
+    Online,
+    Offline,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeBankRedirectData {
+    #[serde(rename = "payment_method_types[]")]
+    pub payment_method_types: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[type]")]
+    pub payment_method_data_type: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[billing_details][name]")]
+    pub billing_name: Secret<String>,
+    #[serde(rename = "payment_method_data[billing_details][email]")]
+    pub billing_email: Option<Secret<String, pii::Email>>,
+    #[serde(flatten)]
+    pub bank_specific_data: Option<StripeBankRedirectSpecificData>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum StripeBankRedirectSpecificData {
+    Ideal {
+        #[serde(rename = "payment_method_data[ideal][bank]")]
+        bank: Option<String>,
+    },
+    Sofort {
+        #[serde(rename = "payment_method_data[sofort][country]")]
+        country: String,
+        #[serde(rename = "payment_method_options[sofort][preferred_language]")]
+        preferred_language: Option<String>,
+    },
+    Eps {
+        #[serde(rename = "payment_method_data[eps][bank]")]
+        bank: Option<String>,
+    },
+    P24 {
+        #[serde(rename = "payment_method_data[p24][bank]")]
+        bank: Option<String>,
+    },
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct StripeBankTransferData {
+    #[serde(rename = "payment_method_types[]")]
+    pub payment_method_types: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[type]")]
+    pub payment_method_data_type: StripePaymentMethodType,
+    #[serde(rename = "payment_method_data[billing_details][name]")]
+    pub billing_name: Secret<String>,
+    #[serde(rename = "payment_method_data[billing_details][email]")]
+    pub billing_email: Secret<String, pii::Email>,
+    #[serde(rename = "payment_method_data[billing_details][address][line1]")]
+    pub billing_address_line1: Option<Secret<String>>,
+    #[serde(rename = "payment_method_data[billing_details][address][city]")]
+    pub billing_address_city: Option<String>,
+    #[serde(rename = "payment_method_data[billing_details][address][state]")]
+    pub billing_address_state: Option<Secret<String>>,
+    #[serde(rename = "payment_method_data[billing_details][address][postal_code]")]
+    pub billing_address_postal_code: Option<Secret<String>>,
+    #[serde(rename = "payment_method_data[billing_details][address][country]")]
+    pub billing_address_country: Option<String>,
+    #[serde(rename = "payment_method_data[boleto][tax_id]")]
+    pub boleto_tax_id: Option<Secret<String>>,
+}
+
 #[derive(Debug, Eq, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum StripePaymentMethodData {
@@ -143,18 +274,32 @@ pub enum StripePaymentMethodData {
     Klarna(StripePayLaterData),
     Affirm(StripePayLaterData),
     AfterpayClearpay(StripePayLaterData),
+    BankDebit(StripeBankDebitData),
+    BankRedirect(StripeBankRedirectData),
+    BankTransfer(StripeBankTransferData),
     Bank,
     Wallet,
     Paypal,
 }
 
-#[derive(Debug, Eq, PartialEq, Serialize)]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum StripePaymentMethodType {
     Card,
     Klarna,
     Affirm,
     AfterpayClearpay,
+    UsBankAccount,
+    SepaDebit,
+    BacsDebit,
+    Ideal,
+    Sofort,
+    Giropay,
+    Eps,
+    Bancontact,
+    P24,
+    Pix,
+    Boleto,
 }
 
 fn validate_shipping_address_against_payment_method(
@@ -201,22 +346,41 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
                                                             // let api::PaymentMethod::Card(a) = item.payment_method_data;
                                                             // let api::PaymentMethod::Card(a) = item.payment_method_data;
 
-        let (payment_data, mandate) = {
-            match item
-                .request
-                .mandate_id
-                .clone()
-                .and_then(|mandate_ids| mandate_ids.connector_mandate_id)
-            {
+        let (payment_data, mandate, payment_method) = {
+            match item.request.mandate_id.clone().and_then(|mandate_ids| {
+                mandate_ids
+                    .connector_mandate_id
+                    .map(|connector_mandate_id| (connector_mandate_id, mandate_ids.payment_method))
+            }) {
                 None => {
                     let payment_method: StripePaymentMethodData =
                         (item.request.payment_method_data.clone(), item.auth_type).try_into()?;
-                    (Some(payment_method), None)
+                    (Some(payment_method), None, None)
                 }
-                Some(mandate_id) => (None, Some(mandate_id)),
+                // Bank debit mandates are referenced by the payment method the bank account was
+                // saved as with Stripe
+                Some((
+                    payment_method_id,
+                    Some(api_models::enums::PaymentMethodType::BankDebit),
+                )) => (None, None, Some(payment_method_id)),
+                Some((mandate_id, _)) => (None, Some(mandate_id), None),
             }
         };
 
+        let is_bank_debit = matches!(
+            item.request.payment_method_data,
+            api::PaymentMethod::BankDebit(_)
+        );
+        let mandate_data = item
+            .request
+            .setup_mandate_details
+            .as_ref()
+            .filter(|_| is_bank_debit)
+            .map(|mandate_details| {
+                StripeMandateRequest::from(&mandate_details.customer_acceptance)
+            });
+        let setup_future_usage = item.request.setup_future_usage.filter(|_| is_bank_debit);
+
         let shipping_address = match item.address.shipping.clone() {
             Some(mut shipping) => StripeShippingAddress {
                 city: shipping.address.as_mut().and_then(|a| a.city.take()),
@@ -255,7 +419,7 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
         let off_session = item
             .request
             .off_session
-            .and_then(|value| mandate.as_ref().map(|_| value));
+            .and_then(|value| mandate.as_ref().or(payment_method.as_ref()).map(|_| value));
 
         Ok(Self {
             amount: item.request.amount, //hopefully we don't loose some cents here
@@ -274,12 +438,32 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for PaymentIntentRequest {
             shipping: shipping_address,
             capture_method: StripeCaptureMethod::from(item.request.capture_method),
             payment_data,
+            mandate_data,
             off_session,
             mandate,
+            payment_method,
+            setup_future_usage,
         })
     }
 }
 
+impl From<&payments::CustomerAcceptance> for StripeMandateRequest {
+    fn from(customer_acceptance: &payments::CustomerAcceptance) -> Self {
+        let online = customer_acceptance.online.as_ref();
+        Self {
+            acceptance_type: match customer_acceptance.acceptance_type {
+                payments::AcceptanceType::Online => StripeMandateAcceptanceType::Online,
+                payments::AcceptanceType::Offline => StripeMandateAcceptanceType::Offline,
+            },
+            accepted_at: customer_acceptance
+                .accepted_at
+                .map(|accepted_at| accepted_at.assume_utc().unix_timestamp()),
+            ip_address: online.map(|online| online.ip_address.clone()),
+            user_agent: online.map(|online| online.user_agent.clone()),
+        }
+    }
+}
+
 impl TryFrom<&types::VerifyRouterData> for SetupIntentRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::VerifyRouterData) -> Result<Self, Self::Error> {
@@ -359,6 +543,7 @@ pub struct PaymentIntentResponse {
     pub statement_descriptor_suffix: Option<String>,
     pub metadata: StripeMetadata,
     pub next_action: Option<StripeNextActionResponse>,
+    pub payment_method: Option<String>,
     pub payment_method_options: Option<StripePaymentMethodOptions>,
 }
 
@@ -384,23 +569,20 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, PaymentIntentResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data = item.response.next_action.as_ref().map(
-            |StripeNextActionResponse::RedirectToUrl(response)| {
-                let mut base_url = response.url.clone();
-                base_url.set_query(None);
-                services::RedirectForm {
-                    url: base_url.to_string(),
-                    method: services::Method::Get,
-                    form_fields: std::collections::HashMap::from_iter(
-                        response
-                            .url
-                            .query_pairs()
-                            .map(|(k, v)| (k.to_string(), v.to_string())),
-                    ),
-                }
-            },
-        );
-
+        let redirection_data = item
+            .response
+            .next_action
+            .as_ref()
+            .and_then(StripeNextActionResponse::get_redirection_data);
+        let connector_metadata = item
+            .response
+            .next_action
+            .as_ref()
+            .and_then(StripeNextActionResponse::get_bank_transfer_instructions)
+            .map(utils::get_bank_transfer_next_steps)
+            .transpose()?;
+
+        let payment_method_id = item.response.payment_method;
         let mandate_reference =
             item.response
                 .payment_method_options
@@ -411,6 +593,17 @@ impl<F, T>
                     StripePaymentMethodOptions::Klarna {} => None,
                     StripePaymentMethodOptions::Affirm {} => None,
                     StripePaymentMethodOptions::AfterpayClearpay {} => None,
+                    StripePaymentMethodOptions::UsBankAccount {}
+                    | StripePaymentMethodOptions::SepaDebit {}
+                    | StripePaymentMethodOptions::BacsDebit {} => payment_method_id,
+                    StripePaymentMethodOptions::Ideal {}
+                    | StripePaymentMethodOptions::Sofort {}
+                    | StripePaymentMethodOptions::Giropay {}
+                    | StripePaymentMethodOptions::Eps {}
+                    | StripePaymentMethodOptions::Bancontact {}
+                    | StripePaymentMethodOptions::P24 {}
+                    | StripePaymentMethodOptions::Pix {}
+                    | StripePaymentMethodOptions::Boleto {} => None,
                 });
 
         Ok(Self {
@@ -424,7 +617,7 @@ impl<F, T>
                 redirect: redirection_data.is_some(),
                 redirection_data,
                 mandate_reference,
-                connector_metadata: None,
+                connector_metadata,
             }),
             amount_captured: Some(item.response.amount_received),
             ..item.data
@@ -440,22 +633,11 @@ impl<F, T>
     fn try_from(
         item: types::ResponseRouterData<F, SetupIntentResponse, T, types::PaymentsResponseData>,
     ) -> Result<Self, Self::Error> {
-        let redirection_data = item.response.next_action.as_ref().map(
-            |StripeNextActionResponse::RedirectToUrl(response)| {
-                let mut base_url = response.url.clone();
-                base_url.set_query(None);
-                services::RedirectForm {
-                    url: base_url.to_string(),
-                    method: services::Method::Get,
-                    form_fields: std::collections::HashMap::from_iter(
-                        response
-                            .url
-                            .query_pairs()
-                            .map(|(k, v)| (k.to_string(), v.to_string())),
-                    ),
-                }
-            },
-        );
+        let redirection_data = item
+            .response
+            .next_action
+            .as_ref()
+            .and_then(StripeNextActionResponse::get_redirection_data);
 
         let mandate_reference =
             item.response
@@ -467,6 +649,17 @@ impl<F, T>
                     StripePaymentMethodOptions::Klarna {} => None,
                     StripePaymentMethodOptions::Affirm {} => None,
                     StripePaymentMethodOptions::AfterpayClearpay {} => None,
+                    StripePaymentMethodOptions::UsBankAccount {}
+                    | StripePaymentMethodOptions::SepaDebit {}
+                    | StripePaymentMethodOptions::BacsDebit {}
+                    | StripePaymentMethodOptions::Ideal {}
+                    | StripePaymentMethodOptions::Sofort {}
+                    | StripePaymentMethodOptions::Giropay {}
+                    | StripePaymentMethodOptions::Eps {}
+                    | StripePaymentMethodOptions::Bancontact {}
+                    | StripePaymentMethodOptions::P24 {}
+                    | StripePaymentMethodOptions::Pix {}
+                    | StripePaymentMethodOptions::Boleto {} => None,
                 });
 
         Ok(Self {
@@ -487,6 +680,54 @@ impl<F, T>
 #[serde(rename_all = "snake_case", remote = "Self")]
 pub enum StripeNextActionResponse {
     RedirectToUrl(StripeRedirectToUrlResponse),
+    BoletoDisplayDetails(StripeBoletoDisplayDetails),
+    PixDisplayQrCode(StripePixDisplayQrCode),
+}
+
+impl StripeNextActionResponse {
+    fn get_redirection_data(&self) -> Option<services::RedirectForm> {
+        match self {
+            Self::RedirectToUrl(response) => {
+                let mut base_url = response.url.clone();
+                base_url.set_query(None);
+                Some(services::RedirectForm {
+                    url: base_url.to_string(),
+                    method: services::Method::Get,
+                    form_fields: std::collections::HashMap::from_iter(
+                        response
+                            .url
+                            .query_pairs()
+                            .map(|(k, v)| (k.to_string(), v.to_string())),
+                    ),
+                })
+            }
+            Self::BoletoDisplayDetails(_) | Self::PixDisplayQrCode(_) => None,
+        }
+    }
+
+    fn get_bank_transfer_instructions(&self) -> Option<api::BankTransferInstructions> {
+        let from_unix_timestamp = |timestamp: Option<i64>| {
+            timestamp
+                .and_then(|timestamp| time::OffsetDateTime::from_unix_timestamp(timestamp).ok())
+                .map(|date_time| time::PrimitiveDateTime::new(date_time.date(), date_time.time()))
+        };
+        match self {
+            Self::RedirectToUrl(_) => None,
+            Self::BoletoDisplayDetails(boleto) => Some(api::BankTransferInstructions {
+                reference: boleto.number.clone(),
+                instructions_url: boleto.hosted_voucher_url.clone(),
+                expires_at: from_unix_timestamp(boleto.expires_at),
+                ..Default::default()
+            }),
+            Self::PixDisplayQrCode(pix) => Some(api::BankTransferInstructions {
+                qr_code_data: pix.data.clone(),
+                qr_code_url: pix.image_url_png.clone(),
+                instructions_url: pix.hosted_instructions_url.clone(),
+                expires_at: from_unix_timestamp(pix.expires_at),
+                ..Default::default()
+            }),
+        }
+    }
 }
 
 // This impl is required because Stripe's response is of the below format, which is externally
@@ -515,6 +756,21 @@ pub struct StripeRedirectToUrlResponse {
     url: Url,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct StripeBoletoDisplayDetails {
+    number: Option<String>,
+    hosted_voucher_url: Option<String>,
+    expires_at: Option<i64>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct StripePixDisplayQrCode {
+    data: Option<String>,
+    image_url_png: Option<String>,
+    hosted_instructions_url: Option<String>,
+    expires_at: Option<i64>,
+}
+
 // REFUND :
 // Type definition for Stripe RefundRequest
 
@@ -622,6 +878,8 @@ pub struct ErrorDetails {
     pub error_type: Option<String>,
     pub message: Option<String>,
     pub param: Option<String>,
+    /// The reason given by the issuer for declining the card
+    pub decline_code: Option<String>,
 }
 
 #[derive(Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
@@ -701,6 +959,17 @@ pub enum StripePaymentMethodOptions {
     Klarna {},
     Affirm {},
     AfterpayClearpay {},
+    UsBankAccount {},
+    SepaDebit {},
+    BacsDebit {},
+    Ideal {},
+    Sofort {},
+    Giropay {},
+    Eps {},
+    Bancontact {},
+    P24 {},
+    Pix {},
+    Boleto {},
 }
 // #[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
 // pub struct Card
@@ -814,6 +1083,15 @@ impl TryFrom<(api::PaymentMethod, enums::AuthenticationType)> for StripePaymentM
                 }
             })),
             api::PaymentMethod::BankTransfer => Ok(Self::Bank),
+            api::PaymentMethod::BankDebit(bank_debit_data) => {
+                Ok(Self::BankDebit(StripeBankDebitData::from(bank_debit_data)))
+            }
+            api::PaymentMethod::BankRedirect(bank_redirect_data) => Ok(Self::BankRedirect(
+                StripeBankRedirectData::try_from(bank_redirect_data)?,
+            )),
+            api::PaymentMethod::LocalBankTransfer(bank_transfer_data) => Ok(Self::BankTransfer(
+                StripeBankTransferData::try_from(bank_transfer_data)?,
+            )),
             api::PaymentMethod::PayLater(pay_later_data) => match pay_later_data {
                 api_models::payments::PayLaterData::KlarnaRedirect {
                     billing_email,
@@ -852,6 +1130,144 @@ impl TryFrom<(api::PaymentMethod, enums::AuthenticationType)> for StripePaymentM
             },
             api::PaymentMethod::Wallet(_) => Ok(Self::Wallet),
             api::PaymentMethod::Paypal => Ok(Self::Paypal),
+            api::PaymentMethod::MandatePayment => Err(errors::ConnectorError::NotImplemented(
+                String::from("Stripe mandate payments without a connector mandate"),
+            )),
+            api::PaymentMethod::Upi(_) => Err(errors::ConnectorError::NotImplemented(
+                String::from("Stripe UPI payments"),
+            )),
+        }
+    }
+}
+
+impl TryFrom<api::BankRedirectData> for StripeBankRedirectData {
+    type Error = errors::ConnectorError;
+    fn try_from(bank_redirect_data: api::BankRedirectData) -> Result<Self, Self::Error> {
+        let billing_details = bank_redirect_data.get_billing_details().clone();
+        let (payment_method_type, bank_specific_data) = match bank_redirect_data {
+            api::BankRedirectData::Ideal { bank_name, .. } => (
+                StripePaymentMethodType::Ideal,
+                Some(StripeBankRedirectSpecificData::Ideal { bank: bank_name }),
+            ),
+            api::BankRedirectData::Sofort {
+                country,
+                preferred_language,
+                ..
+            } => (
+                StripePaymentMethodType::Sofort,
+                Some(StripeBankRedirectSpecificData::Sofort {
+                    country,
+                    preferred_language,
+                }),
+            ),
+            api::BankRedirectData::Giropay { .. } => (StripePaymentMethodType::Giropay, None),
+            api::BankRedirectData::Eps { bank_name, .. } => (
+                StripePaymentMethodType::Eps,
+                Some(StripeBankRedirectSpecificData::Eps { bank: bank_name }),
+            ),
+            api::BankRedirectData::BancontactCard { .. } => {
+                (StripePaymentMethodType::Bancontact, None)
+            }
+            api::BankRedirectData::Przelewy24 { bank_name, .. } => {
+                // Stripe requires the customer's email for Przelewy24
+                fp_utils::when(billing_details.email.is_none(), || {
+                    Err(errors::ConnectorError::MissingRequiredField {
+                        field_name: "billing_details.email",
+                    })
+                })?;
+                (
+                    StripePaymentMethodType::P24,
+                    Some(StripeBankRedirectSpecificData::P24 { bank: bank_name }),
+                )
+            }
+        };
+
+        Ok(Self {
+            payment_method_types: payment_method_type.clone(),
+            payment_method_data_type: payment_method_type,
+            billing_name: billing_details.billing_name,
+            billing_email: billing_details.email,
+            bank_specific_data,
+        })
+    }
+}
+
+impl TryFrom<api::BankTransferData> for StripeBankTransferData {
+    type Error = errors::ConnectorError;
+    fn try_from(bank_transfer_data: api::BankTransferData) -> Result<Self, Self::Error> {
+        let billing_details = bank_transfer_data.get_billing_details().clone();
+        let address = billing_details.address.unwrap_or_default();
+        let (payment_method_type, boleto_tax_id) = match bank_transfer_data {
+            api::BankTransferData::Pix { .. } => (StripePaymentMethodType::Pix, None),
+            api::BankTransferData::Boleto { .. } => (
+                StripePaymentMethodType::Boleto,
+                Some(billing_details.tax_id.ok_or(
+                    errors::ConnectorError::MissingRequiredField {
+                        field_name: "billing_details.tax_id",
+                    },
+                )?),
+            ),
+        };
+
+        Ok(Self {
+            payment_method_types: payment_method_type.clone(),
+            payment_method_data_type: payment_method_type,
+            billing_name: billing_details.name,
+            billing_email: billing_details.email,
+            billing_address_line1: address.line1,
+            billing_address_city: address.city,
+            billing_address_state: address.state,
+            billing_address_postal_code: address.zip,
+            billing_address_country: address.country,
+            boleto_tax_id,
+        })
+    }
+}
+
+impl From<api::BankDebitData> for StripeBankDebitData {
+    fn from(bank_debit_data: api::BankDebitData) -> Self {
+        let billing_details = bank_debit_data.get_billing_details().clone();
+        let address = billing_details.address.unwrap_or_default();
+        let (payment_method_type, bank_specific_data) = match bank_debit_data {
+            api::BankDebitData::AchBankDebit {
+                account_number,
+                routing_number,
+                ..
+            } => (
+                StripePaymentMethodType::UsBankAccount,
+                StripeBankDebitSpecificData::Ach {
+                    account_holder_type: "individual".to_string(),
+                    account_number,
+                    routing_number,
+                },
+            ),
+            api::BankDebitData::SepaBankDebit { iban, .. } => (
+                StripePaymentMethodType::SepaDebit,
+                StripeBankDebitSpecificData::Sepa { iban },
+            ),
+            api::BankDebitData::BacsBankDebit {
+                account_number,
+                sort_code,
+                ..
+            } => (
+                StripePaymentMethodType::BacsDebit,
+                StripeBankDebitSpecificData::Bacs {
+                    account_number,
+                    sort_code,
+                },
+            ),
+        };
+
+        Self {
+            payment_method_types: payment_method_type.clone(),
+            payment_method_data_type: payment_method_type,
+            billing_name: billing_details.name,
+            billing_email: billing_details.email,
+            billing_address_line1: address.line1,
+            billing_address_city: address.city,
+            billing_address_postal_code: address.zip,
+            billing_address_country: address.country,
+            bank_specific_data,
         }
     }
 }
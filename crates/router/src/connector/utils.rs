@@ -214,3 +214,23 @@ pub fn get_header_key_value<'a>(
             errors::ConnectorError::WebhookSourceVerificationFailed
         ))?
 }
+
+/// Encode the instructions the customer follows to complete a bank transfer as the connector
+/// metadata of the payment, from which the next action of the payment is built
+pub fn get_bank_transfer_next_steps(
+    bank_transfer_instructions: api::BankTransferInstructions,
+) -> Result<serde_json::Value, Error> {
+    common_utils::ext_traits::Encode::<api::BankTransferNextSteps>::encode_to_value(
+        &api::BankTransferNextSteps {
+            bank_transfer_instructions,
+        },
+    )
+    .change_context(errors::ConnectorError::ResponseHandlingFailed)
+}
+
+/// Encode how the customer approves a UPI payment as the connector metadata of the payment, from
+/// which the next action of the payment is built
+pub fn get_upi_next_steps(upi_next_steps: api::UpiNextSteps) -> Result<serde_json::Value, Error> {
+    common_utils::ext_traits::Encode::<api::UpiNextSteps>::encode_to_value(&upi_next_steps)
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+}
@@ -289,7 +289,7 @@ impl TryFrom<&types::ConnectorAuthType> for AuthType {
     }
 }
 
-#[derive(Debug, Clone, Default, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PaymentStatus {
     Captured,
@@ -334,14 +334,20 @@ impl From<transformers::Foreign<(PaymentStatus, enums::CaptureMethod)>>
 /// capture_method is not part of response from connector.
 /// This is used to decide payment status while converting connector response to RouterData.
 /// To keep this try_from logic generic in case of AUTHORIZE, SYNC and CAPTURE flows capture_method will be set from RouterData request.
-#[derive(Default, Debug, Clone, Deserialize, PartialEq)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Payment {
     id: String,
     status: PaymentStatus,
-    #[serde(skip_deserializing)]
+    #[serde(skip)]
     pub capture_method: enums::CaptureMethod,
 }
 
+impl Payment {
+    pub fn get_id(&self) -> String {
+        self.id.clone()
+    }
+}
+
 impl<F, T> TryFrom<types::ResponseRouterData<F, Payment, T, types::PaymentsResponseData>>
     for types::RouterData<F, T, types::PaymentsResponseData>
 {
@@ -517,3 +523,42 @@ impl CardProduct {
         }
     }
 }
+
+#[derive(Debug, Deserialize)]
+pub struct WebhookBody {
+    pub payment: Option<Payment>,
+}
+
+impl WebhookBody {
+    pub fn get_payment(self) -> Result<Payment, error_stack::Report<errors::ConnectorError>> {
+        self.payment
+            .ok_or(errors::ConnectorError::WebhookResourceObjectNotFound)
+            .into_report()
+    }
+}
+
+impl TryFrom<&WebhookBody> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(webhook: &WebhookBody) -> Result<Self, Self::Error> {
+        // Refund and payout events carry their own object instead of the payment
+        let payment = webhook
+            .payment
+            .as_ref()
+            .ok_or(errors::ConnectorError::WebhookEventTypeNotFound)
+            .into_report()?;
+        match payment.status {
+            PaymentStatus::Captured
+            | PaymentStatus::Paid
+            | PaymentStatus::CaptureRequested
+            | PaymentStatus::PendingApproval => Ok(Self::PaymentIntentSuccess),
+            PaymentStatus::Rejected | PaymentStatus::RejectedCapture => {
+                Ok(Self::PaymentIntentFailure)
+            }
+            PaymentStatus::ChargebackNotification
+            | PaymentStatus::Cancelled
+            | PaymentStatus::Processing => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound).into_report()
+            }
+        }
+    }
+}
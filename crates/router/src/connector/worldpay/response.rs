@@ -304,3 +304,36 @@ pub struct WorldpayErrorResponse {
     pub error_name: String,
     pub message: String,
 }
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldpayWebhookBody {
+    pub event_details: WorldpayWebhookEventDetails,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorldpayWebhookEventDetails {
+    #[serde(rename = "type")]
+    pub event_type: WorldpayWebhookEventType,
+    #[serde(rename = "_links")]
+    pub links: Option<PaymentLinks>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WorldpayWebhookEventType {
+    Authorized,
+    SentForSettlement,
+    Settled,
+    SettlementFailed,
+    Refused,
+    Cancelled,
+    Error,
+    Expired,
+    SentForRefund,
+    Refunded,
+    RefundFailed,
+    #[serde(other)]
+    Unknown,
+}
@@ -188,3 +188,48 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for WorldpayRefundRequest {
         })
     }
 }
+
+impl TryFrom<WorldpayWebhookEventType> for EventType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(event_type: WorldpayWebhookEventType) -> Result<Self, Self::Error> {
+        match event_type {
+            WorldpayWebhookEventType::Authorized => Ok(Self::Authorized),
+            WorldpayWebhookEventType::SentForSettlement | WorldpayWebhookEventType::Settled => {
+                Ok(Self::Charged)
+            }
+            WorldpayWebhookEventType::SettlementFailed => Ok(Self::CaptureFailed),
+            WorldpayWebhookEventType::Refused | WorldpayWebhookEventType::Expired => {
+                Ok(Self::Refused)
+            }
+            WorldpayWebhookEventType::Cancelled => Ok(Self::Cancelled),
+            WorldpayWebhookEventType::Error => Ok(Self::Error),
+            WorldpayWebhookEventType::SentForRefund => Ok(Self::SentForRefund),
+            WorldpayWebhookEventType::Refunded => Ok(Self::Refunded),
+            WorldpayWebhookEventType::RefundFailed => Ok(Self::RefundFailed),
+            WorldpayWebhookEventType::Unknown => {
+                Err(errors::ConnectorError::WebhookEventTypeNotFound.into())
+            }
+        }
+    }
+}
+
+impl TryFrom<&WorldpayWebhookBody> for api::IncomingWebhookEvent {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(webhook: &WorldpayWebhookBody) -> Result<Self, Self::Error> {
+        match EventType::try_from(webhook.event_details.event_type.clone())? {
+            EventType::Authorized | EventType::Charged => Ok(Self::PaymentIntentSuccess),
+            EventType::Refused | EventType::Error => Ok(Self::PaymentIntentFailure),
+            _ => Err(errors::ConnectorError::WebhookEventTypeNotFound.into()),
+        }
+    }
+}
+
+impl TryFrom<WorldpayWebhookBody> for WorldpayEventResponse {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(webhook: WorldpayWebhookBody) -> Result<Self, Self::Error> {
+        Ok(Self {
+            last_event: EventType::try_from(webhook.event_details.event_type)?,
+            links: None,
+        })
+    }
+}
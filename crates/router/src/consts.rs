@@ -23,3 +23,8 @@ pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
+
+/// Interval between two polls of a UPI payment status (in seconds)
+pub(crate) const UPI_POLL_DELAY_IN_SECS: u16 = 5;
+/// Number of polls of a UPI payment status, when the connector does not tell when it expires
+pub(crate) const UPI_POLL_FREQUENCY: u16 = 60;
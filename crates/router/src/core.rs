@@ -1,11 +1,14 @@
 pub mod admin;
 pub mod api_keys;
+pub mod capabilities;
 pub mod configs;
 pub mod customers;
 pub mod errors;
+pub mod fraud_check;
 pub mod mandate;
 pub mod payment_methods;
 pub mod payments;
+pub mod process_tracker;
 pub mod refunds;
 pub mod utils;
 pub mod webhooks;
@@ -1,11 +1,15 @@
 use common_utils::ext_traits::ValueExt;
-use error_stack::{report, FutureExt, ResultExt};
+use error_stack::{report, FutureExt, IntoReport, ResultExt};
 use storage_models::{enums, merchant_account};
 use uuid::Uuid;
 
 use crate::{
+    configs::settings,
     consts,
-    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::applepay,
+    },
     db::StorageInterface,
     pii::Secret,
     services::api as service_api,
@@ -58,6 +62,8 @@ pub async fn create_merchant_account(
             .attach_printable("Invalid routing algorithm given")?;
     }
 
+    let payment_token_config = encode_payment_token_config(req.payment_token_config.as_ref())?;
+
     let merchant_account = storage::MerchantAccountNew {
         merchant_id: req.merchant_id,
         merchant_name: req.merchant_name,
@@ -79,6 +85,7 @@ pub async fn create_merchant_account(
         publishable_key,
         locker_id: req.locker_id,
         metadata: req.metadata,
+        payment_token_config,
     };
 
     let merchant_account = db
@@ -93,6 +100,30 @@ pub async fn create_merchant_account(
     ))
 }
 
+fn encode_payment_token_config(
+    payment_token_config: Option<&api::PaymentTokenConfig>,
+) -> RouterResult<Option<serde_json::Value>> {
+    if let Some(config) = payment_token_config {
+        utils::when(
+            config.ttl_in_secs == Some(0) || config.max_uses == Some(0),
+            || {
+                Err(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "payment_token_config",
+                })
+                .into_report()
+                .attach_printable("Payment token TTL and max uses must be greater than zero")
+            },
+        )?;
+    }
+
+    payment_token_config
+        .map(utils::Encode::<api::PaymentTokenConfig>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "payment_token_config",
+        })
+}
+
 pub async fn get_merchant_account(
     db: &dyn StorageInterface,
     req: api::MerchantId,
@@ -169,6 +200,7 @@ pub async fn merchant_account_update(
         redirect_to_merchant_with_http_post: req.redirect_to_merchant_with_http_post,
         locker_id: req.locker_id,
         metadata: req.metadata,
+        payment_token_config: encode_payment_token_config(req.payment_token_config.as_ref())?,
         api_key: None,
         publishable_key: None,
     };
@@ -244,6 +276,7 @@ async fn validate_merchant_id<S: Into<String>>(
 
 pub async fn create_payment_connector(
     store: &dyn StorageInterface,
+    conf: &settings::Settings,
     req: api::PaymentConnectorCreate,
     merchant_id: &String,
 ) -> RouterResponse<api::PaymentConnectorCreate> {
@@ -281,6 +314,10 @@ pub async fn create_payment_connector(
             expected_format: "auth_type and api_key".to_string(),
         })?;
 
+    let metadata =
+        applepay::encrypt_certificate_key(conf, &req.connector_name, req.metadata).await?;
+    response.metadata = metadata.clone();
+
     let merchant_connector_account = storage::MerchantConnectorAccountNew {
         merchant_id: Some(merchant_id.to_string()),
         connector_type: Some(req.connector_type.foreign_into()),
@@ -290,7 +327,7 @@ pub async fn create_payment_connector(
         payment_methods_enabled,
         test_mode: req.test_mode,
         disabled: req.disabled,
-        metadata: req.metadata,
+        metadata,
     };
 
     let mca = store
@@ -361,6 +398,7 @@ pub async fn list_payment_connectors(
 
 pub async fn update_payment_connector(
     db: &dyn StorageInterface,
+    conf: &settings::Settings,
     merchant_id: &str,
     merchant_connector_id: &str,
     req: api::PaymentConnectorCreate,
@@ -391,6 +429,9 @@ pub async fn update_payment_connector(
             .collect::<Vec<serde_json::Value>>()
     });
 
+    let metadata =
+        applepay::encrypt_certificate_key(conf, &req.connector_name, req.metadata).await?;
+
     let payment_connector = storage::MerchantConnectorAccountUpdate::Update {
         merchant_id: Some(merchant_id.to_string()),
         connector_type: Some(req.connector_type.foreign_into()),
@@ -400,7 +441,7 @@ pub async fn update_payment_connector(
         payment_methods_enabled,
         test_mode: req.test_mode,
         disabled: req.disabled,
-        metadata: req.metadata,
+        metadata,
     };
 
     let updated_mca = db
@@ -0,0 +1,300 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings,
+    connector,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments::{applepay, PaymentData},
+    },
+    services::ApplicationResponse,
+    types::{
+        api::{self, enums as api_enums},
+        transformers::ForeignInto,
+    },
+};
+
+/// The connectors processing payments, in the order in which their capabilities are listed.
+const PAYMENT_CONNECTORS: &[api_enums::Connector] = &[
+    api_enums::Connector::Aci,
+    api_enums::Connector::Adyen,
+    api_enums::Connector::Applepay,
+    api_enums::Connector::Authorizedotnet,
+    api_enums::Connector::Braintree,
+    api_enums::Connector::Checkout,
+    api_enums::Connector::Cybersource,
+    api_enums::Connector::Dummy,
+    #[cfg(feature = "dummy_connector")]
+    api_enums::Connector::DummyConnector,
+    api_enums::Connector::Fiserv,
+    api_enums::Connector::Globalpay,
+    api_enums::Connector::Klarna,
+    api_enums::Connector::Paypal,
+    api_enums::Connector::Payu,
+    api_enums::Connector::Rapyd,
+    api_enums::Connector::Shift4,
+    api_enums::Connector::Stripe,
+    api_enums::Connector::Worldline,
+    api_enums::Connector::Worldpay,
+];
+
+/// Returns the payment methods, currencies, countries, capture methods and mandates supported by
+/// the connector, as declared by its integration.
+///
+/// Every connector processing payments declares all of its capabilities. Connectors support
+/// mandates only if their integration sends the mandate to them, as a recurring payment or as a
+/// reference to the payment method saved with them.
+pub fn get_connector_capabilities(connector: api_enums::Connector) -> api::ConnectorCapabilities {
+    // The capabilities of the placeholder connector, which has no integration
+    let capabilities = api::ConnectorCapabilities {
+        connector,
+        payment_methods: vec![api_enums::PaymentMethodType::Card],
+        currencies: None,
+        countries: None,
+        capture_methods: vec![api_enums::CaptureMethod::Automatic],
+        supports_mandates: false,
+        supports_network_tokens: false,
+    };
+
+    match get_connector_integration(connector) {
+        Some(integration) => integration.get_capabilities(capabilities),
+        None => capabilities,
+    }
+}
+
+fn get_connector_integration(
+    connector: api_enums::Connector,
+) -> Option<&'static dyn api::ConnectorCommon> {
+    match connector {
+        api_enums::Connector::Aci => Some(&connector::Aci),
+        api_enums::Connector::Adyen => Some(&connector::Adyen),
+        api_enums::Connector::Applepay => Some(&connector::Applepay),
+        api_enums::Connector::Authorizedotnet => Some(&connector::Authorizedotnet),
+        api_enums::Connector::Braintree => Some(&connector::Braintree),
+        api_enums::Connector::Checkout => Some(&connector::Checkout),
+        api_enums::Connector::Cybersource => Some(&connector::Cybersource),
+        // The placeholder connector has no integration, it accepts cards only
+        api_enums::Connector::Dummy => None,
+        #[cfg(feature = "dummy_connector")]
+        api_enums::Connector::DummyConnector => Some(&connector::DummyConnector),
+        api_enums::Connector::Fiserv => Some(&connector::Fiserv),
+        api_enums::Connector::Globalpay => Some(&connector::Globalpay),
+        api_enums::Connector::Klarna => Some(&connector::Klarna),
+        api_enums::Connector::Paypal => Some(&connector::Paypal),
+        api_enums::Connector::Payu => Some(&connector::Payu),
+        api_enums::Connector::Rapyd => Some(&connector::Rapyd),
+        api_enums::Connector::Shift4 => Some(&connector::Shift4),
+        api_enums::Connector::Stripe => Some(&connector::Stripe),
+        api_enums::Connector::Worldline => Some(&connector::Worldline),
+        api_enums::Connector::Worldpay => Some(&connector::Worldpay),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn list_connector_capabilities() -> RouterResponse<Vec<api::ConnectorCapabilities>> {
+    Ok(ApplicationResponse::Json(
+        PAYMENT_CONNECTORS
+            .iter()
+            .map(|connector| get_connector_capabilities(*connector))
+            .collect(),
+    ))
+}
+
+/// Rejects payments which the connector cannot process, before they are sent to it.
+pub fn validate_payment_with_connector<F: Clone>(
+    conf: &settings::Settings,
+    connector: api_enums::Connector,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let requirements = PaymentRequirements::new(conf, payment_data);
+    match get_unsupported_payment_feature(&get_connector_capabilities(connector), &requirements) {
+        Some(message) => Err(report!(errors::ApiErrorResponse::NotSupportedByConnector {
+            message,
+            connector: connector.to_string(),
+        })),
+        None => Ok(()),
+    }
+}
+
+/// Keeps the connectors which can process the payment, so that the payment is not routed to the
+/// other ones.
+pub fn filter_connectors_supporting_payment<F: Clone>(
+    conf: &settings::Settings,
+    connectors: Vec<api::ConnectorData>,
+    payment_data: &PaymentData<F>,
+) -> Vec<api::ConnectorData> {
+    let requirements = PaymentRequirements::new(conf, payment_data);
+    connectors
+        .into_iter()
+        .filter(|connector_data| {
+            get_unsupported_payment_feature(
+                &get_connector_capabilities(connector_data.connector_name),
+                &requirements,
+            )
+            .is_none()
+        })
+        .collect()
+}
+
+/// What a payment requires from the connector processing it.
+#[derive(Debug)]
+struct PaymentRequirements<'a> {
+    payment_method: Option<api_enums::PaymentMethodType>,
+    /// Whether the payment is made with an Apple Pay payment token the router can decrypt
+    is_decryptable_applepay_payment: bool,
+    currency: api_enums::Currency,
+    billing_country: Option<&'a str>,
+    capture_method: api_enums::CaptureMethod,
+    is_mandate_payment: bool,
+}
+
+impl<'a> PaymentRequirements<'a> {
+    fn new<F: Clone>(conf: &settings::Settings, payment_data: &'a PaymentData<F>) -> Self {
+        Self {
+            payment_method: payment_data
+                .payment_attempt
+                .payment_method
+                .map(ForeignInto::foreign_into),
+            is_decryptable_applepay_payment: applepay::is_decryptable_payment_token(
+                conf,
+                payment_data.payment_method_data.as_ref(),
+            ),
+            currency: payment_data.currency.foreign_into(),
+            billing_country: payment_data
+                .address
+                .billing
+                .as_ref()
+                .and_then(|billing| billing.address.as_ref())
+                .and_then(|address| address.country.as_deref()),
+            capture_method: payment_data
+                .payment_attempt
+                .capture_method
+                .unwrap_or_default()
+                .foreign_into(),
+            is_mandate_payment: payment_data.mandate_id.is_some()
+                || payment_data.setup_mandate.is_some(),
+        }
+    }
+}
+
+/// Returns the first feature of the payment the connector does not support, if any.
+fn get_unsupported_payment_feature(
+    capabilities: &api::ConnectorCapabilities,
+    requirements: &PaymentRequirements<'_>,
+) -> Option<String> {
+    if let Some(payment_method) = requirements.payment_method {
+        // Apple Pay payments are card payments for the connectors their payment token is
+        // decrypted for
+        let payment_method = if requirements.is_decryptable_applepay_payment
+            && applepay::should_decrypt_payment_token(capabilities)
+        {
+            api_enums::PaymentMethodType::Card
+        } else {
+            payment_method
+        };
+        if !capabilities.supports_payment_method(payment_method) {
+            return Some(format!("Payment method {payment_method}"));
+        }
+    }
+
+    if !capabilities.supports_currency(requirements.currency) {
+        return Some(format!("Currency {}", requirements.currency));
+    }
+
+    if let Some(country) = requirements.billing_country {
+        if !capabilities.supports_country(country) {
+            return Some(format!("Billing country {country}"));
+        }
+    }
+
+    if !capabilities.supports_capture_method(requirements.capture_method) {
+        return Some(format!("Capture method {}", requirements.capture_method));
+    }
+
+    if requirements.is_mandate_payment && !capabilities.supports_mandates {
+        return Some("Payment with a mandate".to_string());
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_connector_declares_its_capabilities() {
+        for connector in PAYMENT_CONNECTORS {
+            let capabilities = get_connector_capabilities(*connector);
+            assert_eq!(capabilities.connector, *connector);
+            assert!(!capabilities.payment_methods.is_empty());
+            assert!(capabilities.supports_capture_method(api_enums::CaptureMethod::Automatic));
+        }
+    }
+
+    #[test]
+    fn test_unsupported_combinations() {
+        let shift4 = get_connector_capabilities(api_enums::Connector::Shift4);
+        assert!(shift4.supports_payment_method(api_enums::PaymentMethodType::Card));
+        assert!(!shift4.supports_payment_method(api_enums::PaymentMethodType::PayLater));
+
+        let stripe = get_connector_capabilities(api_enums::Connector::Stripe);
+        assert!(stripe.supports_capture_method(api_enums::CaptureMethod::Manual));
+        assert!(!stripe.supports_capture_method(api_enums::CaptureMethod::ManualMultiple));
+        assert!(!stripe.supports_capture_method(api_enums::CaptureMethod::Scheduled));
+
+        let klarna = get_connector_capabilities(api_enums::Connector::Klarna);
+        assert!(klarna.supports_country("us"));
+        assert!(!klarna.supports_country("DE"));
+        assert!(klarna.supports_currency(api_enums::Currency::USD));
+        assert!(!klarna.supports_currency(api_enums::Currency::EUR));
+        assert!(klarna.supports_capture_method(api_enums::CaptureMethod::Manual));
+        assert!(!klarna.supports_mandates);
+
+        let checkout = get_connector_capabilities(api_enums::Connector::Checkout);
+        assert!(!checkout.supports_mandates);
+        let authorizedotnet = get_connector_capabilities(api_enums::Connector::Authorizedotnet);
+        assert!(!authorizedotnet.supports_currency(api_enums::Currency::INR));
+        assert!(!authorizedotnet.supports_capture_method(api_enums::CaptureMethod::Manual));
+
+        let paypal = get_connector_capabilities(api_enums::Connector::Paypal);
+        assert!(paypal.supports_payment_method(api_enums::PaymentMethodType::Paypal));
+        assert!(!paypal.supports_payment_method(api_enums::PaymentMethodType::Card));
+        assert!(!paypal.supports_currency(api_enums::Currency::INR));
+    }
+
+    fn mk_requirements(
+        payment_method: api_enums::PaymentMethodType,
+        is_decryptable_applepay_payment: bool,
+    ) -> PaymentRequirements<'static> {
+        PaymentRequirements {
+            payment_method: Some(payment_method),
+            is_decryptable_applepay_payment,
+            currency: api_enums::Currency::USD,
+            billing_country: Some("US"),
+            capture_method: api_enums::CaptureMethod::Automatic,
+            is_mandate_payment: false,
+        }
+    }
+
+    #[test]
+    fn test_applepay_payments_are_validated_as_card_payments_when_decrypted() {
+        let checkout = get_connector_capabilities(api_enums::Connector::Checkout);
+        let adyen = get_connector_capabilities(api_enums::Connector::Adyen);
+        let shift4 = get_connector_capabilities(api_enums::Connector::Shift4);
+        let wallet = api_enums::PaymentMethodType::Wallet;
+
+        // The payment token is decrypted into a network token for Checkout
+        assert!(
+            get_unsupported_payment_feature(&checkout, &mk_requirements(wallet, true)).is_none()
+        );
+        assert!(
+            get_unsupported_payment_feature(&checkout, &mk_requirements(wallet, false)).is_some()
+        );
+        // Adyen accepts the payment token as it is
+        assert!(get_unsupported_payment_feature(&adyen, &mk_requirements(wallet, true)).is_none());
+        // Shift4 accepts neither Apple Pay nor network tokens
+        assert!(get_unsupported_payment_feature(&shift4, &mk_requirements(wallet, true)).is_some());
+    }
+}
@@ -6,7 +6,7 @@ use storage_models::errors as storage_errors;
 use crate::{
     core::{
         errors::{self, RouterResponse, StorageErrorExt},
-        payment_methods::cards,
+        payment_methods::{cards, network_tokenization},
     },
     db::StorageInterface,
     pii::PeekInterface,
@@ -141,6 +141,7 @@ pub async fn delete_customer(
         Ok(customer_payment_methods) => {
             for pm in customer_payment_methods.into_iter() {
                 if pm.payment_method == enums::PaymentMethodType::Card {
+                    network_tokenization::delete_network_token(state, &pm).await;
                     cards::delete_card(state, &merchant_account.merchant_id, &pm.payment_method_id)
                         .await?;
                 }
@@ -289,3 +290,40 @@ pub async fn update_customer(
         customer_update_response,
     ))
 }
+
+#[instrument(skip(state))]
+pub async fn set_default_payment_method(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    req: customers::CustomerPaymentMethodId,
+) -> RouterResponse<customers::CustomerResponse> {
+    let db = &*state.store;
+    db.find_customer_by_customer_id_merchant_id(&req.customer_id, &merchant_account.merchant_id)
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+
+    let payment_method = db
+        .find_payment_method(&req.payment_method_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+    if payment_method.customer_id != req.customer_id
+        || payment_method.merchant_id != merchant_account.merchant_id
+    {
+        Err(errors::ApiErrorResponse::PaymentMethodNotFound)?
+    }
+
+    let response = db
+        .update_customer_by_customer_id_merchant_id(
+            req.customer_id,
+            merchant_account.merchant_id,
+            storage::CustomerUpdate::DefaultPaymentMethodUpdate {
+                default_payment_method_id: Some(payment_method.payment_method_id),
+            },
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::CustomerNotFound))?;
+
+    Ok(services::ApplicationResponse::Json(response.into()))
+}
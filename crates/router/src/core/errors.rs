@@ -268,6 +268,8 @@ pub enum VaultError {
     SaveCardFailed,
     #[error("Failed to fetch card details from card vault")]
     FetchCardFailed,
+    #[error("Failed to delete card from card vault")]
+    DeleteCardFailed,
     #[error("Failed to encode card vault request")]
     RequestEncodingFailed,
     #[error("Failed to deserialize card vault response")]
@@ -282,6 +284,38 @@ pub enum VaultError {
     UnexpectedResponseError(bytes::Bytes),
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum NetworkTokenizationError {
+    #[error("Failed to provision network token")]
+    ProvisioningFailed,
+    #[error("Failed to fetch network token cryptogram")]
+    CryptogramFetchFailed,
+    #[error("Failed to delete network token")]
+    TokenDeletionFailed,
+    #[error("The card network is not supported for network tokenization")]
+    UnsupportedCardNetwork,
+    #[error("Failed to verify the source of the network token webhook")]
+    WebhookSourceVerificationFailed,
+    #[error("Failed to decode the network token webhook body")]
+    WebhookBodyDecodingFailed,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AccountUpdaterError {
+    #[error("Failed to read the account updater response file")]
+    ResponseFileReadFailed,
+    #[error("Invalid record on line {0} of the account updater response file")]
+    InvalidResponseRecord(usize),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BinLookupError {
+    #[error("Failed to read the BIN table file")]
+    FileReadFailed,
+    #[error("Invalid record on line {0} of the BIN table file")]
+    InvalidRecord(usize),
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum ProcessTrackerError {
     #[error("An unexpected flow was specified")]
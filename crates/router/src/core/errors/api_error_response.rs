@@ -78,6 +78,10 @@ pub enum ApiErrorResponse {
         message = "{message}",
     )]
     GenericUnauthorized { message: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_19", message = "The payment token has expired or has been used the maximum number of times")]
+    PaymentTokenExpired,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_20", message = "{message} is not supported by {connector}")]
+    NotSupportedByConnector { message: String, connector: String },
 
     #[error(error_type = ErrorType::ConnectorError, code = "CE_00", message = "{code}: {message}", ignore = "status_code")]
     ExternalConnectorError {
@@ -135,6 +139,8 @@ pub enum ApiErrorResponse {
     MandateNotFound,
     #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "API Key does not exist in our records")]
     ApiKeyNotFound,
+    #[error(error_type = ErrorType::ObjectNotFound, code = "HE_02", message = "Fraud check does not exist in our records")]
+    FraudCheckNotFound,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "Return URL is not configured and not passed in payments request")]
     ReturnUrlUnavailable,
     #[error(error_type = ErrorType::ValidationError, code = "HE_03", message = "This refund is not possible through Hyperswitch. Please raise the refund through {connector} dashboard")]
@@ -204,6 +210,8 @@ impl actix_web::ResponseError for ApiErrorResponse {
             Self::RefundAmountExceedsPaymentAmount => StatusCode::BAD_REQUEST, // 400
             Self::MaximumRefundCount => StatusCode::BAD_REQUEST, // 400
             Self::PreconditionFailed { .. } => StatusCode::BAD_REQUEST, // 400
+            Self::PaymentTokenExpired => StatusCode::BAD_REQUEST, // 400
+            Self::NotSupportedByConnector { .. } => StatusCode::BAD_REQUEST, // 400
 
             Self::PaymentAuthorizationFailed { .. }
             | Self::PaymentAuthenticationFailed { .. }
@@ -234,7 +242,8 @@ impl actix_web::ResponseError for ApiErrorResponse {
             | Self::ResourceIdNotFound
             | Self::ConfigNotFound
             | Self::AddressNotFound
-            | Self::ApiKeyNotFound => StatusCode::BAD_REQUEST, // 400
+            | Self::ApiKeyNotFound
+            | Self::FraudCheckNotFound => StatusCode::BAD_REQUEST, // 400
             Self::DuplicateMerchantAccount
             | Self::DuplicateMerchantConnectorAccount
             | Self::DuplicatePaymentMethod
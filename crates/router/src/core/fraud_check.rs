@@ -0,0 +1,828 @@
+use std::{fmt::Debug, marker::PhantomData, str::FromStr};
+
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, PaymentData},
+    },
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    services::{self, ApplicationResponse},
+    types::{
+        self,
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums},
+        transformers::ForeignInto,
+    },
+    utils::{self, OptionExt, ValueExt},
+};
+
+pub const REJECTED_ERROR_CODE: &str = "fraud_check_rejected";
+
+/// The fraud and risk management (FRM) connector configured for a merchant, along with the stage
+/// at which it checks payments.
+pub struct FrmInfo {
+    pub frm_connector: api::FrmConnectorData,
+    pub merchant_connector_account: storage::MerchantConnectorAccount,
+    pub frm_stage: storage_enums::FraudCheckStage,
+}
+
+/// Metadata of the merchant connector account of an FRM connector
+#[derive(Debug, Default, serde::Deserialize)]
+struct FrmConnectorMetadata {
+    #[serde(default)]
+    frm_stage: storage_enums::FraudCheckStage,
+}
+
+fn is_captured_automatically(capture_method: Option<storage_enums::CaptureMethod>) -> bool {
+    matches!(
+        capture_method,
+        None | Some(storage_enums::CaptureMethod::Automatic)
+    )
+}
+
+/// What is done with a payment according to the status of its fraud check
+#[derive(Debug, Eq, PartialEq)]
+enum FrmDecision {
+    /// The payment is processed as requested
+    Proceed,
+    /// The authorized payment is captured, as it was to be captured automatically
+    Capture,
+    /// The capture of the payment is held until it is checked or reviewed
+    HoldCapture,
+    /// The payment is failed before it is sent to the processor, or voided once authorized
+    Reject,
+}
+
+fn get_frm_decision(
+    frm_status: storage_enums::FraudCheckStatus,
+    payment_capture_method: Option<storage_enums::CaptureMethod>,
+    is_authorized: bool,
+) -> FrmDecision {
+    let is_captured_automatically = is_captured_automatically(payment_capture_method);
+    match frm_status {
+        storage_enums::FraudCheckStatus::Accept if is_authorized && is_captured_automatically => {
+            FrmDecision::Capture
+        }
+        storage_enums::FraudCheckStatus::Reject => FrmDecision::Reject,
+        storage_enums::FraudCheckStatus::ManualReview
+        | storage_enums::FraudCheckStatus::Pending
+            if !is_authorized && is_captured_automatically =>
+        {
+            FrmDecision::HoldCapture
+        }
+        storage_enums::FraudCheckStatus::Accept
+        | storage_enums::FraudCheckStatus::ManualReview
+        | storage_enums::FraudCheckStatus::Pending => FrmDecision::Proceed,
+    }
+}
+
+/// Rejects the capture of a payment which is not accepted by its fraud checks. The capture of a
+/// payment is held while it is checked or reviewed, and a rejected payment is voided instead.
+pub fn validate_capture(fraud_checks: &[storage::FraudCheck]) -> RouterResult<()> {
+    match fraud_checks
+        .iter()
+        .find(|fraud_check| fraud_check.frm_status != storage_enums::FraudCheckStatus::Accept)
+    {
+        Some(fraud_check) => Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The payment cannot be captured while the status of its fraud check is {}",
+                fraud_check.frm_status
+            ),
+        })),
+        None => Ok(()),
+    }
+}
+
+/// Returns the FRM connector configured for the merchant, if the operation is one that payments
+/// are checked in.
+#[instrument(skip_all)]
+pub async fn get_frm_info<Op: Debug>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    operation: &Op,
+) -> RouterResult<Option<FrmInfo>> {
+    if !matches!(
+        format!("{operation:?}").as_str(),
+        "PaymentConfirm" | "PaymentStatus"
+    ) {
+        return Ok(None);
+    }
+
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_list(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the merchant connector accounts")?;
+
+    merchant_connector_accounts
+        .into_iter()
+        .find(|mca| {
+            mca.connector_type == storage_enums::ConnectorType::PaymentVas
+                && !mca.disabled.unwrap_or(false)
+                && api_enums::FrmConnectors::from_str(&mca.connector_name).is_ok()
+        })
+        .map(|mca| {
+            let frm_connector = api::FrmConnectorData::get_connector_by_name(&mca.connector_name)?;
+            let metadata: FrmConnectorMetadata = mca
+                .metadata
+                .clone()
+                .map(|metadata| metadata.parse_value("FrmConnectorMetadata"))
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "metadata",
+                })?
+                .unwrap_or_default();
+            Ok(FrmInfo {
+                frm_connector,
+                merchant_connector_account: mca,
+                frm_stage: metadata.frm_stage,
+            })
+        })
+        .transpose()
+}
+
+fn construct_frm_router_data<F, G, Req>(
+    frm_info: &FrmInfo,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &PaymentData<G>,
+    request: Req,
+) -> RouterResult<types::RouterData<F, Req, types::FraudCheckResponseData>>
+where
+    G: Clone,
+{
+    let auth_type: types::ConnectorAuthType = frm_info
+        .merchant_connector_account
+        .connector_account_details
+        .clone()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while parsing value for ConnectorAuthType")?;
+
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: frm_info.merchant_connector_account.connector_name.clone(),
+        payment_id: payment_data.payment_attempt.payment_id.clone(),
+        attempt_id: Some(payment_data.payment_attempt.attempt_id.clone()),
+        status: payment_data.payment_attempt.status,
+        payment_method: payment_data
+            .payment_attempt
+            .payment_method
+            .get_required_value("payment_method_type")?,
+        connector_auth_type: auth_type,
+        description: payment_data.payment_intent.description.clone(),
+        return_url: payment_data.payment_intent.return_url.clone(),
+        router_return_url: None,
+        address: payment_data.address.clone(),
+        auth_type: payment_data
+            .payment_attempt
+            .authentication_type
+            .unwrap_or_default(),
+        connector_meta_data: frm_info.merchant_connector_account.metadata.clone(),
+        amount_captured: payment_data.payment_intent.amount_captured,
+        access_token: None,
+        request,
+        response: Err(types::ErrorResponse::default()),
+        payment_method_id: payment_data.payment_attempt.payment_method_id.clone(),
+    })
+}
+
+/// Payments are held for a manual review when the FRM connector could not be reached, so that
+/// they are neither blocked nor captured without being checked.
+fn frm_response_or_manual_review<F, Req>(
+    frm_name: &str,
+    result: errors::CustomResult<
+        types::RouterData<F, Req, types::FraudCheckResponseData>,
+        errors::ConnectorError,
+    >,
+) -> types::FraudCheckResponseData {
+    let error_message = match result.map(|router_data| router_data.response) {
+        Ok(Ok(response)) => return response,
+        Ok(Err(error_response)) => error_response.message,
+        Err(error) => {
+            logger::error!(?error, "Failed to call the FRM connector {frm_name}");
+            error.current_context().to_string()
+        }
+    };
+    types::FraudCheckResponseData {
+        frm_transaction_id: None,
+        frm_status: storage_enums::FraudCheckStatus::ManualReview,
+        score: None,
+        reason: Some(format!("Fraud check failed: {error_message}")),
+    }
+}
+
+async fn call_frm_pre_auth<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    frm_info: &FrmInfo,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<types::FraudCheckResponseData> {
+    let authorize_data = types::PaymentsAuthorizeData::try_from(payment_data.clone())?;
+    let request = types::FraudCheckPreAuthData {
+        amount: authorize_data.amount,
+        currency: authorize_data.currency,
+        payment_method_data: authorize_data.payment_method_data,
+        email: authorize_data.email,
+        browser_info: authorize_data.browser_info,
+        order_details: authorize_data.order_details,
+    };
+    let router_data = construct_frm_router_data::<api::PreAuth, _, _>(
+        frm_info,
+        merchant_account,
+        payment_data,
+        request,
+    )?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::PreAuth,
+        types::FraudCheckPreAuthData,
+        types::FraudCheckResponseData,
+    > = frm_info.frm_connector.connector.get_connector_integration();
+    let result = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await;
+
+    Ok(frm_response_or_manual_review(
+        &frm_info.merchant_connector_account.connector_name,
+        result,
+    ))
+}
+
+async fn call_frm_post_auth<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    frm_info: &FrmInfo,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<types::FraudCheckResponseData> {
+    let request = types::FraudCheckPostAuthData {
+        amount: payment_data.amount.into(),
+        currency: payment_data.currency,
+        email: payment_data.email.clone(),
+        connector: payment_data
+            .payment_attempt
+            .connector
+            .clone()
+            .get_required_value("connector")?,
+        connector_transaction_id: payment_data
+            .payment_attempt
+            .connector_transaction_id
+            .clone(),
+        attempt_status: payment_data.payment_attempt.status,
+    };
+    let router_data = construct_frm_router_data::<api::PostAuth, _, _>(
+        frm_info,
+        merchant_account,
+        payment_data,
+        request,
+    )?;
+
+    let connector_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::PostAuth,
+        types::FraudCheckPostAuthData,
+        types::FraudCheckResponseData,
+    > = frm_info.frm_connector.connector.get_connector_integration();
+    let result = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await;
+
+    Ok(frm_response_or_manual_review(
+        &frm_info.merchant_connector_account.connector_name,
+        result,
+    ))
+}
+
+/// Checks a payment before it is sent to the processor, for merchants checking payments
+/// pre-auth. For merchants checking payments post-auth, the capture of the payment is held until
+/// it is checked.
+///
+/// Returns whether the payment is to be sent to the processor.
+#[instrument(skip_all)]
+pub async fn call_frm_before_connector_call<F, Op>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    frm_info: &FrmInfo,
+    operation: &Op,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<bool>
+where
+    F: Clone,
+    Op: Debug,
+{
+    if format!("{operation:?}") != "PaymentConfirm" {
+        return Ok(true);
+    }
+
+    let db = &*state.store;
+    let payment_capture_method = payment_data.payment_attempt.capture_method;
+    let response = match frm_info.frm_stage {
+        storage_enums::FraudCheckStage::PreAuth => {
+            call_frm_pre_auth(state, merchant_account, frm_info, payment_data).await?
+        }
+        storage_enums::FraudCheckStage::PostAuth => types::FraudCheckResponseData {
+            frm_transaction_id: None,
+            frm_status: storage_enums::FraudCheckStatus::Pending,
+            score: None,
+            reason: None,
+        },
+    };
+
+    let fraud_check = db
+        .insert_fraud_check(storage::FraudCheckNew {
+            frm_id: utils::generate_id(consts::ID_LENGTH, "frm"),
+            merchant_id: merchant_account.merchant_id.clone(),
+            payment_id: payment_data.payment_attempt.payment_id.clone(),
+            attempt_id: payment_data.payment_attempt.attempt_id.clone(),
+            frm_name: frm_info.merchant_connector_account.connector_name.clone(),
+            frm_transaction_id: response.frm_transaction_id,
+            frm_stage: frm_info.frm_stage,
+            frm_status: response.frm_status,
+            frm_score: response.score,
+            frm_reason: response.reason,
+            payment_capture_method,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the fraud check")?;
+
+    match get_frm_decision(fraud_check.frm_status, payment_capture_method, false) {
+        FrmDecision::Proceed | FrmDecision::Capture => Ok(true),
+        FrmDecision::Reject => {
+            reject_payment(db, merchant_account, &fraud_check, payment_data).await?;
+            Ok(false)
+        }
+        FrmDecision::HoldCapture => {
+            hold_capture(db, merchant_account, payment_data).await?;
+            Ok(true)
+        }
+    }
+}
+
+async fn reject_payment<F: Clone>(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    fraud_check: &storage::FraudCheck,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    payment_data.payment_attempt = db
+        .update_payment_attempt(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                connector: None,
+                status: storage_enums::AttemptStatus::Failure,
+                error_code: Some(REJECTED_ERROR_CODE.to_string()),
+                error_message: Some(
+                    fraud_check
+                        .frm_reason
+                        .clone()
+                        .unwrap_or_else(|| "The payment was rejected by a fraud check".to_string()),
+                ),
+                decline_code: Some(storage_enums::DeclineCode::FraudSuspected),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: storage_enums::IntentStatus::Failed,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok(())
+}
+
+async fn hold_capture<F: Clone>(
+    db: &dyn StorageInterface,
+    merchant_account: &storage::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    payment_data.payment_attempt = db
+        .update_payment_attempt(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::CaptureMethodUpdate {
+                capture_method: storage_enums::CaptureMethod::Manual,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok(())
+}
+
+/// Checks a payment held for a post-auth check once it is authorized, and captures or voids it
+/// based on the outcome of the check.
+#[instrument(skip_all)]
+pub async fn call_frm_after_connector_call<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    frm_info: &FrmInfo,
+    customer: &Option<storage::Customer>,
+    payment_data: PaymentData<F>,
+) -> RouterResult<PaymentData<F>>
+where
+    F: Clone + Send,
+{
+    if frm_info.frm_stage != storage_enums::FraudCheckStage::PostAuth
+        || payment_data.payment_attempt.status != storage_enums::AttemptStatus::Authorized
+    {
+        return Ok(payment_data);
+    }
+
+    let db = &*state.store;
+    let pending_fraud_check = db
+        .find_fraud_checks_by_merchant_id_attempt_id(
+            &merchant_account.merchant_id,
+            &payment_data.payment_attempt.attempt_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the fraud checks of the payment attempt")?
+        .into_iter()
+        .find(|fraud_check| {
+            fraud_check.frm_stage == storage_enums::FraudCheckStage::PostAuth
+                && fraud_check.frm_status == storage_enums::FraudCheckStatus::Pending
+        });
+
+    let fraud_check = match pending_fraud_check {
+        Some(fraud_check) => fraud_check,
+        None => return Ok(payment_data),
+    };
+
+    let response = call_frm_post_auth(state, merchant_account, frm_info, &payment_data).await?;
+    let fraud_check = db
+        .update_fraud_check(
+            fraud_check,
+            storage::FraudCheckUpdate::ResponseUpdate {
+                frm_transaction_id: response.frm_transaction_id,
+                frm_status: response.frm_status,
+                frm_score: response.score,
+                frm_reason: response.reason,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the fraud check")?;
+
+    match get_frm_decision(
+        fraud_check.frm_status,
+        fraud_check.payment_capture_method,
+        true,
+    ) {
+        FrmDecision::Capture => {
+            capture_payment(state, merchant_account, customer, payment_data).await
+        }
+        FrmDecision::Reject => void_payment(state, merchant_account, customer, payment_data).await,
+        FrmDecision::Proceed | FrmDecision::HoldCapture => Ok(payment_data),
+    }
+}
+
+fn get_payment_connector<F: Clone>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<api::ConnectorData> {
+    let connector = payment_data
+        .payment_attempt
+        .connector
+        .as_ref()
+        .get_required_value("connector")?;
+    api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector,
+        api::GetToken::Connector,
+    )
+}
+
+/// A payment failing to be captured or voided is left authorized, for the merchant to capture or
+/// void it.
+async fn capture_payment<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    customer: &Option<storage::Customer>,
+    payment_data: PaymentData<F>,
+) -> RouterResult<PaymentData<F>>
+where
+    F: Clone + Send,
+{
+    let connector = get_payment_connector(state, &payment_data)?;
+    let payment_id =
+        api::PaymentIdType::PaymentIntentId(payment_data.payment_intent.payment_id.clone());
+    let result = payments::call_connector_service::<api::Capture, _, types::PaymentsCaptureData>(
+        state,
+        merchant_account,
+        &payment_id,
+        connector,
+        &payments::PaymentCapture,
+        payments::helpers::change_payment_data_flow(payment_data.clone()),
+        customer,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await;
+
+    match result {
+        Ok(captured_payment_data) => Ok(payments::helpers::change_payment_data_flow(
+            captured_payment_data,
+        )),
+        Err(error) => {
+            logger::error!(?error, "Failed to capture the payment accepted by the FRM");
+            Ok(payment_data)
+        }
+    }
+}
+
+async fn void_payment<F>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    customer: &Option<storage::Customer>,
+    payment_data: PaymentData<F>,
+) -> RouterResult<PaymentData<F>>
+where
+    F: Clone + Send,
+{
+    let connector = get_payment_connector(state, &payment_data)?;
+    let payment_id =
+        api::PaymentIdType::PaymentIntentId(payment_data.payment_intent.payment_id.clone());
+    let mut void_payment_data: PaymentData<api::Void> =
+        payments::helpers::change_payment_data_flow(payment_data.clone());
+    void_payment_data.payment_attempt.cancellation_reason =
+        Some("Rejected by a fraud check".to_string());
+    let result = payments::call_connector_service::<api::Void, _, types::PaymentsCancelData>(
+        state,
+        merchant_account,
+        &payment_id,
+        connector,
+        &payments::PaymentCancel,
+        void_payment_data,
+        customer,
+        payments::CallConnectorAction::Trigger,
+    )
+    .await;
+
+    match result {
+        Ok(voided_payment_data) => Ok(payments::helpers::change_payment_data_flow(
+            voided_payment_data,
+        )),
+        Err(error) => {
+            logger::error!(?error, "Failed to void the payment rejected by the FRM");
+            Ok(payment_data)
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_fraud_check(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    frm_id: &str,
+) -> RouterResponse<api::FraudCheckResponse> {
+    let fraud_check = db
+        .find_fraud_check_by_merchant_id_frm_id(&merchant_account.merchant_id, frm_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::FraudCheckNotFound)
+        })?;
+
+    Ok(ApplicationResponse::Json(fraud_check.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn list_review_queue(
+    db: &dyn StorageInterface,
+    merchant_account: storage::MerchantAccount,
+    constraints: api::ListFraudCheckConstraints,
+) -> RouterResponse<Vec<api::FraudCheckResponse>> {
+    let fraud_checks = db
+        .find_fraud_checks_by_merchant_id_status(
+            &merchant_account.merchant_id,
+            storage_enums::FraudCheckStatus::ManualReview,
+            constraints.limit,
+            constraints.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the fraud checks held for review")?;
+
+    Ok(ApplicationResponse::Json(
+        fraud_checks
+            .into_iter()
+            .map(ForeignInto::foreign_into)
+            .collect(),
+    ))
+}
+
+/// Captures or voids a payment held for a manual review, according to the decision of the
+/// merchant. Payments which were to be captured manually are left for the merchant to capture.
+///
+/// The decision is recorded first, as payments are only captured once their fraud check is
+/// accepted.
+#[instrument(skip_all)]
+pub async fn review_fraud_check(
+    state: &AppState,
+    merchant_account: storage::MerchantAccount,
+    frm_id: &str,
+    req: api::FraudCheckReviewRequest,
+) -> RouterResponse<api::FraudCheckResponse> {
+    let db = &*state.store;
+    let fraud_check = db
+        .find_fraud_check_by_merchant_id_frm_id(&merchant_account.merchant_id, frm_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::FraudCheckNotFound)
+        })?;
+
+    if fraud_check.frm_status != storage_enums::FraudCheckStatus::ManualReview {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Only fraud checks held for manual review can be reviewed, the status of this \
+                 fraud check is {}",
+                fraud_check.frm_status
+            ),
+        }));
+    }
+
+    let frm_status: api_enums::FraudCheckStatus = req.decision.into();
+    let fraud_check = db
+        .update_fraud_check(
+            fraud_check,
+            storage::FraudCheckUpdate::ReviewUpdate {
+                frm_status: frm_status.foreign_into(),
+                reviewed_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the fraud check")?;
+
+    match req.decision {
+        api::FraudCheckReviewDecision::Accept => {
+            if is_captured_automatically(fraud_check.payment_capture_method) {
+                payments::payments_core::<api::Capture, api::PaymentsResponse, _, _, _>(
+                    state,
+                    merchant_account.clone(),
+                    payments::PaymentCapture,
+                    api::PaymentsCaptureRequest {
+                        payment_id: Some(fraud_check.payment_id.clone()),
+                        merchant_id: Some(merchant_account.merchant_id.clone()),
+                        ..Default::default()
+                    },
+                    services::AuthFlow::Merchant,
+                    payments::CallConnectorAction::Trigger,
+                )
+                .await?;
+            }
+        }
+        api::FraudCheckReviewDecision::Reject => {
+            payments::payments_core::<api::Void, api::PaymentsResponse, _, _, _>(
+                state,
+                merchant_account.clone(),
+                payments::PaymentCancel,
+                api::PaymentsCancelRequest {
+                    payment_id: fraud_check.payment_id.clone(),
+                    cancellation_reason: Some("Rejected after a fraud review".to_string()),
+                },
+                services::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+            )
+            .await?;
+        }
+    }
+
+    Ok(ApplicationResponse::Json(fraud_check.foreign_into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mk_fraud_check(frm_status: storage_enums::FraudCheckStatus) -> storage::FraudCheck {
+        let now = common_utils::date_time::now();
+        storage::FraudCheck {
+            frm_id: "frm_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            payment_id: "pay_123".to_string(),
+            attempt_id: "pay_123_1".to_string(),
+            frm_name: "signifyd".to_string(),
+            frm_transaction_id: None,
+            frm_stage: storage_enums::FraudCheckStage::PostAuth,
+            frm_status,
+            frm_score: None,
+            frm_reason: None,
+            payment_capture_method: Some(storage_enums::CaptureMethod::Automatic),
+            reviewed_at: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_frm_decision_before_authorization() {
+        use storage_enums::{CaptureMethod, FraudCheckStatus};
+
+        for (frm_status, capture_method, decision) in [
+            (FraudCheckStatus::Accept, None, FrmDecision::Proceed),
+            (
+                FraudCheckStatus::Accept,
+                Some(CaptureMethod::Manual),
+                FrmDecision::Proceed,
+            ),
+            (FraudCheckStatus::Reject, None, FrmDecision::Reject),
+            (
+                FraudCheckStatus::Reject,
+                Some(CaptureMethod::Manual),
+                FrmDecision::Reject,
+            ),
+            (FraudCheckStatus::Pending, None, FrmDecision::HoldCapture),
+            (
+                FraudCheckStatus::ManualReview,
+                Some(CaptureMethod::Automatic),
+                FrmDecision::HoldCapture,
+            ),
+            (
+                FraudCheckStatus::ManualReview,
+                Some(CaptureMethod::Manual),
+                FrmDecision::Proceed,
+            ),
+        ] {
+            assert_eq!(
+                get_frm_decision(frm_status, capture_method, false),
+                decision,
+                "{frm_status} with {capture_method:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_frm_decision_after_authorization() {
+        use storage_enums::{CaptureMethod, FraudCheckStatus};
+
+        for (frm_status, capture_method, decision) in [
+            (FraudCheckStatus::Accept, None, FrmDecision::Capture),
+            (
+                FraudCheckStatus::Accept,
+                Some(CaptureMethod::Manual),
+                FrmDecision::Proceed,
+            ),
+            (FraudCheckStatus::Reject, None, FrmDecision::Reject),
+            (
+                FraudCheckStatus::Reject,
+                Some(CaptureMethod::Manual),
+                FrmDecision::Reject,
+            ),
+            (FraudCheckStatus::Pending, None, FrmDecision::Proceed),
+            (FraudCheckStatus::ManualReview, None, FrmDecision::Proceed),
+        ] {
+            assert_eq!(
+                get_frm_decision(frm_status, capture_method, true),
+                decision,
+                "{frm_status} with {capture_method:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_capture() {
+        assert!(validate_capture(&[]).is_ok());
+        assert!(
+            validate_capture(&[mk_fraud_check(storage_enums::FraudCheckStatus::Accept)]).is_ok()
+        );
+
+        for frm_status in [
+            storage_enums::FraudCheckStatus::Pending,
+            storage_enums::FraudCheckStatus::ManualReview,
+            storage_enums::FraudCheckStatus::Reject,
+        ] {
+            let result = validate_capture(&[mk_fraud_check(frm_status)]);
+            assert!(
+                matches!(
+                    result.as_ref().map_err(|error| error.current_context()),
+                    Err(errors::ApiErrorResponse::PreconditionFailed { .. })
+                ),
+                "{frm_status}"
+            );
+        }
+    }
+}
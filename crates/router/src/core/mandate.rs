@@ -165,6 +165,7 @@ where
                     resp.request.get_setup_mandate_details().map(Clone::clone),
                     maybe_customer,
                     payment_method_id,
+                    resp.payment_method,
                     mandate_reference,
                 ) {
                     logger::error!("{:?}", new_mandate_data);
@@ -172,6 +173,9 @@ where
                         .set_mandate_id(api_models::payments::MandateIds {
                             mandate_id: new_mandate_data.mandate_id.clone(),
                             connector_mandate_id: new_mandate_data.connector_mandate_id.clone(),
+                            payment_method: new_mandate_data
+                                .payment_method
+                                .map(ForeignInto::foreign_into),
                         });
                     state
                         .store
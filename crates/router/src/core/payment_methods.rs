@@ -1,3 +1,8 @@
+pub mod account_updater;
+pub mod bin_lookup;
+pub mod card_vault;
 pub mod cards;
+pub mod network_tokenization;
+pub mod payment_token;
 pub mod transformers;
 pub mod vault;
@@ -0,0 +1,615 @@
+//! Card account updater.
+//!
+//! Card networks let merchants find out whether a saved card was reissued, with a new expiry or a
+//! new card number, or whether the account was closed. Saved cards close to their expiry are
+//! periodically checked in batches with the configured provider. Reissued cards are updated in the
+//! locker and on the payment method, and the merchant is notified with a `payment_method_updated`
+//! outgoing webhook. The merchant is notified of cards whose account was closed with a
+//! `payment_method_closed` outgoing webhook, and decides whether to delete them.
+
+use std::collections::HashMap;
+
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        payment_methods::{cards, transformers as payment_methods},
+        webhooks,
+    },
+    logger,
+    routes::AppState,
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerExt},
+    },
+    utils::OptionExt,
+};
+
+#[derive(Debug, Clone)]
+pub struct AccountUpdaterRequest {
+    /// ID of the payment method the card is saved as
+    pub payment_method_id: String,
+    pub card_number: Secret<String, common_utils::pii::CardNumber>,
+    pub card_exp_month: Secret<String>,
+    pub card_exp_year: Secret<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum AccountUpdaterResult {
+    NoChange,
+    ExpiryUpdated {
+        card_exp_month: Secret<String>,
+        card_exp_year: Secret<String>,
+    },
+    CardNumberUpdated {
+        card_number: Secret<String, common_utils::pii::CardNumber>,
+        card_exp_month: Secret<String>,
+        card_exp_year: Secret<String>,
+    },
+    AccountClosed,
+}
+
+#[async_trait::async_trait]
+pub trait AccountUpdaterProvider: Send + Sync {
+    /// Query the cards with the provider. The results are keyed by payment method ID, cards
+    /// missing from the results are considered unchanged.
+    async fn query_cards(
+        &self,
+        cards: &[AccountUpdaterRequest],
+    ) -> CustomResult<HashMap<String, AccountUpdaterResult>, errors::AccountUpdaterError>;
+}
+
+pub fn get_provider(conf: &settings::AccountUpdater) -> Box<dyn AccountUpdaterProvider> {
+    match conf.provider {
+        settings::AccountUpdaterProviderKind::File => Box::new(FileAccountUpdater {
+            file_path: conf.file_path.clone(),
+        }),
+    }
+}
+
+#[derive(Debug, Default)]
+struct AccountUpdaterSummary {
+    checked: usize,
+    updated: usize,
+    closed: usize,
+    failed: usize,
+}
+
+/// Check the saved cards expiring around now with the account updater provider, and update the
+/// cards that were reissued.
+#[instrument(skip_all)]
+pub async fn start_card_account_updater_workflow(
+    state: &AppState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let conf = &state.conf.account_updater;
+    let provider = get_provider(conf);
+    let id = process.id.clone();
+
+    if !cards::locker_supports_card_updates(state) {
+        logger::info!(
+            "Skipping card account updater run, reissued cards cannot be updated in the external locker"
+        );
+        return process
+            .clone()
+            .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+            .await;
+    }
+
+    let mut merchant_accounts = HashMap::new();
+    backfill_card_expiry(state, &mut merchant_accounts).await?;
+
+    let now = common_utils::date_time::now();
+    let (from_year, from_month) = expiry_months_from(now, -i32::from(conf.lookback_months));
+    let (to_year, to_month) = expiry_months_from(now, i32::from(conf.lookahead_months));
+
+    let mut summary = AccountUpdaterSummary::default();
+    let mut after_id = 0;
+    loop {
+        let payment_methods = db
+            .find_payment_methods_by_card_expiry_range(
+                (&from_year, &from_month),
+                (&to_year, &to_month),
+                after_id,
+                conf.batch_size,
+            )
+            .await?;
+        let Some(last_payment_method) = payment_methods.last() else {
+            break;
+        };
+        after_id = last_payment_method.id;
+        let is_last_batch = i64::try_from(payment_methods.len())
+            .map_or(true, |batch_len| batch_len < conf.batch_size);
+
+        update_card_batch(
+            state,
+            provider.as_ref(),
+            payment_methods,
+            &mut merchant_accounts,
+            &mut summary,
+        )
+        .await?;
+
+        if is_last_batch {
+            break;
+        }
+    }
+
+    logger::info!(
+        checked = summary.checked,
+        updated = summary.updated,
+        closed = summary.closed,
+        failed = summary.failed,
+        "Card account updater run completed"
+    );
+
+    process
+        .clone()
+        .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+        .await
+}
+
+/// Payment methods saved before the card expiry was stored on them have no expiry, so they would
+/// never be found by expiry. Read the expiry of those cards from the locker and store it on the
+/// payment method. Cards that cannot be fetched are retried on the next run.
+async fn backfill_card_expiry(
+    state: &AppState,
+    merchant_accounts: &mut HashMap<String, storage::MerchantAccount>,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let batch_size = state.conf.account_updater.batch_size;
+
+    let (mut backfilled, mut failed) = (0, 0);
+    let mut after_id = 0;
+    loop {
+        let payment_methods = db
+            .find_payment_methods_without_card_expiry(after_id, batch_size)
+            .await?;
+        let Some(last_payment_method) = payment_methods.last() else {
+            break;
+        };
+        after_id = last_payment_method.id;
+        let is_last_batch =
+            i64::try_from(payment_methods.len()).map_or(true, |batch_len| batch_len < batch_size);
+
+        for payment_method in payment_methods {
+            let payment_method_id = payment_method.payment_method_id.clone();
+            match backfill_saved_card_expiry(state, payment_method, merchant_accounts).await {
+                Ok(()) => backfilled += 1,
+                Err(error) => {
+                    logger::warn!(?error, %payment_method_id, "Failed to backfill card expiry");
+                    failed += 1;
+                }
+            }
+        }
+
+        if is_last_batch {
+            break;
+        }
+    }
+
+    if backfilled > 0 || failed > 0 {
+        logger::info!(backfilled, failed, "Card expiry backfill completed");
+    }
+    Ok(())
+}
+
+async fn backfill_saved_card_expiry(
+    state: &AppState,
+    payment_method: storage::PaymentMethod,
+    merchant_accounts: &mut HashMap<String, storage::MerchantAccount>,
+) -> RouterResult<()> {
+    let card = get_saved_card(state, &payment_method, merchant_accounts).await?;
+    let card_exp_month = card.card_exp_month.get_required_value("card_exp_month")?;
+    let card_exp_year = card.card_exp_year.get_required_value("card_exp_year")?;
+    let (card_exp_month, card_exp_year) =
+        cards::normalize_card_expiry(card_exp_month.peek(), card_exp_year.peek());
+
+    state
+        .store
+        .update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::CardUpdate {
+                card_fingerprint: None,
+                card_exp_month: Some(card_exp_month),
+                card_exp_year: Some(card_exp_year),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update card expiry of the payment method")?;
+    Ok(())
+}
+
+async fn update_card_batch(
+    state: &AppState,
+    provider: &dyn AccountUpdaterProvider,
+    payment_methods: Vec<storage::PaymentMethod>,
+    merchant_accounts: &mut HashMap<String, storage::MerchantAccount>,
+    summary: &mut AccountUpdaterSummary,
+) -> RouterResult<()> {
+    let mut requests = Vec::with_capacity(payment_methods.len());
+    let mut saved_cards = HashMap::with_capacity(payment_methods.len());
+
+    for payment_method in payment_methods {
+        let card = match get_saved_card(state, &payment_method, merchant_accounts).await {
+            Ok(card) => card,
+            Err(error) => {
+                logger::warn!(
+                    ?error,
+                    payment_method_id = %payment_method.payment_method_id,
+                    "Skipping card which could not be fetched from the locker"
+                );
+                summary.failed += 1;
+                continue;
+            }
+        };
+        let (Some(card_number), Some(card_exp_month), Some(card_exp_year)) = (
+            card.card_number.clone(),
+            card.card_exp_month.clone(),
+            card.card_exp_year.clone(),
+        ) else {
+            summary.failed += 1;
+            continue;
+        };
+
+        requests.push(AccountUpdaterRequest {
+            payment_method_id: payment_method.payment_method_id.clone(),
+            card_number,
+            card_exp_month,
+            card_exp_year,
+        });
+        saved_cards.insert(
+            payment_method.payment_method_id.clone(),
+            (payment_method, card),
+        );
+    }
+    if requests.is_empty() {
+        return Ok(());
+    }
+    summary.checked += requests.len();
+
+    let results = provider
+        .query_cards(&requests)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to query the account updater provider")?;
+
+    for (payment_method_id, result) in results {
+        let Some((payment_method, card)) = saved_cards.remove(&payment_method_id) else {
+            continue;
+        };
+        let Some(merchant_account) = merchant_accounts.get(&payment_method.merchant_id) else {
+            continue;
+        };
+
+        match result {
+            AccountUpdaterResult::NoChange => {}
+            AccountUpdaterResult::AccountClosed => {
+                logger::info!(%payment_method_id, "Card account was closed by the issuer");
+                summary.closed += 1;
+                if let Err(error) = notify_payment_method_event(
+                    state,
+                    merchant_account,
+                    enums::EventType::PaymentMethodClosed,
+                    payment_method,
+                    card,
+                )
+                .await
+                {
+                    logger::error!(?error, %payment_method_id, "Failed to notify closed card");
+                    summary.failed += 1;
+                }
+            }
+            AccountUpdaterResult::ExpiryUpdated { .. }
+            | AccountUpdaterResult::CardNumberUpdated { .. } => {
+                match update_saved_card(state, merchant_account, payment_method, card, result).await
+                {
+                    Ok(()) => summary.updated += 1,
+                    Err(error) => {
+                        logger::error!(?error, %payment_method_id, "Failed to update saved card");
+                        summary.failed += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn get_saved_card(
+    state: &AppState,
+    payment_method: &storage::PaymentMethod,
+    merchant_accounts: &mut HashMap<String, storage::MerchantAccount>,
+) -> RouterResult<payment_methods::AddCardResponse> {
+    if !merchant_accounts.contains_key(&payment_method.merchant_id) {
+        let merchant_account = state
+            .store
+            .find_merchant_account_by_merchant_id(&payment_method.merchant_id)
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+            })?;
+        merchant_accounts.insert(payment_method.merchant_id.clone(), merchant_account);
+    }
+    let merchant_account = merchant_accounts
+        .get(&payment_method.merchant_id)
+        .get_required_value("merchant_account")?;
+
+    cards::get_card_from_legacy_locker(state, merchant_account, &payment_method.payment_method_id)
+        .await
+        .map(|response| response.card)
+}
+
+/// Update the card in the locker and on the payment method, then notify the merchant.
+#[instrument(skip_all)]
+async fn update_saved_card(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_method: storage::PaymentMethod,
+    card: payment_methods::AddCardResponse,
+    result: AccountUpdaterResult,
+) -> RouterResult<()> {
+    let (card_number, card_exp_month, card_exp_year) = match result {
+        AccountUpdaterResult::NoChange | AccountUpdaterResult::AccountClosed => return Ok(()),
+        AccountUpdaterResult::ExpiryUpdated {
+            card_exp_month,
+            card_exp_year,
+        } => (
+            card.card_number.get_required_value("card_number")?,
+            card_exp_month,
+            card_exp_year,
+        ),
+        AccountUpdaterResult::CardNumberUpdated {
+            card_number,
+            card_exp_month,
+            card_exp_year,
+        } => (card_number, card_exp_month, card_exp_year),
+    };
+    let card = api::CardDetail {
+        card_number,
+        card_exp_month,
+        card_exp_year,
+        card_holder_name: card.name_on_card,
+    };
+
+    let updated_card = cards::update_card_in_locker(
+        state,
+        &card,
+        &merchant_account.merchant_id,
+        &payment_method.customer_id,
+        &payment_method.payment_method_id,
+    )
+    .await?;
+
+    let card_fingerprint = cards::generate_card_fingerprint(
+        &state.conf.secrets.card_fingerprint_secret,
+        &merchant_account.merchant_id,
+        card.card_number.peek(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to generate card fingerprint")?;
+    let (card_exp_month, card_exp_year) =
+        cards::normalize_card_expiry(card.card_exp_month.peek(), card.card_exp_year.peek());
+    let payment_method = state
+        .store
+        .update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::CardUpdate {
+                card_fingerprint: Some(card_fingerprint),
+                card_exp_month: Some(card_exp_month),
+                card_exp_year: Some(card_exp_year),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update card details of the payment method")?;
+
+    notify_payment_method_event(
+        state,
+        merchant_account,
+        enums::EventType::PaymentMethodUpdated,
+        payment_method,
+        updated_card,
+    )
+    .await
+}
+
+/// Notify the merchant of a change to a saved card with an outgoing webhook.
+async fn notify_payment_method_event(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    event_type: enums::EventType,
+    payment_method: storage::PaymentMethod,
+    card: payment_methods::AddCardResponse,
+) -> RouterResult<()> {
+    let card_detail = payment_methods::get_card_detail(&payment_method, card)
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let payment_method_response =
+        cards::mk_payment_method_response(payment_method, Some(card_detail));
+
+    webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        event_type,
+        enums::EventClass::PaymentMethods,
+        None,
+        payment_method_response.payment_method_id.clone(),
+        enums::EventObjectType::PaymentMethodDetails,
+        api::OutgoingWebhookContent::PaymentMethodDetails(payment_method_response),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Failed to trigger the {event_type} webhook"))
+}
+
+/// The `(year, month)` expiry `months` months away from `date`, formatted as stored on payment
+/// methods.
+fn expiry_months_from(date: time::PrimitiveDateTime, months: i32) -> (String, String) {
+    let month_index = date.year() * 12 + i32::from(u8::from(date.month())) - 1 + months;
+    (
+        format!("{:04}", month_index.div_euclid(12)),
+        format!("{:02}", month_index.rem_euclid(12) + 1),
+    )
+}
+
+/// Stand-in for a card network account updater service, reading the updates from a local CSV
+/// file. Each line of the file holds a `card_number,status,new_card_number,new_exp_month,
+/// new_exp_year` record, `status` being one of `no_change`, `expiry_updated`,
+/// `card_number_updated` and `account_closed`. Empty lines and lines starting with `#` are
+/// ignored.
+pub struct FileAccountUpdater {
+    file_path: String,
+}
+
+#[async_trait::async_trait]
+impl AccountUpdaterProvider for FileAccountUpdater {
+    async fn query_cards(
+        &self,
+        cards: &[AccountUpdaterRequest],
+    ) -> CustomResult<HashMap<String, AccountUpdaterResult>, errors::AccountUpdaterError> {
+        let file_path = self.file_path.clone();
+        let contents = tokio::task::spawn_blocking(move || std::fs::read_to_string(file_path))
+            .await
+            .into_report()
+            .change_context(errors::AccountUpdaterError::ResponseFileReadFailed)?
+            .into_report()
+            .change_context(errors::AccountUpdaterError::ResponseFileReadFailed)
+            .attach_printable_lazy(|| format!("Account updater file: {}", self.file_path))?;
+        let mut updates = parse_response_file(&contents)?;
+
+        Ok(cards
+            .iter()
+            .filter_map(|card| {
+                updates
+                    .remove(card.card_number.peek())
+                    .map(|result| (card.payment_method_id.clone(), result))
+            })
+            .collect())
+    }
+}
+
+/// Parse the response file into the results keyed by card number.
+fn parse_response_file(
+    contents: &str,
+) -> CustomResult<HashMap<String, AccountUpdaterResult>, errors::AccountUpdaterError> {
+    contents
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line_number, line)| {
+            parse_response_record(line).ok_or_else(|| {
+                report!(errors::AccountUpdaterError::InvalidResponseRecord(
+                    line_number
+                ))
+            })
+        })
+        .collect()
+}
+
+fn parse_response_record(line: &str) -> Option<(String, AccountUpdaterResult)> {
+    let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+
+    let (card_number, result) = match fields[..] {
+        [card_number, "no_change", ..] => (card_number, AccountUpdaterResult::NoChange),
+        [card_number, "account_closed", ..] => (card_number, AccountUpdaterResult::AccountClosed),
+        [card_number, "expiry_updated", _, exp_month, exp_year] => (
+            card_number,
+            AccountUpdaterResult::ExpiryUpdated {
+                card_exp_month: non_empty(exp_month)?,
+                card_exp_year: non_empty(exp_year)?,
+            },
+        ),
+        [card_number, "card_number_updated", new_card_number, exp_month, exp_year] => (
+            card_number,
+            AccountUpdaterResult::CardNumberUpdated {
+                card_number: non_empty(new_card_number)?,
+                card_exp_month: non_empty(exp_month)?,
+                card_exp_year: non_empty(exp_year)?,
+            },
+        ),
+        _ => return None,
+    };
+
+    (!card_number.is_empty()).then(|| (card_number.to_string(), result))
+}
+
+fn non_empty<S: masking::Strategy<String>>(field: &str) -> Option<Secret<String, S>> {
+    (!field.is_empty()).then(|| Secret::new(field.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_expiry_months_from() {
+        let now = datetime!(2023-02-17 10:00:00);
+        assert_eq!(
+            expiry_months_from(now, 1),
+            ("2023".to_string(), "03".to_string())
+        );
+        assert_eq!(
+            expiry_months_from(now, -3),
+            ("2022".to_string(), "11".to_string())
+        );
+        assert_eq!(
+            expiry_months_from(now, 11),
+            ("2024".to_string(), "01".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_response_file() {
+        let contents = "\
+# card_number,status,new_card_number,new_exp_month,new_exp_year
+4111111111111111,expiry_updated,,03,2027
+
+5555555555554444,card_number_updated,5105105105105100,04,2028
+4000056655665556,account_closed
+378282246310005,no_change,,,
+";
+        let updates = parse_response_file(contents).unwrap();
+        assert_eq!(updates.len(), 4);
+        assert!(matches!(
+            updates.get("4111111111111111"),
+            Some(AccountUpdaterResult::ExpiryUpdated { card_exp_month, card_exp_year })
+                if card_exp_month.peek() == "03" && card_exp_year.peek() == "2027"
+        ));
+        assert!(matches!(
+            updates.get("5555555555554444"),
+            Some(AccountUpdaterResult::CardNumberUpdated { card_number, .. })
+                if card_number.peek() == "5105105105105100"
+        ));
+        assert!(matches!(
+            updates.get("4000056655665556"),
+            Some(AccountUpdaterResult::AccountClosed)
+        ));
+        assert!(matches!(
+            updates.get("378282246310005"),
+            Some(AccountUpdaterResult::NoChange)
+        ));
+    }
+
+    #[test]
+    fn test_parse_invalid_response_record() {
+        for contents in [
+            "4111111111111111,expiry_updated,,,2027",
+            "4111111111111111,card_number_updated,,03,2027",
+            "4111111111111111,reissued",
+            ",account_closed",
+        ] {
+            assert!(parse_response_file(contents).is_err(), "{contents}");
+        }
+    }
+}
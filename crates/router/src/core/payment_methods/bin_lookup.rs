@@ -0,0 +1,297 @@
+//! BIN lookup.
+//!
+//! The leading digits of a card number, its BIN (or IIN), identify the card network, the bank
+//! which issued the card, its funding type and the country it was issued in. The BIN table is
+//! loaded from the configured CSV file when the application starts and kept in memory. A card
+//! number is matched against the longest BIN of the table it starts with, so that ranges of a BIN
+//! can be given more specific details than the BIN itself.
+
+use std::collections::HashMap;
+
+use error_stack::{report, IntoReport, ResultExt};
+use masking::PeekInterface;
+use once_cell::sync::OnceCell;
+
+use crate::{
+    configs::settings,
+    core::errors::{self, CustomResult},
+    logger,
+    types::api::{self, enums as api_enums},
+};
+
+/// Shortest and longest BINs accepted in the BIN table
+const MIN_BIN_LENGTH: usize = 4;
+const MAX_BIN_LENGTH: usize = 11;
+
+/// The BIN table, or `None` if it failed to load, so that the file is read only once
+static BIN_TABLE: OnceCell<Option<BinTable>> = OnceCell::new();
+
+#[derive(Debug, Default)]
+pub struct BinTable {
+    entries: HashMap<String, api::CardInfo>,
+    /// Lengths of the BINs present in the table, longest first
+    bin_lengths: Vec<usize>,
+}
+
+impl BinTable {
+    /// Parse a BIN table from CSV, with lines of the form
+    /// `card_iin,card_network,card_issuer,card_funding_type,card_issuing_country`. All fields but
+    /// the BIN may be empty. Blank lines, lines starting with `#` and a header line are skipped.
+    pub fn parse(contents: &str) -> CustomResult<Self, errors::BinLookupError> {
+        let mut entries = HashMap::new();
+
+        for (line_number, line) in contents
+            .lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        {
+            if line.starts_with("card_iin,") {
+                continue;
+            }
+            let (bin, card_info) = parse_record(line)
+                .ok_or_else(|| report!(errors::BinLookupError::InvalidRecord(line_number)))?;
+            entries.insert(bin, card_info);
+        }
+
+        let mut bin_lengths: Vec<usize> = entries.keys().map(String::len).collect();
+        bin_lengths.sort_unstable_by(|a, b| b.cmp(a));
+        bin_lengths.dedup();
+
+        Ok(Self {
+            entries,
+            bin_lengths,
+        })
+    }
+
+    /// Find the details of the longest BIN the card number starts with.
+    pub fn lookup(&self, card_number: &str) -> Option<&api::CardInfo> {
+        self.bin_lengths
+            .iter()
+            .filter_map(|length| card_number.get(..*length))
+            .find_map(|bin| self.entries.get(bin))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+async fn read_bin_table(
+    conf: &settings::BinLookup,
+) -> CustomResult<BinTable, errors::BinLookupError> {
+    let file_path = conf.file_path.clone();
+    let contents = tokio::task::spawn_blocking(move || std::fs::read_to_string(file_path))
+        .await
+        .into_report()
+        .change_context(errors::BinLookupError::FileReadFailed)?
+        .into_report()
+        .change_context(errors::BinLookupError::FileReadFailed)
+        .attach_printable_lazy(|| format!("BIN table file: {}", conf.file_path))?;
+    BinTable::parse(&contents)
+}
+
+/// Load the BIN table, once, when the application starts. The card details are informational
+/// only, so failing to load the BIN table is logged and every BIN is then handled as unknown.
+pub async fn load_bin_table(conf: &settings::BinLookup) {
+    if !conf.enabled || BIN_TABLE.get().is_some() {
+        return;
+    }
+
+    let table = match read_bin_table(conf).await {
+        Ok(table) => {
+            logger::info!(entries = table.len(), "Loaded the BIN table");
+            Some(table)
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to load the BIN table");
+            None
+        }
+    };
+    // The table may have been loaded concurrently, with the same contents
+    let _ = BIN_TABLE.set(table);
+}
+
+/// Look up the card details of a card number, in the BIN table loaded when the application
+/// started.
+pub fn get_card_info(conf: &settings::BinLookup, card_number: &str) -> Option<api::CardInfo> {
+    if !conf.enabled {
+        return None;
+    }
+
+    BIN_TABLE
+        .get()
+        .and_then(Option::as_ref)
+        .and_then(|table| table.lookup(card_number))
+        .cloned()
+}
+
+/// Fill in the details of a saved card which are not known from the payment method.
+pub fn enrich_card_detail(conf: &settings::BinLookup, card: &mut api::CardDetailFromLocker) {
+    let card_info = card
+        .card_number
+        .as_ref()
+        .and_then(|card_number| get_card_info(conf, card_number.peek()));
+
+    if let Some(card_info) = card_info {
+        card.scheme = card.scheme.take().or(card_info.card_network);
+        card.card_issuer = card.card_issuer.take().or(card_info.card_issuer);
+        card.card_funding_type = card.card_funding_type.or(card_info.card_funding_type);
+        card.issuer_country = card
+            .issuer_country
+            .take()
+            .or(card_info.card_issuing_country);
+    }
+}
+
+fn parse_record(line: &str) -> Option<(String, api::CardInfo)> {
+    let fields = split_record(line)?;
+    let [bin, network, issuer, funding_type, country] = &fields[..] else {
+        return None;
+    };
+
+    if !(MIN_BIN_LENGTH..=MAX_BIN_LENGTH).contains(&bin.len())
+        || !bin.chars().all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let card_funding_type = match funding_type.as_str() {
+        "" => None,
+        funding_type => Some(
+            funding_type
+                .to_lowercase()
+                .parse::<api_enums::CardFundingType>()
+                .ok()?,
+        ),
+    };
+    let card_issuing_country = match country.as_str() {
+        "" => None,
+        country if country.len() == 2 && country.chars().all(|c| c.is_ascii_alphabetic()) => {
+            Some(country.to_uppercase())
+        }
+        _ => return None,
+    };
+
+    Some((
+        bin.to_owned(),
+        api::CardInfo {
+            card_network: non_empty(network),
+            card_issuer: non_empty(issuer),
+            card_funding_type,
+            card_issuing_country,
+        },
+    ))
+}
+
+/// Split a CSV record into its fields. Fields may be enclosed in double quotes, to contain commas,
+/// with double quotes in quoted fields escaped by doubling them.
+fn split_record(line: &str) -> Option<Vec<String>> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field).trim().to_owned()),
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        return None;
+    }
+    fields.push(field.trim().to_owned());
+
+    Some(fields)
+}
+
+fn non_empty(value: &str) -> Option<String> {
+    (!value.is_empty()).then(|| value.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    const BIN_TABLE_CSV: &str = r#"card_iin,card_network,card_issuer,card_funding_type,card_issuing_country
+# Test BINs
+424242,Visa,"Stripe Payments UK, Ltd",credit,gb
+40000566,Visa,,debit,US
+555555,Mastercard,"The ""Test"" Bank",Prepaid,
+"#;
+
+    #[test]
+    fn test_parse_bin_table() {
+        let table = BinTable::parse(BIN_TABLE_CSV).unwrap();
+        assert_eq!(table.len(), 3);
+        assert_eq!(
+            table.lookup("4242424242424242"),
+            Some(&api::CardInfo {
+                card_network: Some("Visa".to_string()),
+                card_issuer: Some("Stripe Payments UK, Ltd".to_string()),
+                card_funding_type: Some(api_enums::CardFundingType::Credit),
+                card_issuing_country: Some("GB".to_string()),
+            })
+        );
+        assert_eq!(
+            table.lookup("5555555555554444"),
+            Some(&api::CardInfo {
+                card_network: Some("Mastercard".to_string()),
+                card_issuer: Some(r#"The "Test" Bank"#.to_string()),
+                card_funding_type: Some(api_enums::CardFundingType::Prepaid),
+                card_issuing_country: None,
+            })
+        );
+    }
+
+    #[test]
+    fn test_lookup_longest_bin() {
+        let table = BinTable::parse("400005,Visa,,credit,US\n40000566,Visa,,debit,US\n").unwrap();
+        let funding_type = |card_number| {
+            table
+                .lookup(card_number)
+                .and_then(|card_info| card_info.card_funding_type)
+        };
+
+        assert_eq!(
+            funding_type("4000056655665556"),
+            Some(api_enums::CardFundingType::Debit)
+        );
+        assert_eq!(
+            funding_type("4000051234567890"),
+            Some(api_enums::CardFundingType::Credit)
+        );
+        assert_eq!(table.lookup("4111111111111111"), None);
+    }
+
+    #[test]
+    fn test_invalid_records() {
+        for (contents, line_number) in [
+            ("424242,Visa,,credit", 1),
+            ("card_iin,card_network,card_issuer,card_funding_type,card_issuing_country\n42a242,Visa,,credit,US", 2),
+            ("424,Visa,,credit,US", 1),
+            ("424242,Visa,,charge,US", 1),
+            ("424242,Visa,,credit,USA", 1),
+            ("\n424242,Visa,\"Unterminated,credit,US", 2),
+        ] {
+            let error = BinTable::parse(contents).unwrap_err();
+            assert!(
+                matches!(
+                    error.current_context(),
+                    errors::BinLookupError::InvalidRecord(line) if *line == line_number
+                ),
+                "{contents}"
+            );
+        }
+    }
+}
@@ -0,0 +1,347 @@
+//! Built-in card vault.
+//!
+//! Cards are stored with envelope encryption: card data is encrypted with a data key specific to
+//! the merchant, and data keys are stored encrypted with the vault's key encryption key (KEK),
+//! which is read from a local key file or decrypted using KMS. Encrypted cards and data keys are
+//! stored in the card vault database, separate from the main database.
+//!
+//! The vault exposes the same contract as the external locker, so that callers only need to pick
+//! the backend based on configuration.
+
+use common_utils::{
+    consts,
+    crypto::{DecodeMessage, EncodeMessage, GcmAes256},
+    generate_id,
+};
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+use tokio::sync::OnceCell;
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, CustomResult},
+        payment_methods::{cards, transformers as payment_methods},
+    },
+    pii,
+    routes::AppState,
+    types::{api, storage},
+    utils::{self, ByteSliceExt},
+};
+
+/// Length of the keys used for encryption, in bytes
+const KEY_LENGTH: usize = 32;
+
+static KEY_ENCRYPTION_KEY: OnceCell<Secret<Vec<u8>>> = OnceCell::const_new();
+
+/// Card data, as stored encrypted in the vault.
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredCard {
+    card_number: Secret<String, pii::CardNumber>,
+    card_exp_month: Secret<String>,
+    card_exp_year: Secret<String>,
+    name_on_card: Option<Secret<String>>,
+}
+
+#[instrument(skip_all)]
+pub async fn add_card(
+    state: &AppState,
+    card: &api::CardDetail,
+    customer_id: &str,
+    merchant_id: &str,
+) -> CustomResult<payment_methods::AddCardResponse, errors::VaultError> {
+    let db = &*state.store;
+    let data_key = get_or_create_data_key(state, merchant_id)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?;
+    let card_fingerprint = fingerprint(state, merchant_id, card.card_number.peek())?;
+    let stored_card = card_from_request(card);
+    let encrypted_card = encrypt_card(&data_key, &stored_card)?;
+
+    // The customer has already saved this card: replace the stored card data, so that the vault
+    // keeps the details the card was last saved with, such as a new expiry
+    let existing_card = db
+        .find_vault_card_by_fingerprint_optional(merchant_id, customer_id, &card_fingerprint)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?;
+    if let Some(existing_card) = existing_card {
+        let vault_card = db
+            .update_vault_card_by_merchant_id_card_id(
+                merchant_id,
+                &existing_card.card_id,
+                storage::VaultCardUpdate {
+                    card_fingerprint,
+                    encrypted_card,
+                },
+            )
+            .await
+            .change_context(errors::VaultError::SaveCardFailed)?;
+        return Ok(mk_add_card_response(vault_card, stored_card, true));
+    }
+
+    let vault_card = db
+        .insert_vault_card(storage::VaultCardNew {
+            card_id: generate_id(consts::ID_LENGTH, "card"),
+            merchant_id: merchant_id.to_string(),
+            customer_id: customer_id.to_string(),
+            card_fingerprint,
+            encrypted_card,
+            created_at: common_utils::date_time::now(),
+        })
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?;
+
+    Ok(mk_add_card_response(vault_card, stored_card, false))
+}
+
+#[instrument(skip_all)]
+pub async fn get_card(
+    state: &AppState,
+    merchant_id: &str,
+    card_id: &str,
+) -> CustomResult<payment_methods::GetCardResponse, errors::VaultError> {
+    let vault_card = state
+        .store
+        .find_vault_card_by_merchant_id_card_id(merchant_id, card_id)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)?;
+    let data_key = get_or_create_data_key(state, merchant_id)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)?;
+
+    let stored_card: StoredCard = decrypt(data_key.peek(), &vault_card.encrypted_card)
+        .change_context(errors::VaultError::FetchCardFailed)?
+        .parse_struct("StoredCard")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+    Ok(payment_methods::GetCardResponse {
+        card: mk_add_card_response(vault_card, stored_card, false),
+    })
+}
+
+/// Replace the card data stored under `card_id`, keeping the card ID unchanged.
+#[instrument(skip_all)]
+pub async fn update_card(
+    state: &AppState,
+    card: &api::CardDetail,
+    merchant_id: &str,
+    card_id: &str,
+) -> CustomResult<payment_methods::AddCardResponse, errors::VaultError> {
+    let data_key = get_or_create_data_key(state, merchant_id)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?;
+    let card_fingerprint = fingerprint(state, merchant_id, card.card_number.peek())?;
+    let stored_card = card_from_request(card);
+    let encrypted_card = encrypt_card(&data_key, &stored_card)?;
+
+    let vault_card = state
+        .store
+        .update_vault_card_by_merchant_id_card_id(
+            merchant_id,
+            card_id,
+            storage::VaultCardUpdate {
+                card_fingerprint,
+                encrypted_card,
+            },
+        )
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?;
+
+    Ok(mk_add_card_response(vault_card, stored_card, false))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_card(
+    state: &AppState,
+    merchant_id: &str,
+    card_id: &str,
+) -> CustomResult<payment_methods::DeleteCardResponse, errors::VaultError> {
+    let vault_card = state
+        .store
+        .delete_vault_card_by_merchant_id_card_id(merchant_id, card_id)
+        .await
+        .change_context(errors::VaultError::DeleteCardFailed)?;
+
+    Ok(payment_methods::DeleteCardResponse {
+        card_id: Some(vault_card.card_id.clone()),
+        external_id: Some(vault_card.card_id),
+        card_isin: None,
+        status: "SUCCESS".to_string(),
+    })
+}
+
+fn card_from_request(card: &api::CardDetail) -> StoredCard {
+    StoredCard {
+        card_number: card.card_number.clone(),
+        card_exp_month: card.card_exp_month.clone(),
+        card_exp_year: card.card_exp_year.clone(),
+        name_on_card: card.card_holder_name.clone(),
+    }
+}
+
+fn encrypt_card(
+    data_key: &Secret<Vec<u8>>,
+    card: &StoredCard,
+) -> CustomResult<Vec<u8>, errors::VaultError> {
+    let encoded_card = utils::Encode::<StoredCard>::encode_to_vec(card)
+        .change_context(errors::VaultError::RequestEncodingFailed)?;
+    encrypt(data_key.peek(), &encoded_card).change_context(errors::VaultError::SaveCardFailed)
+}
+
+fn mk_add_card_response(
+    vault_card: storage::VaultCard,
+    card: StoredCard,
+    duplicate: bool,
+) -> payment_methods::AddCardResponse {
+    payment_methods::AddCardResponse {
+        card_id: vault_card.card_id.clone(),
+        external_id: vault_card.card_id,
+        card_fingerprint: vault_card.card_fingerprint.clone().into(),
+        card_global_fingerprint: vault_card.card_fingerprint.into(),
+        merchant_id: Some(vault_card.merchant_id),
+        card_number: Some(card.card_number),
+        card_exp_year: Some(card.card_exp_year),
+        card_exp_month: Some(card.card_exp_month),
+        name_on_card: card.name_on_card,
+        nickname: None,
+        customer_id: Some(vault_card.customer_id),
+        duplicate: Some(duplicate),
+    }
+}
+
+/// Fetch and decrypt the data key of the merchant, generating one if the merchant does not have
+/// one yet.
+async fn get_or_create_data_key(
+    state: &AppState,
+    merchant_id: &str,
+) -> CustomResult<Secret<Vec<u8>>, errors::EncryptionError> {
+    let db = &*state.store;
+    let kek = get_key_encryption_key(&state.conf).await?;
+
+    let data_key = match db
+        .find_vault_data_key_optional(merchant_id)
+        .await
+        .change_context(errors::EncryptionError)?
+    {
+        Some(data_key) => data_key,
+        None => {
+            let key: [u8; KEY_LENGTH] = rand::random();
+            let new_data_key = storage::VaultDataKeyNew {
+                merchant_id: merchant_id.to_string(),
+                encrypted_key: encrypt(kek.peek(), &key)?,
+                created_at: common_utils::date_time::now(),
+            };
+            match db.insert_vault_data_key(new_data_key).await {
+                Ok(data_key) => data_key,
+                // Another request created the data key concurrently
+                Err(error) if error.current_context().is_db_unique_violation() => db
+                    .find_vault_data_key_optional(merchant_id)
+                    .await
+                    .change_context(errors::EncryptionError)?
+                    .ok_or(errors::EncryptionError)
+                    .into_report()
+                    .attach_printable("Data key not found after a unique violation")?,
+                Err(error) => Err(error.change_context(errors::EncryptionError))?,
+            }
+        }
+    };
+
+    decrypt(kek.peek(), &data_key.encrypted_key).map(Secret::new)
+}
+
+async fn get_key_encryption_key(
+    conf: &settings::Settings,
+) -> CustomResult<&'static Secret<Vec<u8>>, errors::EncryptionError> {
+    KEY_ENCRYPTION_KEY
+        .get_or_try_init(|| async {
+            let hex_key = read_key_encryption_key(conf).await?;
+            let key = hex::decode(hex_key.trim())
+                .into_report()
+                .change_context(errors::EncryptionError)
+                .attach_printable("Key encryption key is not a valid hex string")?;
+            if key.len() != KEY_LENGTH {
+                Err(report!(errors::EncryptionError)
+                    .attach_printable("Key encryption key must be 32 bytes long"))?
+            }
+            Ok(Secret::new(key))
+        })
+        .await
+}
+
+async fn read_key_encryption_key(
+    conf: &settings::Settings,
+) -> CustomResult<String, errors::EncryptionError> {
+    #[cfg(feature = "kms")]
+    if !conf.card_vault.kms_encrypted_kek.is_empty() {
+        return crate::services::encryption::KeyHandler::get_kms_decrypted_key(
+            &conf.jwekey,
+            conf.card_vault.kms_encrypted_kek.clone(),
+        )
+        .await;
+    }
+
+    std::fs::read_to_string(&conf.card_vault.kek_file)
+        .into_report()
+        .change_context(errors::EncryptionError)
+        .attach_printable("Failed to read the key encryption key file")
+}
+
+/// Merchant scoped fingerprint of a card number, used to detect duplicate cards. The fingerprint
+/// is keyed with the configured fingerprint secret rather than the data key, so that the key used
+/// to encrypt cards is never used for anything else.
+fn fingerprint(
+    state: &AppState,
+    merchant_id: &str,
+    card_number: &str,
+) -> CustomResult<String, errors::VaultError> {
+    cards::generate_card_fingerprint(
+        &state.conf.secrets.card_fingerprint_secret,
+        merchant_id,
+        card_number,
+    )
+    .change_context(errors::VaultError::SaveCardFailed)
+}
+
+/// Encrypt the data with a random nonce. The output is the nonce, followed by the ciphertext and
+/// the authentication tag.
+pub(crate) fn encrypt(key: &[u8], data: &[u8]) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+    let nonce: [u8; GcmAes256::NONCE_LENGTH] = rand::random();
+    let (mut ciphertext, mut tag) = GcmAes256::new(nonce.to_vec())
+        .encode_message(key, data)
+        .change_context(errors::EncryptionError)?;
+
+    let mut output = nonce.to_vec();
+    output.append(&mut ciphertext);
+    output.append(&mut tag);
+    Ok(output)
+}
+
+pub(crate) fn decrypt(key: &[u8], data: &[u8]) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+    if data.len() < GcmAes256::NONCE_LENGTH {
+        Err(report!(errors::EncryptionError).attach_printable("Encrypted data is too short"))?
+    }
+    let (nonce, ciphertext) = data.split_at(GcmAes256::NONCE_LENGTH);
+
+    GcmAes256::new(nonce.to_vec())
+        .decode_message(key, ciphertext)
+        .change_context(errors::EncryptionError)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let key: [u8; KEY_LENGTH] = rand::random();
+        let encrypted = encrypt(&key, b"4111111111111111").unwrap();
+        assert_ne!(&encrypted[GcmAes256::NONCE_LENGTH..], b"4111111111111111");
+        assert_eq!(decrypt(&key, &encrypted).unwrap(), b"4111111111111111");
+
+        let other_key: [u8; KEY_LENGTH] = rand::random();
+        assert!(decrypt(&other_key, &encrypted).is_err());
+    }
+}
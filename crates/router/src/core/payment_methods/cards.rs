@@ -1,26 +1,46 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, str::FromStr};
 
-use common_utils::{consts, ext_traits::AsyncExt, generate_id};
+use common_utils::{
+    consts,
+    crypto::{HmacSha256, SignMessage},
+    ext_traits::AsyncExt,
+    generate_id,
+};
 use error_stack::{report, ResultExt};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     core::{
+        capabilities,
         errors::{self, StorageErrorExt},
-        payment_methods::{transformers as payment_methods, vault},
+        payment_methods::{
+            bin_lookup, card_vault, network_tokenization, payment_token,
+            transformers as payment_methods, vault,
+        },
         payments::helpers,
     },
     db,
     pii::prelude::*,
     routes, services,
     types::{
-        api::{self, CreatePaymentMethodExt},
+        api::{self, enums as api_enums, CreatePaymentMethodExt},
         storage::{self, enums},
         transformers::ForeignInto,
     },
     utils::{self, BytesExt, ConnectorResponseExt, OptionExt},
 };
 
+/// Card details stored on the payment method itself, so that saved cards can be deduplicated and
+/// found by expiry without fetching them from the locker.
+#[derive(Clone, Debug)]
+pub struct SavedCardDetails {
+    pub card_fingerprint: String,
+    pub card_exp_month: String,
+    pub card_exp_year: String,
+    /// Details of the card looked up by its BIN
+    pub card_info: Option<api::CardInfo>,
+}
+
 #[instrument(skip_all)]
 pub async fn create_payment_method(
     db: &dyn db::StorageInterface,
@@ -28,16 +48,38 @@ pub async fn create_payment_method(
     customer_id: &str,
     payment_method_id: &str,
     merchant_id: &str,
+    card_details: Option<SavedCardDetails>,
 ) -> errors::CustomResult<storage::PaymentMethod, errors::StorageError> {
+    let (card_fingerprint, card_exp_month, card_exp_year, card_info) = match card_details {
+        Some(card) => (
+            Some(card.card_fingerprint),
+            Some(card.card_exp_month),
+            Some(card.card_exp_year),
+            card.card_info.unwrap_or_default(),
+        ),
+        None => (None, None, None, api::CardInfo::default()),
+    };
     let response = db
         .insert_payment_method(storage::PaymentMethodNew {
             customer_id: customer_id.to_string(),
             merchant_id: merchant_id.to_string(),
             payment_method_id: payment_method_id.to_string(),
             payment_method: req.payment_method.foreign_into(),
-            payment_method_type: req.payment_method_type.map(ForeignInto::foreign_into),
+            payment_method_type: req
+                .bank_account
+                .as_ref()
+                .map(api::BankDebitData::get_bank_debit_type)
+                .or(req.payment_method_type)
+                .map(ForeignInto::foreign_into),
             payment_method_issuer: req.payment_method_issuer.clone(),
             metadata: req.metadata.clone(),
+            card_fingerprint,
+            card_exp_month,
+            card_exp_year,
+            scheme: card_info.card_network,
+            issuer_name: card_info.card_issuer,
+            issuer_country: card_info.card_issuing_country,
+            bank_account_last4: req.bank_account.as_ref().map(api::BankDebitData::get_last4),
             ..storage::PaymentMethodNew::default()
         })
         .await?;
@@ -67,6 +109,7 @@ pub async fn add_payment_method(
                 &customer_id,
                 &payment_method_id,
                 merchant_id,
+                None,
             )
             .await
             .map_err(|error| {
@@ -77,9 +120,18 @@ pub async fn add_payment_method(
                 customer_id: Some(customer_id),
                 payment_method_id: payment_method_id.to_string(),
                 payment_method: req.payment_method,
-                payment_method_type: req.payment_method_type,
+                payment_method_type: req
+                    .bank_account
+                    .as_ref()
+                    .map(api::BankDebitData::get_bank_debit_type)
+                    .or(req.payment_method_type),
                 payment_method_issuer: req.payment_method_issuer,
                 card: None,
+                card_fingerprint: None,
+                bank_account: req
+                    .bank_account
+                    .as_ref()
+                    .map(api::BankAccountResponse::from),
                 metadata: req.metadata,
                 created: Some(common_utils::date_time::now()),
                 payment_method_issuer_code: req.payment_method_issuer_code,
@@ -120,6 +172,7 @@ pub async fn update_customer_payment_method(
         payment_method_issuer: pm.payment_method_issuer,
         payment_method_issuer_code: pm.payment_method_issuer_code.map(|x| x.foreign_into()),
         card: req.card,
+        bank_account: req.bank_account,
         metadata: req.metadata,
         customer_id: Some(pm.customer_id),
     };
@@ -137,21 +190,60 @@ pub async fn add_card(
     let locker = &state.conf.locker;
     let db = &*state.store;
     let merchant_id = &merchant_account.merchant_id;
-    let locker_id = merchant_account
-        .locker_id
-        .to_owned()
-        .get_required_value("locker_id")
-        .change_context(errors::VaultError::SaveCardFailed)?;
 
-    let request = payment_methods::mk_add_card_request(
-        locker,
-        &card,
-        &customer_id,
-        &req,
-        &locker_id,
+    let card_fingerprint = generate_card_fingerprint(
+        &state.conf.secrets.card_fingerprint_secret,
         merchant_id,
-    )?;
-    let response = if !locker.mock_locker {
+        card.card_number.peek(),
+    )
+    .change_context(errors::VaultError::SaveCardFailed)?;
+    let (card_exp_month, card_exp_year) =
+        normalize_card_expiry(card.card_exp_month.peek(), card.card_exp_year.peek());
+    let card_info = bin_lookup::get_card_info(&state.conf.bin_lookup, card.card_number.peek());
+    let saved_card = SavedCardDetails {
+        card_fingerprint: card_fingerprint.clone(),
+        card_exp_month,
+        card_exp_year,
+        card_info: card_info.clone(),
+    };
+
+    // The customer has already saved this card, return the existing payment method
+    if let Some(payment_method) = db
+        .find_payment_method_by_card_fingerprint_optional(
+            merchant_id,
+            &customer_id,
+            &card_fingerprint,
+        )
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?
+    {
+        return mk_duplicate_card_response(
+            state,
+            merchant_account,
+            payment_method,
+            card,
+            req,
+            card_info,
+        )
+        .await;
+    }
+
+    let response = if state.conf.card_vault.enabled {
+        card_vault::add_card(state, &card, &customer_id, merchant_id).await?
+    } else if !locker.mock_locker {
+        let locker_id = merchant_account
+            .locker_id
+            .to_owned()
+            .get_required_value("locker_id")
+            .change_context(errors::VaultError::SaveCardFailed)?;
+        let request = payment_methods::mk_add_card_request(
+            locker,
+            &card,
+            &customer_id,
+            &req,
+            &locker_id,
+            merchant_id,
+        )?;
         let response = services::call_connector_api(state, request)
             .await
             .change_context(errors::VaultError::SaveCardFailed)?;
@@ -171,29 +263,185 @@ pub async fn add_card(
         mock_add_card(db, &card_id, &card, None, None, Some(&customer_id)).await?
     };
 
-    if let Some(false) = response.duplicate {
-        create_payment_method(db, &req, &customer_id, &response.card_id, merchant_id)
-            .await
-            .change_context(errors::VaultError::PaymentMethodCreationFailed)?;
+    let new_payment_method = if let Some(false) = response.duplicate {
+        match create_payment_method(
+            db,
+            &req,
+            &customer_id,
+            &response.card_id,
+            merchant_id,
+            Some(saved_card),
+        )
+        .await
+        {
+            Ok(payment_method) => Some(payment_method),
+            // Another request saved the same card for the customer concurrently, the unique
+            // index on the card fingerprint lets only one of them create the payment method
+            Err(error) if error.current_context().is_db_unique_violation() => {
+                if let Err(error) = delete_card(state, merchant_id, &response.card_id).await {
+                    logger::error!(?error, "Failed to delete the card saved concurrently");
+                }
+                let payment_method = db
+                    .find_payment_method_by_card_fingerprint_optional(
+                        merchant_id,
+                        &customer_id,
+                        &card_fingerprint,
+                    )
+                    .await
+                    .change_context(errors::VaultError::PaymentMethodCreationFailed)?
+                    .get_required_value("payment_method")
+                    .change_context(errors::VaultError::PaymentMethodCreationFailed)?;
+                return mk_duplicate_card_response(
+                    state,
+                    merchant_account,
+                    payment_method,
+                    card,
+                    req,
+                    card_info,
+                )
+                .await;
+            }
+            Err(error) => {
+                Err(error.change_context(errors::VaultError::PaymentMethodCreationFailed))?
+            }
+        }
     } else {
         match db.find_payment_method(&response.card_id).await {
-            Ok(_) => (),
+            Ok(_) => None,
             Err(err) => {
                 if err.current_context().is_db_not_found() {
-                    create_payment_method(db, &req, &customer_id, &response.card_id, merchant_id)
+                    Some(
+                        create_payment_method(
+                            db,
+                            &req,
+                            &customer_id,
+                            &response.card_id,
+                            merchant_id,
+                            Some(saved_card),
+                        )
                         .await
-                        .change_context(errors::VaultError::PaymentMethodCreationFailed)?;
+                        .change_context(errors::VaultError::PaymentMethodCreationFailed)?,
+                    )
                 } else {
-                    Err(errors::VaultError::PaymentMethodCreationFailed)?;
+                    Err(errors::VaultError::PaymentMethodCreationFailed)?
                 }
             }
         }
+    };
+
+    if let Some(payment_method) = new_payment_method {
+        if state.conf.network_tokenization.enabled {
+            network_tokenization::provision_network_token(state, payment_method, &card).await;
+        }
     }
-    let payment_method_resp =
-        payment_methods::mk_add_card_response(card, response, req, merchant_id);
+
+    let payment_method_resp = payment_methods::mk_add_card_response(
+        card,
+        response,
+        req,
+        merchant_id,
+        Some(card_fingerprint),
+        card_info,
+    );
     Ok(payment_method_resp)
 }
 
+/// Response for a card the customer has already saved, returning the existing payment method.
+/// When the locker supports updating cards, the saved card is replaced with the submitted details,
+/// such as a new expiry. Otherwise the response carries the details of the saved card, so that it
+/// matches what is stored either way.
+async fn mk_duplicate_card_response(
+    state: &routes::AppState,
+    merchant_account: &storage::MerchantAccount,
+    payment_method: storage::PaymentMethod,
+    mut card: api::CardDetail,
+    req: api::CreatePaymentMethod,
+    card_info: Option<api::CardInfo>,
+) -> errors::CustomResult<api::PaymentMethodResponse, errors::VaultError> {
+    let merchant_id = &merchant_account.merchant_id;
+    let card_fingerprint = payment_method.card_fingerprint.clone();
+
+    let mut response = if locker_supports_card_updates(state) {
+        let response = update_card_in_locker(
+            state,
+            &card,
+            merchant_id,
+            &payment_method.customer_id,
+            &payment_method.payment_method_id,
+        )
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?;
+
+        let (card_exp_month, card_exp_year) =
+            normalize_card_expiry(card.card_exp_month.peek(), card.card_exp_year.peek());
+        state
+            .store
+            .update_payment_method(
+                payment_method,
+                storage::PaymentMethodUpdate::CardUpdate {
+                    card_fingerprint: None,
+                    card_exp_month: Some(card_exp_month),
+                    card_exp_year: Some(card_exp_year),
+                },
+            )
+            .await
+            .change_context(errors::VaultError::SaveCardFailed)?;
+        response
+    } else {
+        let response =
+            get_card_from_legacy_locker(state, merchant_account, &payment_method.payment_method_id)
+                .await
+                .change_context(errors::VaultError::FetchCardFailed)?
+                .card;
+        if let (Some(card_exp_month), Some(card_exp_year)) =
+            (&response.card_exp_month, &response.card_exp_year)
+        {
+            card.card_exp_month = card_exp_month.clone();
+            card.card_exp_year = card_exp_year.clone();
+        }
+        response
+    };
+    response.duplicate = Some(true);
+    Ok(payment_methods::mk_add_card_response(
+        card,
+        response,
+        req,
+        merchant_id,
+        card_fingerprint,
+        card_info,
+    ))
+}
+
+/// Compute a fingerprint of the card number, scoped to the merchant. The fingerprint is an HMAC
+/// of the card number keyed with a key derived from the configured secret and the merchant ID, so
+/// that fingerprints of the same card cannot be correlated across merchants.
+pub fn generate_card_fingerprint(
+    secret: &str,
+    merchant_id: &str,
+    card_number: &str,
+) -> errors::CustomResult<String, errors::EncryptionError> {
+    let merchant_key = HmacSha256
+        .sign_message(secret.as_bytes(), merchant_id.as_bytes())
+        .change_context(errors::EncryptionError)?;
+    HmacSha256
+        .sign_message(&merchant_key, card_number.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::EncryptionError)
+}
+
+/// Normalize a card expiry to a two digit month and a four digit year, the format expiries are
+/// stored and compared in.
+pub fn normalize_card_expiry(card_exp_month: &str, card_exp_year: &str) -> (String, String) {
+    let (month, year) = (card_exp_month.trim(), card_exp_year.trim());
+    let month = format!("{month:0>2}");
+    let year = if year.len() == 2 {
+        format!("20{year}")
+    } else {
+        year.to_string()
+    };
+    (month, year)
+}
+
 #[instrument(skip_all)]
 pub async fn mock_add_card(
     db: &dyn db::StorageInterface,
@@ -290,14 +538,23 @@ pub async fn mock_delete_card<'a>(
 #[instrument(skip_all)]
 pub async fn get_card_from_legacy_locker<'a>(
     state: &'a routes::AppState,
-    locker_id: &'a str,
+    merchant_account: &'a storage::MerchantAccount,
     card_id: &'a str,
 ) -> errors::RouterResult<payment_methods::GetCardResponse> {
     let locker = &state.conf.locker;
-    let request = payment_methods::mk_get_card_request(locker, locker_id, card_id)
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Making get card request failed")?;
-    let get_card_result = if !locker.mock_locker {
+    let get_card_result = if state.conf.card_vault.enabled {
+        card_vault::get_card(state, &merchant_account.merchant_id, card_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while fetching card from card vault")?
+    } else if !locker.mock_locker {
+        let locker_id = merchant_account
+            .locker_id
+            .as_deref()
+            .get_required_value("locker_id")?;
+        let request = payment_methods::mk_get_card_request(locker, locker_id, card_id)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Making get card request failed")?;
         let response = services::call_connector_api(state, request)
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -322,12 +579,17 @@ pub async fn delete_card<'a>(
     card_id: &'a str,
 ) -> errors::RouterResult<payment_methods::DeleteCardResponse> {
     let locker = &state.conf.locker;
-    let request = payment_methods::mk_delete_card_request(&state.conf.locker, merchant_id, card_id)
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Making Delete card request Failed")?;
-
     let card_delete_failure_message = "Failed while deleting card from card_locker";
-    let delete_card_resp = if !locker.mock_locker {
+    let delete_card_resp = if state.conf.card_vault.enabled {
+        card_vault::delete_card(state, merchant_id, card_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while deleting card from card vault")?
+    } else if !locker.mock_locker {
+        let request =
+            payment_methods::mk_delete_card_request(&state.conf.locker, merchant_id, card_id)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Making Delete card request Failed")?;
         services::call_connector_api(state, request)
             .await
             .get_response_inner("DeleteCardResponse")?
@@ -341,11 +603,53 @@ pub async fn delete_card<'a>(
     Ok(delete_card_resp)
 }
 
+/// Whether saved cards can be replaced with [`update_card_in_locker`]. The external locker does
+/// not support updating cards.
+pub fn locker_supports_card_updates(state: &routes::AppState) -> bool {
+    state.conf.card_vault.enabled || state.conf.locker.mock_locker
+}
+
+/// Replace the card saved under `card_id`, such as when the card is reissued. The external locker
+/// does not support updating cards, only the card vault and the locker mock-up do.
+#[instrument(skip_all)]
+pub async fn update_card_in_locker(
+    state: &routes::AppState,
+    card: &api::CardDetail,
+    merchant_id: &str,
+    customer_id: &str,
+    card_id: &str,
+) -> errors::RouterResult<payment_methods::AddCardResponse> {
+    let update_card_failure_message = "Failed while updating card in card_locker";
+    if state.conf.card_vault.enabled {
+        card_vault::update_card(state, card, merchant_id, card_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while updating card in card vault")
+    } else if !state.conf.locker.mock_locker {
+        Err(errors::ApiErrorResponse::NotImplemented {
+            message: errors::api_error_response::NotImplementedMessage::Reason(
+                "Updating cards in the external locker".to_string(),
+            ),
+        })?
+    } else {
+        let db = &*state.store;
+        mock_delete_card(db, card_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(update_card_failure_message)?;
+        mock_add_card(db, card_id, card, None, None, Some(customer_id))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(update_card_failure_message)
+    }
+}
+
 pub async fn list_payment_methods(
-    db: &dyn db::StorageInterface,
+    state: &routes::AppState,
     merchant_account: storage::MerchantAccount,
     mut req: api::ListPaymentMethodRequest,
 ) -> errors::RouterResponse<api::ListPaymentMethodResponse> {
+    let db = &*state.store;
     let payment_intent = helpers::verify_client_secret(
         db,
         merchant_account.storage_scheme,
@@ -383,20 +687,30 @@ pub async fn list_payment_methods(
             error.to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
         })?;
 
+    let card_info = req
+        .card_bin
+        .as_deref()
+        .and_then(|card_bin| bin_lookup::get_card_info(&state.conf.bin_lookup, card_bin));
+
     let mut response: HashSet<api::ListPaymentMethod> = HashSet::new();
     for mca in all_mcas {
         let payment_methods = match mca.payment_methods_enabled {
             Some(pm) => pm,
             None => continue,
         };
+        let connector_capabilities = api_enums::Connector::from_str(&mca.connector_name)
+            .ok()
+            .map(capabilities::get_connector_capabilities);
 
         filter_payment_methods(
             payment_methods,
             &mut req,
             &mut response,
+            connector_capabilities.as_ref(),
             payment_intent.as_ref(),
             payment_attempt.as_ref(),
             address.as_ref(),
+            card_info.as_ref(),
         )
         .await?;
     }
@@ -412,13 +726,16 @@ pub async fn list_payment_methods(
         )))
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn filter_payment_methods(
     payment_methods: Vec<serde_json::Value>,
     req: &mut api::ListPaymentMethodRequest,
     resp: &mut HashSet<api::ListPaymentMethod>,
+    connector_capabilities: Option<&api::ConnectorCapabilities>,
     payment_intent: Option<&storage::PaymentIntent>,
     payment_attempt: Option<&storage::PaymentAttempt>,
     address: Option<&storage::Address>,
+    card_info: Option<&api::CardInfo>,
 ) -> errors::CustomResult<(), errors::ApiErrorResponse> {
     for payment_method in payment_methods.into_iter() {
         if let Ok(payment_method_object) =
@@ -427,6 +744,13 @@ async fn filter_payment_methods(
             if filter_recurring_based(&payment_method_object, req.recurring_enabled)
                 && filter_installment_based(&payment_method_object, req.installment_payment_enabled)
                 && filter_amount_based(&payment_method_object, req.amount)
+                && filter_card_info_based(&payment_method_object, card_info)
+                && filter_connector_capabilities_based(
+                    &payment_method_object,
+                    connector_capabilities,
+                    payment_intent,
+                    address,
+                )
             {
                 let mut payment_method_object = payment_method_object;
 
@@ -520,6 +844,68 @@ fn filter_installment_based(
     })
 }
 
+/// Filter out card payment methods not supporting the network or the funding type of the card.
+/// Cards of an unknown BIN are not filtered on.
+fn filter_card_info_based(
+    payment_method: &api::ListPaymentMethod,
+    card_info: Option<&api::CardInfo>,
+) -> bool {
+    let card_info = match card_info {
+        Some(card_info) if payment_method.payment_method == api_enums::PaymentMethodType::Card => {
+            card_info
+        }
+        _ => return true,
+    };
+
+    let network_check = match (&payment_method.payment_schemes, &card_info.card_network) {
+        (Some(schemes), Some(network)) => schemes
+            .iter()
+            .any(|scheme| scheme.eq_ignore_ascii_case(network)),
+        _ => true,
+    };
+    let funding_type_check = match (
+        &payment_method.payment_method_types,
+        card_info.card_funding_type,
+    ) {
+        (Some(types), Some(api_enums::CardFundingType::Credit)) => {
+            types.contains(&api_enums::PaymentMethodSubType::Credit)
+        }
+        (Some(types), Some(api_enums::CardFundingType::Debit)) => {
+            types.contains(&api_enums::PaymentMethodSubType::Debit)
+        }
+        _ => true,
+    };
+
+    network_check && funding_type_check
+}
+
+/// Filter out payment methods which the connector cannot process for the payment, even though they
+/// are enabled on the merchant connector account.
+fn filter_connector_capabilities_based(
+    payment_method: &api::ListPaymentMethod,
+    connector_capabilities: Option<&api::ConnectorCapabilities>,
+    payment_intent: Option<&storage::PaymentIntent>,
+    address: Option<&storage::Address>,
+) -> bool {
+    let capabilities = match connector_capabilities {
+        Some(capabilities) => capabilities,
+        None => return true,
+    };
+
+    let currency_check = payment_intent
+        .and_then(|payment_intent| payment_intent.currency)
+        .map_or(true, |currency| {
+            capabilities.supports_currency(currency.foreign_into())
+        });
+    let country_check = address
+        .and_then(|address| address.country.as_deref())
+        .map_or(true, |country| capabilities.supports_country(country));
+
+    capabilities.supports_payment_method(payment_method.payment_method)
+        && currency_check
+        && country_check
+}
+
 async fn filter_payment_country_based(
     pm: &api::ListPaymentMethod,
     address: Option<&storage::Address>,
@@ -565,6 +951,24 @@ async fn filter_payment_mandate_based(
     Ok(recurring_filter)
 }
 
+/// Order the saved payment methods of a customer with the default payment method first, followed
+/// by the most recently used ones, and the most recently saved ones among those never used.
+fn sort_customer_payment_methods(
+    mut payment_methods: Vec<storage::PaymentMethod>,
+    default_payment_method_id: Option<&str>,
+) -> Vec<storage::PaymentMethod> {
+    payment_methods.sort_by(|a, b| {
+        let is_default = |pm: &storage::PaymentMethod| {
+            default_payment_method_id == Some(pm.payment_method_id.as_str())
+        };
+        is_default(b)
+            .cmp(&is_default(a))
+            .then_with(|| b.last_used_at.cmp(&a.last_used_at))
+            .then_with(|| b.created_at.cmp(&a.created_at))
+    });
+    payment_methods
+}
+
 pub async fn list_customer_payment_method(
     state: &routes::AppState,
     merchant_account: storage::MerchantAccount,
@@ -609,25 +1013,37 @@ pub async fn list_customer_payment_method(
             errors::ApiErrorResponse::PaymentMethodNotFound
         ));
     }
+    let default_payment_method_id = db
+        .find_customer_optional_by_customer_id_merchant_id(
+            customer_id,
+            &merchant_account.merchant_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching the customer")?
+        .and_then(|customer| customer.default_payment_method_id);
+    let resp = sort_customer_payment_methods(resp, default_payment_method_id.as_deref());
+
     let mut vec = Vec::new();
     for pm in resp.into_iter() {
         let payment_token = generate_id(consts::ID_LENGTH, "token");
+        let default_payment_method =
+            default_payment_method_id.as_deref() == Some(pm.payment_method_id.as_str());
         let card = if pm.payment_method == enums::PaymentMethodType::Card {
-            let locker_id = merchant_account
-                .locker_id
-                .to_owned()
-                .get_required_value("locker_id")?;
-            Some(get_lookup_key_from_locker(state, &payment_token, &pm, &locker_id).await?)
+            Some(get_lookup_key_from_locker(state, &payment_token, &pm, &merchant_account).await?)
         } else {
             None
         };
+        let bank_account = get_bank_account_details(&pm);
         //Need validation for enabled payment method ,querying MCA
         let pma = api::CustomerPaymentMethod {
             payment_token: payment_token.to_string(),
+            payment_method_id: pm.payment_method_id,
             customer_id: pm.customer_id,
             payment_method: pm.payment_method.foreign_into(),
             payment_method_type: pm.payment_method_type.map(ForeignInto::foreign_into),
             payment_method_issuer: pm.payment_method_issuer,
+            bank_account,
             card,
             metadata: pm.metadata,
             payment_method_issuer_code: pm
@@ -639,6 +1055,9 @@ pub async fn list_customer_payment_method(
                 api_models::payment_methods::PaymentExperience::RedirectToUrl,
             ]),
             created: Some(pm.created_at),
+            default_payment_method,
+            last_used_at: pm.last_used_at,
+            usage_count: pm.usage_count,
         };
         vec.push(pma);
     }
@@ -655,17 +1074,19 @@ pub async fn get_lookup_key_from_locker(
     state: &routes::AppState,
     payment_token: &str,
     pm: &storage::PaymentMethod,
-    locker_id: &str,
+    merchant_account: &storage::MerchantAccount,
 ) -> errors::RouterResult<api::CardDetailFromLocker> {
     let get_card_resp =
-        get_card_from_legacy_locker(state, locker_id, pm.payment_method_id.as_str()).await?;
-    let card_detail = payment_methods::get_card_detail(pm, get_card_resp.card)
+        get_card_from_legacy_locker(state, merchant_account, pm.payment_method_id.as_str()).await?;
+    let mut card_detail = payment_methods::get_card_detail(pm, get_card_resp.card)
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Get Card Details Failed")?;
+    bin_lookup::enrich_card_detail(&state.conf.bin_lookup, &mut card_detail);
     let card = card_detail.clone();
     let resp =
         BasiliskCardSupport::create_payment_method_data_in_locker(state, payment_token, card, pm)
             .await?;
+    payment_token::register_payment_token(state, payment_token).await?;
     Ok(resp)
 }
 
@@ -712,7 +1133,7 @@ impl BasiliskCardSupport {
         .attach_printable("Error getting Value1 for locker")?;
         let value2 = payment_methods::mk_card_value2(
             None,
-            None,
+            pm.card_fingerprint.clone(),
             None,
             Some(pm.customer_id.to_string()),
             Some(pm.payment_method_id.to_string()),
@@ -854,39 +1275,58 @@ pub async fn retrieve_payment_method(
             error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
         })?;
     let card = if pm.payment_method == enums::PaymentMethodType::Card {
-        let locker_id = merchant_account.locker_id.get_required_value("locker_id")?;
         let get_card_resp =
-            get_card_from_legacy_locker(state, &locker_id, &pm.payment_method_id).await?;
-        let card_detail = payment_methods::get_card_detail(&pm, get_card_resp.card)
+            get_card_from_legacy_locker(state, &merchant_account, &pm.payment_method_id).await?;
+        let mut card_detail = payment_methods::get_card_detail(&pm, get_card_resp.card)
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed while getting card details from locker")?;
+        bin_lookup::enrich_card_detail(&state.conf.bin_lookup, &mut card_detail);
         Some(card_detail)
     } else {
         None
     };
     Ok(services::ApplicationResponse::Json(
-        api::PaymentMethodResponse {
-            merchant_id: pm.merchant_id,
-            customer_id: Some(pm.customer_id),
-            payment_method_id: pm.payment_method_id,
-            payment_method: pm.payment_method.foreign_into(),
-            payment_method_type: pm.payment_method_type.map(ForeignInto::foreign_into),
-            payment_method_issuer: pm.payment_method_issuer,
-            card,
-            metadata: pm.metadata,
-            created: Some(pm.created_at),
-            payment_method_issuer_code: pm
-                .payment_method_issuer_code
-                .map(ForeignInto::foreign_into),
-            recurring_enabled: false,           //[#219]
-            installment_payment_enabled: false, //[#219]
-            payment_experience: Some(vec![
-                api_models::payment_methods::PaymentExperience::RedirectToUrl,
-            ]), //[#219],
-        },
+        mk_payment_method_response(pm, card),
     ))
 }
 
+/// Details of the bank account saved as the payment method, for bank debit payment methods.
+fn get_bank_account_details(pm: &storage::PaymentMethod) -> Option<api::BankAccountResponse> {
+    let bank_debit_type: api_enums::PaymentMethodSubType = pm.payment_method_type?.foreign_into();
+    pm.bank_account_last4
+        .clone()
+        .map(|last4| api::BankAccountResponse {
+            bank_debit_type,
+            last4,
+        })
+}
+
+pub fn mk_payment_method_response(
+    pm: storage::PaymentMethod,
+    card: Option<api::CardDetailFromLocker>,
+) -> api::PaymentMethodResponse {
+    let bank_account = get_bank_account_details(&pm);
+    api::PaymentMethodResponse {
+        merchant_id: pm.merchant_id,
+        customer_id: Some(pm.customer_id),
+        payment_method_id: pm.payment_method_id,
+        payment_method: pm.payment_method.foreign_into(),
+        payment_method_type: pm.payment_method_type.map(ForeignInto::foreign_into),
+        payment_method_issuer: pm.payment_method_issuer,
+        bank_account,
+        card,
+        card_fingerprint: pm.card_fingerprint,
+        metadata: pm.metadata,
+        created: Some(pm.created_at),
+        payment_method_issuer_code: pm.payment_method_issuer_code.map(ForeignInto::foreign_into),
+        recurring_enabled: false,           //[#219]
+        installment_payment_enabled: false, //[#219]
+        payment_experience: Some(vec![
+            api_models::payment_methods::PaymentExperience::RedirectToUrl,
+        ]), //[#219],
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn delete_payment_method(
     state: &routes::AppState,
@@ -909,7 +1349,33 @@ pub async fn delete_payment_method(
             error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
         })?;
 
+    let customer = state
+        .store
+        .find_customer_optional_by_customer_id_merchant_id(&pm.customer_id, &pm.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching the customer")?;
+    if let Some(customer) = customer.filter(|customer| {
+        customer.default_payment_method_id.as_deref() == Some(pm.payment_method_id.as_str())
+    }) {
+        state
+            .store
+            .update_customer_by_customer_id_merchant_id(
+                customer.customer_id,
+                customer.merchant_id,
+                storage::CustomerUpdate::DefaultPaymentMethodUpdate {
+                    default_payment_method_id: None,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed while unsetting the default payment method of the customer",
+            )?;
+    }
+
     if pm.payment_method == enums::PaymentMethodType::Card {
+        network_tokenization::delete_network_token(state, &pm).await;
         let response = delete_card(state, &pm.merchant_id, &payment_method_id).await?;
         if response.status == "success" {
             print!("Card From locker deleted Successfully")
@@ -925,3 +1391,98 @@ pub async fn delete_payment_method(
         },
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_card_fingerprint_is_merchant_scoped() {
+        let fingerprint_1 =
+            generate_card_fingerprint("secret", "merchant_1", "4111111111111111").unwrap();
+        let fingerprint_2 =
+            generate_card_fingerprint("secret", "merchant_1", "4111111111111111").unwrap();
+        let fingerprint_3 =
+            generate_card_fingerprint("secret", "merchant_2", "4111111111111111").unwrap();
+        let fingerprint_4 =
+            generate_card_fingerprint("other_secret", "merchant_1", "4111111111111111").unwrap();
+        assert_eq!(fingerprint_1, fingerprint_2);
+        assert_ne!(fingerprint_1, fingerprint_3);
+        assert_ne!(fingerprint_1, fingerprint_4);
+    }
+
+    fn mk_payment_method(
+        payment_method_id: &str,
+        created_at: time::PrimitiveDateTime,
+        last_used_at: Option<time::PrimitiveDateTime>,
+    ) -> storage::PaymentMethod {
+        storage::PaymentMethod {
+            id: 0,
+            customer_id: "cus_123".to_string(),
+            merchant_id: "merchant_123".to_string(),
+            payment_method_id: payment_method_id.to_string(),
+            accepted_currency: None,
+            scheme: None,
+            token: None,
+            cardholder_name: None,
+            issuer_name: None,
+            issuer_country: None,
+            payer_country: None,
+            is_stored: None,
+            swift_code: None,
+            direct_debit_token: None,
+            created_at,
+            last_modified: created_at,
+            payment_method: enums::PaymentMethodType::Card,
+            payment_method_type: None,
+            payment_method_issuer: None,
+            payment_method_issuer_code: None,
+            metadata: None,
+            card_fingerprint: None,
+            network_token_reference_id: None,
+            network_token_status: None,
+            network_token_last4: None,
+            network_token_exp_month: None,
+            network_token_exp_year: None,
+            card_exp_month: None,
+            card_exp_year: None,
+            last_used_at,
+            usage_count: i32::from(last_used_at.is_some()),
+            bank_account_last4: None,
+        }
+    }
+
+    #[test]
+    fn test_sort_customer_payment_methods() {
+        let now = common_utils::date_time::now();
+        let day = time::Duration::days(1);
+        let payment_methods = vec![
+            mk_payment_method("pm_saved_first", now - day * 5, None),
+            mk_payment_method("pm_used_long_ago", now - day * 4, Some(now - day * 3)),
+            mk_payment_method("pm_default", now - day * 6, Some(now - day * 6)),
+            mk_payment_method("pm_saved_last", now - day, None),
+            mk_payment_method("pm_used_recently", now - day * 4, Some(now - day)),
+        ];
+
+        let sorted = sort_customer_payment_methods(payment_methods.clone(), Some("pm_default"));
+        let sorted_ids: Vec<_> = sorted
+            .iter()
+            .map(|pm| pm.payment_method_id.as_str())
+            .collect();
+        assert_eq!(
+            sorted_ids,
+            [
+                "pm_default",
+                "pm_used_recently",
+                "pm_used_long_ago",
+                "pm_saved_last",
+                "pm_saved_first",
+            ]
+        );
+
+        // Without a default, the default candidate is ordered by its last use
+        let sorted = sort_customer_payment_methods(payment_methods, None);
+        assert_eq!(sorted[2].payment_method_id, "pm_default");
+    }
+}
@@ -0,0 +1,486 @@
+//! Network tokenization of saved cards.
+//!
+//! Saved cards are provisioned as network tokens with the configured token service provider.
+//! For each payment made with a saved card, a cryptogram is requested from the provider and passed
+//! on to the connector along with the token. Whenever the token or the cryptogram is unavailable,
+//! payments fall back to the card number.
+
+use base64::Engine;
+use common_utils::{
+    consts::ID_LENGTH,
+    crypto::{HmacSha256, SignMessage, VerifySignature},
+    generate_id,
+};
+use error_stack::{IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, CustomResult, RouterResponse, StorageErrorExt},
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self, api,
+        storage::{self, enums},
+    },
+    utils::ByteSliceExt,
+};
+
+/// Header containing the hex encoded HMAC-SHA256 signature of the lifecycle webhook body
+const WEBHOOK_SIGNATURE_HEADER: &str = "x-network-token-signature";
+
+#[derive(Debug, Clone)]
+pub struct ProvisionedNetworkToken {
+    pub token_reference_id: String,
+    pub status: enums::NetworkTokenStatus,
+    pub token_last4: String,
+    pub token_exp_month: String,
+    pub token_exp_year: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct NetworkTokenCryptogram {
+    pub token_number: Secret<String, common_utils::pii::CardNumber>,
+    pub token_service: types::NetworkTokenService,
+    pub cryptogram: Secret<String>,
+    pub eci: Option<String>,
+}
+
+/// Change to a network token notified by the token service provider, for instance when the card
+/// was reissued or suspended by the issuer.
+#[derive(Debug, Clone)]
+pub struct NetworkTokenLifecycleEvent {
+    pub token_reference_id: String,
+    pub status: enums::NetworkTokenStatus,
+    pub token_last4: Option<String>,
+    pub token_exp_month: Option<String>,
+    pub token_exp_year: Option<String>,
+}
+
+#[async_trait::async_trait]
+pub trait NetworkTokenProvider: Send + Sync {
+    async fn provision_token(
+        &self,
+        card: &api::CardDetail,
+    ) -> CustomResult<ProvisionedNetworkToken, errors::NetworkTokenizationError>;
+
+    async fn get_cryptogram(
+        &self,
+        token_reference_id: &str,
+        amount: i64,
+        currency: enums::Currency,
+    ) -> CustomResult<NetworkTokenCryptogram, errors::NetworkTokenizationError>;
+
+    async fn delete_token(
+        &self,
+        token_reference_id: &str,
+    ) -> CustomResult<(), errors::NetworkTokenizationError>;
+
+    fn verify_webhook_source(
+        &self,
+        headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+        secret: &str,
+    ) -> CustomResult<bool, errors::NetworkTokenizationError>;
+
+    fn parse_lifecycle_event(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<NetworkTokenLifecycleEvent, errors::NetworkTokenizationError>;
+}
+
+pub fn get_provider(conf: &settings::NetworkTokenization) -> Box<dyn NetworkTokenProvider> {
+    match conf.provider {
+        settings::NetworkTokenProviderKind::Mock => Box::new(MockNetworkTokenProvider),
+    }
+}
+
+/// Provision a network token for a newly saved card. Failures are logged and ignored, payments
+/// made with the card use the card number in that case.
+#[instrument(skip_all)]
+pub async fn provision_network_token(
+    state: &AppState,
+    payment_method: storage::PaymentMethod,
+    card: &api::CardDetail,
+) {
+    let provider = get_provider(&state.conf.network_tokenization);
+    let token = match provider.provision_token(card).await {
+        Ok(token) => token,
+        Err(error) => {
+            logger::warn!(?error, "Failed to provision network token");
+            return;
+        }
+    };
+
+    let update = storage::PaymentMethodUpdate::NetworkTokenUpdate {
+        network_token_reference_id: Some(token.token_reference_id),
+        network_token_status: Some(token.status),
+        network_token_last4: Some(token.token_last4),
+        network_token_exp_month: Some(token.token_exp_month),
+        network_token_exp_year: Some(token.token_exp_year),
+    };
+    if let Err(error) = state
+        .store
+        .update_payment_method(payment_method, update)
+        .await
+    {
+        logger::error!(?error, "Failed to store provisioned network token");
+    }
+}
+
+/// Network token and a fresh cryptogram for a payment made with the saved card, if the card has an
+/// active network token.
+#[instrument(skip_all)]
+pub async fn get_network_token_data(
+    state: &AppState,
+    payment_method_id: &str,
+    amount: i64,
+    currency: enums::Currency,
+) -> Option<types::NetworkTokenData> {
+    let payment_method = state
+        .store
+        .find_payment_method(payment_method_id)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to fetch payment method"))
+        .ok()?;
+
+    let (
+        Some(token_reference_id),
+        Some(enums::NetworkTokenStatus::Active),
+        Some(exp_month),
+        Some(exp_year),
+    ) = (
+        payment_method.network_token_reference_id,
+        payment_method.network_token_status,
+        payment_method.network_token_exp_month,
+        payment_method.network_token_exp_year,
+    )
+    else {
+        return None;
+    };
+
+    let provider = get_provider(&state.conf.network_tokenization);
+    let cryptogram = provider
+        .get_cryptogram(&token_reference_id, amount, currency)
+        .await
+        .map_err(|error| {
+            logger::warn!(
+                ?error,
+                "Failed to fetch network token cryptogram, falling back to the card number"
+            )
+        })
+        .ok()?;
+
+    Some(types::NetworkTokenData {
+        token_number: cryptogram.token_number,
+        token_exp_month: exp_month.into(),
+        token_exp_year: exp_year.into(),
+        token_service: cryptogram.token_service,
+        cryptogram: cryptogram.cryptogram,
+        eci: cryptogram.eci,
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn delete_network_token(state: &AppState, payment_method: &storage::PaymentMethod) {
+    let Some(token_reference_id) = payment_method.network_token_reference_id.as_deref() else {
+        return;
+    };
+
+    let provider = get_provider(&state.conf.network_tokenization);
+    if let Err(error) = provider.delete_token(token_reference_id).await {
+        logger::error!(?error, "Failed to delete network token");
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn handle_lifecycle_webhook(
+    state: &AppState,
+    req: &actix_web::HttpRequest,
+    body: actix_web::web::Bytes,
+) -> RouterResponse<serde_json::Value> {
+    let conf = &state.conf.network_tokenization;
+    // The route is only mounted when network tokenization is enabled, never verify webhooks with
+    // an empty secret regardless
+    if !conf.enabled || conf.webhook_secret.is_empty() {
+        Err(errors::ApiErrorResponse::InvalidRequestUrl)?
+    }
+    let provider = get_provider(conf);
+
+    let source_verified = provider
+        .verify_webhook_source(req.headers(), &body, &conf.webhook_secret)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to verify network token webhook source")?;
+    if !source_verified {
+        Err(errors::ApiErrorResponse::GenericUnauthorized {
+            message: "Webhook signature verification failed".to_string(),
+        })?
+    }
+
+    let event = provider.parse_lifecycle_event(&body).change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid network token lifecycle event".to_string(),
+        },
+    )?;
+
+    let db = &*state.store;
+    let payment_method = db
+        .find_payment_method_by_network_token_reference_id(&event.token_reference_id)
+        .await
+        .map_err(|error| {
+            error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+        })?;
+
+    let update = storage::PaymentMethodUpdate::NetworkTokenUpdate {
+        network_token_reference_id: None,
+        network_token_status: Some(event.status),
+        network_token_last4: event.token_last4,
+        network_token_exp_month: event.token_exp_month,
+        network_token_exp_year: event.token_exp_year,
+    };
+    db.update_payment_method(payment_method, update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update network token of payment method")?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Token service provider emulated locally, for development and testing.
+///
+/// Tokens are derived from their reference IDs, so that the provider does not need to store any
+/// state. Visa cards are tokenized with VTS, Mastercard cards with MDES, and other cards are
+/// rejected.
+pub struct MockNetworkTokenProvider;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct MockLifecycleEvent {
+    token_reference_id: String,
+    event_type: MockLifecycleEventType,
+    token_last4: Option<String>,
+    token_exp_month: Option<String>,
+    token_exp_year: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum MockLifecycleEventType {
+    TokenUpdated,
+    TokenSuspended,
+    TokenResumed,
+    TokenDeleted,
+}
+
+impl MockNetworkTokenProvider {
+    fn token_service(card_number: &str) -> Option<types::NetworkTokenService> {
+        match card_number.chars().next()? {
+            '4' => Some(types::NetworkTokenService::Vts),
+            '2' | '5' => Some(types::NetworkTokenService::Mdes),
+            _ => None,
+        }
+    }
+
+    /// Token service of a token reference ID, encoded in its prefix
+    fn token_service_of_reference(token_reference_id: &str) -> Option<types::NetworkTokenService> {
+        match token_reference_id.split_once('_')?.0 {
+            "vts" => Some(types::NetworkTokenService::Vts),
+            "mdes" => Some(types::NetworkTokenService::Mdes),
+            _ => None,
+        }
+    }
+
+    /// A 16 digit token number, valid as per the Luhn algorithm, derived from the reference ID.
+    fn token_number(
+        token_service: types::NetworkTokenService,
+        token_reference_id: &str,
+    ) -> CustomResult<String, errors::NetworkTokenizationError> {
+        let prefix = match token_service {
+            types::NetworkTokenService::Vts => "489537",
+            types::NetworkTokenService::Mdes => "522222",
+        };
+        let digest = HmacSha256
+            .sign_message(prefix.as_bytes(), token_reference_id.as_bytes())
+            .change_context(errors::NetworkTokenizationError::ProvisioningFailed)?;
+
+        let mut token_number = prefix.to_string();
+        token_number.extend(
+            digest
+                .iter()
+                .take(9)
+                .map(|byte| char::from(b'0' + byte % 10)),
+        );
+        token_number.push(luhn_check_digit(&token_number));
+        Ok(token_number)
+    }
+}
+
+#[async_trait::async_trait]
+impl NetworkTokenProvider for MockNetworkTokenProvider {
+    async fn provision_token(
+        &self,
+        card: &api::CardDetail,
+    ) -> CustomResult<ProvisionedNetworkToken, errors::NetworkTokenizationError> {
+        let token_service = Self::token_service(card.card_number.peek())
+            .ok_or(errors::NetworkTokenizationError::UnsupportedCardNetwork)
+            .into_report()?;
+        let prefix = match token_service {
+            types::NetworkTokenService::Vts => "vts",
+            types::NetworkTokenService::Mdes => "mdes",
+        };
+        let token_reference_id = generate_id(ID_LENGTH, prefix);
+        let mut token_number = Self::token_number(token_service, &token_reference_id)?;
+
+        Ok(ProvisionedNetworkToken {
+            token_reference_id,
+            status: enums::NetworkTokenStatus::Active,
+            token_last4: token_number.split_off(token_number.len() - 4),
+            token_exp_month: card.card_exp_month.peek().clone(),
+            token_exp_year: card.card_exp_year.peek().clone(),
+        })
+    }
+
+    async fn get_cryptogram(
+        &self,
+        token_reference_id: &str,
+        _amount: i64,
+        _currency: enums::Currency,
+    ) -> CustomResult<NetworkTokenCryptogram, errors::NetworkTokenizationError> {
+        let token_service = Self::token_service_of_reference(token_reference_id)
+            .ok_or(errors::NetworkTokenizationError::CryptogramFetchFailed)
+            .into_report()
+            .attach_printable("Unknown token reference ID")?;
+        let token_number = Self::token_number(token_service, token_reference_id)
+            .change_context(errors::NetworkTokenizationError::CryptogramFetchFailed)?;
+        let cryptogram = rand::random::<[u8; 20]>();
+        let eci = match token_service {
+            types::NetworkTokenService::Vts => "07",
+            types::NetworkTokenService::Mdes => "02",
+        };
+
+        Ok(NetworkTokenCryptogram {
+            token_number: token_number.into(),
+            token_service,
+            cryptogram: consts::BASE64_ENGINE.encode(cryptogram).into(),
+            eci: Some(eci.to_string()),
+        })
+    }
+
+    async fn delete_token(
+        &self,
+        _token_reference_id: &str,
+    ) -> CustomResult<(), errors::NetworkTokenizationError> {
+        Ok(())
+    }
+
+    fn verify_webhook_source(
+        &self,
+        headers: &actix_web::http::header::HeaderMap,
+        body: &[u8],
+        secret: &str,
+    ) -> CustomResult<bool, errors::NetworkTokenizationError> {
+        let Some(signature) = headers
+            .get(WEBHOOK_SIGNATURE_HEADER)
+            .and_then(|signature| signature.to_str().ok())
+            .and_then(|signature| hex::decode(signature).ok())
+        else {
+            return Ok(false);
+        };
+
+        HmacSha256
+            .verify_signature(secret.as_bytes(), &signature, body)
+            .change_context(errors::NetworkTokenizationError::WebhookSourceVerificationFailed)
+    }
+
+    fn parse_lifecycle_event(
+        &self,
+        body: &[u8],
+    ) -> CustomResult<NetworkTokenLifecycleEvent, errors::NetworkTokenizationError> {
+        let event: MockLifecycleEvent = body
+            .parse_struct("MockLifecycleEvent")
+            .change_context(errors::NetworkTokenizationError::WebhookBodyDecodingFailed)?;
+        let status = match event.event_type {
+            MockLifecycleEventType::TokenUpdated | MockLifecycleEventType::TokenResumed => {
+                enums::NetworkTokenStatus::Active
+            }
+            MockLifecycleEventType::TokenSuspended => enums::NetworkTokenStatus::Suspended,
+            MockLifecycleEventType::TokenDeleted => enums::NetworkTokenStatus::Deleted,
+        };
+
+        Ok(NetworkTokenLifecycleEvent {
+            token_reference_id: event.token_reference_id,
+            status,
+            token_last4: event.token_last4,
+            token_exp_month: event.token_exp_month,
+            token_exp_year: event.token_exp_year,
+        })
+    }
+}
+
+fn luhn_check_digit(number: &str) -> char {
+    let sum: u32 = number
+        .chars()
+        .rev()
+        .filter_map(|digit| digit.to_digit(10))
+        .enumerate()
+        .map(|(index, digit)| match (index % 2 == 0, digit * 2) {
+            (true, doubled) if doubled > 9 => doubled - 9,
+            (true, doubled) => doubled,
+            (false, _) => digit,
+        })
+        .sum();
+    char::from_digit((10 - sum % 10) % 10, 10).unwrap_or('0')
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn card(card_number: &str) -> api::CardDetail {
+        api::CardDetail {
+            card_number: card_number.to_string().into(),
+            card_exp_month: "10".to_string().into(),
+            card_exp_year: "2030".to_string().into(),
+            card_holder_name: None,
+        }
+    }
+
+    #[test]
+    fn test_luhn_check_digit() {
+        assert_eq!(luhn_check_digit("424242424242424"), '2');
+        assert_eq!(luhn_check_digit("411111111111111"), '1');
+        assert_eq!(luhn_check_digit("555555555555444"), '4');
+    }
+
+    #[actix_rt::test]
+    async fn test_mock_provider_round_trip() {
+        let provider = MockNetworkTokenProvider;
+        let token = provider
+            .provision_token(&card("4111111111111111"))
+            .await
+            .unwrap();
+        assert!(token.token_reference_id.starts_with("vts_"));
+
+        let cryptogram = provider
+            .get_cryptogram(&token.token_reference_id, 100, enums::Currency::USD)
+            .await
+            .unwrap();
+        let token_number = cryptogram.token_number.peek();
+        assert_eq!(cryptogram.token_service, types::NetworkTokenService::Vts);
+        assert!(token_number.starts_with("489537"));
+        assert!(token_number.ends_with(&token.token_last4));
+        assert_eq!(
+            luhn_check_digit(&token_number[..15]),
+            token_number.chars().last().unwrap()
+        );
+
+        assert!(provider
+            .provision_token(&card("371449635398431"))
+            .await
+            .is_err());
+    }
+}
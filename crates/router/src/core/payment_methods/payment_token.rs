@@ -0,0 +1,320 @@
+//! Payment token expiry and usage limits.
+//!
+//! Payment tokens reference payment method data stored in the temporary locker. Every token is
+//! registered when it is issued. A token can only be used within the expiry configured for the
+//! merchant, and for at most the number of uses configured for the merchant.
+//!
+//! A use of the token is reserved atomically before a payment confirmed with it is sent to the
+//! connector, so that concurrent confirmations cannot use the token more times than allowed. The
+//! use is released if the connector is not called or declines the payment method data, so that
+//! the payment can be retried with the same token. Confirmations failing with an error keep the
+//! use, as the connector may have charged the payment method. The payment method data is deleted
+//! from the temporary locker once the token is used up, and tokens older than the longest expiry
+//! a merchant can configure are periodically purged, along with their data.
+
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+use time::{Duration, PrimitiveDateTime};
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, RouterResult},
+        payment_methods::vault,
+    },
+    logger,
+    routes::AppState,
+    types::{
+        api,
+        storage::{self, enums as storage_enums, ProcessTrackerExt},
+    },
+    utils::{self, ValueExt},
+};
+
+/// Register a newly issued payment token, for its uses to be counted.
+pub async fn register_payment_token(state: &AppState, token: &str) -> RouterResult<()> {
+    state
+        .store
+        .insert_payment_token(storage::PaymentTokenNew {
+            token: token.to_owned(),
+            created_at: common_utils::date_time::now(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to register payment token")?;
+
+    Ok(())
+}
+
+/// Use of a payment token reserved for a payment attempt, with [`reserve_payment_token_use`].
+#[derive(Debug)]
+pub struct PaymentTokenReservation {
+    token: String,
+    /// Whether the reserved use is the last one allowed for the token
+    is_last_use: bool,
+}
+
+/// Check that the payment token can be used to confirm a payment, to reject payments with an
+/// expired or used up token early. Fails if the token has expired, or has been used the maximum
+/// number of times configured for the merchant. The use itself is reserved before the connector
+/// is called, with [`reserve_payment_token_use`].
+#[instrument(skip_all)]
+pub async fn validate_payment_token(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    token: &str,
+) -> RouterResult<()> {
+    let (created_after, max_uses) = get_payment_token_constraints(state, merchant_account)?;
+    let payment_token = state
+        .store
+        .find_payment_token_optional(token)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the payment token")?;
+
+    utils::when(
+        !payment_token.map_or(false, |payment_token| {
+            is_payment_token_usable(&payment_token, created_after, max_uses)
+        }),
+        || Err(report!(errors::ApiErrorResponse::PaymentTokenExpired)),
+    )
+}
+
+/// Reserve a use of the payment token for a payment attempt, before the payment is sent to the
+/// connector. The use is counted atomically, and fails if the token has expired or has been used
+/// the maximum number of times configured for the merchant.
+#[instrument(skip_all)]
+pub async fn reserve_payment_token_use(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    token: &str,
+) -> RouterResult<PaymentTokenReservation> {
+    let (created_after, max_uses) = get_payment_token_constraints(state, merchant_account)?;
+    let payment_token = state
+        .store
+        .record_payment_token_use(token, created_after, max_uses)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reserve a use of the payment token")?
+        .ok_or(errors::ApiErrorResponse::PaymentTokenExpired)
+        .into_report()?;
+
+    Ok(PaymentTokenReservation {
+        token: payment_token.token,
+        is_last_use: payment_token.use_count >= max_uses,
+    })
+}
+
+/// Settle the use of the payment token reserved for a payment attempt. The use is released if the
+/// connector was not called or declined the payment method data, so that the payment can be
+/// retried with the same token. Otherwise the payment method data is deleted from the temporary
+/// locker once the token is used up.
+#[instrument(skip_all)]
+pub async fn settle_payment_token_use(
+    state: &AppState,
+    reservation: PaymentTokenReservation,
+    is_connector_called: bool,
+    attempt_status: storage_enums::AttemptStatus,
+) -> RouterResult<()> {
+    if !is_connector_called || !is_token_used_by_attempt(attempt_status) {
+        state
+            .store
+            .release_payment_token_use(&reservation.token)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to release the use of the payment token")?;
+    } else if reservation.is_last_use {
+        vault::Vault::delete_locker_payment_method_by_lookup_key(state, &Some(reservation.token))
+            .await;
+    }
+    Ok(())
+}
+
+/// The creation time after which payment tokens of the merchant are not expired, and the maximum
+/// number of uses of the tokens.
+fn get_payment_token_constraints(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+) -> RouterResult<(PrimitiveDateTime, i32)> {
+    let payment_token_config = merchant_account
+        .payment_token_config
+        .clone()
+        .map(|config| config.parse_value::<api::PaymentTokenConfig>("PaymentTokenConfig"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the payment token config of the merchant")?;
+    let (ttl_in_secs, max_uses) =
+        get_payment_token_limits(&state.conf.payment_token, payment_token_config);
+
+    let created_after = common_utils::date_time::now() - Duration::seconds(ttl_in_secs.into());
+    Ok((created_after, max_uses))
+}
+
+fn is_payment_token_usable(
+    payment_token: &storage::PaymentToken,
+    created_after: PrimitiveDateTime,
+    max_uses: i32,
+) -> bool {
+    payment_token.created_at > created_after && payment_token.use_count < max_uses
+}
+
+/// Whether the payment method data of the token was accepted by the connector for the attempt.
+fn is_token_used_by_attempt(attempt_status: storage_enums::AttemptStatus) -> bool {
+    !matches!(
+        attempt_status,
+        storage_enums::AttemptStatus::Started
+            | storage_enums::AttemptStatus::AuthenticationFailed
+            | storage_enums::AttemptStatus::RouterDeclined
+            | storage_enums::AttemptStatus::AuthorizationFailed
+            | storage_enums::AttemptStatus::Failure
+            | storage_enums::AttemptStatus::PaymentMethodAwaited
+            | storage_enums::AttemptStatus::ConfirmationAwaited
+    )
+}
+
+/// The expiry in seconds and the maximum number of uses of payment tokens, for a merchant with
+/// the given payment token config.
+fn get_payment_token_limits(
+    conf: &settings::PaymentToken,
+    payment_token_config: Option<api::PaymentTokenConfig>,
+) -> (u32, i32) {
+    let config = payment_token_config.unwrap_or_default();
+    let ttl_in_secs = config
+        .ttl_in_secs
+        .unwrap_or(conf.default_ttl_in_secs)
+        .min(conf.max_ttl_in_secs);
+    let max_uses = config.max_uses.unwrap_or(conf.default_max_uses);
+
+    (ttl_in_secs, i32::try_from(max_uses).unwrap_or(i32::MAX))
+}
+
+/// Purge the payment tokens which expired for all merchants, along with their payment method data
+/// in the temporary locker.
+#[instrument(skip_all)]
+pub async fn start_payment_token_purge_workflow(
+    state: &AppState,
+    process: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let conf = &state.conf.payment_token;
+    let created_before =
+        common_utils::date_time::now() - Duration::seconds(conf.max_ttl_in_secs.into());
+
+    let mut purged = 0;
+    loop {
+        let payment_tokens = db
+            .find_payment_tokens_created_before(created_before, conf.purge_batch_size)
+            .await?;
+        if payment_tokens.is_empty() {
+            break;
+        }
+        let is_last_batch = i64::try_from(payment_tokens.len())
+            .map_or(true, |batch_len| batch_len < conf.purge_batch_size);
+
+        let tokens: Vec<String> = payment_tokens
+            .into_iter()
+            .map(|payment_token| payment_token.token)
+            .collect();
+        vault::Vault::delete_expired_payment_method_data(state, &tokens).await?;
+        purged += tokens.len();
+        db.delete_payment_tokens(tokens).await?;
+
+        if is_last_batch {
+            break;
+        }
+    }
+
+    logger::info!(purged, "Payment token purge completed");
+
+    let id = process.id.clone();
+    process
+        .clone()
+        .finish_with_status(db, format!("COMPLETED_BY_PT_{id}"))
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payment_token_limits() {
+        let conf = settings::PaymentToken {
+            default_ttl_in_secs: 900,
+            max_ttl_in_secs: 3600,
+            default_max_uses: 1,
+            purge_batch_size: 100,
+        };
+
+        assert_eq!(get_payment_token_limits(&conf, None), (900, 1));
+        assert_eq!(
+            get_payment_token_limits(
+                &conf,
+                Some(api::PaymentTokenConfig {
+                    ttl_in_secs: Some(7200),
+                    max_uses: Some(3),
+                })
+            ),
+            (3600, 3)
+        );
+        assert_eq!(
+            get_payment_token_limits(
+                &conf,
+                Some(api::PaymentTokenConfig {
+                    ttl_in_secs: Some(60),
+                    max_uses: Some(u32::MAX),
+                })
+            ),
+            (60, i32::MAX)
+        );
+    }
+
+    #[test]
+    fn test_payment_token_usable() {
+        let now = common_utils::date_time::now();
+        let payment_token = storage::PaymentToken {
+            token: "token_123".to_string(),
+            use_count: 1,
+            created_at: now,
+            last_used_at: Some(now),
+        };
+
+        assert!(is_payment_token_usable(
+            &payment_token,
+            now - Duration::minutes(15),
+            2
+        ));
+        // Used the maximum number of times
+        assert!(!is_payment_token_usable(
+            &payment_token,
+            now - Duration::minutes(15),
+            1
+        ));
+        // Expired
+        assert!(!is_payment_token_usable(
+            &payment_token,
+            now + Duration::seconds(1),
+            2
+        ));
+    }
+
+    #[test]
+    fn test_declined_attempts_do_not_use_token() {
+        for status in [
+            storage_enums::AttemptStatus::Authorized,
+            storage_enums::AttemptStatus::Charged,
+            storage_enums::AttemptStatus::Pending,
+            storage_enums::AttemptStatus::AuthenticationPending,
+        ] {
+            assert!(is_token_used_by_attempt(status), "{status:?}");
+        }
+        for status in [
+            storage_enums::AttemptStatus::AuthorizationFailed,
+            storage_enums::AttemptStatus::AuthenticationFailed,
+            storage_enums::AttemptStatus::RouterDeclined,
+            storage_enums::AttemptStatus::Failure,
+        ] {
+            assert!(!is_token_used_by_attempt(status), "{status:?}");
+        }
+    }
+}
@@ -101,12 +101,17 @@ pub fn mk_add_card_response(
     response: AddCardResponse,
     req: api::CreatePaymentMethod,
     merchant_id: &str,
+    card_fingerprint: Option<String>,
+    card_info: Option<api::CardInfo>,
 ) -> api::PaymentMethodResponse {
     let mut card_number = card.card_number.peek().to_owned();
+    let card_info = card_info.unwrap_or_default();
     let card = api::CardDetailFromLocker {
-        scheme: None,
+        scheme: card_info.card_network,
         last4_digits: Some(card_number.split_off(card_number.len() - 4)),
-        issuer_country: None, // [#256] bin mapping
+        issuer_country: card_info.card_issuing_country,
+        card_issuer: card_info.card_issuer,
+        card_funding_type: card_info.card_funding_type,
         card_number: Some(card.card_number),
         expiry_month: Some(card.card_exp_month),
         expiry_year: Some(card.card_exp_year),
@@ -122,6 +127,8 @@ pub fn mk_add_card_response(
         payment_method_type: req.payment_method_type,
         payment_method_issuer: req.payment_method_issuer,
         card: Some(card),
+        card_fingerprint,
+        bank_account: None,
         metadata: req.metadata,
         created: Some(common_utils::date_time::now()),
         payment_method_issuer_code: req.payment_method_issuer_code,
@@ -186,6 +193,8 @@ pub fn get_card_detail(
     let card_detail = api::CardDetailFromLocker {
         scheme: pm.scheme.clone(),
         issuer_country: pm.issuer_country.clone(),
+        card_issuer: pm.issuer_name.clone(),
+        card_funding_type: None,
         last4_digits: Some(last4_digits.split_off(last4_digits.len() - 4)),
         card_number: Some(card_number),
         expiry_month: response.card_exp_month,
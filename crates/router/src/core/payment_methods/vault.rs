@@ -7,15 +7,18 @@ use router_env::{instrument, tracing};
 
 #[cfg(not(feature = "basilisk"))]
 use crate::types::storage;
+#[cfg(feature = "basilisk")]
+use crate::{core::payment_methods::transformers as payment_methods, services, utils::BytesExt};
 use crate::{
-    core::errors::{self, CustomResult, RouterResult},
+    core::{
+        errors::{self, CustomResult, RouterResult},
+        payment_methods::payment_token,
+    },
     logger, routes,
     types::api,
     utils::{self, StringExt},
 };
 #[cfg(feature = "basilisk")]
-use crate::{core::payment_methods::transformers as payment_methods, services, utils::BytesExt};
-#[cfg(feature = "basilisk")]
 const VAULT_SERVICE_NAME: &str = "CARD";
 #[cfg(feature = "basilisk")]
 const VAULT_VERSION: &str = "0";
@@ -268,6 +271,7 @@ impl Vault {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error getting Value12 for locker")?;
 
+        let is_new_token = token_id.is_none();
         let lookup_key = token_id.unwrap_or_else(|| generate_id_with_default_len("token"));
 
         let db_value = MockTokenizeDBValue { value1, value2 };
@@ -303,6 +307,10 @@ impl Vault {
                 .attach_printable("Mock tokenization save to db failed update")?;
         }
 
+        if is_new_token {
+            payment_token::register_payment_token(state, &lookup_key).await?;
+        }
+
         Ok(lookup_key)
     }
 
@@ -319,6 +327,25 @@ impl Vault {
             }
         }
     }
+
+    #[instrument(skip_all)]
+    pub async fn delete_expired_payment_method_data(
+        state: &routes::AppState,
+        lookup_keys: &[String],
+    ) -> RouterResult<()> {
+        let db = &*state.store;
+        for lookup_key in lookup_keys {
+            if let Err(error) = db.delete_config_by_key(lookup_key).await {
+                if !error.current_context().is_db_not_found() {
+                    return Err(error)
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to delete expired payment method data");
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "basilisk")]
@@ -354,9 +381,15 @@ impl Vault {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error getting Value12 for locker")?;
 
+        let is_new_token = token_id.is_none();
         let lookup_key = token_id.unwrap_or_else(|| generate_id_with_default_len("token"));
 
-        create_tokenize(state, value1, Some(value2), lookup_key).await
+        let lookup_key = create_tokenize(state, value1, Some(value2), lookup_key).await?;
+        if is_new_token {
+            payment_token::register_payment_token(state, &lookup_key).await?;
+        }
+
+        Ok(lookup_key)
     }
 
     #[instrument(skip_all)]
@@ -378,6 +411,28 @@ impl Vault {
             }
         }
     }
+
+    /// Delete the payment method data stored longer ago than the longest payment token expiry.
+    /// The temporary locker deletes its expired entries by date, so the lookup keys are only used
+    /// to bound the number of entries deleted.
+    #[instrument(skip_all)]
+    pub async fn delete_expired_payment_method_data(
+        state: &routes::AppState,
+        lookup_keys: &[String],
+    ) -> RouterResult<()> {
+        let buffer_minutes = state.conf.payment_token.max_ttl_in_secs / 60;
+        let delete_resp = delete_tokenized_data_by_date(
+            state,
+            i32::try_from(buffer_minutes).unwrap_or(i32::MAX),
+            i32::try_from(lookup_keys.len()).unwrap_or(i32::MAX),
+        )
+        .await?;
+        if delete_resp != "Ok" {
+            logger::error!("Error: Deleting expired data from locker : {}", delete_resp);
+        }
+
+        Ok(())
+    }
 }
 
 //------------------------------------------------TokenizeService------------------------------------------------
@@ -558,3 +613,63 @@ pub async fn delete_tokenized_data(
             .attach_printable(format!("Got 4xx from the basilisk locker: {err:?}")),
     }
 }
+
+#[cfg(feature = "basilisk")]
+pub async fn delete_tokenized_data_by_date(
+    state: &routes::AppState,
+    buffer_minutes: i32,
+    max_rows: i32,
+) -> RouterResult<String> {
+    let payload_to_be_encrypted = api::DeleteTokenizeByDateRequest {
+        buffer_minutes,
+        service_name: VAULT_SERVICE_NAME.to_string(),
+        max_rows,
+    };
+    let payload = serde_json::to_string(&payload_to_be_encrypted)
+        .map_err(|_x| errors::ApiErrorResponse::InternalServerError)?;
+    let encrypted_payload = services::encrypt_jwe(&state.conf.jwekey, &payload)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error getting Encrypt JWE response")?;
+    let create_tokenize_request = api::TokenizePayloadEncrypted {
+        payload: encrypted_payload,
+        key_id: services::get_key_id(&state.conf.jwekey).to_string(),
+        version: Some(VAULT_VERSION.to_string()),
+    };
+    let request = payment_methods::mk_crud_locker_request(
+        &state.conf.locker,
+        "/tokenize/delete/date",
+        create_tokenize_request,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Making Delete Tokenized by date request failed")?;
+    let response = services::call_connector_api(state, request)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    match response {
+        Ok(r) => {
+            let resp: api::TokenizePayloadEncrypted = r
+                .response
+                .parse_struct("TokenizePayloadEncrypted")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Decoding Failed for TokenizePayloadEncrypted")?;
+            let decrypted_payload =
+                services::decrypt_jwe(&state.conf.jwekey, &resp.payload, &resp.key_id)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "DeleteTokenizedByDateApi: Decrypt Jwe failed for TokenizePayloadEncrypted",
+                    )?;
+            let delete_response = decrypted_payload
+                .parse_struct("Delete")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Error getting TokenizePayloadEncrypted from tokenize response",
+                )?;
+            Ok(delete_response)
+        }
+        Err(err) => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable(format!("Got 4xx from the basilisk locker: {err:?}")),
+    }
+}
@@ -1,4 +1,7 @@
 pub mod access_token;
+pub mod applepay;
+pub mod authentication;
+pub mod decline_codes;
 pub mod flows;
 pub mod helpers;
 pub mod operations;
@@ -22,8 +25,10 @@ use self::{
 };
 use crate::{
     core::{
+        capabilities,
         errors::{self, RouterResponse, RouterResult},
-        payment_methods::vault,
+        fraud_check,
+        payment_methods::payment_token,
     },
     db::StorageInterface,
     logger, pii,
@@ -117,6 +122,35 @@ where
     )
     .await?;
 
+    // Payments created with `confirm` set are validated too, their operation only becomes a
+    // confirmation once the trackers are updated. Apple Pay payment tokens are decrypted here so
+    // that invalid tokens are rejected before the payment is confirmed.
+    if let api::ConnectorCallType::Single(ref connector) = connector_details {
+        if is_payment_confirmation(&operation, &payment_data) {
+            capabilities::validate_payment_with_connector(
+                &state.conf,
+                connector.connector_name,
+                &payment_data,
+            )?;
+            applepay::decrypt_payment_token(
+                state,
+                &merchant_account,
+                connector.connector_name,
+                &mut payment_data,
+            )
+            .await?;
+        }
+    }
+
+    // The use of the payment token is reserved before the payment is sent to the connector, so that
+    // concurrent confirmations cannot use the token more times than allowed
+    let payment_token_reservation = match payment_data.token {
+        Some(ref token) if is_payment_confirmation(&operation, &payment_data) => {
+            Some(payment_token::reserve_payment_token_use(state, &merchant_account, token).await?)
+        }
+        _ => None,
+    };
+
     let (operation, mut payment_data) = operation
         .to_update_tracker()?
         .update_trackers(
@@ -134,61 +168,120 @@ where
         .await?;
 
     if should_call_connector(&operation, &payment_data) {
-        payment_data = match connector_details {
-            api::ConnectorCallType::Single(connector) => {
-                call_connector_service(
+        let authentication_connector = authentication::get_authentication_connector(
+            state,
+            &merchant_account,
+            &operation,
+            &payment_data,
+        )
+        .await?;
+        let mut should_continue_payment = match authentication_connector {
+            Some((ref connector_name, ref connector)) => {
+                authentication::authenticate_payment(
                     state,
                     &merchant_account,
-                    &validate_result.payment_id,
+                    connector_name,
                     connector,
-                    &operation,
-                    payment_data,
-                    &customer,
-                    call_connector_action,
+                    &mut payment_data,
                 )
                 .await?
             }
-            api::ConnectorCallType::Multiple(connectors) => {
-                call_multiple_connectors_service(
+            None => true,
+        };
+
+        let frm_info = fraud_check::get_frm_info(state, &merchant_account, &operation).await?;
+        if should_continue_payment {
+            if let Some(ref frm_info) = frm_info {
+                should_continue_payment = fraud_check::call_frm_before_connector_call(
                     state,
                     &merchant_account,
-                    connectors,
+                    frm_info,
                     &operation,
-                    payment_data,
-                    &customer,
+                    &mut payment_data,
                 )
-                .await?
+                .await?;
             }
-            api::ConnectorCallType::Routing => {
-                let connector = payment_data
-                    .payment_attempt
-                    .connector
-                    .clone()
-                    .get_required_value("connector")
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("No connector selected for routing")?;
-
-                let connector_data = api::ConnectorData::get_connector_by_name(
-                    &state.conf.connectors,
-                    &connector,
-                    api::GetToken::Connector,
-                )
-                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        }
+
+        if should_continue_payment {
+            payment_data = match connector_details {
+                api::ConnectorCallType::Single(connector) => {
+                    call_connector_service(
+                        state,
+                        &merchant_account,
+                        &validate_result.payment_id,
+                        connector,
+                        &operation,
+                        payment_data,
+                        &customer,
+                        call_connector_action,
+                    )
+                    .await?
+                }
+                api::ConnectorCallType::Multiple(connectors) => {
+                    call_multiple_connectors_service(
+                        state,
+                        &merchant_account,
+                        connectors,
+                        &operation,
+                        payment_data,
+                        &customer,
+                    )
+                    .await?
+                }
+                api::ConnectorCallType::Routing => {
+                    let connector = payment_data
+                        .payment_attempt
+                        .connector
+                        .clone()
+                        .get_required_value("connector")
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("No connector selected for routing")?;
+
+                    let connector_data = api::ConnectorData::get_connector_by_name(
+                        &state.conf.connectors,
+                        &connector,
+                        api::GetToken::Connector,
+                    )
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+                    call_connector_service(
+                        state,
+                        &merchant_account,
+                        &validate_result.payment_id,
+                        connector_data,
+                        &operation,
+                        payment_data,
+                        &customer,
+                        call_connector_action,
+                    )
+                    .await?
+                }
+            };
 
-                call_connector_service(
+            if let Some(ref frm_info) = frm_info {
+                payment_data = fraud_check::call_frm_after_connector_call(
                     state,
                     &merchant_account,
-                    &validate_result.payment_id,
-                    connector_data,
-                    &operation,
-                    payment_data,
+                    frm_info,
                     &customer,
-                    call_connector_action,
+                    payment_data,
                 )
-                .await?
+                .await?;
             }
-        };
-        vault::Vault::delete_locker_payment_method_by_lookup_key(state, &payment_data.token).await
+        }
+
+        // The use is released when the connector is not called, and reserved again once the
+        // customer completes the challenge
+        if let Some(reservation) = payment_token_reservation {
+            payment_token::settle_payment_token_use(
+                state,
+                reservation,
+                should_continue_payment,
+                payment_data.payment_attempt.status,
+            )
+            .await?;
+        }
     }
     Ok((payment_data, req, customer))
 }
@@ -260,25 +353,75 @@ where
             field_name: "payment_id",
         })?;
 
-    let connector_data = api::ConnectorData::get_connector_by_name(
-        &state.conf.connectors,
-        &connector,
-        api::GetToken::Connector,
-    )?;
+    let payment_attempt = state
+        .store
+        .find_payment_attempt_by_payment_id_merchant_id(
+            &resource_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|err| {
+            errors::StorageErrorExt::to_not_found_response(
+                err,
+                errors::ApiErrorResponse::PaymentNotFound,
+            )
+        })?;
 
-    let flow_type = connector_data
-        .connector
-        .get_flow_type(&query_params)
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to decide the response flow")?;
+    // The customer is back from the challenge of the authentication connector, the payment is
+    // confirmed again with the result of the authentication
+    let response = if authentication::is_awaiting_challenge(&payment_attempt) {
+        let payment_intent = state
+            .store
+            .find_payment_intent_by_payment_id_merchant_id(
+                &resource_id,
+                &merchant_account.merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .map_err(|err| {
+                errors::StorageErrorExt::to_not_found_response(
+                    err,
+                    errors::ApiErrorResponse::PaymentNotFound,
+                )
+            })?;
 
-    let response = payments_response_for_redirection_flows(
-        state,
-        merchant_account.clone(),
-        req.clone(),
-        flow_type,
-    )
-    .await;
+        payments_core::<api::Authorize, api::PaymentsResponse, _, _, _>(
+            state,
+            merchant_account.clone(),
+            PaymentConfirm,
+            api::PaymentsRequest {
+                payment_id: Some(api::PaymentIdType::PaymentIntentId(resource_id.clone())),
+                merchant_id: Some(merchant_account.merchant_id.clone()),
+                confirm: Some(true),
+                return_url: payment_intent.return_url,
+                ..Default::default()
+            },
+            services::api::AuthFlow::Merchant,
+            CallConnectorAction::Trigger,
+        )
+        .await
+    } else {
+        let connector_data = api::ConnectorData::get_connector_by_name(
+            &state.conf.connectors,
+            &connector,
+            api::GetToken::Connector,
+        )?;
+
+        let flow_type = connector_data
+            .connector
+            .get_flow_type(&query_params)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to decide the response flow")?;
+
+        payments_response_for_redirection_flows(
+            state,
+            merchant_account.clone(),
+            req.clone(),
+            flow_type,
+        )
+        .await
+    };
 
     let payments_response =
         match response.change_context(errors::ApiErrorResponse::NotImplemented {
@@ -509,6 +652,9 @@ where
     pub sessions_token: Vec<api::SessionToken>,
     pub card_cvc: Option<pii::Secret<String>>,
     pub email: Option<masking::Secret<String, pii::Email>>,
+    pub network_token: Option<types::NetworkTokenData>,
+    pub authentication_data: Option<types::AuthenticationData>,
+    pub card_info: Option<api::CardInfo>,
 }
 
 #[derive(Debug, Default)]
@@ -559,6 +705,18 @@ where
     }
 }
 
+/// Whether the operation confirms the payment, sending it to the connector with its payment method.
+pub fn is_payment_confirmation<Op: Debug, F: Clone>(
+    operation: &Op,
+    payment_data: &PaymentData<F>,
+) -> bool {
+    match format!("{operation:?}").as_str() {
+        "PaymentConfirm" => true,
+        "PaymentCreate" => payment_data.confirm == Some(true),
+        _ => false,
+    }
+}
+
 pub fn should_call_connector<Op: Debug, F: Clone>(
     operation: &Op,
     payment_data: &PaymentData<F>,
@@ -639,7 +797,7 @@ pub async fn add_process_sync_task(
 
         resource_id: api::PaymentIdType::PaymentAttemptId(payment_attempt.attempt_id.clone()),
         param: None,
-        connector: None,
+        connector: payment_attempt.connector.clone(),
     };
     let runner = "PAYMENTS_SYNC_WORKFLOW";
     let task = "PAYMENTS_SYNC";
@@ -703,6 +861,13 @@ where
             Ok(api::ConnectorCallType::Single(connector_data))
         }
 
-        call_type @ api::ConnectorCallType::Multiple(_) => Ok(call_type),
+        // Only the connectors which can process the payment are called
+        api::ConnectorCallType::Multiple(connectors) => Ok(api::ConnectorCallType::Multiple(
+            capabilities::filter_connectors_supporting_payment(
+                &state.conf,
+                connectors,
+                payment_data,
+            ),
+        )),
     }
 }
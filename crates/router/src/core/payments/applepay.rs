@@ -0,0 +1,1246 @@
+//! Decryption of Apple Pay payment tokens in the router.
+//!
+//! Apple Pay payment tokens are encrypted with the public key of the merchant's payment processing
+//! certificate, and are forwarded as-is to processors supporting Apple Pay. For processors which
+//! accept network tokens instead, the token is decrypted with the private key of the certificate,
+//! and the device account number and cryptogram it contains are passed on to the processor as a
+//! network token.
+//!
+//! Payment tokens are only decrypted once their signature is verified against the Apple root
+//! certificate authority, and tokens signed more than a few minutes before the payment are
+//! rejected, as Apple requires.
+//!
+//! Merchants opting in store the private key with the metadata of their `applepay` connector
+//! account. The key is encrypted with the router's certificate encryption key before it is stored,
+//! and only decrypted to decrypt payment tokens.
+
+use base64::Engine;
+use common_utils::{date_time, pii};
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use openssl::{
+    derive::Deriver,
+    encrypt::Decrypter,
+    hash::MessageDigest,
+    pkcs7::{Pkcs7, Pkcs7Flags},
+    pkey::{PKey, Private},
+    rsa::Padding,
+    stack::Stack,
+    symm::{self, Cipher},
+    x509::{store::X509StoreBuilder, X509Ref, X509StoreContext, X509VerifyResult, X509},
+};
+use router_env::{instrument, tracing};
+use serde::Deserialize;
+use tokio::sync::OnceCell;
+
+use super::PaymentData;
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        capabilities,
+        errors::{self, CustomResult, RouterResult},
+        payment_methods::card_vault,
+    },
+    routes::AppState,
+    types::{self, api, storage},
+    utils::ValueExt,
+};
+
+/// Name of the connector account holding the Apple Pay merchant configuration
+const APPLEPAY_CONNECTOR_NAME: &str = "applepay";
+
+/// Length of the key the payment processing certificate keys are encrypted with, in bytes
+const KEY_LENGTH: usize = 32;
+
+/// Apple Pay encrypts payment data with AES-GCM and an initialization vector of 16 zero bytes
+const GCM_IV: [u8; 16] = [0; 16];
+
+/// Length of the AES-GCM authentication tag appended to the payment data, in bytes
+const GCM_TAG_LENGTH: usize = 16;
+
+/// Extension marking the leaf certificate payment tokens are signed with, 1.2.840.113635.100.6.29
+const LEAF_CERTIFICATE_OID: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x63, 0x64, 0x06, 0x1d];
+
+/// Extension marking the Apple Pay intermediate certificate authority, 1.2.840.113635.100.6.2.14
+const INTERMEDIATE_CERTIFICATE_OID: &[u8] =
+    &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x63, 0x64, 0x06, 0x02, 0x0e];
+
+/// Signing time attribute of PKCS #7 signatures, 1.2.840.113549.1.9.5
+const SIGNING_TIME_OID: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x09, 0x05];
+
+/// Payment tokens signed longer than this before the payment may be replayed, and are rejected
+const MAX_TOKEN_AGE: time::Duration = time::Duration::minutes(5);
+
+/// Maximum nesting of the BER encoded signatures of payment tokens, well beyond that of valid
+/// signatures, so that crafted signatures cannot exhaust the stack
+const MAX_BER_DEPTH: usize = 32;
+
+static CERTIFICATE_ENCRYPTION_KEY: OnceCell<Secret<Vec<u8>>> = OnceCell::const_new();
+
+static APPLE_ROOT_CERTIFICATE: OnceCell<X509> = OnceCell::const_new();
+
+/// Apple Pay configuration stored with the metadata of the merchant's `applepay` connector account.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct ApplepayDecryptionMetadata {
+    merchant_identifier: Option<String>,
+    /// PEM encoded private key of the payment processing certificate, as provided by the merchant
+    payment_processing_certificate_key: Option<Secret<String>>,
+    /// Base64 encoded private key of the payment processing certificate, encrypted with the
+    /// certificate encryption key
+    encrypted_payment_processing_certificate_key: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ApplepayPaymentToken {
+    #[serde(rename_all = "camelCase")]
+    Token {
+        payment_data: ApplepayPaymentData,
+    },
+    PaymentData(ApplepayPaymentData),
+}
+
+#[derive(Debug, Deserialize)]
+struct ApplepayPaymentData {
+    version: ApplepayTokenVersion,
+    data: String,
+    /// Base64 encoded detached PKCS #7 signature of the header keys, the data and the transaction
+    signature: String,
+    header: ApplepayTokenHeader,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+enum ApplepayTokenVersion {
+    #[serde(rename = "EC_v1")]
+    EcV1,
+    #[serde(rename = "RSA_v1")]
+    RsaV1,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApplepayTokenHeader {
+    /// Ephemeral public key the symmetric key is derived with, for `EC_v1` tokens
+    ephemeral_public_key: Option<String>,
+    /// Symmetric key encrypted with the public key of the merchant, for `RSA_v1` tokens
+    wrapped_key: Option<String>,
+    /// Base64 encoded SHA-256 hash of the public key the token was encrypted for
+    public_key_hash: String,
+    /// Hex encoded identifier of the transaction on the device
+    transaction_id: String,
+    /// Hex encoded SHA-256 hash of the application data of the payment request, if any
+    application_data: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApplepayDecryptedData {
+    application_primary_account_number: Secret<String, pii::CardNumber>,
+    /// Expiry date of the device account number, as `YYMMDD`
+    application_expiration_date: String,
+    payment_data_type: ApplepayPaymentDataType,
+    payment_data: ApplepayCryptogramData,
+}
+
+#[derive(Debug, PartialEq, Eq, Deserialize)]
+enum ApplepayPaymentDataType {
+    #[serde(rename = "3DSecure")]
+    ThreeDSecure,
+    #[serde(rename = "EMV")]
+    Emv,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApplepayCryptogramData {
+    online_payment_cryptogram: Secret<String>,
+    eci_indicator: Option<String>,
+}
+
+/// Whether the payment is made with an Apple Pay payment token the router is configured to decrypt.
+pub fn is_decryptable_payment_token(
+    conf: &settings::Settings,
+    payment_method_data: Option<&api::PaymentMethod>,
+) -> bool {
+    conf.applepay_decryption.enabled
+        && matches!(
+            payment_method_data,
+            Some(api::PaymentMethod::Wallet(api::WalletData {
+                issuer_name: api::enums::WalletIssuer::ApplePay,
+                token: Some(_),
+            }))
+        )
+}
+
+/// Whether Apple Pay payment tokens are decrypted for the connector, which then processes them as
+/// card payments made with a network token.
+pub fn should_decrypt_payment_token(capabilities: &api::ConnectorCapabilities) -> bool {
+    capabilities.supports_network_tokens && !supports_applepay_natively(capabilities)
+}
+
+/// Processors accepting wallet payments accept Apple Pay payment tokens, and decrypt them
+/// themselves.
+fn supports_applepay_natively(capabilities: &api::ConnectorCapabilities) -> bool {
+    capabilities.supports_payment_method(api::enums::PaymentMethodType::Wallet)
+}
+
+/// Encrypts the payment processing certificate key provided with the metadata of an `applepay`
+/// connector account, so that the key is never stored in plain text.
+pub async fn encrypt_certificate_key(
+    conf: &settings::Settings,
+    connector_name: &str,
+    metadata: Option<serde_json::Value>,
+) -> RouterResult<Option<serde_json::Value>> {
+    if connector_name != APPLEPAY_CONNECTOR_NAME {
+        return Ok(metadata);
+    }
+    let Some(mut metadata) = metadata else {
+        return Ok(None);
+    };
+    let decryption_metadata: ApplepayDecryptionMetadata = metadata
+        .clone()
+        .parse_value("ApplepayDecryptionMetadata")
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "metadata",
+        })?;
+    let Some(certificate_key) = decryption_metadata.payment_processing_certificate_key else {
+        return Ok(Some(metadata));
+    };
+
+    if !conf.applepay_decryption.enabled {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Apple Pay token decryption is not enabled".to_string(),
+        }))?
+    }
+    PKey::private_key_from_pem(certificate_key.peek().as_bytes())
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "metadata.payment_processing_certificate_key",
+        })?;
+
+    let key = get_certificate_encryption_key(conf)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let encrypted_key = card_vault::encrypt(key.peek(), certificate_key.peek().as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encrypt the payment processing certificate key")?;
+
+    if let Some(metadata) = metadata.as_object_mut() {
+        metadata.remove("payment_processing_certificate_key");
+        metadata.insert(
+            "encrypted_payment_processing_certificate_key".to_string(),
+            consts::BASE64_ENGINE.encode(encrypted_key).into(),
+        );
+    }
+    Ok(Some(metadata))
+}
+
+/// Decrypts the Apple Pay payment token of the payment into a network token, when the payment is
+/// made with Apple Pay through a processor which accepts network tokens but not Apple Pay.
+#[instrument(skip_all)]
+pub async fn decrypt_payment_token<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector: api::enums::Connector,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    if payment_data.network_token.is_some()
+        || !is_decryptable_payment_token(&state.conf, payment_data.payment_method_data.as_ref())
+        || !should_decrypt_payment_token(&capabilities::get_connector_capabilities(connector))
+    {
+        return Ok(());
+    }
+    let Some(api::PaymentMethod::Wallet(api::WalletData {
+        token: Some(ref token),
+        ..
+    })) = payment_data.payment_method_data
+    else {
+        return Ok(());
+    };
+
+    let merchant_connector_account = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_connector(
+            &merchant_account.merchant_id,
+            APPLEPAY_CONNECTOR_NAME,
+        )
+        .await;
+    // Decryption is only not configured if the merchant has no Apple Pay connector account
+    let merchant_connector_account = match merchant_connector_account {
+        Ok(merchant_connector_account) => Some(merchant_connector_account),
+        Err(error) if error.current_context().is_db_not_found() => None,
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the Apple Pay connector account"))?,
+    };
+    let decryption_metadata: Option<ApplepayDecryptionMetadata> = merchant_connector_account
+        .and_then(|mca| mca.metadata)
+        .map(|metadata| metadata.parse_value("ApplepayDecryptionMetadata"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the Apple Pay connector metadata")?;
+    let Some(ApplepayDecryptionMetadata {
+        merchant_identifier: Some(merchant_identifier),
+        encrypted_payment_processing_certificate_key: Some(encrypted_certificate_key),
+        ..
+    }) = decryption_metadata
+    else {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Apple Pay payment token decryption is not configured for the merchant"
+                .to_string(),
+        }));
+    };
+
+    let certificate_key = decrypt_certificate_key(&state.conf, &encrypted_certificate_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decrypt the payment processing certificate key")?;
+    let root_certificate = get_apple_root_certificate(&state.conf)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let decrypted_data = decrypt_token(
+        token,
+        &certificate_key,
+        &merchant_identifier,
+        root_certificate,
+    )
+    .change_context(errors::ApiErrorResponse::InvalidDataValue {
+        field_name: "payment_method_data.wallet.token",
+    })?;
+
+    let network_token = get_network_token_data(decrypted_data)
+        .ok_or(errors::ApiErrorResponse::NotSupportedByConnector {
+            message: "Apple Pay payment data of this card network".to_string(),
+            connector: connector.to_string(),
+        })
+        .into_report()?;
+    payment_data.network_token = Some(network_token);
+    Ok(())
+}
+
+fn decrypt_token(
+    token: &str,
+    certificate_key: &PKey<Private>,
+    merchant_identifier: &str,
+    root_certificate: &X509Ref,
+) -> CustomResult<ApplepayDecryptedData, errors::EncryptionError> {
+    let token: ApplepayPaymentToken = serde_json::from_str(token)
+        .into_report()
+        .change_context(errors::EncryptionError)
+        .attach_printable("Apple Pay payment token is not valid JSON")?;
+    let payment_data = match token {
+        ApplepayPaymentToken::Token { payment_data }
+        | ApplepayPaymentToken::PaymentData(payment_data) => payment_data,
+    };
+
+    let signing_time = verify_signature(&payment_data, root_certificate)?;
+    validate_signing_time(signing_time, date_time::now())?;
+
+    // Tokens encrypted for another certificate of the merchant cannot be decrypted with this key
+    let public_key_hash = certificate_key
+        .public_key_to_der()
+        .map(|public_key| consts::BASE64_ENGINE.encode(openssl::sha::sha256(&public_key)))
+        .into_report()
+        .change_context(errors::EncryptionError)?;
+    if public_key_hash != payment_data.header.public_key_hash {
+        Err(report!(errors::EncryptionError).attach_printable(
+            "Apple Pay payment token was encrypted for another payment processing certificate",
+        ))?
+    }
+
+    let (cipher, symmetric_key) = match payment_data.version {
+        ApplepayTokenVersion::EcV1 => {
+            let ephemeral_public_key = payment_data
+                .header
+                .ephemeral_public_key
+                .as_deref()
+                .ok_or(errors::EncryptionError)
+                .into_report()
+                .attach_printable("Missing ephemeral public key in the EC_v1 token header")?;
+            let shared_secret = get_shared_secret(certificate_key, ephemeral_public_key)?;
+            (
+                Cipher::aes_256_gcm(),
+                derive_symmetric_key(&shared_secret, merchant_identifier).to_vec(),
+            )
+        }
+        ApplepayTokenVersion::RsaV1 => {
+            let wrapped_key = payment_data
+                .header
+                .wrapped_key
+                .as_deref()
+                .ok_or(errors::EncryptionError)
+                .into_report()
+                .attach_printable("Missing wrapped key in the RSA_v1 token header")?;
+            (
+                Cipher::aes_128_gcm(),
+                unwrap_symmetric_key(certificate_key, wrapped_key)?,
+            )
+        }
+    };
+
+    let encrypted_data = consts::BASE64_ENGINE
+        .decode(&payment_data.data)
+        .into_report()
+        .change_context(errors::EncryptionError)?;
+    if encrypted_data.len() < GCM_TAG_LENGTH {
+        Err(report!(errors::EncryptionError).attach_printable("Encrypted data is too short"))?
+    }
+    let (ciphertext, tag) = encrypted_data.split_at(encrypted_data.len() - GCM_TAG_LENGTH);
+    let decrypted_data = symm::decrypt_aead(
+        cipher,
+        &symmetric_key,
+        Some(&GCM_IV[..]),
+        &[],
+        ciphertext,
+        tag,
+    )
+    .into_report()
+    .change_context(errors::EncryptionError)
+    .attach_printable("Failed to decrypt the Apple Pay payment data")?;
+
+    serde_json::from_slice(&decrypted_data)
+        .into_report()
+        .change_context(errors::EncryptionError)
+        .attach_printable("Decrypted Apple Pay payment data is not valid")
+}
+
+/// Verifies the signature of the payment token as specified by Apple, and returns its signing time.
+/// The signature must be made with a leaf certificate issued by the Apple Pay intermediate
+/// certificate authority, itself issued by the Apple root certificate authority.
+fn verify_signature(
+    payment_data: &ApplepayPaymentData,
+    root_certificate: &X509Ref,
+) -> CustomResult<time::PrimitiveDateTime, errors::EncryptionError> {
+    let signature = consts::BASE64_ENGINE
+        .decode(&payment_data.signature)
+        .into_report()
+        .change_context(errors::EncryptionError)?;
+    let (certificates, signing_time) = parse_signature(&signature)
+        .ok_or(errors::EncryptionError)
+        .into_report()
+        .attach_printable("Apple Pay payment token signature is not a valid PKCS #7 signature")?;
+    let leaf_certificate = certificates
+        .iter()
+        .find(|certificate| has_extension(certificate, LEAF_CERTIFICATE_OID))
+        .ok_or(errors::EncryptionError)
+        .into_report()
+        .attach_printable("Missing leaf certificate in the Apple Pay payment token signature")?;
+    let intermediate_certificate = certificates
+        .iter()
+        .find(|certificate| has_extension(certificate, INTERMEDIATE_CERTIFICATE_OID))
+        .ok_or(errors::EncryptionError)
+        .into_report()
+        .attach_printable(
+            "Missing intermediate certificate in the Apple Pay payment token signature",
+        )?;
+
+    let header_key = match payment_data.version {
+        ApplepayTokenVersion::EcV1 => payment_data.header.ephemeral_public_key.as_deref(),
+        ApplepayTokenVersion::RsaV1 => payment_data.header.wrapped_key.as_deref(),
+    }
+    .ok_or(errors::EncryptionError)
+    .into_report()
+    .attach_printable("Missing key in the Apple Pay payment token header")?;
+    let mut signed_data = consts::BASE64_ENGINE
+        .decode(header_key)
+        .into_report()
+        .change_context(errors::EncryptionError)?;
+    consts::BASE64_ENGINE
+        .decode_vec(&payment_data.data, &mut signed_data)
+        .into_report()
+        .change_context(errors::EncryptionError)?;
+    for hex_value in std::iter::once(&payment_data.header.transaction_id)
+        .chain(payment_data.header.application_data.as_ref())
+    {
+        signed_data.extend(
+            hex::decode(hex_value)
+                .into_report()
+                .change_context(errors::EncryptionError)?,
+        );
+    }
+
+    let verify = || {
+        let mut store = X509StoreBuilder::new()?;
+        store.add_cert(root_certificate.to_owned())?;
+        let store = store.build();
+
+        let mut chain = Stack::new()?;
+        chain.push(intermediate_certificate.to_owned())?;
+        let mut context = X509StoreContext::new()?;
+        let is_chain_valid = context.init(&store, leaf_certificate, &chain, |context| {
+            context.verify_cert()
+        })? && intermediate_certificate.issued(leaf_certificate)
+            == X509VerifyResult::OK;
+
+        // The chain is verified above, the signature must be made with its leaf certificate
+        let mut signer = Stack::new()?;
+        signer.push(leaf_certificate.to_owned())?;
+        let is_signature_valid = Pkcs7::from_der(&signature)?
+            .verify(
+                &signer,
+                &store,
+                Some(signed_data.as_slice()),
+                None,
+                Pkcs7Flags::NOVERIFY | Pkcs7Flags::NOINTERN | Pkcs7Flags::BINARY,
+            )
+            .is_ok();
+        Ok::<_, openssl::error::ErrorStack>(is_chain_valid && is_signature_valid)
+    };
+    if !verify()
+        .into_report()
+        .change_context(errors::EncryptionError)?
+    {
+        Err(report!(errors::EncryptionError)
+            .attach_printable("Apple Pay payment token signature is not valid"))?
+    }
+    Ok(signing_time)
+}
+
+/// Rejects payment tokens signed too long before the payment, which may be replayed.
+fn validate_signing_time(
+    signing_time: time::PrimitiveDateTime,
+    now: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::EncryptionError> {
+    if (now - signing_time).abs() > MAX_TOKEN_AGE {
+        Err(report!(errors::EncryptionError).attach_printable(format!(
+            "Apple Pay payment token was signed at {signing_time}, too long before the payment"
+        )))?
+    }
+    Ok(())
+}
+
+/// Element of BER encoded data, as found in the signatures of payment tokens.
+#[derive(Debug, Clone, Copy)]
+struct BerElement<'a> {
+    tag: u8,
+    /// Whole encoding of the element, tag and length included
+    encoding: &'a [u8],
+    contents: &'a [u8],
+}
+
+/// Reads the first element of BER encoded data, and returns it with the data following it. Only
+/// the single byte tags PKCS #7 and X.509 use are supported.
+fn read_ber_element(data: &[u8]) -> Option<(BerElement<'_>, &[u8])> {
+    read_nested_ber_element(data, 0)
+}
+
+fn read_nested_ber_element(data: &[u8], depth: usize) -> Option<(BerElement<'_>, &[u8])> {
+    if depth > MAX_BER_DEPTH {
+        return None;
+    }
+    let tag = *data.first()?;
+    if tag & 0x1f == 0x1f {
+        return None;
+    }
+    let length = *data.get(1)?;
+    let (header_length, contents_length, trailer_length) = if length == 0x80 {
+        // Constructed elements of indefinite length end with two zero bytes, Apple Pay signatures
+        // are encoded this way
+        if tag & 0x20 == 0 {
+            return None;
+        }
+        let mut contents = data.get(2..)?;
+        let mut contents_length = 0;
+        while !contents.starts_with(&[0, 0]) {
+            let (element, rest) = read_nested_ber_element(contents, depth + 1)?;
+            contents_length += element.encoding.len();
+            contents = rest;
+        }
+        (2, contents_length, 2)
+    } else if length < 0x80 {
+        (2, usize::from(length), 0)
+    } else {
+        let length_bytes = usize::from(length & 0x7f);
+        if length_bytes > std::mem::size_of::<u32>() {
+            return None;
+        }
+        let contents_length = data
+            .get(2..2 + length_bytes)?
+            .iter()
+            .fold(0, |length, byte| (length << 8) | usize::from(*byte));
+        (2 + length_bytes, contents_length, 0)
+    };
+
+    let encoding_length = header_length
+        .checked_add(contents_length)?
+        .checked_add(trailer_length)?;
+    let encoding = data.get(..encoding_length)?;
+    let contents = encoding.get(header_length..header_length + contents_length)?;
+    Some((
+        BerElement {
+            tag,
+            encoding,
+            contents,
+        },
+        data.get(encoding_length..)?,
+    ))
+}
+
+/// Iterates over the BER encoded elements of the contents of a constructed element.
+fn ber_elements(mut data: &[u8]) -> impl Iterator<Item = BerElement<'_>> {
+    std::iter::from_fn(move || {
+        let (element, rest) = read_ber_element(data)?;
+        data = rest;
+        Some(element)
+    })
+}
+
+/// Certificates and signing time of a PKCS #7 signature, which are not exposed by OpenSSL.
+fn parse_signature(signature: &[u8]) -> Option<(Vec<X509>, time::PrimitiveDateTime)> {
+    // ContentInfo ::= SEQUENCE { contentType, [0] EXPLICIT SignedData }
+    let (content_info, _) = read_ber_element(signature)?;
+    let content = ber_elements(content_info.contents).nth(1)?;
+    let (signed_data, _) = read_ber_element(content.contents)?;
+
+    // SignedData ::= SEQUENCE { version, digestAlgorithms, contentInfo,
+    //     certificates [0] IMPLICIT OPTIONAL, crls [1] IMPLICIT OPTIONAL, signerInfos SET }
+    let mut certificates = Vec::new();
+    let mut signer_infos = None;
+    for element in ber_elements(signed_data.contents).skip(3) {
+        match element.tag {
+            0xa0 => {
+                certificates = ber_elements(element.contents)
+                    .map(|certificate| X509::from_der(certificate.encoding).ok())
+                    .collect::<Option<_>>()?
+            }
+            0x31 => signer_infos = Some(element),
+            _ => {}
+        }
+    }
+
+    // SignerInfo ::= SEQUENCE { version, issuerAndSerialNumber, digestAlgorithm,
+    //     authenticatedAttributes [0] IMPLICIT OPTIONAL, ... }
+    let (signer_info, _) = read_ber_element(signer_infos?.contents)?;
+    let signed_attributes = ber_elements(signer_info.contents)
+        .nth(3)
+        .filter(|element| element.tag == 0xa0)?;
+    let signing_time = ber_elements(signed_attributes.contents).find_map(|attribute| {
+        // Attribute ::= SEQUENCE { type, values SET }
+        let (attribute_type, values) = read_ber_element(attribute.contents)?;
+        if attribute_type.contents != SIGNING_TIME_OID {
+            return None;
+        }
+        let (values, _) = read_ber_element(values)?;
+        let (value, _) = read_ber_element(values.contents)?;
+        parse_asn1_time(value)
+    })?;
+
+    Some((certificates, signing_time))
+}
+
+/// Parses the UTC time of an ASN.1 `UTCTime` or `GeneralizedTime` element.
+fn parse_asn1_time(element: BerElement<'_>) -> Option<time::PrimitiveDateTime> {
+    let value = std::str::from_utf8(element.contents)
+        .ok()?
+        .strip_suffix('Z')?;
+    let (year, value) = match element.tag {
+        // YYMMDDHHMMSS, with years from 1950 to 2049
+        0x17 => {
+            let year: i32 = value.get(..2)?.parse().ok()?;
+            (
+                if year < 50 { 2000 + year } else { 1900 + year },
+                value.get(2..)?,
+            )
+        }
+        // YYYYMMDDHHMMSS
+        0x18 => (value.get(..4)?.parse().ok()?, value.get(4..)?),
+        _ => return None,
+    };
+    let field = |range: std::ops::Range<usize>| value.get(range)?.parse::<u8>().ok();
+
+    let date = time::Date::from_calendar_date(
+        year,
+        time::Month::try_from(field(0..2)?).ok()?,
+        field(2..4)?,
+    )
+    .ok()?;
+    let time = time::Time::from_hms(field(4..6)?, field(6..8)?, field(8..10)?).ok()?;
+    Some(time::PrimitiveDateTime::new(date, time))
+}
+
+/// Whether the certificate has an extension of the given DER encoded object identifier.
+fn has_extension(certificate: &X509Ref, oid: &[u8]) -> bool {
+    let Ok(certificate) = certificate.to_der() else {
+        return false;
+    };
+    // Certificate ::= SEQUENCE { tbsCertificate SEQUENCE { ..., extensions [3] EXPLICIT }, ... }
+    let extensions = read_ber_element(&certificate)
+        .and_then(|(certificate, _)| read_ber_element(certificate.contents))
+        .and_then(|(tbs_certificate, _)| {
+            ber_elements(tbs_certificate.contents).find(|element| element.tag == 0xa3)
+        })
+        .and_then(|extensions| read_ber_element(extensions.contents));
+    let Some((extensions, _)) = extensions else {
+        return false;
+    };
+
+    // Extension ::= SEQUENCE { extnID, critical BOOLEAN DEFAULT FALSE, extnValue OCTET STRING }
+    ber_elements(extensions.contents).any(|extension| {
+        read_ber_element(extension.contents)
+            .map_or(false, |(extension_id, _)| extension_id.contents == oid)
+    })
+}
+
+/// Elliptic curve Diffie-Hellman shared secret of the merchant and the ephemeral key of the token.
+fn get_shared_secret(
+    certificate_key: &PKey<Private>,
+    ephemeral_public_key: &str,
+) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+    let ephemeral_public_key = consts::BASE64_ENGINE
+        .decode(ephemeral_public_key)
+        .into_report()
+        .change_context(errors::EncryptionError)?;
+    let ephemeral_public_key = PKey::public_key_from_der(&ephemeral_public_key)
+        .into_report()
+        .change_context(errors::EncryptionError)?;
+
+    let mut deriver = Deriver::new(certificate_key)
+        .into_report()
+        .change_context(errors::EncryptionError)?;
+    deriver
+        .set_peer(&ephemeral_public_key)
+        .into_report()
+        .change_context(errors::EncryptionError)?;
+    deriver
+        .derive_to_vec()
+        .into_report()
+        .change_context(errors::EncryptionError)
+}
+
+/// Symmetric key of `EC_v1` tokens, derived from the shared secret with the single step key
+/// derivation function of NIST SP 800-56A, as specified by Apple.
+fn derive_symmetric_key(shared_secret: &[u8], merchant_identifier: &str) -> [u8; 32] {
+    const KDF_ALGORITHM: &[u8] = b"\x0did-aes256-GCM";
+    const KDF_PARTY_U: &[u8] = b"Apple";
+    let kdf_party_v = openssl::sha::sha256(merchant_identifier.as_bytes());
+
+    let mut hasher = openssl::sha::Sha256::new();
+    hasher.update(&1_u32.to_be_bytes());
+    hasher.update(shared_secret);
+    hasher.update(KDF_ALGORITHM);
+    hasher.update(KDF_PARTY_U);
+    hasher.update(&kdf_party_v);
+    hasher.finish()
+}
+
+/// Symmetric key of `RSA_v1` tokens, encrypted with RSA-OAEP and SHA-256.
+fn unwrap_symmetric_key(
+    certificate_key: &PKey<Private>,
+    wrapped_key: &str,
+) -> CustomResult<Vec<u8>, errors::EncryptionError> {
+    let wrapped_key = consts::BASE64_ENGINE
+        .decode(wrapped_key)
+        .into_report()
+        .change_context(errors::EncryptionError)?;
+
+    let unwrap = || {
+        let mut decrypter = Decrypter::new(certificate_key)?;
+        decrypter.set_rsa_padding(Padding::PKCS1_OAEP)?;
+        decrypter.set_rsa_oaep_md(MessageDigest::sha256())?;
+        decrypter.set_rsa_mgf1_md(MessageDigest::sha256())?;
+        let mut symmetric_key = vec![0; decrypter.decrypt_len(&wrapped_key)?];
+        let length = decrypter.decrypt(&wrapped_key, &mut symmetric_key)?;
+        symmetric_key.truncate(length);
+        Ok::<_, openssl::error::ErrorStack>(symmetric_key)
+    };
+    unwrap()
+        .into_report()
+        .change_context(errors::EncryptionError)
+        .attach_printable("Failed to unwrap the symmetric key of the Apple Pay payment token")
+}
+
+/// Device account number and cryptogram of the decrypted payment data, when it can be processed
+/// as a network token. Payment data in the EMV format, and device account numbers of networks
+/// without a supported token service, can only be processed by processors supporting Apple Pay.
+fn get_network_token_data(
+    decrypted_data: ApplepayDecryptedData,
+) -> Option<types::NetworkTokenData> {
+    if decrypted_data.payment_data_type != ApplepayPaymentDataType::ThreeDSecure {
+        return None;
+    }
+    let token_service = match decrypted_data
+        .application_primary_account_number
+        .peek()
+        .chars()
+        .next()
+    {
+        Some('4') => types::NetworkTokenService::Vts,
+        Some('2' | '5') => types::NetworkTokenService::Mdes,
+        _ => return None,
+    };
+    let expiration_date = decrypted_data.application_expiration_date;
+    let (Some(exp_year), Some(exp_month)) = (expiration_date.get(0..2), expiration_date.get(2..4))
+    else {
+        return None;
+    };
+
+    Some(types::NetworkTokenData {
+        token_exp_month: Secret::new(exp_month.to_string()),
+        token_exp_year: Secret::new(format!("20{exp_year}")),
+        token_number: decrypted_data.application_primary_account_number,
+        token_service,
+        cryptogram: decrypted_data.payment_data.online_payment_cryptogram,
+        eci: decrypted_data.payment_data.eci_indicator,
+    })
+}
+
+async fn decrypt_certificate_key(
+    conf: &settings::Settings,
+    encrypted_certificate_key: &str,
+) -> CustomResult<PKey<Private>, errors::EncryptionError> {
+    let key = get_certificate_encryption_key(conf).await?;
+    let encrypted_certificate_key = consts::BASE64_ENGINE
+        .decode(encrypted_certificate_key)
+        .into_report()
+        .change_context(errors::EncryptionError)?;
+    let certificate_key = card_vault::decrypt(key.peek(), &encrypted_certificate_key)?;
+    PKey::private_key_from_pem(&certificate_key)
+        .into_report()
+        .change_context(errors::EncryptionError)
+}
+
+async fn get_certificate_encryption_key(
+    conf: &settings::Settings,
+) -> CustomResult<&'static Secret<Vec<u8>>, errors::EncryptionError> {
+    CERTIFICATE_ENCRYPTION_KEY
+        .get_or_try_init(|| async {
+            let hex_key = read_certificate_encryption_key(conf).await?;
+            let key = hex::decode(hex_key.trim())
+                .into_report()
+                .change_context(errors::EncryptionError)
+                .attach_printable("Certificate encryption key is not a valid hex string")?;
+            if key.len() != KEY_LENGTH {
+                Err(report!(errors::EncryptionError)
+                    .attach_printable("Certificate encryption key must be 32 bytes long"))?
+            }
+            Ok(Secret::new(key))
+        })
+        .await
+}
+
+async fn get_apple_root_certificate(
+    conf: &settings::Settings,
+) -> CustomResult<&'static X509, errors::EncryptionError> {
+    APPLE_ROOT_CERTIFICATE
+        .get_or_try_init(|| async {
+            let certificate = std::fs::read(&conf.applepay_decryption.root_certificate_file)
+                .into_report()
+                .change_context(errors::EncryptionError)
+                .attach_printable("Failed to read the Apple root certificate file")?;
+            // Apple distributes the root certificate DER encoded
+            X509::from_pem(&certificate)
+                .or_else(|_| X509::from_der(&certificate))
+                .into_report()
+                .change_context(errors::EncryptionError)
+                .attach_printable("Apple root certificate is neither PEM nor DER encoded")
+        })
+        .await
+}
+
+async fn read_certificate_encryption_key(
+    conf: &settings::Settings,
+) -> CustomResult<String, errors::EncryptionError> {
+    #[cfg(feature = "kms")]
+    if !conf.applepay_decryption.kms_encrypted_key.is_empty() {
+        return crate::services::encryption::KeyHandler::get_kms_decrypted_key(
+            &conf.jwekey,
+            conf.applepay_decryption.kms_encrypted_key.clone(),
+        )
+        .await;
+    }
+
+    std::fs::read_to_string(&conf.applepay_decryption.key_file)
+        .into_report()
+        .change_context(errors::EncryptionError)
+        .attach_printable("Failed to read the certificate encryption key file")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use openssl::{
+        asn1::Asn1Time,
+        bn::BigNum,
+        ec::{EcGroup, EcKey},
+        encrypt::Encrypter,
+        nid::Nid,
+        rsa::Rsa,
+        x509::{
+            extension::{BasicConstraints, KeyUsage},
+            X509Extension, X509NameBuilder,
+        },
+    };
+
+    use super::*;
+
+    const MERCHANT_IDENTIFIER: &str = "merchant.com.example";
+
+    const LEAF_OID: Option<&str> = Some("1.2.840.113635.100.6.29");
+
+    const TRANSACTION_ID: &str = "d3b28af6ea1bff0d2d9ec8d9e4d3b1fe35c1e5e8f8e1b0e3e2c4a5b6c7d8e9f0";
+
+    /// Certificates payment tokens are signed with, in place of the Apple ones
+    struct SigningCertificates {
+        root: X509,
+        intermediate: X509,
+        leaf: X509,
+        leaf_key: PKey<Private>,
+    }
+
+    impl SigningCertificates {
+        fn new(leaf_oid: Option<&str>) -> Self {
+            let root_key = generate_ec_key();
+            let root = issue_certificate("Root CA", &root_key, None, true, None);
+            let intermediate_key = generate_ec_key();
+            let intermediate = issue_certificate(
+                "Intermediate CA",
+                &intermediate_key,
+                Some((&root, &root_key)),
+                true,
+                Some("1.2.840.113635.100.6.2.14"),
+            );
+            let leaf_key = generate_ec_key();
+            let leaf = issue_certificate(
+                "Leaf",
+                &leaf_key,
+                Some((&intermediate, &intermediate_key)),
+                false,
+                leaf_oid,
+            );
+            Self {
+                root,
+                intermediate,
+                leaf,
+                leaf_key,
+            }
+        }
+
+        fn sign(&self, signed_data: &[u8]) -> String {
+            let mut certificates = Stack::new().unwrap();
+            certificates.push(self.intermediate.clone()).unwrap();
+            let signature = Pkcs7::sign(
+                &self.leaf,
+                &self.leaf_key,
+                &certificates,
+                signed_data,
+                Pkcs7Flags::DETACHED | Pkcs7Flags::BINARY,
+            )
+            .unwrap();
+            consts::BASE64_ENGINE.encode(signature.to_der().unwrap())
+        }
+    }
+
+    fn issue_certificate(
+        common_name: &str,
+        key: &PKey<Private>,
+        issuer: Option<(&X509, &PKey<Private>)>,
+        is_certificate_authority: bool,
+        extension_oid: Option<&str>,
+    ) -> X509 {
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", common_name).unwrap();
+        let name = name.build();
+
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        let serial_number = BigNum::from_u32(rand::random()).unwrap();
+        builder
+            .set_serial_number(&serial_number.to_asn1_integer().unwrap())
+            .unwrap();
+        builder.set_subject_name(&name).unwrap();
+        match issuer {
+            Some((issuer, _)) => builder.set_issuer_name(issuer.subject_name()).unwrap(),
+            None => builder.set_issuer_name(&name).unwrap(),
+        }
+        builder.set_pubkey(key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(1).unwrap())
+            .unwrap();
+        if is_certificate_authority {
+            builder
+                .append_extension(BasicConstraints::new().critical().ca().build().unwrap())
+                .unwrap();
+            builder
+                .append_extension(KeyUsage::new().critical().key_cert_sign().build().unwrap())
+                .unwrap();
+        }
+        if let Some(oid) = extension_oid {
+            builder
+                .append_extension(X509Extension::new(None, None, oid, "ASN1:NULL").unwrap())
+                .unwrap();
+        }
+        let signing_key = issuer.map_or(key, |(_, issuer_key)| issuer_key);
+        builder.sign(signing_key, MessageDigest::sha256()).unwrap();
+        builder.build()
+    }
+
+    fn decrypted_data(account_number: &str, payment_data_type: &str) -> serde_json::Value {
+        serde_json::json!({
+            "applicationPrimaryAccountNumber": account_number,
+            "applicationExpirationDate": "281231",
+            "currencyCode": "840",
+            "transactionAmount": 1000,
+            "deviceManufacturerIdentifier": "040010030273",
+            "paymentDataType": payment_data_type,
+            "paymentData": {
+                "onlinePaymentCryptogram": "AgAAAAAABk4DWZ4C28yUQAAAAAA=",
+                "eciIndicator": "7"
+            }
+        })
+    }
+
+    fn encrypt_payment_data(cipher: Cipher, key: &[u8], data: &serde_json::Value) -> String {
+        let mut tag = [0; GCM_TAG_LENGTH];
+        let mut encrypted_data = symm::encrypt_aead(
+            cipher,
+            key,
+            Some(&GCM_IV[..]),
+            &[],
+            data.to_string().as_bytes(),
+            &mut tag,
+        )
+        .unwrap();
+        encrypted_data.extend_from_slice(&tag);
+        consts::BASE64_ENGINE.encode(encrypted_data)
+    }
+
+    fn public_key_hash(key: &PKey<Private>) -> String {
+        consts::BASE64_ENGINE.encode(openssl::sha::sha256(&key.public_key_to_der().unwrap()))
+    }
+
+    fn generate_ec_key() -> PKey<Private> {
+        let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+        PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap()
+    }
+
+    /// Signature of the header key, the encrypted data and the transaction identifier of a token
+    fn sign_token(
+        certificates: &SigningCertificates,
+        header_key: &[u8],
+        encrypted_data: &str,
+    ) -> String {
+        let signed_data = [
+            header_key,
+            &consts::BASE64_ENGINE.decode(encrypted_data).unwrap(),
+            &hex::decode(TRANSACTION_ID).unwrap(),
+        ]
+        .concat();
+        certificates.sign(&signed_data)
+    }
+
+    fn ec_payment_data(
+        merchant_key: &PKey<Private>,
+        data: &serde_json::Value,
+        certificates: &SigningCertificates,
+    ) -> serde_json::Value {
+        let ephemeral_key = generate_ec_key();
+        let mut deriver = Deriver::new(&ephemeral_key).unwrap();
+        deriver.set_peer(merchant_key).unwrap();
+        let shared_secret = deriver.derive_to_vec().unwrap();
+        let symmetric_key = derive_symmetric_key(&shared_secret, MERCHANT_IDENTIFIER);
+        let ephemeral_public_key = ephemeral_key.public_key_to_der().unwrap();
+        let encrypted_data = encrypt_payment_data(Cipher::aes_256_gcm(), &symmetric_key, data);
+
+        serde_json::json!({
+            "version": "EC_v1",
+            "signature": sign_token(certificates, &ephemeral_public_key, &encrypted_data),
+            "data": encrypted_data,
+            "header": {
+                "ephemeralPublicKey": consts::BASE64_ENGINE.encode(ephemeral_public_key),
+                "publicKeyHash": public_key_hash(merchant_key),
+                "transactionId": TRANSACTION_ID
+            }
+        })
+    }
+
+    fn ec_token(
+        merchant_key: &PKey<Private>,
+        data: &serde_json::Value,
+        certificates: &SigningCertificates,
+    ) -> String {
+        serde_json::json!({
+            "paymentData": ec_payment_data(merchant_key, data, certificates),
+            "paymentMethod": {
+                "displayName": "Visa 0224",
+                "network": "Visa",
+                "type": "debit"
+            },
+            "transactionIdentifier": "D3B28AF6EA1BFF0D2D9EC8D9E4D3B1FE35C1E5E8F8E1B0E3E2C4A5B6C7D8E9F0"
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn test_decrypt_ec_token() {
+        let certificates = SigningCertificates::new(LEAF_OID);
+        let merchant_key = generate_ec_key();
+        let token = ec_token(
+            &merchant_key,
+            &decrypted_data("4761120010000492", "3DSecure"),
+            &certificates,
+        );
+
+        let decrypted_data = decrypt_token(
+            &token,
+            &merchant_key,
+            MERCHANT_IDENTIFIER,
+            &certificates.root,
+        )
+        .unwrap();
+        let network_token = get_network_token_data(decrypted_data).unwrap();
+        assert_eq!(network_token.token_number.peek(), "4761120010000492");
+        assert_eq!(network_token.token_exp_month.peek(), "12");
+        assert_eq!(network_token.token_exp_year.peek(), "2028");
+        assert_eq!(network_token.token_service, types::NetworkTokenService::Vts);
+        assert_eq!(
+            network_token.cryptogram.peek(),
+            "AgAAAAAABk4DWZ4C28yUQAAAAAA="
+        );
+        assert_eq!(network_token.eci.as_deref(), Some("7"));
+
+        // The symmetric key is derived from the merchant identifier
+        assert!(decrypt_token(
+            &token,
+            &merchant_key,
+            "merchant.com.other",
+            &certificates.root
+        )
+        .is_err());
+        // Tokens encrypted for another certificate are rejected
+        assert!(decrypt_token(
+            &token,
+            &generate_ec_key(),
+            MERCHANT_IDENTIFIER,
+            &certificates.root
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_decrypt_rsa_token() {
+        let certificates = SigningCertificates::new(LEAF_OID);
+        let merchant_key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+        let symmetric_key: [u8; 16] = rand::random();
+
+        let mut encrypter = Encrypter::new(&merchant_key).unwrap();
+        encrypter.set_rsa_padding(Padding::PKCS1_OAEP).unwrap();
+        encrypter.set_rsa_oaep_md(MessageDigest::sha256()).unwrap();
+        encrypter.set_rsa_mgf1_md(MessageDigest::sha256()).unwrap();
+        let mut wrapped_key = vec![0; encrypter.encrypt_len(&symmetric_key).unwrap()];
+        let length = encrypter.encrypt(&symmetric_key, &mut wrapped_key).unwrap();
+        wrapped_key.truncate(length);
+
+        let data = decrypted_data("5204245250001488", "3DSecure");
+        let encrypted_data = encrypt_payment_data(Cipher::aes_128_gcm(), &symmetric_key, &data);
+        let token = serde_json::json!({
+            "version": "RSA_v1",
+            "signature": sign_token(&certificates, &wrapped_key, &encrypted_data),
+            "data": encrypted_data,
+            "header": {
+                "wrappedKey": consts::BASE64_ENGINE.encode(wrapped_key),
+                "publicKeyHash": public_key_hash(&merchant_key),
+                "transactionId": TRANSACTION_ID
+            }
+        })
+        .to_string();
+
+        let decrypted_data = decrypt_token(
+            &token,
+            &merchant_key,
+            MERCHANT_IDENTIFIER,
+            &certificates.root,
+        )
+        .unwrap();
+        let network_token = get_network_token_data(decrypted_data).unwrap();
+        assert_eq!(network_token.token_number.peek(), "5204245250001488");
+        assert_eq!(
+            network_token.token_service,
+            types::NetworkTokenService::Mdes
+        );
+    }
+
+    #[test]
+    fn test_unsupported_payment_data_is_not_a_network_token() {
+        let emv: ApplepayDecryptedData =
+            serde_json::from_value(decrypted_data("4761120010000492", "EMV")).unwrap();
+        assert!(get_network_token_data(emv).is_none());
+
+        let amex: ApplepayDecryptedData =
+            serde_json::from_value(decrypted_data("374245455400126", "3DSecure")).unwrap();
+        assert!(get_network_token_data(amex).is_none());
+    }
+
+    #[test]
+    fn test_verify_signature() {
+        let certificates = SigningCertificates::new(LEAF_OID);
+        let data = decrypted_data("4761120010000492", "3DSecure");
+        let payment_data = ec_payment_data(&generate_ec_key(), &data, &certificates);
+
+        let valid: ApplepayPaymentData = serde_json::from_value(payment_data.clone()).unwrap();
+        let signing_time = verify_signature(&valid, &certificates.root).unwrap();
+        assert!(validate_signing_time(signing_time, date_time::now()).is_ok());
+
+        // Tampered tokens are rejected
+        let mut tampered: ApplepayPaymentData = serde_json::from_value(payment_data).unwrap();
+        tampered.header.transaction_id = "00".repeat(32);
+        assert!(verify_signature(&tampered, &certificates.root).is_err());
+
+        // Tokens signed by another certificate authority are rejected
+        let foreign_root = SigningCertificates::new(LEAF_OID).root;
+        assert!(verify_signature(&valid, &foreign_root).is_err());
+
+        // Tokens signed by certificates which are not payment token leaf certificates are rejected
+        let certificates = SigningCertificates::new(None);
+        let payment_data = ec_payment_data(&generate_ec_key(), &data, &certificates);
+        let unmarked: ApplepayPaymentData = serde_json::from_value(payment_data).unwrap();
+        assert!(verify_signature(&unmarked, &certificates.root).is_err());
+    }
+
+    #[test]
+    fn test_validate_signing_time() {
+        let now = date_time::now();
+        assert!(validate_signing_time(now - time::Duration::minutes(4), now).is_ok());
+        assert!(validate_signing_time(now - time::Duration::minutes(6), now).is_err());
+        assert!(validate_signing_time(now + time::Duration::minutes(6), now).is_err());
+    }
+
+    #[test]
+    fn test_read_ber_element_of_indefinite_length() {
+        let data = [0x30, 0x80, 0x04, 0x01, 0xaa, 0x00, 0x00, 0x05, 0x00];
+        let (element, rest) = read_ber_element(&data).unwrap();
+        assert_eq!(element.tag, 0x30);
+        assert_eq!(element.contents, [0x04, 0x01, 0xaa]);
+        assert_eq!(element.encoding.len(), 7);
+        assert_eq!(rest, [0x05, 0x00]);
+    }
+
+    #[test]
+    fn test_read_ber_element_rejects_deep_nesting() {
+        let nested = |depth| {
+            let mut data = [0x30, 0x80].repeat(depth);
+            data.extend([0x05, 0x00]);
+            data.extend([0x00, 0x00].repeat(depth));
+            data
+        };
+        assert!(read_ber_element(&nested(MAX_BER_DEPTH)).is_some());
+        assert!(read_ber_element(&nested(MAX_BER_DEPTH + 2)).is_none());
+        assert!(read_ber_element(&nested(100_000)).is_none());
+    }
+
+    #[test]
+    fn test_should_decrypt_payment_token() {
+        let should_decrypt = |connector| {
+            should_decrypt_payment_token(&capabilities::get_connector_capabilities(connector))
+        };
+        // Adyen decrypts Apple Pay payment tokens itself
+        assert!(!should_decrypt(api::enums::Connector::Adyen));
+        assert!(should_decrypt(api::enums::Connector::Checkout));
+        // Shift4 accepts neither Apple Pay nor network tokens
+        assert!(!should_decrypt(api::enums::Connector::Shift4));
+    }
+}
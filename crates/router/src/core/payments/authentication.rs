@@ -0,0 +1,242 @@
+use std::{fmt::Debug, str::FromStr};
+
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{self, flows::ConstructFlowSpecificData, helpers, PaymentData},
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        self,
+        api::{self, enums as api_enums},
+        storage::{self, enums as storage_enums},
+    },
+    utils,
+};
+
+pub const AUTHENTICATION_FAILED_ERROR_CODE: &str = "authentication_failed";
+
+/// Returns the authentication connector configured for the merchant, if the payment is to be
+/// authenticated before it is authorized.
+#[instrument(skip_all)]
+pub async fn get_authentication_connector<F: Clone, Op: Debug>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    operation: &Op,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<Option<(String, api::AuthenticationConnectorData)>> {
+    // Payments made with a mandate are not authenticated by the customer
+    let is_authenticated_by_customer = format!("{operation:?}") == "PaymentConfirm"
+        && payment_data.mandate_id.is_none()
+        && payment_data
+            .payment_attempt
+            .authentication_type
+            .unwrap_or_default()
+            == storage_enums::AuthenticationType::ThreeDs
+        && matches!(
+            payment_data.payment_method_data,
+            Some(api::PaymentMethod::Card(_))
+        );
+    if !is_authenticated_by_customer {
+        return Ok(None);
+    }
+
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_list(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the merchant connector accounts")?;
+
+    merchant_connector_accounts
+        .into_iter()
+        .find(|mca| {
+            mca.connector_type == storage_enums::ConnectorType::AuthenticationProcessor
+                && !mca.disabled.unwrap_or(false)
+                && api_enums::AuthenticationConnectors::from_str(&mca.connector_name).is_ok()
+        })
+        .map(|mca| {
+            api::AuthenticationConnectorData::get_connector_by_name(&mca.connector_name)
+                .map(|connector| (mca.connector_name, connector))
+        })
+        .transpose()
+}
+
+/// Authenticates the payment with the authentication connector, so that the processor authorizes
+/// it with the result of the authentication.
+///
+/// When a challenge is required, the payment is stopped until the customer completes it and comes
+/// back to the router, where the payment is confirmed again with the result of the authentication.
+/// When the authentication connector cannot be reached, the payment is authorized without the
+/// result of an authentication, leaving 3DS to the processor. Returns whether the payment is to be
+/// sent to the processor.
+#[instrument(skip_all)]
+pub async fn authenticate_payment<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    connector_name: &str,
+    connector: &api::AuthenticationConnectorData,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<bool> {
+    let authentication_payment_data: PaymentData<api::Authenticate> =
+        helpers::change_payment_data_flow(payment_data.clone());
+    let router_data: types::ConnectorAuthenticationRouterData = authentication_payment_data
+        .construct_router_data(state, connector_name, merchant_account)
+        .await?;
+
+    let response = match router_data
+        .decide_flow(state, connector, payments::CallConnectorAction::Trigger)
+        .await
+    {
+        Ok(router_data) => router_data.response,
+        Err(error) => {
+            logger::error!(?error, "Failed to call the authentication connector");
+            return Ok(true);
+        }
+    };
+
+    let response = match response {
+        Ok(response) => response,
+        Err(error_response) => {
+            logger::error!(
+                ?error_response,
+                "The authentication connector failed to authenticate the payment"
+            );
+            return Ok(true);
+        }
+    };
+
+    match response.status {
+        types::AuthenticationStatus::Authenticated | types::AuthenticationStatus::Attempted => {
+            payment_data.authentication_data = response.authentication_data;
+            Ok(true)
+        }
+        types::AuthenticationStatus::ChallengeRequired => match response.redirection_data {
+            Some(redirection_data) => {
+                await_challenge(
+                    state,
+                    merchant_account,
+                    response.authentication_id,
+                    redirection_data,
+                    payment_data,
+                )
+                .await?;
+                Ok(false)
+            }
+            // The customer came back without completing the challenge
+            None if payment_data.payment_attempt.authentication_id.is_some() => Ok(false),
+            None => {
+                logger::warn!(
+                    "The authentication connector required a challenge without a challenge URL"
+                );
+                Ok(true)
+            }
+        },
+        types::AuthenticationStatus::Unavailable => Ok(true),
+        types::AuthenticationStatus::Failed | types::AuthenticationStatus::Rejected => {
+            let db = &*state.store;
+            payment_data.payment_attempt = db
+                .update_payment_attempt(
+                    payment_data.payment_attempt.clone(),
+                    storage::PaymentAttemptUpdate::ErrorUpdate {
+                        connector: None,
+                        status: storage_enums::AttemptStatus::AuthenticationFailed,
+                        error_code: Some(AUTHENTICATION_FAILED_ERROR_CODE.to_string()),
+                        error_message: Some(
+                            response.reason.unwrap_or_else(|| {
+                                "The customer was not authenticated".to_string()
+                            }),
+                        ),
+                        decline_code: None,
+                    },
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+                })?;
+
+            payment_data.payment_intent = db
+                .update_payment_intent(
+                    payment_data.payment_intent.clone(),
+                    storage::PaymentIntentUpdate::PGStatusUpdate {
+                        status: storage_enums::IntentStatus::Failed,
+                    },
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .map_err(|error| {
+                    error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+                })?;
+
+            Ok(false)
+        }
+    }
+}
+
+/// Whether the payment is stopped until the customer completes the challenge of its
+/// authentication, the processor not having been called yet
+pub fn is_awaiting_challenge(payment_attempt: &storage::PaymentAttempt) -> bool {
+    payment_attempt.status == storage_enums::AttemptStatus::AuthenticationPending
+        && payment_attempt.authentication_id.is_some()
+        && payment_attempt.connector_transaction_id.is_none()
+}
+
+/// Stops the payment until the customer completes the challenge, sending the customer to the
+/// challenge through the start-pay URL of the payment.
+async fn await_challenge<F: Clone>(
+    state: &AppState,
+    merchant_account: &storage::MerchantAccount,
+    authentication_id: String,
+    redirection_data: services::RedirectForm,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    payment_data.payment_attempt = db
+        .update_payment_attempt(
+            payment_data.payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::AuthenticationUpdate {
+                status: storage_enums::AttemptStatus::AuthenticationPending,
+                authentication_id,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    let authentication_data =
+        utils::Encode::<services::RedirectForm>::encode_to_value(&redirection_data)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encode the challenge of the authentication")?;
+    payment_data.connector_response = db
+        .update_connector_response(
+            payment_data.connector_response.clone(),
+            storage::ConnectorResponseUpdate::ResponseUpdate {
+                connector_transaction_id: None,
+                authentication_data: Some(authentication_data),
+                encoded_data: None,
+                connector_name: None,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: storage_enums::IntentStatus::RequiresCustomerAction,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| error.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))?;
+
+    Ok(())
+}
@@ -0,0 +1,294 @@
+use std::str::FromStr;
+
+use crate::types::{self, api::enums as api_enums, storage::enums::DeclineCode};
+
+/// The error codes of a connector, along with the decline code each of them is unified to.
+type DeclineCodeMapping = &'static [(&'static str, DeclineCode)];
+
+/// Error codes and `decline_code`s returned by Stripe
+const STRIPE_DECLINE_CODES: DeclineCodeMapping = &[
+    ("insufficient_funds", DeclineCode::InsufficientFunds),
+    ("do_not_honor", DeclineCode::DoNotHonor),
+    ("expired_card", DeclineCode::ExpiredCard),
+    ("invalid_expiry_month", DeclineCode::InvalidExpiryDate),
+    ("invalid_expiry_year", DeclineCode::InvalidExpiryDate),
+    ("incorrect_cvc", DeclineCode::IncorrectCvc),
+    ("invalid_cvc", DeclineCode::IncorrectCvc),
+    ("incorrect_number", DeclineCode::IncorrectNumber),
+    ("invalid_number", DeclineCode::IncorrectNumber),
+    ("invalid_amount", DeclineCode::InvalidAmount),
+    ("amount_too_large", DeclineCode::InvalidAmount),
+    ("card_not_supported", DeclineCode::CardNotSupported),
+    ("currency_not_supported", DeclineCode::CardNotSupported),
+    ("lost_card", DeclineCode::LostCard),
+    ("stolen_card", DeclineCode::StolenCard),
+    ("pickup_card", DeclineCode::RestrictedCard),
+    ("restricted_card", DeclineCode::RestrictedCard),
+    ("fraudulent", DeclineCode::FraudSuspected),
+    ("merchant_blacklist", DeclineCode::FraudSuspected),
+    (
+        "transaction_not_allowed",
+        DeclineCode::TransactionNotAllowed,
+    ),
+    ("not_permitted", DeclineCode::TransactionNotAllowed),
+    (
+        "authentication_required",
+        DeclineCode::AuthenticationRequired,
+    ),
+    ("card_velocity_exceeded", DeclineCode::CardVelocityExceeded),
+    (
+        "withdrawal_count_limit_exceeded",
+        DeclineCode::CardVelocityExceeded,
+    ),
+    ("issuer_not_available", DeclineCode::IssuerUnavailable),
+    ("try_again_later", DeclineCode::IssuerUnavailable),
+    ("processing_error", DeclineCode::ProcessingError),
+    ("generic_decline", DeclineCode::GenericDecline),
+    ("card_declined", DeclineCode::GenericDecline),
+];
+
+/// Refusal reason codes returned by Adyen
+const ADYEN_DECLINE_CODES: DeclineCodeMapping = &[
+    ("2", DeclineCode::GenericDecline),
+    ("4", DeclineCode::ProcessingError),
+    ("5", DeclineCode::RestrictedCard),
+    ("6", DeclineCode::ExpiredCard),
+    ("7", DeclineCode::InvalidAmount),
+    ("8", DeclineCode::IncorrectNumber),
+    ("9", DeclineCode::IssuerUnavailable),
+    ("10", DeclineCode::CardNotSupported),
+    ("12", DeclineCode::InsufficientFunds),
+    ("14", DeclineCode::FraudSuspected),
+    ("20", DeclineCode::FraudSuspected),
+    ("22", DeclineCode::FraudSuspected),
+    ("24", DeclineCode::IncorrectCvc),
+    ("25", DeclineCode::RestrictedCard),
+    ("27", DeclineCode::DoNotHonor),
+    ("28", DeclineCode::CardVelocityExceeded),
+    ("29", DeclineCode::CardVelocityExceeded),
+    ("31", DeclineCode::FraudSuspected),
+    ("38", DeclineCode::AuthenticationRequired),
+];
+
+/// Error codes and response codes returned by Checkout
+const CHECKOUT_DECLINE_CODES: DeclineCodeMapping = &[
+    ("card_expired", DeclineCode::ExpiredCard),
+    ("card_expiry_month_invalid", DeclineCode::InvalidExpiryDate),
+    ("card_expiry_year_invalid", DeclineCode::InvalidExpiryDate),
+    ("card_number_invalid", DeclineCode::IncorrectNumber),
+    ("cvv_invalid", DeclineCode::IncorrectCvc),
+    ("amount_invalid", DeclineCode::InvalidAmount),
+    ("20005", DeclineCode::DoNotHonor),
+    ("20013", DeclineCode::InvalidAmount),
+    ("20014", DeclineCode::IncorrectNumber),
+    ("20051", DeclineCode::InsufficientFunds),
+    ("20054", DeclineCode::ExpiredCard),
+    ("20057", DeclineCode::TransactionNotAllowed),
+    ("20059", DeclineCode::FraudSuspected),
+    ("20061", DeclineCode::CardVelocityExceeded),
+    ("20062", DeclineCode::RestrictedCard),
+    ("20065", DeclineCode::CardVelocityExceeded),
+    ("20091", DeclineCode::IssuerUnavailable),
+    ("30041", DeclineCode::LostCard),
+    ("30043", DeclineCode::StolenCard),
+];
+
+/// Error reasons returned by Cybersource
+const CYBERSOURCE_DECLINE_CODES: DeclineCodeMapping = &[
+    ("INSUFFICIENT_FUND", DeclineCode::InsufficientFunds),
+    ("EXCEEDS_CREDIT_LIMIT", DeclineCode::InsufficientFunds),
+    ("PROCESSOR_DECLINED", DeclineCode::DoNotHonor),
+    ("EXPIRED_CARD", DeclineCode::ExpiredCard),
+    ("INVALID_CVN", DeclineCode::IncorrectCvc),
+    ("CV_FAILED", DeclineCode::IncorrectCvc),
+    ("INVALID_ACCOUNT", DeclineCode::IncorrectNumber),
+    ("INVALID_AMOUNT", DeclineCode::InvalidAmount),
+    ("CARD_TYPE_NOT_ACCEPTED", DeclineCode::CardNotSupported),
+    ("STOLEN_LOST_CARD", DeclineCode::LostCard),
+    ("DECISION_PROFILE_REJECT", DeclineCode::FraudSuspected),
+    (
+        "CONSUMER_AUTHENTICATION_REQUIRED",
+        DeclineCode::AuthenticationRequired,
+    ),
+    ("PROCESSOR_UNAVAILABLE", DeclineCode::IssuerUnavailable),
+    ("SYSTEM_ERROR", DeclineCode::ProcessingError),
+    ("GENERAL_DECLINE", DeclineCode::GenericDecline),
+];
+
+/// Error codes returned by Shift4
+const SHIFT4_DECLINE_CODES: DeclineCodeMapping = &[
+    ("insufficient_funds", DeclineCode::InsufficientFunds),
+    ("expired_card", DeclineCode::ExpiredCard),
+    ("invalid_expiry_month", DeclineCode::InvalidExpiryDate),
+    ("invalid_expiry_year", DeclineCode::InvalidExpiryDate),
+    ("incorrect_cvc", DeclineCode::IncorrectCvc),
+    ("invalid_cvc", DeclineCode::IncorrectCvc),
+    ("invalid_number", DeclineCode::IncorrectNumber),
+    ("lost_or_stolen", DeclineCode::LostCard),
+    ("suspected_fraud", DeclineCode::FraudSuspected),
+    ("blacklisted", DeclineCode::FraudSuspected),
+    ("limit_exceeded", DeclineCode::CardVelocityExceeded),
+    (
+        "authentication_required",
+        DeclineCode::AuthenticationRequired,
+    ),
+    ("processing_error", DeclineCode::ProcessingError),
+    ("card_declined", DeclineCode::GenericDecline),
+];
+
+/// Response reason codes returned by Authorize.net
+const AUTHORIZEDOTNET_DECLINE_CODES: DeclineCodeMapping = &[
+    ("2", DeclineCode::GenericDecline),
+    ("3", DeclineCode::GenericDecline),
+    ("4", DeclineCode::RestrictedCard),
+    ("5", DeclineCode::InvalidAmount),
+    ("6", DeclineCode::IncorrectNumber),
+    ("7", DeclineCode::InvalidExpiryDate),
+    ("8", DeclineCode::ExpiredCard),
+    ("17", DeclineCode::CardNotSupported),
+    ("19", DeclineCode::ProcessingError),
+    ("37", DeclineCode::IncorrectNumber),
+    ("44", DeclineCode::IncorrectCvc),
+    ("45", DeclineCode::IncorrectCvc),
+    ("65", DeclineCode::IncorrectCvc),
+    ("250", DeclineCode::FraudSuspected),
+    ("251", DeclineCode::FraudSuspected),
+    ("254", DeclineCode::FraudSuspected),
+];
+
+/// Result codes returned by ACI
+const ACI_DECLINE_CODES: DeclineCodeMapping = &[
+    ("800.100.100", DeclineCode::GenericDecline),
+    ("800.100.151", DeclineCode::IncorrectNumber),
+    ("800.100.152", DeclineCode::DoNotHonor),
+    ("800.100.153", DeclineCode::IncorrectCvc),
+    ("800.100.155", DeclineCode::InsufficientFunds),
+    ("800.100.157", DeclineCode::InvalidExpiryDate),
+    ("800.100.158", DeclineCode::FraudSuspected),
+    ("800.100.159", DeclineCode::StolenCard),
+    ("800.100.160", DeclineCode::RestrictedCard),
+    ("800.100.161", DeclineCode::CardVelocityExceeded),
+    ("800.100.162", DeclineCode::CardVelocityExceeded),
+    ("800.100.163", DeclineCode::CardVelocityExceeded),
+    ("800.100.168", DeclineCode::RestrictedCard),
+    ("800.100.169", DeclineCode::CardNotSupported),
+    ("800.100.170", DeclineCode::TransactionNotAllowed),
+    ("800.100.171", DeclineCode::RestrictedCard),
+];
+
+/// Processor response codes returned by Braintree
+const BRAINTREE_DECLINE_CODES: DeclineCodeMapping = &[
+    ("2000", DeclineCode::DoNotHonor),
+    ("2001", DeclineCode::InsufficientFunds),
+    ("2002", DeclineCode::CardVelocityExceeded),
+    ("2003", DeclineCode::CardVelocityExceeded),
+    ("2004", DeclineCode::ExpiredCard),
+    ("2005", DeclineCode::IncorrectNumber),
+    ("2006", DeclineCode::InvalidExpiryDate),
+    ("2008", DeclineCode::IncorrectNumber),
+    ("2010", DeclineCode::IncorrectCvc),
+    ("2012", DeclineCode::LostCard),
+    ("2013", DeclineCode::StolenCard),
+    ("2014", DeclineCode::FraudSuspected),
+    ("2015", DeclineCode::TransactionNotAllowed),
+    ("2024", DeclineCode::CardNotSupported),
+    ("2038", DeclineCode::GenericDecline),
+    ("2046", DeclineCode::GenericDecline),
+    ("2047", DeclineCode::RestrictedCard),
+    ("2057", DeclineCode::RestrictedCard),
+    ("2099", DeclineCode::AuthenticationRequired),
+    ("3000", DeclineCode::IssuerUnavailable),
+];
+
+fn get_decline_code_mapping(connector: api_enums::Connector) -> Option<DeclineCodeMapping> {
+    match connector {
+        api_enums::Connector::Aci => Some(ACI_DECLINE_CODES),
+        api_enums::Connector::Adyen => Some(ADYEN_DECLINE_CODES),
+        api_enums::Connector::Authorizedotnet => Some(AUTHORIZEDOTNET_DECLINE_CODES),
+        api_enums::Connector::Braintree => Some(BRAINTREE_DECLINE_CODES),
+        api_enums::Connector::Checkout => Some(CHECKOUT_DECLINE_CODES),
+        api_enums::Connector::Cybersource => Some(CYBERSOURCE_DECLINE_CODES),
+        api_enums::Connector::Shift4 => Some(SHIFT4_DECLINE_CODES),
+        api_enums::Connector::Stripe => Some(STRIPE_DECLINE_CODES),
+        _ => None,
+    }
+}
+
+/// Unifies the error returned by the connector into a decline code, so that the reason for which a
+/// payment was declined can be understood without knowing the error codes of every connector.
+///
+/// The reason of the error is looked up before its code, as connectors returning both give a more
+/// specific reason. Errors which cannot be unified are not given a decline code.
+pub fn get_decline_code(
+    connector_name: &str,
+    error_response: &types::ErrorResponse,
+) -> Option<DeclineCode> {
+    let connector = api_enums::Connector::from_str(connector_name).ok()?;
+    let mapping = get_decline_code_mapping(connector)?;
+
+    [
+        error_response.reason.as_deref(),
+        Some(error_response.code.as_str()),
+    ]
+    .into_iter()
+    .flatten()
+    // Checkout returns several error codes, which are joined together
+    .flat_map(|code| code.split(" & "))
+    .find_map(|code| {
+        mapping
+            .iter()
+            .find(|(connector_code, _)| connector_code.eq_ignore_ascii_case(code.trim()))
+            .map(|(_, decline_code)| *decline_code)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_response(code: &str, reason: Option<&str>) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: code.to_string(),
+            message: "Your card was declined.".to_string(),
+            reason: reason.map(ToString::to_string),
+            status_code: 402,
+        }
+    }
+
+    #[test]
+    fn test_decline_code_prefers_the_reason_over_the_code() {
+        let error = error_response("card_declined", Some("insufficient_funds"));
+        assert_eq!(
+            get_decline_code("stripe", &error),
+            Some(DeclineCode::InsufficientFunds)
+        );
+
+        let error = error_response("card_declined", None);
+        assert_eq!(
+            get_decline_code("stripe", &error),
+            Some(DeclineCode::GenericDecline)
+        );
+    }
+
+    #[test]
+    fn test_decline_code_of_joined_error_codes() {
+        let error = error_response("request_invalid & card_expired", None);
+        assert_eq!(
+            get_decline_code("checkout", &error),
+            Some(DeclineCode::ExpiredCard)
+        );
+    }
+
+    #[test]
+    fn test_unknown_decline_code() {
+        assert_eq!(
+            get_decline_code("adyen", &error_response("999", None)),
+            None
+        );
+        assert_eq!(get_decline_code("klarna", &error_response("2", None)), None);
+        assert_eq!(
+            get_decline_code("unknown", &error_response("2", None)),
+            None
+        );
+    }
+}
@@ -1,3 +1,4 @@
+pub mod authenticate_flow;
 pub mod authorize_flow;
 pub mod cancel_flow;
 pub mod capture_flow;
@@ -0,0 +1,115 @@
+use std::marker::PhantomData;
+
+use async_trait::async_trait;
+use error_stack::ResultExt;
+
+use super::ConstructFlowSpecificData;
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResult, StorageErrorExt},
+        payments::{self, PaymentData},
+    },
+    routes::AppState,
+    services,
+    types::{self, api, storage},
+    utils::{OptionExt, ValueExt},
+};
+
+#[async_trait]
+impl
+    ConstructFlowSpecificData<
+        api::Authenticate,
+        types::ConnectorAuthenticationData,
+        types::AuthenticationResponseData,
+    > for PaymentData<api::Authenticate>
+{
+    async fn construct_router_data<'a>(
+        &self,
+        state: &AppState,
+        connector_id: &str,
+        merchant_account: &storage::MerchantAccount,
+    ) -> RouterResult<types::ConnectorAuthenticationRouterData> {
+        let merchant_connector_account = state
+            .store
+            .find_merchant_connector_account_by_merchant_id_connector(
+                &merchant_account.merchant_id,
+                connector_id,
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(
+                    errors::ApiErrorResponse::MerchantConnectorAccountNotFound,
+                )
+            })?;
+
+        let auth_type: types::ConnectorAuthType = merchant_connector_account
+            .connector_account_details
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while parsing value for ConnectorAuthType")?;
+
+        let authorize_data = types::PaymentsAuthorizeData::try_from(self.clone())?;
+        // The customer is sent back to the router once they complete a challenge
+        let router_return_url = Some(payments::helpers::create_redirect_url(
+            &state.conf.server,
+            &self.payment_attempt,
+            &merchant_connector_account.connector_name,
+        ));
+
+        Ok(types::RouterData {
+            flow: PhantomData,
+            merchant_id: merchant_account.merchant_id.clone(),
+            connector: merchant_connector_account.connector_name,
+            payment_id: self.payment_attempt.payment_id.clone(),
+            attempt_id: Some(self.payment_attempt.attempt_id.clone()),
+            status: self.payment_attempt.status,
+            payment_method: self
+                .payment_attempt
+                .payment_method
+                .get_required_value("payment_method_type")?,
+            connector_auth_type: auth_type,
+            description: self.payment_intent.description.clone(),
+            return_url: self.payment_intent.return_url.clone(),
+            router_return_url,
+            address: self.address.clone(),
+            auth_type: self.payment_attempt.authentication_type.unwrap_or_default(),
+            connector_meta_data: merchant_connector_account.metadata,
+            amount_captured: self.payment_intent.amount_captured,
+            access_token: None,
+            request: types::ConnectorAuthenticationData {
+                amount: authorize_data.amount,
+                currency: authorize_data.currency,
+                payment_method_data: authorize_data.payment_method_data,
+                email: authorize_data.email,
+                browser_info: authorize_data.browser_info,
+                authentication_id: self.payment_attempt.authentication_id.clone(),
+            },
+            response: Err(types::ErrorResponse::default()),
+            payment_method_id: self.payment_attempt.payment_method_id.clone(),
+        })
+    }
+}
+
+impl types::ConnectorAuthenticationRouterData {
+    pub async fn decide_flow<'a, 'b>(
+        &'b self,
+        state: &'a AppState,
+        connector: &api::AuthenticationConnectorData,
+        call_connector_action: payments::CallConnectorAction,
+    ) -> RouterResult<Self> {
+        let connector_integration: services::BoxedConnectorIntegration<
+            '_,
+            api::Authenticate,
+            types::ConnectorAuthenticationData,
+            types::AuthenticationResponseData,
+        > = connector.connector.get_connector_integration();
+        services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            self,
+            call_connector_action,
+        )
+        .await
+        .map_err(|error| error.to_payment_failed_response())
+    }
+}
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use common_utils::{ext_traits::AsyncExt, fp_utils};
 // TODO : Evaluate all the helper functions ()
 use error_stack::{report, IntoReport, ResultExt};
-use masking::ExposeOptionInterface;
+use masking::{ExposeOptionInterface, PeekInterface};
 use router_env::{instrument, tracing};
 use uuid::Uuid;
 
@@ -16,7 +16,7 @@ use crate::{
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        payment_methods::{cards, vault},
+        payment_methods::{bin_lookup, cards, network_tokenization, vault},
     },
     db::StorageInterface,
     routes::AppState,
@@ -172,13 +172,6 @@ pub async fn get_token_for_recurring_mandate(
             error.to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
         })?;
 
-    let token = Uuid::new_v4().to_string();
-    let locker_id = merchant_account
-        .locker_id
-        .to_owned()
-        .get_required_value("locker_id")?;
-    let _ = cards::get_lookup_key_from_locker(state, &token, &payment_method, &locker_id).await?;
-
     if let Some(payment_method_from_request) = req.payment_method {
         let pm: storage_enums::PaymentMethodType = payment_method_from_request.foreign_into();
         if pm != payment_method.payment_method {
@@ -190,6 +183,21 @@ pub async fn get_token_for_recurring_mandate(
         }
     };
 
+    // Bank accounts are not stored in the locker, they are debited through the mandate held
+    // by the connector
+    if payment_method.payment_method == storage_enums::PaymentMethodType::BankDebit {
+        utils::when(mandate.connector_mandate_id.is_none(), || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "mandate has not been set up with the connector".into()
+            }))
+        })?;
+        return Ok((None, Some(payment_method.payment_method)));
+    }
+
+    let token = Uuid::new_v4().to_string();
+    let _ =
+        cards::get_lookup_key_from_locker(state, &token, &payment_method, merchant_account).await?;
+
     Ok((Some(token), Some(payment_method.payment_method)))
 }
 
@@ -520,6 +528,7 @@ pub(crate) async fn call_payment_method(
                                 payment_method_issuer: None,
                                 payment_method_issuer_code: None,
                                 card: Some(card_detail),
+                                bank_account: None,
                                 metadata: None,
                                 customer_id: Some(customer_id),
                             };
@@ -544,6 +553,38 @@ pub(crate) async fn call_payment_method(
                         .attach_printable("Missing Customer Object")),
                     }
                 }
+                api::PaymentMethod::BankDebit(bank_debit) => match maybe_customer {
+                    Some(customer) => {
+                        let payment_method_request = api::CreatePaymentMethod {
+                            payment_method: payment_method_type.foreign_into(),
+                            payment_method_type: Some(bank_debit.get_bank_debit_type()),
+                            payment_method_issuer: None,
+                            payment_method_issuer_code: None,
+                            card: None,
+                            bank_account: Some(bank_debit.clone()),
+                            metadata: None,
+                            customer_id: Some(customer.customer_id.clone()),
+                        };
+                        let resp = cards::add_payment_method(
+                            state,
+                            payment_method_request,
+                            merchant_account,
+                        )
+                        .await
+                        .attach_printable("Error on adding payment method")?;
+                        match resp {
+                            crate::services::ApplicationResponse::Json(payment_method) => {
+                                Ok(payment_method)
+                            }
+                            _ => Err(report!(errors::ApiErrorResponse::InternalServerError)
+                                .attach_printable("Error on adding payment method")),
+                        }
+                    }
+                    None => Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                        field_name: "customer"
+                    })
+                    .attach_printable("Missing Customer Object")),
+                },
                 _ => {
                     let payment_method_request = api::CreatePaymentMethod {
                         payment_method: payment_method_type.foreign_into(),
@@ -551,6 +592,7 @@ pub(crate) async fn call_payment_method(
                         payment_method_issuer: None,
                         payment_method_issuer_code: None,
                         card: None,
+                        bank_account: None,
                         metadata: None,
                         customer_id: None,
                     };
@@ -700,6 +742,23 @@ pub async fn make_pm_data<'a, F: Clone, R>(
                 Some(api::PaymentMethod::Card(card)) => {
                     payment_data.payment_attempt.payment_method =
                         Some(storage_enums::PaymentMethodType::Card);
+                    if payment_data.payment_attempt.payment_method_id.is_none() {
+                        payment_data.payment_attempt.payment_method_id =
+                            supplementary_data.payment_method_id.clone();
+                    }
+                    if let Some(payment_method_id) = supplementary_data
+                        .payment_method_id
+                        .as_deref()
+                        .filter(|_| state.conf.network_tokenization.enabled)
+                    {
+                        payment_data.network_token = network_tokenization::get_network_token_data(
+                            state,
+                            payment_method_id,
+                            payment_data.amount.into(),
+                            payment_data.currency,
+                        )
+                        .await;
+                    }
                     if let Some(cvc) = card_cvc {
                         let mut updated_card = card;
                         updated_card.card_cvc = cvc;
@@ -757,6 +816,11 @@ pub async fn make_pm_data<'a, F: Clone, R>(
             Ok(pm_opt.to_owned())
         }
         (pm @ Some(api::PaymentMethod::PayLater(_)), _) => Ok(pm.to_owned()),
+        // Bank account details are not stored in the locker
+        (pm @ Some(api::PaymentMethod::BankDebit(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethod::BankRedirect(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethod::LocalBankTransfer(_)), _) => Ok(pm.to_owned()),
+        (pm @ Some(api::PaymentMethod::Upi(_)), _) => Ok(pm.to_owned()),
         (pm_opt @ Some(pm @ api::PaymentMethod::Wallet(_)), _) => {
             let token = vault::Vault::store_payment_method_data_in_locker(
                 state,
@@ -771,9 +835,25 @@ pub async fn make_pm_data<'a, F: Clone, R>(
         _ => Ok(None),
     }?;
 
+    if let Some(api::PaymentMethod::Card(card)) = &payment_method {
+        payment_data.card_info =
+            bin_lookup::get_card_info(&state.conf.bin_lookup, card.card_number.peek());
+    }
+
     Ok((operation, payment_method))
 }
 
+/// Encode the card details looked up for the payment, to be stored on the payment attempt.
+pub(crate) fn encode_card_info(
+    card_info: Option<&api::CardInfo>,
+) -> RouterResult<Option<serde_json::Value>> {
+    card_info
+        .map(utils::Encode::<api::CardInfo>::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode card info")
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_capture_method(
     capture_method: storage_enums::CaptureMethod,
@@ -821,6 +901,26 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+/// Bank debits need the customer's authorization to debit their bank account, which is recorded
+/// as a mandate.
+#[instrument(skip_all)]
+pub(crate) fn validate_bank_debit_mandate(req: &api::PaymentsRequest) -> RouterResult<()> {
+    utils::when(
+        matches!(
+            req.payment_method_data,
+            Some(api::PaymentMethod::BankDebit(_))
+        ) && req.mandate_data.is_none()
+            && req.mandate_id.is_none(),
+        || {
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "mandate_data",
+            })
+        },
+    )?;
+
+    Ok(())
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
     req: &api::PaymentsRequest,
@@ -1085,6 +1185,7 @@ pub fn generate_mandate(
     setup_mandate_details: Option<api::MandateData>,
     customer: &Option<storage::Customer>,
     payment_method_id: String,
+    payment_method: storage_enums::PaymentMethodType,
     connector_mandate_id: Option<String>,
 ) -> Option<storage::MandateNew> {
     match (setup_mandate_details, customer) {
@@ -1099,6 +1200,7 @@ pub fn generate_mandate(
                 .set_customer_id(cus.customer_id.clone())
                 .set_merchant_id(merchant_id)
                 .set_payment_method_id(payment_method_id)
+                .set_payment_method(Some(payment_method))
                 .set_connector(connector)
                 .set_mandate_status(storage_enums::MandateStatus::Active)
                 .set_connector_mandate_id(connector_mandate_id)
@@ -1210,6 +1312,34 @@ pub(crate) fn get_payment_id_from_client_secret(cs: &str) -> String {
     cs.split('_').take(2).collect::<Vec<&str>>().join("_")
 }
 
+/// Moves the payment data to another flow, to call the connector for that flow
+pub fn change_payment_data_flow<F: Clone, T: Clone>(
+    payment_data: PaymentData<F>,
+) -> PaymentData<T> {
+    PaymentData {
+        flow: std::marker::PhantomData,
+        payment_intent: payment_data.payment_intent,
+        payment_attempt: payment_data.payment_attempt,
+        connector_response: payment_data.connector_response,
+        amount: payment_data.amount,
+        mandate_id: payment_data.mandate_id,
+        currency: payment_data.currency,
+        setup_mandate: payment_data.setup_mandate,
+        address: payment_data.address,
+        token: payment_data.token,
+        confirm: payment_data.confirm,
+        force_sync: payment_data.force_sync,
+        payment_method_data: payment_data.payment_method_data,
+        refunds: payment_data.refunds,
+        sessions_token: payment_data.sessions_token,
+        card_cvc: payment_data.card_cvc,
+        email: payment_data.email,
+        network_token: payment_data.network_token,
+        authentication_data: payment_data.authentication_data,
+        card_info: payment_data.card_info,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
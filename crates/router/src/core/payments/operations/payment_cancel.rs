@@ -98,8 +98,11 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
 
         payment_attempt.cancellation_reason = request.cancellation_reason.clone();
 
+        // A partially captured payment can be cancelled to release the rest of its authorization
+        let is_partially_captured = payment_attempt.status == enums::AttemptStatus::PartialCharged;
+
         match payment_intent.status {
-            status if status != enums::IntentStatus::RequiresCapture => {
+            status if status != enums::IntentStatus::RequiresCapture && !is_partially_captured => {
                 Err(errors::ApiErrorResponse::InvalidRequestData {
                     message: "You cannot cancel the payment that has not been authorized"
                         .to_string(),
@@ -129,6 +132,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
                     connector_response,
                     sessions_token: vec![],
                     card_cvc: None,
+                    network_token: None,
+                    authentication_data: None,
+                    card_info: None,
                 },
                 None,
             )),
@@ -8,6 +8,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        fraud_check,
         payments::{self, helpers, operations},
     },
     db::StorageInterface,
@@ -82,6 +83,13 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
 
         helpers::validate_capture_method(capture_method)?;
 
+        let fraud_checks = db
+            .find_fraud_checks_by_merchant_id_attempt_id(merchant_id, &payment_attempt.attempt_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the fraud checks of the payment attempt")?;
+        fraud_check::validate_capture(&fraud_checks)?;
+
         currency = payment_attempt.currency.get_required_value("currency")?;
 
         amount = payment_attempt.amount.into();
@@ -139,6 +147,9 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: None,
+                network_token: None,
+                authentication_data: None,
+                card_info: None,
             },
             None,
         ))
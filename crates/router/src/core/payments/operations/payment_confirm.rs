@@ -9,6 +9,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        payment_methods::payment_token,
         payments::{helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
         utils as core_utils,
     },
@@ -110,6 +111,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             &token,
         )?;
 
+        if let Some(token) = &token {
+            payment_token::validate_payment_token(state, merchant_account, token).await?;
+        }
+
         payment_attempt.payment_method = payment_method_type.or(payment_attempt.payment_method);
         payment_attempt.browser_info = browser_info;
         currency = payment_attempt.currency.get_required_value("currency")?;
@@ -181,6 +186,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 refunds: vec![],
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
+                network_token: None,
+                authentication_data: None,
+                card_info: None,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -297,6 +305,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
 
         let connector = payment_data.payment_attempt.connector.clone();
         let payment_token = payment_data.token.clone();
+        let card_info = helpers::encode_card_info(payment_data.card_info.as_ref())?;
 
         payment_data.payment_attempt = db
             .update_payment_attempt(
@@ -310,6 +319,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     browser_info,
                     connector,
                     payment_token,
+                    card_info,
                 },
                 storage_scheme,
             )
@@ -376,6 +386,7 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentConfir
             })?;
 
         helpers::validate_payment_method_fields_present(request)?;
+        helpers::validate_bank_debit_mandate(request)?;
 
         let mandate_type = helpers::validate_mandate(request)?;
         let payment_id = core_utils::get_or_generate_id("payment_id", &given_payment_id, "pay")?;
@@ -12,6 +12,7 @@ use crate::{
     consts,
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        payment_methods::payment_token,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
         utils as core_utils,
     },
@@ -69,6 +70,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             )
             .await?;
 
+        if let (Some(true), Some(token)) = (request.confirm, &token) {
+            payment_token::validate_payment_token(state, merchant_account, token).await?;
+        }
+
         let shipping_address = helpers::get_address_for_payment_request(
             db,
             request.shipping.as_ref(),
@@ -158,6 +163,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 Some(mandate.map(|mandate_obj| api_models::payments::MandateIds {
                     mandate_id: mandate_obj.mandate_id,
                     connector_mandate_id: mandate_obj.connector_mandate_id,
+                    payment_method: mandate_obj.payment_method.map(ForeignInto::foreign_into),
                 }))
             })
             .await
@@ -192,6 +198,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
+                network_token: None,
+                authentication_data: None,
+                card_info: None,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -295,6 +304,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
 
         let payment_token = payment_data.token.clone();
         let connector = payment_data.payment_attempt.connector.clone();
+        let card_info = helpers::encode_card_info(payment_data.card_info.as_ref())?;
 
         payment_data.payment_attempt = db
             .update_payment_attempt(
@@ -302,6 +312,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                 storage::PaymentAttemptUpdate::UpdateTrackers {
                     payment_token,
                     connector,
+                    card_info,
                 },
                 storage_scheme,
             )
@@ -370,6 +381,7 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest> for PaymentCreate
         })?;
 
         helpers::validate_payment_method_fields_present(request)?;
+        helpers::validate_bank_debit_mandate(request)?;
 
         let payment_id = core_utils::get_or_generate_id("payment_id", &given_payment_id, "pay")?;
 
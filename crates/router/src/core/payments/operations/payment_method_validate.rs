@@ -151,6 +151,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::VerifyRequest> for Paym
                 refunds: vec![],
                 sessions_token: vec![],
                 card_cvc: None,
+                network_token: None,
+                authentication_data: None,
+                card_info: None,
             },
             Some(payments::CustomerDetails {
                 customer_id: request.customer_id.clone(),
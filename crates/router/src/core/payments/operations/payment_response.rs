@@ -6,9 +6,10 @@ use super::{Operation, PostUpdateTracker};
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::PaymentData,
+        payments::{decline_codes, PaymentData},
     },
     db::StorageInterface,
+    logger,
     services::RedirectForm,
     types::{
         self, api,
@@ -66,6 +67,31 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsSyncData> for
     where
         F: 'b + Send,
     {
+        // Connectors that vault the payment method once the customer approves the payment only
+        // return the reference to the mandate when the payment is completed
+        if let (
+            Some(mandate_id),
+            Ok(types::PaymentsResponseData::TransactionResponse {
+                mandate_reference: Some(connector_mandate_id),
+                ..
+            }),
+        ) = (
+            payment_data.payment_attempt.mandate_id.as_ref(),
+            response.response.as_ref(),
+        ) {
+            db.update_mandate_by_merchant_id_mandate_id(
+                &response.merchant_id,
+                mandate_id,
+                storage::MandateUpdate::ConnectorReferenceUpdate {
+                    connector_mandate_id: Some(connector_mandate_id.clone()),
+                },
+            )
+            .await
+            .map_err(|error| {
+                error.to_not_found_response(errors::ApiErrorResponse::MandateNotFound)
+            })?;
+        }
+
         payment_response_update_tracker(db, payment_id, payment_data, response, storage_scheme)
             .await
     }
@@ -167,6 +193,7 @@ async fn payment_response_update_tracker<F: Clone, T>(
             Some(storage::PaymentAttemptUpdate::ErrorUpdate {
                 connector: Some(router_data.connector.clone()),
                 status: storage::enums::AttemptStatus::Failure,
+                decline_code: decline_codes::get_decline_code(&router_data.connector, &err),
                 error_message: Some(err.message),
                 error_code: Some(err.code),
             }),
@@ -228,6 +255,7 @@ async fn payment_response_update_tracker<F: Clone, T>(
         },
     };
 
+    let was_successful = is_successful_attempt(payment_data.payment_attempt.status);
     payment_data.payment_attempt = match payment_attempt_update {
         Some(payment_attempt_update) => db
             .update_payment_attempt(
@@ -242,6 +270,15 @@ async fn payment_response_update_tracker<F: Clone, T>(
         None => payment_data.payment_attempt,
     };
 
+    if !was_successful && is_successful_attempt(payment_data.payment_attempt.status) {
+        if let Some(payment_method_id) = payment_data.payment_attempt.payment_method_id.as_deref() {
+            // Failing to record the usage of the payment method should not fail the payment
+            if let Err(error) = db.record_payment_method_usage(payment_method_id).await {
+                logger::error!(?error, "Failed to record the usage of the payment method");
+            }
+        }
+    }
+
     payment_data.connector_response = match connector_response_update {
         Some(connector_response_update) => db
             .update_connector_response(
@@ -287,3 +324,10 @@ async fn payment_response_update_tracker<F: Clone, T>(
 
     Ok(payment_data)
 }
+
+fn is_successful_attempt(status: enums::AttemptStatus) -> bool {
+    matches!(
+        status,
+        enums::AttemptStatus::Authorized | enums::AttemptStatus::Charged
+    )
+}
@@ -157,6 +157,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsSessionRequest>
                 sessions_token: vec![],
                 connector_response,
                 card_cvc: None,
+                network_token: None,
+                authentication_data: None,
+                card_info: None,
             },
             Some(customer_details),
         ))
@@ -143,6 +143,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsStartRequest> f
                 refunds: vec![],
                 sessions_token: vec![],
                 card_cvc: None,
+                network_token: None,
+                authentication_data: None,
+                card_info: None,
             },
             Some(customer_details),
         ))
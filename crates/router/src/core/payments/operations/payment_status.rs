@@ -268,6 +268,9 @@ async fn get_tracker_for_sync<
             refunds,
             sessions_token: vec![],
             card_cvc: None,
+            network_token: None,
+            authentication_data: None,
+            card_info: None,
         },
         None,
     ))
@@ -173,6 +173,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 Some(mandate.map(|mandate_obj| api_models::payments::MandateIds {
                     mandate_id: mandate_obj.mandate_id,
                     connector_mandate_id: mandate_obj.connector_mandate_id,
+                    payment_method: mandate_obj.payment_method.map(ForeignInto::foreign_into),
                 }))
             })
             .await
@@ -218,6 +219,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 connector_response,
                 sessions_token: vec![],
                 card_cvc: request.card_cvc.clone(),
+                network_token: None,
+                authentication_data: None,
+                card_info: None,
             },
             Some(CustomerDetails {
                 customer_id: request.customer_id.clone(),
@@ -309,6 +313,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     == storage_enums::IntentStatus::RequiresPaymentMethod;
 
         let payment_method = payment_data.payment_attempt.payment_method;
+        let card_info = helpers::encode_card_info(payment_data.card_info.as_ref())?;
 
         let get_attempt_status = || {
             if is_payment_method_unavailable {
@@ -328,6 +333,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     authentication_type: None,
                     payment_method,
                     payment_token: payment_data.token.clone(),
+                    card_info,
                 },
                 storage_scheme,
             )
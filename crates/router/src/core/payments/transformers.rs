@@ -6,6 +6,7 @@ use router_env::{instrument, tracing};
 use super::{flows::Feature, PaymentAddress, PaymentData};
 use crate::{
     configs::settings::Server,
+    consts,
     core::{
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payments::{self, helpers},
@@ -250,6 +251,13 @@ where
     } else {
         Some(refunds.into_iter().map(ForeignInto::foreign_into).collect())
     };
+    let card_info: Option<api::CardInfo> = payment_attempt
+        .card_info
+        .clone()
+        .map(|card_info| card_info.parse_value("CardInfo"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to decode card info")?;
 
     Ok(match payment_request {
         Some(request) => {
@@ -262,17 +270,9 @@ where
                 let mut response: api::PaymentsResponse = request
                     .try_into()
                     .map_err(|_| errors::ApiErrorResponse::InternalServerError)?;
-                let mut next_action_response = None;
-                if payment_intent.status == enums::IntentStatus::RequiresCustomerAction {
-                    next_action_response = Some(api::NextAction {
-                        next_action_type: api::NextActionType::RedirectToUrl,
-                        redirect_to_url: Some(helpers::create_startpay_url(
-                            server,
-                            &payment_attempt,
-                            &payment_intent,
-                        )),
-                    })
-                }
+                let next_action_response = (payment_intent.status
+                    == enums::IntentStatus::RequiresCustomerAction)
+                    .then(|| get_next_action(server, &payment_attempt, &payment_intent));
 
                 services::ApplicationResponse::Json(
                     response
@@ -315,9 +315,13 @@ where
                             payment_method_data.map(api::PaymentMethodDataResponse::from),
                             auth_flow == services::AuthFlow::Merchant,
                         )
+                        .set_card_info(card_info)
                         .set_payment_token(payment_attempt.payment_token)
                         .set_error_message(payment_attempt.error_message)
                         .set_error_code(payment_attempt.error_code)
+                        .set_decline_code(
+                            payment_attempt.decline_code.map(ForeignInto::foreign_into),
+                        )
                         .set_shipping(address.shipping)
                         .set_billing(address.billing)
                         .to_owned()
@@ -366,7 +370,9 @@ where
                 .map(ForeignInto::foreign_into),
             error_message: payment_attempt.error_message,
             error_code: payment_attempt.error_code,
+            decline_code: payment_attempt.decline_code.map(ForeignInto::foreign_into),
             payment_method_data: payment_method_data.map(api::PaymentMethodDataResponse::from),
+            card_info,
             email: customer
                 .as_ref()
                 .and_then(|cus| cus.email.as_ref().map(|s| s.to_owned())),
@@ -386,6 +392,78 @@ where
     })
 }
 
+/// The action the customer takes to complete the payment, based on what the connector asked for
+fn get_next_action(
+    server: &Server,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+) -> api::NextAction {
+    let bank_transfer_next_steps =
+        payment_attempt
+            .connector_metadata
+            .clone()
+            .and_then(|metadata| {
+                metadata
+                    .parse_value::<api::BankTransferNextSteps>("BankTransferNextSteps")
+                    .ok()
+            });
+    let upi_next_steps = payment_attempt
+        .connector_metadata
+        .clone()
+        .and_then(|metadata| {
+            metadata
+                .parse_value::<api::UpiNextSteps>("UpiNextSteps")
+                .ok()
+        });
+
+    match (bank_transfer_next_steps, upi_next_steps) {
+        (Some(next_steps), _) => api::NextAction {
+            next_action_type: api::NextActionType::DisplayBankTransferInstructions,
+            redirect_to_url: None,
+            bank_transfer_instructions: Some(next_steps.bank_transfer_instructions),
+            upi_intent_uri: None,
+            poll_config: None,
+        },
+        (None, Some(next_steps)) => {
+            let next_action_type = match next_steps.upi_flow {
+                api::enums::PaymentMethodSubType::UpiIntent => api::NextActionType::InvokeUpiApp,
+                _ => api::NextActionType::WaitForCustomerApproval,
+            };
+            // Poll until the collect request or intent expires, if the connector told us when
+            let frequency = next_steps
+                .expires_at
+                .map(|expires_at| {
+                    let seconds_left =
+                        (expires_at - common_utils::date_time::now()).whole_seconds();
+                    let polls_left = seconds_left / i64::from(consts::UPI_POLL_DELAY_IN_SECS);
+                    u16::try_from(polls_left.max(1)).unwrap_or(u16::MAX)
+                })
+                .unwrap_or(consts::UPI_POLL_FREQUENCY);
+            api::NextAction {
+                next_action_type,
+                redirect_to_url: None,
+                bank_transfer_instructions: None,
+                upi_intent_uri: next_steps.upi_intent_uri,
+                poll_config: Some(api::PollConfig {
+                    delay_in_secs: consts::UPI_POLL_DELAY_IN_SECS,
+                    frequency,
+                }),
+            }
+        }
+        (None, None) => api::NextAction {
+            next_action_type: api::NextActionType::RedirectToUrl,
+            redirect_to_url: Some(helpers::create_startpay_url(
+                server,
+                payment_attempt,
+                payment_intent,
+            )),
+            bank_transfer_instructions: None,
+            upi_intent_uri: None,
+            poll_config: None,
+        },
+    }
+}
+
 impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
 
@@ -415,10 +493,20 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
 
         let order_details = parsed_metadata.and_then(|data| data.order_details);
 
+        // Payments debited through a mandate held by the connector, such as recurring bank
+        // debits, carry no payment method details
+        let is_connector_mandate_payment = payment_data
+            .mandate_id
+            .as_ref()
+            .and_then(|mandate_ids| mandate_ids.connector_mandate_id.as_ref())
+            .is_some();
+        let payment_method_data = match payment_data.payment_method_data {
+            None if is_connector_mandate_payment => api::PaymentMethod::MandatePayment,
+            payment_method_data => payment_method_data.get_required_value("payment_method_data")?,
+        };
+
         Ok(Self {
-            payment_method_data: payment_data
-                .payment_method_data
-                .get_required_value("payment_method_data")?,
+            payment_method_data,
             setup_future_usage: payment_data.payment_intent.setup_future_usage,
             mandate_id: payment_data.mandate_id.clone(),
             off_session: payment_data.mandate_id.as_ref().map(|_| true),
@@ -431,6 +519,8 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsAuthorizeData {
             browser_info,
             email: payment_data.email,
             order_details,
+            network_token: payment_data.network_token,
+            authentication_data: payment_data.authentication_data,
         })
     }
 }
@@ -464,6 +554,7 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsCaptureData {
                 .connector_transaction_id
                 .ok_or(errors::ApiErrorResponse::MerchantConnectorAccountNotFound)?,
             amount: payment_data.amount.into(),
+            connector_meta: payment_data.payment_attempt.connector_metadata,
         })
     }
 }
@@ -480,6 +571,7 @@ impl<F: Clone> TryFrom<PaymentData<F>> for types::PaymentsCancelData {
                     field_name: "connector_transaction_id",
                 })?,
             cancellation_reason: payment_data.payment_attempt.cancellation_reason,
+            connector_meta: payment_data.payment_attempt.connector_metadata,
         })
     }
 }
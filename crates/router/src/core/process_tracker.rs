@@ -0,0 +1,381 @@
+use error_stack::ResultExt;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        refunds,
+    },
+    db::StorageInterface,
+    scheduler::{process_data, utils as pt_utils, workflows::payment_sync},
+    services::ApplicationResponse,
+    types::{api, storage, storage::enums},
+    utils::{self, OptionExt},
+};
+
+const CANCELLED_BY_ADMIN: &str = "CANCELLED_BY_ADMIN";
+
+/// Maximum number of tasks returned by a single list request
+const LIST_MAX_LIMIT: i64 = 100;
+
+pub async fn list_processes(
+    db: &dyn StorageInterface,
+    constraints: api::ProcessTrackerListConstraints,
+) -> RouterResponse<Vec<api::ProcessTrackerResponse>> {
+    validate_list_constraints(&constraints)?;
+    let processes = db
+        .filter_processes_by_constraints(&constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list process tracker tasks")?;
+
+    Ok(ApplicationResponse::Json(
+        processes.into_iter().map(From::from).collect(),
+    ))
+}
+
+pub async fn retrieve_process(
+    db: &dyn StorageInterface,
+    id: &str,
+) -> RouterResponse<api::ProcessTrackerResponse> {
+    let process = find_process(db, id).await?;
+    Ok(ApplicationResponse::Json(process.into()))
+}
+
+/// Retry history of the task as recorded by its runner, with its retry schedule projected from the
+/// current retry schedule of its connector. Projected retries do not reflect schedule changes made
+/// since they were run, or retries forced by an admin.
+pub async fn retrieve_retry_schedule(
+    db: &dyn StorageInterface,
+    id: &str,
+) -> RouterResponse<api::ProcessTrackerRetryScheduleResponse> {
+    let process = find_process(db, id).await?;
+    let mapping = get_task_pt_mapping(db, &process).await;
+
+    Ok(ApplicationResponse::Json(project_retry_schedule(
+        process, &mapping,
+    )))
+}
+
+fn project_retry_schedule(
+    process: storage::ProcessTracker,
+    mapping: &process_data::ConnectorPTMapping,
+) -> api::ProcessTrackerRetryScheduleResponse {
+    let merchant_id = merchant_id_from_task(&process).unwrap_or_default();
+    let projected_retry = |retry_count| api::ProcessTrackerRetry {
+        retry_count,
+        delay_in_seconds: pt_utils::get_schedule_time(mapping.clone(), &merchant_id, retry_count),
+    };
+
+    let projected_retries = (1..=process.retry_count).map(projected_retry).collect();
+    let next_retry = (process.status != enums::ProcessTrackerStatus::Finish
+        && process.retry_count < mapping.max_retries_count)
+        .then(|| projected_retry(process.retry_count + 1));
+
+    api::ProcessTrackerRetryScheduleResponse {
+        connector: pt_utils::connector_from_task(&process),
+        id: process.id,
+        runner: process.runner,
+        retry_count: process.retry_count,
+        max_retries_count: mapping.max_retries_count,
+        attempts: storage::ProcessTrackerAttempt::from_events(&process.event),
+        projected_retries,
+        next_retry,
+        schedule_time: process.schedule_time,
+    }
+}
+
+/// Make the task eligible to be picked up by the producer immediately.
+pub async fn force_retry(
+    db: &dyn StorageInterface,
+    id: &str,
+) -> RouterResponse<api::ProcessTrackerResponse> {
+    let process = find_idle_process(db, id).await?;
+    let now = common_utils::date_time::now();
+    let process = update_process(
+        db,
+        process,
+        storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: Some(now),
+            tracking_data: None,
+            business_status: Some("Pending".to_string()),
+            status: Some(enums::ProcessTrackerStatus::Pending),
+            updated_at: Some(now),
+        },
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(process.into()))
+}
+
+pub async fn reschedule(
+    db: &dyn StorageInterface,
+    id: &str,
+    request: api::ProcessTrackerRescheduleRequest,
+) -> RouterResponse<api::ProcessTrackerResponse> {
+    let process = find_idle_process(db, id).await?;
+    let schedule_time = match request.schedule_time {
+        Some(schedule_time) => schedule_time,
+        None => {
+            let mapping = get_task_pt_mapping(db, &process).await;
+            let merchant_id = merchant_id_from_task(&process).unwrap_or_default();
+            let delta = pt_utils::get_schedule_time(mapping, &merchant_id, process.retry_count + 1);
+            pt_utils::get_time_from_delta(delta).ok_or(
+                errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Retries for this task have been exhausted, provide a schedule_time"
+                        .to_string(),
+                },
+            )?
+        }
+    };
+
+    let process = update_process(
+        db,
+        process,
+        storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: Some(schedule_time),
+            tracking_data: None,
+            business_status: None,
+            status: Some(enums::ProcessTrackerStatus::Pending),
+            updated_at: Some(common_utils::date_time::now()),
+        },
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(process.into()))
+}
+
+pub async fn cancel(
+    db: &dyn StorageInterface,
+    id: &str,
+) -> RouterResponse<api::ProcessTrackerResponse> {
+    let process = find_idle_process(db, id).await?;
+    let process = update_process(
+        db,
+        process,
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: enums::ProcessTrackerStatus::Finish,
+            business_status: Some(CANCELLED_BY_ADMIN.to_string()),
+        },
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(process.into()))
+}
+
+pub async fn summary(
+    db: &dyn StorageInterface,
+    constraints: api::ProcessTrackerSummaryConstraints,
+) -> RouterResponse<api::ProcessTrackerSummaryResponse> {
+    let counts = db
+        .count_processes_by_status(constraints.runner.as_deref())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count process tracker tasks")?
+        .into_iter()
+        .map(|(status, count)| api::ProcessTrackerStatusCount { status, count })
+        .collect();
+
+    Ok(ApplicationResponse::Json(
+        api::ProcessTrackerSummaryResponse {
+            runner: constraints.runner,
+            counts,
+        },
+    ))
+}
+
+async fn find_process(
+    db: &dyn StorageInterface,
+    id: &str,
+) -> RouterResult<storage::ProcessTracker> {
+    db.find_process_by_id(id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch process tracker task")?
+        .get_required_value("process")
+        .change_context(errors::ApiErrorResponse::ResourceIdNotFound)
+}
+
+/// Fetch a task, rejecting it if it is currently held by a consumer.
+async fn find_idle_process(
+    db: &dyn StorageInterface,
+    id: &str,
+) -> RouterResult<storage::ProcessTracker> {
+    let process = find_process(db, id).await?;
+    if matches!(
+        process.status,
+        enums::ProcessTrackerStatus::Processing | enums::ProcessTrackerStatus::ProcessStarted
+    ) {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Task {id} is currently being processed"),
+        })?
+    }
+    Ok(process)
+}
+
+async fn update_process(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    update: storage::ProcessTrackerUpdate,
+) -> RouterResult<storage::ProcessTracker> {
+    db.update_process(process, update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update process tracker task")
+}
+
+fn validate_list_constraints(constraints: &api::ProcessTrackerListConstraints) -> RouterResult<()> {
+    utils::when(
+        constraints.limit < 1 || constraints.limit > LIST_MAX_LIMIT,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("limit should be in between 1 and {LIST_MAX_LIMIT}"),
+            })
+        },
+    )?;
+    utils::when(constraints.skip.unwrap_or_default() < 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "skip should not be negative".to_string(),
+        })
+    })?;
+    Ok(())
+}
+
+/// Retry schedule applied by the runner of the task for its connector.
+async fn get_task_pt_mapping(
+    db: &dyn StorageInterface,
+    process: &storage::ProcessTracker,
+) -> process_data::ConnectorPTMapping {
+    let Some(connector) = pt_utils::connector_from_task(process) else {
+        return Default::default();
+    };
+    match process.runner.as_deref() {
+        Some("REFUND_WORKFLOW_ROUTER") => refunds::get_refund_sync_pt_mapping(db, &connector).await,
+        _ => payment_sync::get_connector_pt_mapping(db, &connector).await,
+    }
+}
+
+fn merchant_id_from_task(process: &storage::ProcessTracker) -> Option<String> {
+    process
+        .tracking_data
+        .get("merchant_id")
+        .and_then(serde_json::Value::as_str)
+        .map(ToOwned::to_owned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_process(
+        retry_count: i32,
+        status: enums::ProcessTrackerStatus,
+    ) -> storage::ProcessTracker {
+        let now = common_utils::date_time::now();
+        storage::ProcessTracker {
+            id: "SYNC_PAYMENT_pay_123".to_string(),
+            name: Some("PAYMENTS_SYNC".to_string()),
+            tag: vec!["SYNC".to_string(), "PAYMENT".to_string()],
+            runner: Some("PAYMENTS_SYNC_WORKFLOW".to_string()),
+            retry_count,
+            schedule_time: Some(now),
+            rule: String::new(),
+            tracking_data: serde_json::json!({
+                "merchant_id": "merchant_123",
+                "connector": "stripe"
+            }),
+            business_status: "Pending".to_string(),
+            status,
+            event: vec![],
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_retry_schedule_is_projected_from_mapping() {
+        let mapping = process_data::ConnectorPTMapping::default();
+        let schedule = project_retry_schedule(
+            get_process(2, enums::ProcessTrackerStatus::Pending),
+            &mapping,
+        );
+
+        assert_eq!(schedule.connector.as_deref(), Some("stripe"));
+        assert_eq!(schedule.max_retries_count, mapping.max_retries_count);
+        assert_eq!(
+            schedule
+                .projected_retries
+                .iter()
+                .map(|retry| (retry.retry_count, retry.delay_in_seconds))
+                .collect::<Vec<_>>(),
+            vec![(1, Some(300)), (2, Some(300))]
+        );
+        let next_retry = schedule.next_retry.map(|retry| retry.retry_count);
+        assert_eq!(next_retry, Some(3));
+    }
+
+    #[test]
+    fn test_no_next_retry_for_exhausted_or_finished_tasks() {
+        let mapping = process_data::ConnectorPTMapping::default();
+        let exhausted = project_retry_schedule(
+            get_process(
+                mapping.max_retries_count,
+                enums::ProcessTrackerStatus::Pending,
+            ),
+            &mapping,
+        );
+        assert!(exhausted.next_retry.is_none());
+
+        let finished = project_retry_schedule(
+            get_process(1, enums::ProcessTrackerStatus::Finish),
+            &mapping,
+        );
+        assert!(finished.next_retry.is_none());
+    }
+
+    #[test]
+    fn test_attempts_are_read_from_events() {
+        let attempt = |retry_count, error: Option<&str>| storage::ProcessTrackerAttempt {
+            retry_count,
+            attempted_at: common_utils::date_time::now(),
+            error: error.map(ToOwned::to_owned),
+        };
+        let mut process = get_process(2, enums::ProcessTrackerStatus::Pending);
+        process.event = vec![
+            serde_json::to_string(&attempt(0, None)).unwrap_or_default(),
+            "UNRECOGNIZED_EVENT".to_string(),
+            serde_json::to_string(&attempt(1, Some("Insufficient funds"))).unwrap_or_default(),
+        ];
+
+        let schedule =
+            project_retry_schedule(process, &process_data::ConnectorPTMapping::default());
+        assert_eq!(
+            schedule
+                .attempts
+                .iter()
+                .map(|attempt| (attempt.retry_count, attempt.error.as_deref()))
+                .collect::<Vec<_>>(),
+            vec![(0, None), (1, Some("Insufficient funds"))]
+        );
+    }
+
+    #[test]
+    fn test_list_limit_is_capped() {
+        let constraints = |limit, skip| api::ProcessTrackerListConstraints {
+            runner: None,
+            status: None,
+            merchant_id: None,
+            limit,
+            skip,
+        };
+
+        assert!(validate_list_constraints(&constraints(LIST_MAX_LIMIT, None)).is_ok());
+        assert!(validate_list_constraints(&constraints(1, Some(10))).is_ok());
+        assert!(validate_list_constraints(&constraints(LIST_MAX_LIMIT + 1, None)).is_err());
+        assert!(validate_list_constraints(&constraints(0, None)).is_err());
+        assert!(validate_list_constraints(&constraints(10, Some(-1))).is_err());
+    }
+}
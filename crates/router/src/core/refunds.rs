@@ -12,7 +12,7 @@ use crate::{
     },
     db, logger,
     routes::AppState,
-    scheduler::{process_data, utils as process_tracker_utils, workflows::payment_sync},
+    scheduler::{process_data, utils as process_tracker_utils},
     services,
     types::{
         self,
@@ -695,11 +695,12 @@ pub async fn sync_refund_with_gateway_workflow(
                 .await?
         }
         _ => {
-            payment_sync::retry_sync_task(
+            retry_refund_sync_task(
                 &*state.store,
                 response.connector,
                 response.merchant_id,
                 refund_tracker.to_owned(),
+                response.error_message,
             )
             .await?
         }
@@ -820,6 +821,7 @@ pub fn refund_to_refund_core_workflow_model(
         connector_transaction_id: refund.connector_transaction_id.clone(),
         merchant_id: refund.merchant_id.clone(),
         payment_id: refund.payment_id.clone(),
+        connector: Some(refund.connector.clone()),
     }
 }
 
@@ -905,12 +907,10 @@ pub async fn add_refund_execute_task(
     Ok(response)
 }
 
-pub async fn get_refund_sync_process_schedule_time(
+pub async fn get_refund_sync_pt_mapping(
     db: &dyn db::StorageInterface,
     connector: &str,
-    merchant_id: &str,
-    retry_count: i32,
-) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
+) -> process_data::ConnectorPTMapping {
     let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
         db::get_and_deserialize_key(
             db,
@@ -919,13 +919,22 @@ pub async fn get_refund_sync_process_schedule_time(
         )
         .await;
 
-    let mapping = match redis_mapping {
+    match redis_mapping {
         Ok(x) => x,
         Err(err) => {
             logger::error!("Error: while getting connector mapping: {}", err);
             process_data::ConnectorPTMapping::default()
         }
-    };
+    }
+}
+
+pub async fn get_refund_sync_process_schedule_time(
+    db: &dyn db::StorageInterface,
+    connector: &str,
+    merchant_id: &str,
+    retry_count: i32,
+) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
+    let mapping = get_refund_sync_pt_mapping(db, connector).await;
 
     let time_delta =
         process_tracker_utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
@@ -938,14 +947,15 @@ pub async fn retry_refund_sync_task(
     connector: String,
     merchant_id: String,
     pt: storage::ProcessTracker,
+    error: Option<String>,
 ) -> Result<(), errors::ProcessTrackerError> {
     let schedule_time =
         get_refund_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count).await?;
 
     match schedule_time {
-        Some(s_time) => pt.retry(db, s_time).await,
+        Some(s_time) => pt.retry(db, s_time, error).await,
         None => {
-            pt.finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+            pt.finish_with_error(db, "RETRIES_EXCEEDED".to_string(), error)
                 .await
         }
     }
@@ -92,7 +92,7 @@ async fn payments_incoming_webhook_flow(
 
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
-async fn create_event_and_trigger_outgoing_webhook(
+pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     state: AppState,
     merchant_account: storage::MerchantAccount,
     event_type: enums::EventType,
@@ -119,10 +119,6 @@ async fn create_event_and_trigger_outgoing_webhook(
         .change_context(errors::WebhooksFlowError::WebhookEventCreationFailed)?;
 
     if state.conf.webhooks.outgoing_enabled {
-        let arbiter = actix::Arbiter::try_current()
-            .ok_or(errors::WebhooksFlowError::ForkFlowFailed)
-            .into_report()?;
-
         let outgoing_webhook = api::OutgoingWebhook {
             merchant_id: merchant_account.merchant_id.clone(),
             event_id: event.event_id,
@@ -131,14 +127,24 @@ async fn create_event_and_trigger_outgoing_webhook(
             timestamp: event.created_at,
         };
 
-        arbiter.spawn(async move {
+        let trigger_webhook = async move {
             let result =
                 trigger_webhook_to_merchant(merchant_account, outgoing_webhook, state.store).await;
 
             if let Err(e) = result {
                 logger::error!(?e);
             }
-        });
+        };
+
+        match actix::Arbiter::try_current() {
+            Some(arbiter) => {
+                arbiter.spawn(trigger_webhook);
+            }
+            // Outside of the server's arbiters, such as in the scheduler
+            None => {
+                tokio::spawn(trigger_webhook);
+            }
+        }
     }
 
     Ok(())
@@ -1,11 +1,13 @@
 pub mod address;
 pub mod api_keys;
 pub mod cache;
+pub mod card_vault;
 pub mod configs;
 pub mod connector_response;
 pub mod customers;
 pub mod ephemeral_key;
 pub mod events;
+pub mod fraud_check;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -13,8 +15,10 @@ pub mod merchant_connector_account;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payment_token;
 pub mod process_tracker;
 pub mod queue;
+pub mod recurring_task;
 pub mod refund;
 pub mod reverse_lookup;
 
@@ -38,11 +42,13 @@ pub trait StorageInterface:
     + dyn_clone::DynClone
     + address::AddressInterface
     + api_keys::ApiKeyInterface
+    + card_vault::CardVaultInterface
     + configs::ConfigInterface
     + connector_response::ConnectorResponseInterface
     + customers::CustomerInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
+    + fraud_check::FraudCheckInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -51,8 +57,10 @@ pub trait StorageInterface:
     + payment_attempt::PaymentAttemptInterface
     + payment_intent::PaymentIntentInterface
     + payment_method::PaymentMethodInterface
+    + payment_token::PaymentTokenInterface
     + process_tracker::ProcessTrackerInterface
     + queue::QueueInterface
+    + recurring_task::RecurringTaskInterface
     + refund::RefundInterface
     + reverse_lookup::ReverseLookupInterface
     + 'static
@@ -1,7 +1,17 @@
 use error_stack::ResultExt;
 
 use super::Store;
-use crate::core::errors::{self, CustomResult};
+use crate::{
+    cache::{self, Cacheable},
+    core::errors::{self, CustomResult},
+    logger,
+};
+
+/// Number of times a cache invalidation is published before giving up
+const PUBLISH_MAX_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry of a failed publish, doubled on every further retry
+const PUBLISH_RETRY_DELAY: std::time::Duration = std::time::Duration::from_millis(100);
 
 pub async fn get_or_populate_cache<T, F, Fut>(
     store: &Store,
@@ -34,6 +44,37 @@ where
     }
 }
 
+/// Look up `key` in the in-memory `cache` first, falling back to the Redis cache (and `fun`) on a
+/// miss and populating the in-memory cache with the result. The result is not cached if an
+/// eviction happened while it was fetched, as it may predate the change evicted.
+pub async fn get_or_populate_in_memory<T, F, Fut>(
+    store: &Store,
+    key: &str,
+    fun: F,
+    cache: &cache::Cache,
+) -> CustomResult<T, errors::StorageError>
+where
+    T: Cacheable + serde::Serialize + serde::de::DeserializeOwned + std::fmt::Debug + Clone,
+    F: FnOnce() -> Fut + Send,
+    Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
+{
+    if !cache::is_enabled() {
+        return get_or_populate_cache(store, key, fun).await;
+    }
+
+    match cache.get_val::<T>(key) {
+        Some(val) => Ok(val),
+        None => {
+            let generation = cache.generation();
+            let val = get_or_populate_cache(store, key, fun).await?;
+            cache
+                .push_if_current(key.to_string(), val.clone(), generation)
+                .await;
+            Ok(val)
+        }
+    }
+}
+
 pub async fn redact_cache<T, F, Fut>(
     store: &Store,
     key: &str,
@@ -52,3 +93,37 @@ where
         .change_context(errors::StorageError::KVError)?;
     Ok(data)
 }
+
+/// Redact the Redis cache entry for `key`, evict it from the in-memory cache of this instance and
+/// notify the other router instances to evict it from theirs.
+///
+/// The change has been written by the time the notification is published, so a failure to publish
+/// is logged rather than returned; the other instances then serve the stale entry until it expires.
+pub async fn publish_and_redact<'a, T, F, Fut>(
+    store: &Store,
+    key: cache::CacheKind<'a>,
+    fun: F,
+) -> CustomResult<T, errors::StorageError>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + std::fmt::Debug,
+    F: FnOnce() -> Fut + Send,
+    Fut: futures::Future<Output = CustomResult<T, errors::StorageError>> + Send,
+{
+    let data = redact_cache(store, key.key(), fun).await?;
+    cache::invalidate(key.clone()).await;
+
+    let mut attempt = 1;
+    while let Err(error) = store
+        .redis_conn
+        .publish(cache::PUB_SUB_CHANNEL, key.clone())
+        .await
+    {
+        if attempt == PUBLISH_MAX_ATTEMPTS {
+            logger::error!(?error, "Failed to publish cache invalidation for {:?}", key);
+            break;
+        }
+        tokio::time::sleep(PUBLISH_RETRY_DELAY * 2u32.pow(attempt - 1)).await;
+        attempt += 1;
+    }
+    Ok(data)
+}
@@ -0,0 +1,218 @@
+use error_stack::{IntoReport, ResultExt};
+
+use super::{MockDb, Store};
+use crate::{
+    connection::{pg_connection, PgPool},
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CardVaultInterface {
+    async fn find_vault_data_key_optional(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Option<storage::VaultDataKey>, errors::StorageError>;
+
+    async fn insert_vault_data_key(
+        &self,
+        data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError>;
+
+    async fn insert_vault_card(
+        &self,
+        card: storage::VaultCardNew,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+
+    async fn find_vault_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+
+    async fn find_vault_card_by_fingerprint_optional(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultCard>, errors::StorageError>;
+
+    async fn update_vault_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+        card: storage::VaultCardUpdate,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+
+    async fn delete_vault_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError>;
+}
+
+impl Store {
+    fn vault_pool(&self) -> CustomResult<&PgPool, errors::StorageError> {
+        self.vault_pool
+            .as_ref()
+            .ok_or_else(|| {
+                errors::StorageError::ValueNotFound("card vault database pool".to_string())
+            })
+            .into_report()
+            .attach_printable("The card vault is not enabled")
+    }
+}
+
+#[async_trait::async_trait]
+impl CardVaultInterface for Store {
+    async fn find_vault_data_key_optional(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Option<storage::VaultDataKey>, errors::StorageError> {
+        let conn = pg_connection(self.vault_pool()?).await;
+        storage::VaultDataKey::find_optional_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_vault_data_key(
+        &self,
+        data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        let conn = pg_connection(self.vault_pool()?).await;
+        data_key
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn insert_vault_card(
+        &self,
+        card: storage::VaultCardNew,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let conn = pg_connection(self.vault_pool()?).await;
+        card.insert(&conn).await.map_err(Into::into).into_report()
+    }
+
+    async fn find_vault_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let conn = pg_connection(self.vault_pool()?).await;
+        storage::VaultCard::find_by_merchant_id_card_id(&conn, merchant_id, card_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_vault_card_by_fingerprint_optional(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultCard>, errors::StorageError> {
+        let conn = pg_connection(self.vault_pool()?).await;
+        storage::VaultCard::find_optional_by_fingerprint(
+            &conn,
+            merchant_id,
+            customer_id,
+            card_fingerprint,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn update_vault_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+        card: storage::VaultCardUpdate,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let conn = pg_connection(self.vault_pool()?).await;
+        storage::VaultCard::update_by_merchant_id_card_id(&conn, merchant_id, card_id, card)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_vault_card_by_merchant_id_card_id(
+        &self,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        let conn = pg_connection(self.vault_pool()?).await;
+        storage::VaultCard::delete_by_merchant_id_card_id(&conn, merchant_id, card_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl CardVaultInterface for MockDb {
+    async fn find_vault_data_key_optional(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Option<storage::VaultDataKey>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_vault_data_key(
+        &self,
+        _data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_vault_card(
+        &self,
+        _card: storage::VaultCardNew,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_vault_card_by_merchant_id_card_id(
+        &self,
+        _merchant_id: &str,
+        _card_id: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_vault_card_by_fingerprint_optional(
+        &self,
+        _merchant_id: &str,
+        _customer_id: &str,
+        _card_fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultCard>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_vault_card_by_merchant_id_card_id(
+        &self,
+        _merchant_id: &str,
+        _card_id: &str,
+        _card: storage::VaultCardUpdate,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_vault_card_by_merchant_id_card_id(
+        &self,
+        _merchant_id: &str,
+        _card_id: &str,
+    ) -> CustomResult<storage::VaultCard, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
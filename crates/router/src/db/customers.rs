@@ -184,6 +184,7 @@ impl CustomerInterface for MockDb {
             description: customer_data.description,
             created_at: common_utils::date_time::now(),
             metadata: customer_data.metadata,
+            default_payment_method_id: None,
         };
         customers.push(customer.clone());
         Ok(customer)
@@ -0,0 +1,162 @@
+use error_stack::IntoReport;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait FraudCheckInterface {
+    async fn insert_fraud_check(
+        &self,
+        fraud_check: storage::FraudCheckNew,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError>;
+
+    async fn update_fraud_check(
+        &self,
+        this: storage::FraudCheck,
+        fraud_check_update: storage::FraudCheckUpdate,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError>;
+
+    async fn find_fraud_check_by_merchant_id_frm_id(
+        &self,
+        merchant_id: &str,
+        frm_id: &str,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError>;
+
+    async fn find_fraud_checks_by_merchant_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::FraudCheck>, errors::StorageError>;
+
+    async fn find_fraud_checks_by_merchant_id_status(
+        &self,
+        merchant_id: &str,
+        frm_status: enums::FraudCheckStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::FraudCheck>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl FraudCheckInterface for Store {
+    async fn insert_fraud_check(
+        &self,
+        fraud_check: storage::FraudCheckNew,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        fraud_check
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_fraud_check(
+        &self,
+        this: storage::FraudCheck,
+        fraud_check_update: storage::FraudCheckUpdate,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, fraud_check_update)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_fraud_check_by_merchant_id_frm_id(
+        &self,
+        merchant_id: &str,
+        frm_id: &str,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::FraudCheck::find_by_merchant_id_frm_id(&conn, merchant_id, frm_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_fraud_checks_by_merchant_id_attempt_id(
+        &self,
+        merchant_id: &str,
+        attempt_id: &str,
+    ) -> CustomResult<Vec<storage::FraudCheck>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::FraudCheck::find_by_merchant_id_attempt_id(&conn, merchant_id, attempt_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_fraud_checks_by_merchant_id_status(
+        &self,
+        merchant_id: &str,
+        frm_status: enums::FraudCheckStatus,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::FraudCheck>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::FraudCheck::find_by_merchant_id_status(
+            &conn,
+            merchant_id,
+            frm_status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl FraudCheckInterface for MockDb {
+    async fn insert_fraud_check(
+        &self,
+        _fraud_check: storage::FraudCheckNew,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_fraud_check(
+        &self,
+        _this: storage::FraudCheck,
+        _fraud_check_update: storage::FraudCheckUpdate,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_fraud_check_by_merchant_id_frm_id(
+        &self,
+        _merchant_id: &str,
+        _frm_id: &str,
+    ) -> CustomResult<storage::FraudCheck, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_fraud_checks_by_merchant_id_attempt_id(
+        &self,
+        _merchant_id: &str,
+        _attempt_id: &str,
+    ) -> CustomResult<Vec<storage::FraudCheck>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_fraud_checks_by_merchant_id_status(
+        &self,
+        _merchant_id: &str,
+        _frm_status: enums::FraudCheckStatus,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::FraudCheck>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
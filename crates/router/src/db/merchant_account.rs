@@ -81,7 +81,13 @@ impl MerchantAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::get_or_populate_cache(self, merchant_id, fetch_func).await
+            super::cache::get_or_populate_in_memory(
+                self,
+                merchant_id,
+                fetch_func,
+                &crate::cache::ACCOUNTS_CACHE,
+            )
+            .await
         }
     }
 
@@ -106,7 +112,12 @@ impl MerchantAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::redact_cache(self, &_merchant_id, update_func).await
+            super::cache::publish_and_redact(
+                self,
+                crate::cache::CacheKind::Accounts(_merchant_id.into()),
+                update_func,
+            )
+            .await
         }
     }
 
@@ -134,7 +145,12 @@ impl MerchantAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::redact_cache(self, merchant_id, update_func).await
+            super::cache::publish_and_redact(
+                self,
+                crate::cache::CacheKind::Accounts(merchant_id.into()),
+                update_func,
+            )
+            .await
         }
     }
 
@@ -179,7 +195,12 @@ impl MerchantAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::redact_cache(self, merchant_id, delete_func).await
+            super::cache::publish_and_redact(
+                self,
+                crate::cache::CacheKind::Accounts(merchant_id.into()),
+                delete_func,
+            )
+            .await
         }
     }
 }
@@ -215,6 +236,7 @@ impl MerchantAccountInterface for MockDb {
             storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
             locker_id: merchant_account.locker_id,
             metadata: merchant_account.metadata,
+            payment_token_config: merchant_account.payment_token_config,
         };
         accounts.push(account.clone());
         Ok(account)
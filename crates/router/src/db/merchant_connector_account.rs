@@ -172,7 +172,13 @@ impl MerchantConnectorAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::get_or_populate_cache(self, merchant_connector_id, find_call).await
+            super::cache::get_or_populate_in_memory(
+                self,
+                merchant_connector_id,
+                find_call,
+                &crate::cache::ACCOUNTS_CACHE,
+            )
+            .await
         }
     }
 
@@ -211,7 +217,12 @@ impl MerchantConnectorAccountInterface for Store {
 
         #[cfg(feature = "accounts_cache")]
         {
-            super::cache::redact_cache(self, &_merchant_connector_id, update_call).await
+            super::cache::publish_and_redact(
+                self,
+                crate::cache::CacheKind::Accounts(_merchant_connector_id.into()),
+                update_call,
+            )
+            .await
         }
 
         #[cfg(not(feature = "accounts_cache"))]
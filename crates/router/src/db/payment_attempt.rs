@@ -244,6 +244,9 @@ impl PaymentAttemptInterface for MockDb {
             payment_token: None,
             error_code: payment_attempt.error_code,
             connector_metadata: None,
+            card_info: payment_attempt.card_info,
+            decline_code: payment_attempt.decline_code,
+            authentication_id: payment_attempt.authentication_id,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -383,6 +386,9 @@ mod storage {
                         payment_token: payment_attempt.payment_token.clone(),
                         error_code: payment_attempt.error_code.clone(),
                         connector_metadata: payment_attempt.connector_metadata.clone(),
+                        card_info: payment_attempt.card_info.clone(),
+                        decline_code: payment_attempt.decline_code,
+                        authentication_id: payment_attempt.authentication_id.clone(),
                     };
 
                     let field = format!("pa_{}", created_attempt.attempt_id);
@@ -397,24 +403,22 @@ mod storage {
                         })
                         .into_report(),
                         Ok(HsetnxReply::KeySet) => {
-                            let conn = pg_connection(&self.master_pool).await;
-
                             //Reverse lookup for attempt_id
-                            ReverseLookupNew {
-                                lookup_id: format!(
-                                    "{}_{}",
-                                    &created_attempt.merchant_id,
-                                    // [#439]: Change this to `attempt_id`
-                                    &created_attempt.payment_id,
-                                ),
-                                pk_id: key,
-                                sk_id: field,
-                                source: "payment_attempt".to_string(),
-                            }
-                            .insert(&conn)
-                            .await
-                            .map_err(Into::<errors::StorageError>::into)
-                            .into_report()?;
+                            self.insert_reverse_lookup(
+                                ReverseLookupNew {
+                                    lookup_id: format!(
+                                        "{}_{}",
+                                        &created_attempt.merchant_id,
+                                        // [#439]: Change this to `attempt_id`
+                                        &created_attempt.payment_id,
+                                    ),
+                                    pk_id: key,
+                                    sk_id: field,
+                                    source: "payment_attempt".to_string(),
+                                },
+                                storage_scheme,
+                            )
+                            .await?;
 
                             let redis_entry = kv::TypedSql {
                                 op: kv::DBOperation::Insert {
@@ -468,26 +472,36 @@ mod storage {
                         .map(|_| updated_attempt)
                         .change_context(errors::StorageError::KVError)?;
 
-                    let conn = pg_connection(&self.master_pool).await;
-                    // Reverse lookup for connector_transaction_id
-                    if let (None, Some(connector_transaction_id)) = (
-                        old_connector_transaction_id,
-                        &updated_attempt.connector_transaction_id,
-                    ) {
-                        let field = format!("pa_{}", updated_attempt.attempt_id);
-                        ReverseLookupNew {
-                            lookup_id: format!(
-                                "{}_{}",
-                                &updated_attempt.merchant_id, connector_transaction_id
-                            ),
-                            pk_id: key.clone(),
-                            sk_id: field.clone(),
-                            source: "payment_attempt".to_string(),
+                    // Reverse lookup for connector_transaction_id, replacing the lookup of the
+                    // previous connector transaction, if any
+                    if old_connector_transaction_id != &updated_attempt.connector_transaction_id {
+                        if let Some(old_connector_transaction_id) = old_connector_transaction_id {
+                            self.delete_reverse_lookup(
+                                &format!(
+                                    "{}_{}",
+                                    &updated_attempt.merchant_id, old_connector_transaction_id
+                                ),
+                                storage_scheme,
+                            )
+                            .await?;
+                        }
+                        if let Some(connector_transaction_id) =
+                            &updated_attempt.connector_transaction_id
+                        {
+                            self.insert_reverse_lookup(
+                                ReverseLookupNew {
+                                    lookup_id: format!(
+                                        "{}_{}",
+                                        &updated_attempt.merchant_id, connector_transaction_id
+                                    ),
+                                    pk_id: key.clone(),
+                                    sk_id: field.clone(),
+                                    source: "payment_attempt".to_string(),
+                                },
+                                storage_scheme,
+                            )
+                            .await?;
                         }
-                        .insert(&conn)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
                     }
 
                     let redis_entry = kv::TypedSql {
@@ -531,11 +545,7 @@ mod storage {
                 enums::MerchantStorageScheme::RedisKv => {
                     // [#439]: get the attempt_id from payment_intent
                     let key = format!("{merchant_id}_{payment_id}");
-                    let lookup = self
-                        .get_lookup_by_lookup_id(&key)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
+                    let lookup = self.get_lookup_by_lookup_id(&key).await?;
 
                     db_utils::try_redis_get_else_try_database_get(
                         self.redis_conn.get_hash_field_and_deserialize(
@@ -574,11 +584,7 @@ mod storage {
                 enums::MerchantStorageScheme::RedisKv => {
                     // We assume that PaymentAttempt <=> PaymentIntent is a one-to-one relation for now
                     let lookup_id = format!("{merchant_id}_{connector_transaction_id}");
-                    let lookup = self
-                        .get_lookup_by_lookup_id(&lookup_id)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
+                    let lookup = self.get_lookup_by_lookup_id(&lookup_id).await?;
                     let key = &lookup.pk_id;
 
                     db_utils::try_redis_get_else_try_database_get(
@@ -637,11 +643,7 @@ mod storage {
 
                 enums::MerchantStorageScheme::RedisKv => {
                     let lookup_id = format!("{merchant_id}_{connector_txn_id}");
-                    let lookup = self
-                        .get_lookup_by_lookup_id(&lookup_id)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
+                    let lookup = self.get_lookup_by_lookup_id(&lookup_id).await?;
 
                     let key = &lookup.pk_id;
                     db_utils::try_redis_get_else_try_database_get(
@@ -675,11 +677,7 @@ mod storage {
 
                 enums::MerchantStorageScheme::RedisKv => {
                     let lookup_id = format!("{merchant_id}_{attempt_id}");
-                    let lookup = self
-                        .get_lookup_by_lookup_id(&lookup_id)
-                        .await
-                        .map_err(Into::<errors::StorageError>::into)
-                        .into_report()?;
+                    let lookup = self.get_lookup_by_lookup_id(&lookup_id).await?;
                     let key = &lookup.pk_id;
                     db_utils::try_redis_get_else_try_database_get(
                         self.redis_conn.get_hash_field_and_deserialize(
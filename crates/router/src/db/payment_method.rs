@@ -20,6 +20,13 @@ pub trait PaymentMethodInterface {
         merchant_id: &str,
     ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
 
+    async fn find_payment_method_by_card_fingerprint_optional(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<Option<storage::PaymentMethod>, errors::StorageError>;
+
     async fn insert_payment_method(
         &self,
         m: storage::PaymentMethodNew,
@@ -30,6 +37,36 @@ pub trait PaymentMethodInterface {
         merchant_id: &str,
         payment_method_id: &str,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+
+    async fn find_payment_method_by_network_token_reference_id(
+        &self,
+        network_token_reference_id: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+
+    async fn find_payment_methods_by_card_expiry_range(
+        &self,
+        from: (&str, &str),
+        to: (&str, &str),
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
+
+    async fn find_payment_methods_without_card_expiry(
+        &self,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
+
+    async fn update_payment_method(
+        &self,
+        payment_method: storage::PaymentMethod,
+        payment_method_update: storage::PaymentMethodUpdate,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+
+    async fn record_payment_method_usage(
+        &self,
+        payment_method_id: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -45,6 +82,24 @@ impl PaymentMethodInterface for Store {
             .into_report()
     }
 
+    async fn find_payment_method_by_card_fingerprint_optional(
+        &self,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<Option<storage::PaymentMethod>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::PaymentMethod::find_optional_by_merchant_id_customer_id_card_fingerprint(
+            &conn,
+            merchant_id,
+            customer_id,
+            card_fingerprint,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn insert_payment_method(
         &self,
         m: storage::PaymentMethodNew,
@@ -80,6 +135,74 @@ impl PaymentMethodInterface for Store {
         .map_err(Into::into)
         .into_report()
     }
+
+    async fn find_payment_method_by_network_token_reference_id(
+        &self,
+        network_token_reference_id: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::PaymentMethod::find_by_network_token_reference_id(
+            &conn,
+            network_token_reference_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn find_payment_methods_by_card_expiry_range(
+        &self,
+        from: (&str, &str),
+        to: (&str, &str),
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::PaymentMethod::find_cards_by_expiry_range(&conn, from, to, after_id, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payment_methods_without_card_expiry(
+        &self,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::PaymentMethod::find_cards_without_expiry(&conn, after_id, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_payment_method(
+        &self,
+        payment_method: storage::PaymentMethod,
+        payment_method_update: storage::PaymentMethodUpdate,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        payment_method
+            .update(&conn, payment_method_update)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn record_payment_method_usage(
+        &self,
+        payment_method_id: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::PaymentMethod::record_usage(
+            &conn,
+            payment_method_id,
+            common_utils::date_time::now(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -92,6 +215,16 @@ impl PaymentMethodInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_payment_method_by_card_fingerprint_optional(
+        &self,
+        _merchant_id: &str,
+        _customer_id: &str,
+        _card_fingerprint: &str,
+    ) -> CustomResult<Option<storage::PaymentMethod>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn insert_payment_method(
         &self,
         _m: storage::PaymentMethodNew,
@@ -117,4 +250,49 @@ impl PaymentMethodInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_payment_method_by_network_token_reference_id(
+        &self,
+        _network_token_reference_id: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_methods_by_card_expiry_range(
+        &self,
+        _from: (&str, &str),
+        _to: (&str, &str),
+        _after_id: i32,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_methods_without_card_expiry(
+        &self,
+        _after_id: i32,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_method(
+        &self,
+        _payment_method: storage::PaymentMethod,
+        _payment_method_update: storage::PaymentMethodUpdate,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn record_payment_method_usage(
+        &self,
+        _payment_method_id: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
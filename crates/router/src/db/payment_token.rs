@@ -0,0 +1,182 @@
+use error_stack::IntoReport;
+use time::PrimitiveDateTime;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentTokenInterface {
+    async fn insert_payment_token(
+        &self,
+        payment_token: storage::PaymentTokenNew,
+    ) -> CustomResult<storage::PaymentToken, errors::StorageError>;
+
+    async fn find_payment_token_optional(
+        &self,
+        token: &str,
+    ) -> CustomResult<Option<storage::PaymentToken>, errors::StorageError>;
+
+    /// Record a use of the payment token, if it was created after `created_after` and was used
+    /// fewer than `max_uses` times. Returns `None` if the token cannot be used.
+    async fn record_payment_token_use(
+        &self,
+        token: &str,
+        created_after: PrimitiveDateTime,
+        max_uses: i32,
+    ) -> CustomResult<Option<storage::PaymentToken>, errors::StorageError>;
+
+    /// Release a use of the payment token recorded with `record_payment_token_use`. Returns
+    /// whether a use was released.
+    async fn release_payment_token_use(
+        &self,
+        token: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn find_payment_tokens_created_before(
+        &self,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentToken>, errors::StorageError>;
+
+    async fn delete_payment_tokens(
+        &self,
+        tokens: Vec<String>,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentTokenInterface for Store {
+    async fn insert_payment_token(
+        &self,
+        payment_token: storage::PaymentTokenNew,
+    ) -> CustomResult<storage::PaymentToken, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        payment_token
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payment_token_optional(
+        &self,
+        token: &str,
+    ) -> CustomResult<Option<storage::PaymentToken>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::PaymentToken::find_optional_by_token(&conn, token)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn record_payment_token_use(
+        &self,
+        token: &str,
+        created_after: PrimitiveDateTime,
+        max_uses: i32,
+    ) -> CustomResult<Option<storage::PaymentToken>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::PaymentToken::record_use(
+            &conn,
+            token,
+            created_after,
+            max_uses,
+            common_utils::date_time::now(),
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    async fn release_payment_token_use(
+        &self,
+        token: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::PaymentToken::release_use(&conn, token)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_payment_tokens_created_before(
+        &self,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentToken>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::PaymentToken::find_created_before(&conn, created_before, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn delete_payment_tokens(
+        &self,
+        tokens: Vec<String>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::PaymentToken::delete_by_tokens(&conn, tokens)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentTokenInterface for MockDb {
+    async fn insert_payment_token(
+        &self,
+        _payment_token: storage::PaymentTokenNew,
+    ) -> CustomResult<storage::PaymentToken, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_token_optional(
+        &self,
+        _token: &str,
+    ) -> CustomResult<Option<storage::PaymentToken>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn record_payment_token_use(
+        &self,
+        _token: &str,
+        _created_after: PrimitiveDateTime,
+        _max_uses: i32,
+    ) -> CustomResult<Option<storage::PaymentToken>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn release_payment_token_use(
+        &self,
+        _token: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_tokens_created_before(
+        &self,
+        _created_before: PrimitiveDateTime,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentToken>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_payment_tokens(
+        &self,
+        _tokens: Vec<String>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
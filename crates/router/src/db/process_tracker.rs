@@ -5,7 +5,10 @@ use super::{MockDb, Store};
 use crate::{
     connection::pg_connection,
     core::errors::{self, CustomResult},
-    types::storage::{self, enums},
+    types::{
+        api,
+        storage::{self, enums, ProcessTrackerDbExt},
+    },
 };
 
 #[async_trait::async_trait]
@@ -50,6 +53,16 @@ pub trait ProcessTrackerInterface {
         status: enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn filter_processes_by_constraints(
+        &self,
+        constraints: &api::ProcessTrackerListConstraints,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn count_processes_by_status(
+        &self,
+        runner: Option<&str>,
+    ) -> CustomResult<Vec<(enums::ProcessTrackerStatus, i64)>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -143,6 +156,28 @@ impl ProcessTrackerInterface for Store {
             .map_err(Into::into)
             .into_report()
     }
+
+    async fn filter_processes_by_constraints(
+        &self,
+        constraints: &api::ProcessTrackerListConstraints,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::ProcessTracker::filter_by_constraints(&conn, constraints)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn count_processes_by_status(
+        &self,
+        runner: Option<&str>,
+    ) -> CustomResult<Vec<(enums::ProcessTrackerStatus, i64)>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::ProcessTracker::count_by_status(&conn, runner)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
 }
 
 #[async_trait::async_trait]
@@ -232,4 +267,20 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn filter_processes_by_constraints(
+        &self,
+        _constraints: &api::ProcessTrackerListConstraints,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn count_processes_by_status(
+        &self,
+        _runner: Option<&str>,
+    ) -> CustomResult<Vec<(enums::ProcessTrackerStatus, i64)>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
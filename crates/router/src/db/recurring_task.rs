@@ -0,0 +1,121 @@
+use error_stack::IntoReport;
+use time::PrimitiveDateTime;
+
+use super::{MockDb, Store};
+use crate::{
+    connection::pg_connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait RecurringTaskInterface {
+    async fn insert_recurring_task(
+        &self,
+        recurring_task: storage::RecurringTaskNew,
+    ) -> CustomResult<storage::RecurringTask, errors::StorageError>;
+
+    async fn find_recurring_task_by_id(
+        &self,
+        id: &str,
+    ) -> CustomResult<Option<storage::RecurringTask>, errors::StorageError>;
+
+    async fn update_recurring_task(
+        &self,
+        this: storage::RecurringTask,
+        recurring_task: storage::RecurringTaskUpdate,
+    ) -> CustomResult<storage::RecurringTask, errors::StorageError>;
+
+    async fn find_due_recurring_tasks(
+        &self,
+        time: PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::RecurringTask>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RecurringTaskInterface for Store {
+    async fn insert_recurring_task(
+        &self,
+        recurring_task: storage::RecurringTaskNew,
+    ) -> CustomResult<storage::RecurringTask, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        recurring_task
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_recurring_task_by_id(
+        &self,
+        id: &str,
+    ) -> CustomResult<Option<storage::RecurringTask>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::RecurringTask::find_by_id(&conn, id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn update_recurring_task(
+        &self,
+        this: storage::RecurringTask,
+        recurring_task: storage::RecurringTaskUpdate,
+    ) -> CustomResult<storage::RecurringTask, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        this.update(&conn, recurring_task)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    async fn find_due_recurring_tasks(
+        &self,
+        time: PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::RecurringTask>, errors::StorageError> {
+        let conn = pg_connection(&self.master_pool).await;
+        storage::RecurringTask::find_due(&conn, time, limit)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl RecurringTaskInterface for MockDb {
+    async fn insert_recurring_task(
+        &self,
+        _recurring_task: storage::RecurringTaskNew,
+    ) -> CustomResult<storage::RecurringTask, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_recurring_task_by_id(
+        &self,
+        _id: &str,
+    ) -> CustomResult<Option<storage::RecurringTask>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_recurring_task(
+        &self,
+        _this: storage::RecurringTask,
+        _recurring_task: storage::RecurringTaskUpdate,
+    ) -> CustomResult<storage::RecurringTask, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_due_recurring_tasks(
+        &self,
+        _time: PrimitiveDateTime,
+        _limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::RecurringTask>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -1,36 +1,243 @@
-use storage_models::{errors, StorageResult};
-
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection::pg_connection,
-    types::storage::reverse_lookup::{ReverseLookup, ReverseLookupNew},
+    core::errors::{self, CustomResult},
+    types::storage::{
+        enums,
+        reverse_lookup::{ReverseLookup, ReverseLookupNew},
+    },
 };
 
 #[async_trait::async_trait]
 pub trait ReverseLookupInterface {
-    async fn insert_reverse_lookup(&self, _new: ReverseLookupNew) -> StorageResult<ReverseLookup>;
-    async fn get_lookup_by_lookup_id(&self, _id: &str) -> StorageResult<ReverseLookup>;
+    async fn insert_reverse_lookup(
+        &self,
+        _new: ReverseLookupNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<ReverseLookup, errors::StorageError>;
+    async fn get_lookup_by_lookup_id(
+        &self,
+        _id: &str,
+    ) -> CustomResult<ReverseLookup, errors::StorageError>;
+    async fn delete_reverse_lookup(
+        &self,
+        _id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<bool, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl ReverseLookupInterface for Store {
-    async fn insert_reverse_lookup(&self, new: ReverseLookupNew) -> StorageResult<ReverseLookup> {
-        let conn = pg_connection(&self.master_pool).await;
-        new.insert(&conn).await
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::IntoReport;
+
+    use super::ReverseLookupInterface;
+    use crate::{
+        connection::pg_connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{
+            enums,
+            reverse_lookup::{ReverseLookup, ReverseLookupNew},
+        },
+    };
+
+    #[async_trait::async_trait]
+    impl ReverseLookupInterface for Store {
+        async fn insert_reverse_lookup(
+            &self,
+            new: ReverseLookupNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            new.insert(&conn).await.map_err(Into::into).into_report()
+        }
+
+        async fn get_lookup_by_lookup_id(
+            &self,
+            id: &str,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            ReverseLookup::find_by_lookup_id(id, &conn)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+
+        async fn delete_reverse_lookup(
+            &self,
+            id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<bool, errors::StorageError> {
+            let conn = pg_connection(&self.master_pool).await;
+            ReverseLookup::delete_by_lookup_id(&conn, id)
+                .await
+                .map_err(Into::into)
+                .into_report()
+        }
+    }
+}
+
+#[cfg(feature = "kv_store")]
+mod storage {
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::SetnxReply;
+
+    use super::ReverseLookupInterface;
+    use crate::{
+        connection::pg_connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{
+            enums, kv,
+            reverse_lookup::{ReverseLookup, ReverseLookupNew},
+        },
+        utils::{db_utils, storage_partitioning::PartitionKey},
+    };
+
+    /// Reverse lookups written in KV mode are held in Redis until the drainer has inserted them
+    /// in the database, and expire afterwards.
+    fn get_redis_key(lookup_id: &str) -> String {
+        format!("reverse_lookup_{lookup_id}")
     }
 
-    async fn get_lookup_by_lookup_id(&self, id: &str) -> StorageResult<ReverseLookup> {
-        let conn = pg_connection(&self.master_pool).await;
-        ReverseLookup::find_by_lookup_id(id, &conn).await
+    #[async_trait::async_trait]
+    impl ReverseLookupInterface for Store {
+        async fn insert_reverse_lookup(
+            &self,
+            new: ReverseLookupNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await;
+                    new.insert(&conn).await.map_err(Into::into).into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let created_lookup = ReverseLookup {
+                        lookup_id: new.lookup_id.clone(),
+                        sk_id: new.sk_id.clone(),
+                        pk_id: new.pk_id.clone(),
+                        source: new.source.clone(),
+                    };
+                    let redis_value = serde_json::to_string(&created_lookup)
+                        .into_report()
+                        .change_context(errors::StorageError::KVError)?;
+
+                    match self
+                        .redis_conn
+                        .set_key_if_not_exist(
+                            &get_redis_key(&created_lookup.lookup_id),
+                            redis_value.as_str(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::KVError)?
+                    {
+                        SetnxReply::KeyNotSet => Err(errors::StorageError::DuplicateValue {
+                            entity: "reverse lookup",
+                            key: Some(created_lookup.lookup_id),
+                        })
+                        .into_report(),
+                        SetnxReply::KeySet => {
+                            let redis_entry = kv::TypedSql {
+                                op: kv::DBOperation::Insert {
+                                    insertable: kv::Insertable::ReverseLookUp(new),
+                                },
+                            };
+                            self.push_to_drainer_stream::<ReverseLookup>(
+                                redis_entry,
+                                PartitionKey::LookupId {
+                                    lookup_id: &created_lookup.lookup_id,
+                                },
+                            )
+                            .await?;
+                            Ok(created_lookup)
+                        }
+                    }
+                }
+            }
+        }
+
+        async fn get_lookup_by_lookup_id(
+            &self,
+            id: &str,
+        ) -> CustomResult<ReverseLookup, errors::StorageError> {
+            let database_call = || async {
+                let conn = pg_connection(&self.master_pool).await;
+                ReverseLookup::find_by_lookup_id(id, &conn)
+                    .await
+                    .map_err(Into::into)
+                    .into_report()
+            };
+            db_utils::try_redis_get_else_try_database_get(
+                self.redis_conn
+                    .get_and_deserialize_key(&get_redis_key(id), "ReverseLookup"),
+                database_call,
+            )
+            .await
+        }
+
+        async fn delete_reverse_lookup(
+            &self,
+            id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<bool, errors::StorageError> {
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = pg_connection(&self.master_pool).await;
+                    ReverseLookup::delete_by_lookup_id(&conn, id)
+                        .await
+                        .map_err(Into::into)
+                        .into_report()
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    self.redis_conn
+                        .delete_key(&get_redis_key(id))
+                        .await
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Delete {
+                            deletable: kv::Deletable::ReverseLookUp {
+                                lookup_id: id.to_string(),
+                            },
+                        },
+                    };
+                    self.push_to_drainer_stream::<ReverseLookup>(
+                        redis_entry,
+                        PartitionKey::LookupId { lookup_id: id },
+                    )
+                    .await?;
+                    Ok(true)
+                }
+            }
+        }
     }
 }
 
 #[async_trait::async_trait]
 impl ReverseLookupInterface for MockDb {
-    async fn insert_reverse_lookup(&self, _new: ReverseLookupNew) -> StorageResult<ReverseLookup> {
-        Err(errors::DatabaseError::NotFound.into())
+    async fn insert_reverse_lookup(
+        &self,
+        _new: ReverseLookupNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<ReverseLookup, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_lookup_by_lookup_id(
+        &self,
+        _id: &str,
+    ) -> CustomResult<ReverseLookup, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
     }
-    async fn get_lookup_by_lookup_id(&self, _id: &str) -> StorageResult<ReverseLookup> {
-        Err(errors::DatabaseError::NotFound.into())
+
+    async fn delete_reverse_lookup(
+        &self,
+        _id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
     }
 }
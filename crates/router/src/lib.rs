@@ -1,6 +1,7 @@
 #![forbid(unsafe_code)]
 #![recursion_limit = "256"]
 
+pub mod cache;
 #[cfg(feature = "stripe")]
 pub mod compatibility;
 pub mod configs;
@@ -55,7 +56,7 @@ pub mod headers {
 pub mod pii {
     //! Personal Identifiable Information protection.
 
-    pub(crate) use common_utils::pii::{CardNumber, Email};
+    pub(crate) use common_utils::pii::{BankAccountNumber, CardNumber, Email, IpAddress, UpiVpa};
     #[doc(inline)]
     pub use masking::*;
 }
@@ -98,7 +99,10 @@ pub fn mk_app(
     {
         server_app = server_app
             .service(routes::MerchantAccount::server(state.clone()))
-            .service(routes::ApiKeys::server(state.clone()));
+            .service(routes::ApiKeys::server(state.clone()))
+            .service(routes::ProcessTracker::server(state.clone()))
+            .service(routes::FraudCheck::server(state.clone()))
+            .service(routes::Capabilities::server(state.clone()));
     }
 
     #[cfg(feature = "stripe")]
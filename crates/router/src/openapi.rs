@@ -58,6 +58,7 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
         (name = "API Key", description = "Create and manage API Keys"),
+        (name = "Fraud Check", description = "Review payments checked by fraud and risk management connectors"),
     ),
     paths(
         crate::routes::refunds::refunds_create,
@@ -96,11 +97,16 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::routes::customers::customers_retrieve,
         crate::routes::customers::customers_update,
         crate::routes::customers::customers_delete,
+        crate::routes::customers::customers_set_default_payment_method,
         crate::routes::api_keys::api_key_create,
         crate::routes::api_keys::api_key_retrieve,
         crate::routes::api_keys::api_key_update,
         crate::routes::api_keys::api_key_revoke,
         crate::routes::api_keys::api_key_list,
+        crate::routes::fraud_check::fraud_check_review_queue,
+        crate::routes::fraud_check::fraud_check_retrieve,
+        crate::routes::fraud_check::fraud_check_review,
+        crate::routes::capabilities::connector_capabilities_list,
     ),
     components(schemas(
         crate::types::api::refunds::RefundRequest,
@@ -127,11 +133,13 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::RoutingAlgorithm,
         api_models::enums::PaymentMethodType,
         api_models::enums::PaymentMethodSubType,
+        api_models::enums::CardFundingType,
         api_models::enums::ConnectorType,
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
         api_models::enums::CaptureMethod,
         api_models::enums::FutureUsage,
+        api_models::enums::DeclineCode,
         api_models::enums::AuthenticationType,
         api_models::enums::WalletIssuer,
         api_models::enums::Connector,
@@ -155,6 +163,16 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::MandateData,
         api_models::payments::PhoneDetails,
         api_models::payments::PaymentMethod,
+        api_models::bank_accounts::BankDebitData,
+        api_models::bank_accounts::BankDebitBilling,
+        api_models::bank_accounts::BankAccountResponse,
+        api_models::payments::BankRedirectData,
+        api_models::payments::BankRedirectBilling,
+        api_models::payments::BankTransferData,
+        api_models::payments::BankTransferBilling,
+        api_models::payments::BankTransferInstructions,
+        api_models::payments::UpiData,
+        api_models::payments::PollConfig,
         api_models::payments::MandateType,
         api_models::payments::AcceptanceType,
         api_models::payments::MandateAmountData,
@@ -163,6 +181,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::CustomerAcceptance,
         api_models::payments::PaymentsRequest,
         api_models::payments::PaymentsResponse,
+        api_models::cards::CardInfo,
         api_models::payment_methods::PaymentExperience,
         api_models::payments::PaymentsStartRequest,
         api_models::payments::PaymentRetrieveBody,
@@ -190,12 +209,19 @@ Never share your secret api keys. Keep them guarded and secure.
         crate::types::api::admin::MerchantConnectorId,
         crate::types::api::admin::MerchantDetails,
         crate::types::api::admin::WebhookDetails,
+        crate::types::api::admin::PaymentTokenConfig,
         crate::types::api::api_keys::ApiKeyExpiration,
         crate::types::api::api_keys::CreateApiKeyRequest,
         crate::types::api::api_keys::CreateApiKeyResponse,
         crate::types::api::api_keys::RetrieveApiKeyResponse,
         crate::types::api::api_keys::RevokeApiKeyResponse,
-        crate::types::api::api_keys::UpdateApiKeyRequest
+        crate::types::api::api_keys::UpdateApiKeyRequest,
+        api_models::enums::FraudCheckStage,
+        api_models::enums::FraudCheckStatus,
+        api_models::fraud_check::FraudCheckResponse,
+        api_models::fraud_check::FraudCheckReviewRequest,
+        api_models::fraud_check::FraudCheckReviewDecision,
+        api_models::capabilities::ConnectorCapabilities
     ))
 )]
 pub struct ApiDoc;
@@ -1,21 +1,25 @@
 pub mod admin;
 pub mod api_keys;
 pub mod app;
+pub mod capabilities;
 pub mod configs;
 pub mod customers;
 pub mod ephemeral_key;
+pub mod fraud_check;
 pub mod health;
 pub mod mandates;
 pub mod metrics;
 pub mod payment_methods;
 pub mod payments;
 pub mod payouts;
+pub mod process_tracker;
 pub mod refunds;
 pub mod webhooks;
 
 pub use self::app::{
-    ApiKeys, AppState, Configs, Customers, EphemeralKey, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentMethods, Payments, Payouts, Refunds, Webhooks,
+    ApiKeys, AppState, Capabilities, Configs, Customers, EphemeralKey, FraudCheck, Health,
+    Mandates, MerchantAccount, MerchantConnectorAccount, PaymentMethods, Payments, Payouts,
+    ProcessTracker, Refunds, Webhooks,
 };
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
@@ -171,7 +171,7 @@ pub async fn payment_connector_create(
         state.get_ref(),
         &req,
         json_payload.into_inner(),
-        |state, _, req| create_payment_connector(&*state.store, req, &merchant_id),
+        |state, _, req| create_payment_connector(&*state.store, &state.conf, req, &merchant_id),
         &auth::AdminApiAuth,
     )
     .await
@@ -288,7 +288,13 @@ pub async fn payment_connector_update(
         &req,
         json_payload.into_inner(),
         |state, _, req| {
-            update_payment_connector(&*state.store, &merchant_id, &merchant_connector_id, req)
+            update_payment_connector(
+                &*state.store,
+                &state.conf,
+                &merchant_id,
+                &merchant_connector_id,
+                req,
+            )
         },
         &auth::AdminApiAuth,
     )
@@ -2,13 +2,14 @@ use actix_web::{web, Scope};
 
 use super::health::*;
 #[cfg(feature = "olap")]
-use super::{admin::*, api_keys::*};
+use super::{admin::*, api_keys::*, capabilities::*, fraud_check::*, process_tracker::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, mandates::*, payments::*, payouts::*, refunds::*};
 #[cfg(feature = "oltp")]
 use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
 use crate::{
     configs::settings::Settings,
+    core::payment_methods::bin_lookup,
     db::{MockDb, StorageImpl, StorageInterface},
     services::Store,
 };
@@ -47,6 +48,7 @@ impl AppState {
             }
             StorageImpl::Mock => Box::new(MockDb::new(&conf).await),
         };
+        bin_lookup::load_bin_table(&conf.bin_lookup).await;
 
         Self {
             flow_name: String::from("default"),
@@ -145,6 +147,10 @@ impl Customers {
                 .service(
                     web::resource("/{customer_id}/payment_methods")
                         .route(web::get().to(list_customer_payment_method_api)),
+                )
+                .service(
+                    web::resource("/{customer_id}/payment_methods/{payment_method_id}/default")
+                        .route(web::post().to(customers_set_default_payment_method)),
                 );
         }
         route
@@ -311,12 +317,19 @@ pub struct Webhooks;
 #[cfg(feature = "oltp")]
 impl Webhooks {
     pub fn server(config: AppState) -> Scope {
-        web::scope("/webhooks")
-            .app_data(web::Data::new(config))
-            .service(
-                web::resource("/{merchant_id}/{connector}")
-                    .route(web::post().to(receive_incoming_webhook)),
-            )
+        let network_tokenization_enabled = config.conf.network_tokenization.enabled;
+        let mut route = web::scope("/webhooks").app_data(web::Data::new(config));
+        // Token lifecycle webhooks are only accepted when network tokenization is enabled
+        if network_tokenization_enabled {
+            route = route.service(
+                web::resource("/network_tokens")
+                    .route(web::post().to(receive_network_token_webhook)),
+            );
+        }
+        route.service(
+            web::resource("/{merchant_id}/{connector}")
+                .route(web::post().to(receive_incoming_webhook)),
+        )
     }
 }
 
@@ -352,3 +365,51 @@ impl ApiKeys {
             )
     }
 }
+
+pub struct ProcessTracker;
+
+#[cfg(feature = "olap")]
+impl ProcessTracker {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/process_tracker")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/list").route(web::get().to(process_tracker_list)))
+            .service(web::resource("/summary").route(web::get().to(process_tracker_summary)))
+            .service(web::resource("/{id}").route(web::get().to(process_tracker_retrieve)))
+            .service(
+                web::resource("/{id}/retry_schedule")
+                    .route(web::get().to(process_tracker_retry_schedule)),
+            )
+            .service(web::resource("/{id}/retry").route(web::post().to(process_tracker_retry)))
+            .service(
+                web::resource("/{id}/reschedule").route(web::post().to(process_tracker_reschedule)),
+            )
+            .service(web::resource("/{id}/cancel").route(web::post().to(process_tracker_cancel)))
+    }
+}
+
+pub struct FraudCheck;
+
+#[cfg(feature = "olap")]
+impl FraudCheck {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/fraud_checks")
+            .app_data(web::Data::new(state))
+            .service(web::resource("/review_queue").route(web::get().to(fraud_check_review_queue)))
+            .service(web::resource("/{frm_id}").route(web::get().to(fraud_check_retrieve)))
+            .service(web::resource("/{frm_id}/review").route(web::post().to(fraud_check_review)))
+    }
+}
+
+pub struct Capabilities;
+
+#[cfg(feature = "olap")]
+impl Capabilities {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connectors")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/capabilities").route(web::get().to(connector_capabilities_list)),
+            )
+    }
+}
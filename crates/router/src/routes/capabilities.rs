@@ -0,0 +1,36 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::capabilities,
+    services::{api, authentication as auth},
+};
+
+/// Connector Capabilities - List
+///
+/// List the payment methods, currencies, countries, capture methods and mandates supported by each
+/// connector.
+#[utoipa::path(
+    get,
+    path = "/connectors/capabilities",
+    responses(
+        (status = 200, description = "Capabilities of the connectors", body = Vec<ConnectorCapabilities>),
+    ),
+    tag = "Connector Capabilities",
+    operation_id = "List Connector Capabilities"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorCapabilitiesList))]
+pub async fn connector_capabilities_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (),
+        |_, _, _| capabilities::list_connector_capabilities(),
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
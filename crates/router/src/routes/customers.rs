@@ -151,6 +151,52 @@ pub async fn customers_delete(
     .await
 }
 
+// Set Default Payment Method
+
+///
+/// Set the saved payment method to be preselected for the customer in future payments.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/payment_methods/{payment_method_id}/default",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("payment_method_id" = String, Path, description = "The unique identifier of the saved payment method, as listed for the Customer"),
+    ),
+    responses(
+        (status = 200, description = "Default Payment Method was Set", body = CustomerResponse),
+        (status = 404, description = "Customer or Payment Method was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Set the Default Payment Method of a Customer"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersSetDefaultPaymentMethod))]
+pub async fn customers_set_default_payment_method(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> HttpResponse {
+    let (customer_id, payment_method_id) = path.into_inner();
+    let payload = customers::CustomerPaymentMethodId {
+        customer_id,
+        payment_method_id,
+    };
+
+    let auth =
+        match auth::is_ephemeral_auth(req.headers(), &*state.store, &payload.customer_id).await {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(err),
+        };
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        payload,
+        set_default_payment_method,
+        &*auth,
+    )
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::CustomersGetMandates))]
 pub async fn get_customer_mandates(
     state: web::Data<AppState>,
@@ -0,0 +1,114 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::fraud_check,
+    services::{api, authentication as auth},
+    types::api::fraud_check as fraud_check_types,
+};
+
+/// Fraud Check - List Review Queue
+///
+/// List the fraud checks which hold payments for a manual review.
+#[utoipa::path(
+    get,
+    path = "/fraud_checks/review_queue",
+    params(
+        ("limit" = Option<i64>, Query, description = "The maximum number of fraud checks to include in the response"),
+        ("offset" = Option<i64>, Query, description = "The number of fraud checks to skip when retrieving the list"),
+    ),
+    responses(
+        (status = 200, description = "List of fraud checks held for review", body = Vec<FraudCheckResponse>),
+    ),
+    tag = "Fraud Check",
+    operation_id = "List Fraud Checks held for Review"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::FraudCheckReviewQueue))]
+pub async fn fraud_check_review_queue(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<fraud_check_types::ListFraudCheckConstraints>,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        query.into_inner(),
+        |state, merchant_account, constraints| {
+            fraud_check::list_review_queue(&*state.store, merchant_account, constraints)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Fraud Check - Retrieve
+///
+/// Retrieve a fraud check made on a payment.
+#[utoipa::path(
+    get,
+    path = "/fraud_checks/{frm_id}",
+    params(("frm_id" = String, Path, description = "The identifier for the fraud check")),
+    responses(
+        (status = 200, description = "Fraud check retrieved", body = FraudCheckResponse),
+        (status = 400, description = "Fraud check does not exist in our records"),
+    ),
+    tag = "Fraud Check",
+    operation_id = "Retrieve a Fraud Check"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::FraudCheckRetrieve))]
+pub async fn fraud_check_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let frm_id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        &frm_id,
+        |state, merchant_account, frm_id| {
+            fraud_check::retrieve_fraud_check(&*state.store, merchant_account, frm_id)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
+
+/// Fraud Check - Review
+///
+/// Accept or reject a payment held for a manual review, capturing or voiding the payment.
+#[utoipa::path(
+    post,
+    path = "/fraud_checks/{frm_id}/review",
+    request_body = FraudCheckReviewRequest,
+    params(("frm_id" = String, Path, description = "The identifier for the fraud check")),
+    responses(
+        (status = 200, description = "Fraud check reviewed", body = FraudCheckResponse),
+        (status = 400, description = "Fraud check does not exist in our records"),
+    ),
+    tag = "Fraud Check",
+    operation_id = "Review a Fraud Check"
+)]
+#[instrument(skip_all, fields(flow = ?Flow::FraudCheckReview))]
+pub async fn fraud_check_review(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<fraud_check_types::FraudCheckReviewRequest>,
+) -> impl Responder {
+    let frm_id = path.into_inner();
+    let payload = json_payload.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (&frm_id, payload),
+        |state, merchant_account, (frm_id, payload)| {
+            fraud_check::review_fraud_check(state, merchant_account, frm_id, payload)
+        },
+        &auth::ApiKeyAuth,
+    )
+    .await
+}
@@ -82,9 +82,7 @@ pub async fn list_payment_method_api(
         state.get_ref(),
         &req,
         payload,
-        |state, merchant_account, req| {
-            cards::list_payment_methods(&*state.store, merchant_account, req)
-        },
+        |state, merchant_account, req| cards::list_payment_methods(state, merchant_account, req),
         &*auth,
     )
     .await
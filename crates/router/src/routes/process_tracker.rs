@@ -0,0 +1,137 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::process_tracker,
+    services::{api, authentication as auth},
+    types::api as api_types,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerList))]
+pub async fn process_tracker_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<api_types::ProcessTrackerListConstraints>,
+) -> impl Responder {
+    let constraints = query.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        constraints,
+        |state, _, constraints| process_tracker::list_processes(&*state.store, constraints),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerSummary))]
+pub async fn process_tracker_summary(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<api_types::ProcessTrackerSummaryConstraints>,
+) -> impl Responder {
+    let constraints = query.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        constraints,
+        |state, _, constraints| process_tracker::summary(&*state.store, constraints),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRetrieve))]
+pub async fn process_tracker_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        &id,
+        |state, _, id| process_tracker::retrieve_process(&*state.store, id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRetrySchedule))]
+pub async fn process_tracker_retry_schedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        &id,
+        |state, _, id| process_tracker::retrieve_retry_schedule(&*state.store, id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRetry))]
+pub async fn process_tracker_retry(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        &id,
+        |state, _, id| process_tracker::force_retry(&*state.store, id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerReschedule))]
+pub async fn process_tracker_reschedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: Option<web::Json<api_types::ProcessTrackerRescheduleRequest>>,
+) -> impl Responder {
+    let id = path.into_inner();
+    let payload = json_payload.map(web::Json::into_inner).unwrap_or_default();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        (id, payload),
+        |state, _, (id, payload)| process_tracker::reschedule(&*state.store, &id, payload),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerCancel))]
+pub async fn process_tracker_cancel(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let id = path.into_inner();
+
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        &id,
+        |state, _, id| process_tracker::cancel(&*state.store, id),
+        &auth::AdminApiAuth,
+    )
+    .await
+}
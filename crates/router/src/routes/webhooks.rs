@@ -3,7 +3,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::webhooks,
+    core::{payment_methods::network_tokenization, webhooks},
     services::{api, authentication as auth},
 };
 
@@ -27,3 +27,19 @@ pub async fn receive_incoming_webhook(
     )
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::NetworkTokenWebhookReceive))]
+pub async fn receive_network_token_webhook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Bytes,
+) -> impl Responder {
+    api::server_wrap(
+        state.get_ref(),
+        &req,
+        body,
+        |state, _, body| network_tokenization::handle_lifecycle_webhook(state, &req, body),
+        &auth::NoAuth,
+    )
+    .await
+}
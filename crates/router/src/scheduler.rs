@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 
 pub mod consumer;
+pub mod limiter;
 pub mod metrics;
 pub mod producer;
 pub mod types;
@@ -14,6 +14,7 @@ use time::PrimitiveDateTime;
 use uuid::Uuid;
 
 use super::{
+    limiter::WorkflowLimiter,
     metrics,
     workflows::{self, ProcessTrackerWorkflow},
 };
@@ -53,6 +54,7 @@ pub async fn start_consumer(
     ));
 
     let consumer_operation_counter = sync::Arc::new(atomic::AtomicU64::new(0));
+    let limiter = sync::Arc::new(WorkflowLimiter::new());
     let signal = get_allowed_signals()
         .map_err(|error| {
             logger::error!("Signal Handler Error: {:?}", error);
@@ -78,6 +80,7 @@ pub async fn start_consumer(
                     state.clone(),
                     options.clone(),
                     settings.clone(),
+                    sync::Arc::clone(&limiter),
                     |err| {
                         logger::error!(%err);
                     },
@@ -113,6 +116,7 @@ pub async fn consumer_operations(
     state: &AppState,
     _options: &super::SchedulerOptions,
     settings: &settings::SchedulerSettings,
+    limiter: sync::Arc<WorkflowLimiter>,
 ) -> CustomResult<(), errors::ProcessTrackerError> {
     let stream_name = settings.stream.clone();
     let group_name = settings.consumer.consumer_group.clone();
@@ -141,11 +145,24 @@ pub async fn consumer_operations(
 
         metrics::TASK_CONSUMED.add(&metrics::CONTEXT, 1, &[]);
         let runner = pt_utils::runner_from_task(task)?;
+
+        let retry_after = pt_utils::connector_from_task(task)
+            .and_then(|connector| limiter.check_rate_limit(runner, &connector));
+        if let Some(retry_after) = retry_after {
+            logger::debug!(pt.id = %task.id, ?retry_after, "Connector rate limit reached, deferring task");
+            if let Err(error) = pt_utils::defer_task(&*state.store, task.clone(), retry_after).await
+            {
+                logger::error!(pt.id = %task.id, %error, "Failed while deferring rate limited task");
+            }
+            continue;
+        }
+
         handler.push(tokio::task::spawn(start_workflow(
             state.clone(),
             task.clone(),
             pickup_time,
             runner,
+            sync::Arc::clone(&limiter),
         )))
     }
     future::join_all(handler).await;
@@ -195,14 +212,17 @@ pub async fn fetch_consumer_tasks(
 }
 
 // Accept flow_options if required
-#[instrument(skip(state), fields(workflow_id))]
+#[instrument(skip(state, limiter), fields(workflow_id))]
 pub async fn start_workflow(
     state: AppState,
     process: storage::ProcessTracker,
     _pickup_time: PrimitiveDateTime,
     runner: workflows::PTRunner,
+    limiter: sync::Arc<WorkflowLimiter>,
 ) {
     tracing::Span::current().record("workflow_id", Uuid::new_v4().to_string());
+    // Held until the workflow completes, to bound the number of concurrent tasks of this runner
+    let _permit = limiter.acquire(runner).await;
     workflows::perform_workflow_execution(&state, process, runner).await
 }
 
@@ -239,15 +259,11 @@ pub async fn consumer_error_handler<E: fmt::Display + fmt::Debug>(
     logger::error!(pt.name = ?process.name, pt.id = %process.id, ?error, "ERROR: Failed while executing workflow");
 
     let db: &dyn StorageInterface = &*state.store;
-    db.process_tracker_update_process_status_by_ids(
-        vec![process.id],
-        storage::ProcessTrackerUpdate::StatusUpdate {
-            status: enums::ProcessTrackerStatus::Finish,
-            business_status: Some("GLOBAL_ERROR".to_string()),
-        },
-    )
-    .await
-    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+    process
+        .finish_with_error(db, "GLOBAL_ERROR".to_string(), Some(error.to_string()))
+        .await
+        .into_report()
+        .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
     Ok(())
 }
 
@@ -0,0 +1,123 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use strum::IntoEnumIterator;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use super::workflows::PTRunner;
+
+/// Enforces the concurrency and per-connector rate limits declared by each runner, within a
+/// single consumer.
+pub struct WorkflowLimiter {
+    concurrency: HashMap<PTRunner, Arc<Semaphore>>,
+    rate_limit_windows: Mutex<HashMap<(PTRunner, String), RateLimitWindow>>,
+}
+
+struct RateLimitWindow {
+    started_at: Instant,
+    count: u32,
+}
+
+impl WorkflowLimiter {
+    pub fn new() -> Self {
+        let concurrency = PTRunner::iter()
+            .map(|runner| {
+                let permits = runner.config().max_concurrency;
+                (runner, Arc::new(Semaphore::new(permits)))
+            })
+            .collect();
+
+        Self {
+            concurrency,
+            rate_limit_windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wait until the runner is below its concurrency limit. The returned permit must be held for
+    /// as long as the task is being executed.
+    pub async fn acquire(&self, runner: PTRunner) -> Option<OwnedSemaphorePermit> {
+        let semaphore = self.concurrency.get(&runner)?;
+        Arc::clone(semaphore).acquire_owned().await.ok()
+    }
+
+    /// Record the execution of a task of `runner` against `connector`. If the connector's rate
+    /// limit for the current window is exhausted, returns the duration after which the task may be
+    /// retried without recording it.
+    pub fn check_rate_limit(&self, runner: PTRunner, connector: &str) -> Option<Duration> {
+        let rate_limit = runner.config().connector_rate_limit?;
+        let window = Duration::from_secs(rate_limit.window_in_seconds.into());
+        let now = Instant::now();
+
+        let mut windows = self
+            .rate_limit_windows
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let entry = windows
+            .entry((runner, connector.to_owned()))
+            .or_insert(RateLimitWindow {
+                started_at: now,
+                count: 0,
+            });
+
+        let elapsed = now.saturating_duration_since(entry.started_at);
+        if elapsed >= window {
+            entry.started_at = now;
+            entry.count = 0;
+        }
+
+        if entry.count >= rate_limit.max_tasks {
+            Some(window.saturating_sub(elapsed))
+        } else {
+            entry.count += 1;
+            None
+        }
+    }
+}
+
+impl Default for WorkflowLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod limiter_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_rate_limit_is_per_connector() {
+        let limiter = WorkflowLimiter::new();
+        let runner = PTRunner::RefundWorkflowRouter;
+        let max_tasks = runner.config().connector_rate_limit.unwrap().max_tasks;
+
+        for _ in 0..max_tasks {
+            assert!(limiter.check_rate_limit(runner, "stripe").is_none());
+        }
+        assert!(limiter.check_rate_limit(runner, "stripe").is_some());
+        assert!(limiter.check_rate_limit(runner, "adyen").is_none());
+        assert!(limiter
+            .check_rate_limit(PTRunner::PaymentsSyncWorkflow, "stripe")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn test_concurrency_limit() {
+        let limiter = WorkflowLimiter::new();
+        let runner = PTRunner::RefundWorkflowRouter;
+        let max_concurrency = runner.config().max_concurrency;
+
+        let mut permits = Vec::new();
+        for _ in 0..max_concurrency {
+            permits.push(limiter.acquire(runner).await.unwrap());
+        }
+        let semaphore = limiter.concurrency.get(&runner).unwrap();
+        assert_eq!(semaphore.available_permits(), 0);
+
+        drop(permits);
+        assert_eq!(semaphore.available_permits(), max_concurrency);
+    }
+}
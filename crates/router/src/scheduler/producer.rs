@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
 
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
@@ -11,7 +11,7 @@ use crate::{
     db::StorageInterface,
     logger::{debug, error, info, warn},
     routes::AppState,
-    scheduler::{utils::*, SchedulerFlow, SchedulerOptions},
+    scheduler::{types::CronSchedule, utils::*, SchedulerFlow, SchedulerOptions},
     types::storage::{self, enums::ProcessTrackerStatus},
 };
 
@@ -66,10 +66,15 @@ pub async fn run_producer_flow(
         state,
         settings,
         move || async {
-            let tasks = fetch_producer_tasks(&*state.store, op, settings).await?;
+            if let Err(error) = materialize_recurring_tasks(&*state.store).await {
+                // Failing to materialize recurring tasks must not prevent scheduling other tasks
+                error!(%error, "Failed to materialize recurring tasks");
+            }
+
+            let mut tasks = fetch_producer_tasks(&*state.store, op, settings).await?;
             debug!("Producer count of tasks {}", tasks.len());
 
-            // [#268]: Allow task based segregation of tasks
+            sort_by_priority(&mut tasks);
 
             divide_and_append_tasks(state, SchedulerFlow::Producer, tasks, settings).await?;
 
@@ -133,3 +138,87 @@ pub async fn fetch_producer_tasks(
     metrics::TASKS_PICKED_COUNT.add(&metrics::CONTEXT, new_tasks.len() as u64, &[]);
     Ok(new_tasks)
 }
+
+/// Create a process tracker task for every recurring task that is due, and advance the recurring
+/// task to the next occurrence of its schedule. Occurrences missed while the producer was not
+/// running are skipped, only a single task is created for them.
+#[instrument(skip_all)]
+pub async fn materialize_recurring_tasks(
+    db: &dyn StorageInterface,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let now = common_utils::date_time::now();
+    let recurring_tasks = db
+        .find_due_recurring_tasks(now, None)
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessFetchingFailed)?;
+
+    for recurring_task in recurring_tasks {
+        let recurring_task_id = recurring_task.id.clone();
+        if let Err(error) = materialize_recurring_task(db, recurring_task, now).await {
+            error!(%error, %recurring_task_id, "Failed to materialize recurring task");
+        }
+    }
+
+    Ok(())
+}
+
+async fn materialize_recurring_task(
+    db: &dyn StorageInterface,
+    recurring_task: storage::RecurringTask,
+    now: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let schedule = match CronSchedule::from_str(&recurring_task.schedule) {
+        Ok(schedule) => schedule,
+        Err(error) => {
+            warn!(%error, recurring_task_id = %recurring_task.id, "Disabling recurring task");
+            db.update_recurring_task(recurring_task, storage::RecurringTaskUpdate::Disable)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+            return Ok(());
+        }
+    };
+
+    // The process ID is derived from the occurrence, so that an occurrence is never materialized
+    // twice, even if updating the recurring task below fails
+    let process_id = format!(
+        "{}_{}",
+        recurring_task.id,
+        recurring_task.next_run_at.assume_utc().unix_timestamp()
+    );
+    let process = storage::ProcessTrackerNew {
+        id: process_id,
+        name: Some(recurring_task.name.clone()),
+        tag: recurring_task.tag.clone(),
+        runner: Some(recurring_task.runner.clone()),
+        retry_count: 0,
+        // Scheduled immediately, as the occurrence may be older than the producer's fetch window
+        schedule_time: Some(now),
+        rule: String::new(),
+        tracking_data: recurring_task.tracking_data.clone(),
+        business_status: String::from("Pending"),
+        status: ProcessTrackerStatus::New,
+        event: vec![],
+        created_at: now,
+        updated_at: now,
+    };
+    match db.insert_process(process).await {
+        Ok(_) => (),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            debug!(recurring_task_id = %recurring_task.id, "Occurrence already materialized");
+        }
+        Err(error) => Err(error.change_context(errors::ProcessTrackerError::ProcessUpdateFailed))?,
+    }
+
+    let update = match schedule.next_after(now) {
+        Some(next_run_at) => storage::RecurringTaskUpdate::RunUpdate {
+            next_run_at,
+            last_run_at: now,
+        },
+        None => storage::RecurringTaskUpdate::Disable,
+    };
+    db.update_recurring_task(recurring_task, update)
+        .await
+        .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+    Ok(())
+}
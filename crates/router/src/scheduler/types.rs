@@ -1,15 +1,19 @@
 pub mod batch;
 pub mod config;
+pub mod cron;
 pub mod flow;
 pub mod options;
 pub mod process_data;
+pub mod runner_config;
 pub mod state;
 
 pub use self::{
     batch::ProcessTrackerBatch,
     config::SchedulerConfig,
+    cron::CronSchedule,
     flow::SchedulerFlow,
     options::{Milliseconds, SchedulerOptions},
     process_data::ProcessData,
+    runner_config::{RateLimit, RunnerConfig},
     state::{DummyWorkflowState, WorkflowState},
 };
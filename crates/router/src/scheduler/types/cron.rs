@@ -0,0 +1,229 @@
+//! Cron expressions used to schedule recurring tasks.
+//!
+//! An expression has five whitespace separated fields: minute (`0-59`), hour (`0-23`), day of
+//! month (`1-31`), month (`1-12`) and day of week (`0-7`, both `0` and `7` being Sunday). Each
+//! field accepts `*`, single values, ranges (`1-5`), lists (`1,15`) and steps (`*/15`, `0-30/10`).
+//! As in Vixie cron, when both the day of month and day of week fields are restricted, a day
+//! matching either of them is considered a match. All times are in UTC.
+
+use std::str::FromStr;
+
+use error_stack::{report, Report};
+use time::{Date, Duration, PrimitiveDateTime, Time};
+
+use crate::core::errors;
+
+/// Number of years to look ahead for the next occurrence of a schedule, before giving up.
+const MAX_YEARS_LOOKAHEAD: i32 = 5;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl FromStr for CronSchedule {
+    type Err = Report<errors::ValidationError>;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        let [minute, hour, day_of_month, month, day_of_week] = fields[..] else {
+            return Err(invalid_expression(
+                "cron expression must have exactly five fields",
+            ));
+        };
+
+        let mut days_of_week = parse_field(day_of_week, 0, 7)?;
+        // Fold `7` into `0`, both representing Sunday
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            minutes: parse_field(minute, 0, 59)?,
+            hours: parse_field(hour, 0, 23)?,
+            days_of_month: parse_field(day_of_month, 1, 31)?,
+            months: parse_field(month, 1, 12)?,
+            days_of_week,
+            day_of_month_restricted: !day_of_month.starts_with('*'),
+            day_of_week_restricted: !day_of_week.starts_with('*'),
+        })
+    }
+}
+
+impl CronSchedule {
+    /// The first time matching the schedule strictly after `after`, truncated to the minute.
+    /// Returns `None` if the schedule does not match any time in the next few years, as is the
+    /// case for expressions such as `0 0 31 2 *`.
+    pub fn next_after(&self, after: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        let start = after.checked_add(Duration::minutes(1))?;
+        let mut date = start.date();
+        let (mut hour, mut minute) = (start.hour(), start.minute());
+        let last_year = date.year() + MAX_YEARS_LOOKAHEAD;
+
+        while date.year() <= last_year {
+            if self.matches_date(date) {
+                for h in (hour..24).filter(|h| is_set(self.hours, *h)) {
+                    let first_minute = if h == hour { minute } else { 0 };
+                    if let Some(m) = (first_minute..60).find(|m| is_set(self.minutes, *m)) {
+                        let time = Time::from_hms(h, m, 0).ok()?;
+                        return Some(PrimitiveDateTime::new(date, time));
+                    }
+                }
+            }
+            date = date.next_day()?;
+            hour = 0;
+            minute = 0;
+        }
+
+        None
+    }
+
+    fn matches_date(&self, date: Date) -> bool {
+        if !is_set(self.months, u8::from(date.month())) {
+            return false;
+        }
+
+        let day_of_month = is_set(self.days_of_month, date.day());
+        let day_of_week = is_set(self.days_of_week, date.weekday().number_days_from_sunday());
+        if self.day_of_month_restricted && self.day_of_week_restricted {
+            day_of_month || day_of_week
+        } else {
+            day_of_month && day_of_week
+        }
+    }
+}
+
+fn is_set(field: u64, value: u8) -> bool {
+    field & (1 << value) != 0
+}
+
+fn parse_field(field: &str, min: u8, max: u8) -> Result<u64, Report<errors::ValidationError>> {
+    let mut values = 0;
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, Some(parse_value(step)?)),
+            None => (part, None),
+        };
+
+        let (start, end) = match range.split_once('-') {
+            _ if range == "*" => (min, max),
+            Some((start, end)) => (parse_value(start)?, parse_value(end)?),
+            // A single value with a step, such as `5/15`, runs up to the maximum of the field
+            None if step.is_some() => (parse_value(range)?, max),
+            None => {
+                let value = parse_value(range)?;
+                (value, value)
+            }
+        };
+
+        if start < min || end > max || start > end {
+            return Err(invalid_expression(&format!(
+                "`{part}` is out of the allowed range {min}-{max}"
+            )));
+        }
+        let step = match step {
+            Some(0) => return Err(invalid_expression("step must be greater than zero")),
+            Some(step) => usize::from(step),
+            None => 1,
+        };
+
+        for value in (start..=end).step_by(step) {
+            values |= 1 << value;
+        }
+    }
+
+    Ok(values)
+}
+
+fn parse_value(value: &str) -> Result<u8, Report<errors::ValidationError>> {
+    value
+        .parse()
+        .map_err(|_| invalid_expression(&format!("`{value}` is not a valid number")))
+}
+
+fn invalid_expression(message: &str) -> Report<errors::ValidationError> {
+    report!(errors::ValidationError::InvalidValue {
+        message: format!("Invalid cron expression: {message}"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn next(expression: &str, after: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        CronSchedule::from_str(expression)
+            .unwrap()
+            .next_after(after)
+    }
+
+    #[test]
+    fn test_every_fifteen_minutes() {
+        assert_eq!(
+            next("*/15 * * * *", datetime!(2023-02-10 10:07:30)),
+            Some(datetime!(2023-02-10 10:15:00))
+        );
+        assert_eq!(
+            next("*/15 * * * *", datetime!(2023-02-10 23:45:00)),
+            Some(datetime!(2023-02-11 00:00:00))
+        );
+    }
+
+    #[test]
+    fn test_nightly() {
+        assert_eq!(
+            next("30 2 * * *", datetime!(2023-02-10 02:30:00)),
+            Some(datetime!(2023-02-11 02:30:00))
+        );
+        assert_eq!(
+            next("0 0 1 * *", datetime!(2023-12-15 12:00:00)),
+            Some(datetime!(2024-01-01 00:00:00))
+        );
+    }
+
+    #[test]
+    fn test_day_of_week() {
+        // 2023-02-10 is a Friday
+        assert_eq!(
+            next("0 9 * * 1-5", datetime!(2023-02-10 10:00:00)),
+            Some(datetime!(2023-02-13 09:00:00))
+        );
+        assert_eq!(
+            next("0 9 * * 7", datetime!(2023-02-10 10:00:00)),
+            Some(datetime!(2023-02-12 09:00:00))
+        );
+        // Either the 15th or a Monday
+        assert_eq!(
+            next("0 0 15 * 1", datetime!(2023-02-10 10:00:00)),
+            Some(datetime!(2023-02-13 00:00:00))
+        );
+    }
+
+    #[test]
+    fn test_impossible_schedule() {
+        assert_eq!(next("0 0 31 2 *", datetime!(2023-02-10 10:00:00)), None);
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        for expression in [
+            "* * * *",
+            "60 * * * *",
+            "*/0 * * * *",
+            "5-1 * * * *",
+            "a * * * *",
+        ] {
+            assert!(CronSchedule::from_str(expression).is_err(), "{expression}");
+        }
+    }
+}
@@ -18,7 +18,7 @@ pub struct RetryMapping {
     pub count: Vec<i32>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ConnectorPTMapping {
     pub default_mapping: RetryMapping,
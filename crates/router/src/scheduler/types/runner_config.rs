@@ -0,0 +1,17 @@
+/// Scheduling characteristics declared by each process tracker runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunnerConfig {
+    /// Tasks of runners with a higher priority are batched (and hence consumed) first.
+    pub priority: u8,
+    /// Maximum number of tasks of the runner executed concurrently by a single consumer.
+    pub max_concurrency: usize,
+    /// Maximum number of tasks of the runner executed against a single connector within a time
+    /// window, if any.
+    pub connector_rate_limit: Option<RateLimit>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub max_tasks: u32,
+    pub window_in_seconds: u32,
+}
@@ -3,17 +3,18 @@ use std::{
     time as std_time,
 };
 
-use error_stack::{report, ResultExt};
+use error_stack::{report, IntoReport, ResultExt};
 use futures::StreamExt;
 use redis_interface::{RedisConnectionPool, RedisEntryId};
 use router_env::opentelemetry;
 use tokio::sync::oneshot;
 use uuid::Uuid;
 
-use super::{consumer, metrics, process_data, workflows};
+use super::{consumer, limiter::WorkflowLimiter, metrics, process_data, workflows};
 use crate::{
     configs::settings::SchedulerSettings,
     core::errors::{self, CustomResult},
+    db::StorageInterface,
     logger,
     routes::AppState,
     scheduler::{ProcessTrackerBatch, SchedulerFlow},
@@ -134,6 +135,18 @@ pub async fn update_status_and_append(
     }
 }
 
+/// Order tasks by the priority of their runners (highest first), so that tasks of higher priority
+/// runners are batched, and hence consumed, first. Tasks with an unknown runner are placed last.
+pub fn sort_by_priority(tasks: &mut [storage::ProcessTracker]) {
+    tasks.sort_by_key(|task| {
+        std::cmp::Reverse(
+            runner_from_task(task)
+                .map(|runner| runner.config().priority)
+                .unwrap_or_default(),
+        )
+    });
+}
+
 pub fn divide(
     tasks: Vec<storage::ProcessTracker>,
     conf: &SchedulerSettings,
@@ -244,6 +257,7 @@ pub async fn consumer_operation_handler<E>(
     state: AppState,
     options: sync::Arc<super::SchedulerOptions>,
     settings: sync::Arc<SchedulerSettings>,
+    limiter: sync::Arc<WorkflowLimiter>,
     error_handler_fun: E,
     consumer_operation_counter: sync::Arc<atomic::AtomicU64>,
 ) where
@@ -253,7 +267,7 @@ pub async fn consumer_operation_handler<E>(
     consumer_operation_counter.fetch_add(1, atomic::Ordering::Release);
     let start_time = std_time::Instant::now();
 
-    match consumer::consumer_operations(&state, &options, &settings).await {
+    match consumer::consumer_operations(&state, &options, &settings, limiter).await {
         Ok(_) => (),
         Err(err) => error_handler_fun(err),
     }
@@ -273,6 +287,41 @@ pub fn runner_from_task(
     Ok(runner.parse_enum("PTRunner")?)
 }
 
+/// Extract the connector a task operates against from its tracking data, if present.
+pub fn connector_from_task(task: &storage::ProcessTracker) -> Option<String> {
+    task.tracking_data
+        .get("connector")
+        .and_then(serde_json::Value::as_str)
+        .map(ToOwned::to_owned)
+}
+
+/// Move a task back to `Pending` with its schedule time pushed out by `delay`, without counting
+/// it as a retry.
+pub async fn defer_task(
+    db: &dyn StorageInterface,
+    task: storage::ProcessTracker,
+    delay: std_time::Duration,
+) -> CustomResult<(), errors::ProcessTrackerError> {
+    let delay = time::Duration::try_from(delay)
+        .into_report()
+        .change_context(errors::ProcessTrackerError::UnexpectedFlow)?;
+    db.update_process(
+        task,
+        storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: Some(common_utils::date_time::now().saturating_add(delay)),
+            tracking_data: None,
+            business_status: None,
+            status: Some(ProcessTrackerStatus::Pending),
+            updated_at: Some(common_utils::date_time::now()),
+        },
+    )
+    .await
+    .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+    Ok(())
+}
+
 pub fn add_histogram_metrics(
     pickup_time: &time::PrimitiveDateTime,
     task: &mut storage::ProcessTracker,
@@ -3,14 +3,32 @@ use router_env::{instrument, tracing};
 use serde::{Deserialize, Serialize};
 use strum::EnumString;
 
-use crate::{core::errors, routes::AppState, scheduler::consumer, types::storage};
+use crate::{
+    core::errors,
+    routes::AppState,
+    scheduler::{consumer, RateLimit, RunnerConfig},
+    types::storage,
+};
+pub mod card_account_updater;
 pub mod payment_sync;
+pub mod payment_token_purge;
 pub mod refund_router;
 
 macro_rules! runners {
-    ($($body:tt),*) => {
+    ($($body:ident => $config:expr),*) => {
         as_item! {
-            #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, EnumString)]
+            #[derive(
+                Serialize,
+                Deserialize,
+                Clone,
+                Copy,
+                Debug,
+                PartialEq,
+                Eq,
+                Hash,
+                EnumString,
+                strum::EnumIter,
+            )]
             #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
             #[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
             pub enum PTRunner {
@@ -22,6 +40,15 @@ macro_rules! runners {
             pub struct $body;
         } )*
 
+        impl PTRunner {
+            /// Priority, concurrency and rate limits declared for the runner
+            pub fn config(&self) -> RunnerConfig {
+                match self {
+                    $( Self::$body => $config, )*
+                }
+            }
+        }
+
         #[instrument(skip(state))]
         pub async fn perform_workflow_execution<'a>(state: &AppState, process: storage::ProcessTracker, runner: PTRunner)
         where
@@ -43,8 +70,32 @@ macro_rules! as_item {
 }
 
 runners! {
-    PaymentsSyncWorkflow,
-    RefundWorkflowRouter
+    PaymentsSyncWorkflow => RunnerConfig {
+        priority: 10,
+        max_concurrency: 100,
+        connector_rate_limit: Some(RateLimit {
+            max_tasks: 50,
+            window_in_seconds: 1,
+        }),
+    },
+    RefundWorkflowRouter => RunnerConfig {
+        priority: 5,
+        max_concurrency: 20,
+        connector_rate_limit: Some(RateLimit {
+            max_tasks: 10,
+            window_in_seconds: 1,
+        }),
+    },
+    CardAccountUpdaterWorkflow => RunnerConfig {
+        priority: 1,
+        max_concurrency: 1,
+        connector_rate_limit: None,
+    },
+    PaymentTokenPurgeWorkflow => RunnerConfig {
+        priority: 1,
+        max_concurrency: 1,
+        connector_rate_limit: None,
+    }
 }
 
 #[async_trait]
@@ -0,0 +1,25 @@
+use super::{CardAccountUpdaterWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::payment_methods::account_updater, errors, logger::error, routes::AppState, types::storage,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for CardAccountUpdaterWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        account_updater::start_card_account_updater_workflow(state, &process).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, ?error, "Failed while executing card account updater workflow");
+        Ok(())
+    }
+}
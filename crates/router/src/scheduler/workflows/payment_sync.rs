@@ -72,6 +72,7 @@ impl ProcessTrackerWorkflow for PaymentsSyncWorkflow {
                     connector,
                     payment_data.payment_attempt.merchant_id,
                     process,
+                    payment_data.payment_attempt.error_message,
                 )
                 .await?
             }
@@ -89,21 +90,28 @@ impl ProcessTrackerWorkflow for PaymentsSyncWorkflow {
     }
 }
 
-pub async fn get_sync_process_schedule_time(
+pub async fn get_connector_pt_mapping(
     db: &dyn StorageInterface,
     connector: &str,
-    merchant_id: &str,
-    retry_count: i32,
-) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
+) -> process_data::ConnectorPTMapping {
     let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
         get_and_deserialize_key(db, &format!("pt_mapping_{connector}"), "ConnectorPTMapping").await;
-    let mapping = match redis_mapping {
+    match redis_mapping {
         Ok(x) => x,
         Err(err) => {
             logger::info!("Redis Mapping Error: {}", err);
             process_data::ConnectorPTMapping::default()
         }
-    };
+    }
+}
+
+pub async fn get_sync_process_schedule_time(
+    db: &dyn StorageInterface,
+    connector: &str,
+    merchant_id: &str,
+    retry_count: i32,
+) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
+    let mapping = get_connector_pt_mapping(db, connector).await;
     let time_delta = utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
 
     Ok(utils::get_time_from_delta(time_delta))
@@ -114,14 +122,15 @@ pub async fn retry_sync_task(
     connector: String,
     merchant_id: String,
     pt: storage::ProcessTracker,
+    error: Option<String>,
 ) -> Result<(), errors::ProcessTrackerError> {
     let schedule_time =
         get_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count).await?;
 
     match schedule_time {
-        Some(s_time) => pt.retry(db, s_time).await,
+        Some(s_time) => pt.retry(db, s_time, error).await,
         None => {
-            pt.finish_with_status(db, "RETRIES_EXCEEDED".to_string())
+            pt.finish_with_error(db, "RETRIES_EXCEEDED".to_string(), error)
                 .await
         }
     }
@@ -0,0 +1,25 @@
+use super::{PaymentTokenPurgeWorkflow, ProcessTrackerWorkflow};
+use crate::{
+    core::payment_methods::payment_token, errors, logger::error, routes::AppState, types::storage,
+};
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow for PaymentTokenPurgeWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        payment_token::start_payment_token_purge_workflow(state, &process).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, ?error, "Failed while executing payment token purge workflow");
+        Ok(())
+    }
+}
@@ -1,6 +1,5 @@
 pub mod api;
 pub mod authentication;
-#[cfg(feature = "basilisk")]
 pub mod encryption;
 pub mod logger;
 
@@ -17,6 +16,8 @@ pub struct Store {
     #[cfg(feature = "olap")]
     pub replica_pool: PgPool,
     pub redis_conn: Arc<redis_interface::RedisConnectionPool>,
+    /// Pool for the card vault database, present only when the card vault is enabled
+    pub vault_pool: Option<PgPool>,
     #[cfg(feature = "kv_store")]
     pub(crate) config: StoreConfig,
 }
@@ -30,11 +31,20 @@ pub(crate) struct StoreConfig {
 
 impl Store {
     pub async fn new(config: &crate::configs::settings::Settings, test_transaction: bool) -> Self {
+        let redis_conn = Arc::new(crate::connection::redis_connection(config).await);
+        #[cfg(feature = "accounts_cache")]
+        Self::listen_for_cache_invalidation(redis_conn.clone()).await;
+
         Self {
             master_pool: diesel_make_pg_pool(&config.master_database, test_transaction).await,
             #[cfg(feature = "olap")]
             replica_pool: diesel_make_pg_pool(&config.replica_database, test_transaction).await,
-            redis_conn: Arc::new(crate::connection::redis_connection(config).await),
+            redis_conn,
+            vault_pool: if config.card_vault.enabled {
+                Some(diesel_make_pg_pool(&config.card_vault.database, test_transaction).await)
+            } else {
+                None
+            },
             #[cfg(feature = "kv_store")]
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
@@ -43,6 +53,71 @@ impl Store {
         }
     }
 
+    /// Subscribe to the cache invalidation channel and evict keys from the in-memory caches as
+    /// invalidation messages arrive. The subscription is made again whenever the connection is
+    /// re-established or the subscriber fails, and the in-memory caches are bypassed until it is.
+    #[cfg(feature = "accounts_cache")]
+    async fn listen_for_cache_invalidation(redis_conn: Arc<redis_interface::RedisConnectionPool>) {
+        use futures::StreamExt;
+
+        use crate::{cache, logger};
+
+        let mut messages = redis_conn.on_message();
+        let mut reconnects = redis_conn.on_subscriber_reconnect();
+        let mut errors = redis_conn.on_subscriber_error();
+        tokio::spawn(async move {
+            Self::subscribe_to_cache_invalidation(&redis_conn).await;
+            loop {
+                tokio::select! {
+                    Some((_channel, message)) = messages.next() => {
+                        match cache::CacheKind::try_from(message) {
+                            Ok(kind) => cache::invalidate(kind).await,
+                            Err(error) => {
+                                logger::error!(?error, "Received invalid cache invalidation message")
+                            }
+                        }
+                    }
+                    Some(error) = errors.next() => {
+                        // Evictions may have been missed, the subscription is checked by
+                        // subscribing again rather than waiting for a reconnect that may not come
+                        logger::error!(%error, "Cache invalidation subscriber failed");
+                        cache::set_subscribed(false);
+                        Self::subscribe_to_cache_invalidation(&redis_conn).await;
+                    }
+                    Some(()) = reconnects.next() => {
+                        cache::set_subscribed(false);
+                        Self::subscribe_to_cache_invalidation(&redis_conn).await;
+                    }
+                    else => break,
+                }
+            }
+        });
+    }
+
+    /// Subscribe to the cache invalidation channel, retrying until the subscription succeeds.
+    #[cfg(feature = "accounts_cache")]
+    async fn subscribe_to_cache_invalidation(redis_conn: &redis_interface::RedisConnectionPool) {
+        use crate::{cache, logger};
+
+        const RETRY_INTERVAL: std::time::Duration = std::time::Duration::from_secs(5);
+
+        loop {
+            match redis_conn.subscribe(cache::PUB_SUB_CHANNEL).await {
+                Ok(_) => {
+                    cache::set_subscribed(true);
+                    return;
+                }
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        "Failed to subscribe to the cache invalidation channel"
+                    );
+                    tokio::time::sleep(RETRY_INTERVAL).await;
+                }
+            }
+        }
+    }
+
     #[cfg(feature = "kv_store")]
     pub fn get_drainer_stream_name(&self, shard_key: &str) -> String {
         // Example: {shard_5}_drainer_stream
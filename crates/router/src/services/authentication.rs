@@ -46,6 +46,22 @@ impl AuthenticateAndFetch<storage::MerchantAccount, AppState> for ApiKeyAuth {
     }
 }
 
+/// Authentication for endpoints which authenticate requests themselves, such as webhooks signed
+/// by the sender.
+#[derive(Debug)]
+pub struct NoAuth;
+
+#[async_trait]
+impl AuthenticateAndFetch<(), AppState> for NoAuth {
+    async fn authenticate_and_fetch(
+        &self,
+        _request_headers: &HeaderMap,
+        _state: &AppState,
+    ) -> RouterResult<()> {
+        Ok(())
+    }
+}
+
 #[derive(Debug)]
 pub struct AdminApiAuth;
 
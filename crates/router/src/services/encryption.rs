@@ -1,15 +1,18 @@
 use std::{num::Wrapping, str};
 
-use error_stack::{report, IntoReport, ResultExt};
+#[cfg(feature = "basilisk")]
+use error_stack::report;
+use error_stack::{IntoReport, ResultExt};
 #[cfg(feature = "basilisk")]
 use josekit::jwe;
 use rand;
 use ring::{aead::*, error::Unspecified};
 
+#[cfg(feature = "basilisk")]
+use crate::utils;
 use crate::{
     configs::settings::Jwekey,
     core::errors::{self, CustomResult},
-    utils,
 };
 
 struct NonceGen {
@@ -57,6 +60,7 @@ mod kms {
     use aws_config::meta::region::RegionProviderChain;
     use aws_sdk_kms::{types::Blob, Client, Region};
     use base64::Engine;
+    use error_stack::report;
 
     use super::*;
     use crate::consts;
@@ -205,6 +209,7 @@ pub async fn encrypt_jwe(
     Ok(jwt)
 }
 
+#[cfg(feature = "basilisk")]
 pub async fn decrypt_jwe(
     keys: &Jwekey,
     jwt: &str,
@@ -244,10 +249,9 @@ mod tests {
     #![allow(clippy::unwrap_used, clippy::expect_used)]
 
     use super::*;
-    use crate::{
-        configs::settings,
-        utils::{self, ValueExt},
-    };
+    #[cfg(feature = "basilisk")]
+    use crate::configs::settings;
+    use crate::utils::{self, ValueExt};
 
     fn generate_key() -> [u8; 32] {
         let key: [u8; 32] = rand::random();
@@ -264,6 +268,7 @@ mod tests {
         assert_eq!(dec_data, "Test_Encrypt".to_string());
     }
 
+    #[cfg(feature = "basilisk")]
     #[actix_rt::test]
     async fn test_jwe() {
         let conf = settings::Settings::new().unwrap();
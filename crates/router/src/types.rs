@@ -13,7 +13,7 @@ pub mod transformers;
 use std::marker::PhantomData;
 
 pub use api_models::enums::Connector;
-use common_utils::pii::Email;
+use common_utils::pii::{CardNumber, Email};
 use error_stack::{IntoReport, ResultExt};
 
 use self::{api::payments, storage::enums as storage_enums};
@@ -35,6 +35,14 @@ pub type RefundSyncRouterData = RouterData<api::RSync, RefundsData, RefundsRespo
 pub type RefreshTokenRouterData =
     RouterData<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
 
+pub type FrmPreAuthRouterData =
+    RouterData<api::PreAuth, FraudCheckPreAuthData, FraudCheckResponseData>;
+pub type FrmPostAuthRouterData =
+    RouterData<api::PostAuth, FraudCheckPostAuthData, FraudCheckResponseData>;
+
+pub type ConnectorAuthenticationRouterData =
+    RouterData<api::Authenticate, ConnectorAuthenticationData, AuthenticationResponseData>;
+
 pub type PaymentsResponseRouterData<R> =
     ResponseRouterData<api::Authorize, R, PaymentsAuthorizeData, PaymentsResponseData>;
 pub type PaymentsCancelResponseRouterData<R> =
@@ -48,6 +56,14 @@ pub type PaymentsCaptureResponseRouterData<R> =
 
 pub type RefundsResponseRouterData<F, R> =
     ResponseRouterData<F, R, RefundsData, RefundsResponseData>;
+pub type FrmResponseRouterData<F, R, Request> =
+    ResponseRouterData<F, R, Request, FraudCheckResponseData>;
+pub type ConnectorAuthenticationResponseRouterData<R> = ResponseRouterData<
+    api::Authenticate,
+    R,
+    ConnectorAuthenticationData,
+    AuthenticationResponseData,
+>;
 
 pub type PaymentsAuthorizeType =
     dyn services::ConnectorIntegration<api::Authorize, PaymentsAuthorizeData, PaymentsResponseData>;
@@ -68,6 +84,20 @@ pub type RefundSyncType =
 pub type RefreshTokenType =
     dyn services::ConnectorIntegration<api::AccessTokenAuth, AccessTokenRequestData, AccessToken>;
 
+pub type FrmPreAuthType =
+    dyn services::ConnectorIntegration<api::PreAuth, FraudCheckPreAuthData, FraudCheckResponseData>;
+pub type FrmPostAuthType = dyn services::ConnectorIntegration<
+    api::PostAuth,
+    FraudCheckPostAuthData,
+    FraudCheckResponseData,
+>;
+
+pub type ConnectorAuthenticationType = dyn services::ConnectorIntegration<
+    api::Authenticate,
+    ConnectorAuthenticationData,
+    AuthenticationResponseData,
+>;
+
 pub type VerifyRouterData = RouterData<api::Verify, VerifyRequestData, PaymentsResponseData>;
 
 #[derive(Debug, Clone)]
@@ -115,6 +145,12 @@ pub struct PaymentsAuthorizeData {
     pub setup_mandate_details: Option<payments::MandateData>,
     pub browser_info: Option<BrowserInformation>,
     pub order_details: Option<api_models::payments::OrderDetails>,
+    /// Network token of the saved card along with a cryptogram generated for this payment.
+    /// Connectors supporting network tokens should use it instead of the card number when present.
+    pub network_token: Option<NetworkTokenData>,
+    /// Result of the 3DS authentication of the payment by an authentication connector.
+    /// Connectors should authorize the payment as already authenticated when present.
+    pub authentication_data: Option<AuthenticationData>,
 }
 
 #[derive(Debug, Clone)]
@@ -123,6 +159,8 @@ pub struct PaymentsCaptureData {
     pub currency: storage_enums::Currency,
     pub connector_transaction_id: String,
     pub amount: i64,
+    /// Metadata stored from the response of the connector for the payment
+    pub connector_meta: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone)]
@@ -137,6 +175,8 @@ pub struct PaymentsSyncData {
 pub struct PaymentsCancelData {
     pub connector_transaction_id: String,
     pub cancellation_reason: Option<String>,
+    /// Metadata stored from the response of the connector for the payment
+    pub connector_meta: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Clone)]
@@ -243,6 +283,105 @@ pub struct BrowserInformation {
     pub user_agent: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct NetworkTokenData {
+    pub token_number: masking::Secret<String, CardNumber>,
+    pub token_exp_month: masking::Secret<String>,
+    pub token_exp_year: masking::Secret<String>,
+    pub token_service: NetworkTokenService,
+    pub cryptogram: masking::Secret<String>,
+    pub eci: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkTokenService {
+    /// Visa Token Service
+    Vts,
+    /// Mastercard Digital Enablement Service
+    Mdes,
+}
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckPreAuthData {
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payment_method_data: payments::PaymentMethod,
+    pub email: Option<masking::Secret<String, Email>>,
+    pub browser_info: Option<BrowserInformation>,
+    pub order_details: Option<api_models::payments::OrderDetails>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckPostAuthData {
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub email: Option<masking::Secret<String, Email>>,
+    /// The processor the payment was authorized with
+    pub connector: String,
+    pub connector_transaction_id: Option<String>,
+    pub attempt_status: storage_enums::AttemptStatus,
+}
+
+#[derive(Debug, Clone)]
+pub struct FraudCheckResponseData {
+    pub frm_transaction_id: Option<String>,
+    pub frm_status: storage_enums::FraudCheckStatus,
+    pub score: Option<i32>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectorAuthenticationData {
+    pub amount: i64,
+    pub currency: storage_enums::Currency,
+    pub payment_method_data: payments::PaymentMethod,
+    pub email: Option<masking::Secret<String, Email>>,
+    pub browser_info: Option<BrowserInformation>,
+    /// The authentication awaiting a challenge, whose result is retrieved instead of
+    /// authenticating the payment again
+    pub authentication_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthenticationResponseData {
+    pub authentication_id: String,
+    pub status: AuthenticationStatus,
+    pub authentication_data: Option<AuthenticationData>,
+    pub reason: Option<String>,
+    /// Where the customer is sent to complete the challenge, when one is required
+    pub redirection_data: Option<services::RedirectForm>,
+}
+
+/// Outcome of a 3DS authentication, as given by the transaction status (`transStatus`) of
+/// EMV 3DS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthenticationStatus {
+    /// The customer was authenticated without a challenge (`Y`)
+    Authenticated,
+    /// Authentication was attempted, but the issuer does not support it (`A`)
+    Attempted,
+    /// The issuer requires the customer to complete a challenge (`C`)
+    ChallengeRequired,
+    /// Authentication could not be performed (`U`)
+    Unavailable,
+    /// The customer was not authenticated (`N`)
+    Failed,
+    /// The issuer rejected the authentication and the payment should not be authorized (`R`)
+    Rejected,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuthenticationData {
+    /// The cryptogram proving the authentication (CAVV, AAV etc)
+    pub authentication_value: masking::Secret<String>,
+    pub eci: Option<String>,
+    /// Transaction ID assigned by the directory server
+    pub ds_transaction_id: Option<String>,
+    /// The version of 3DS the payment was authenticated with, e.g. `2.2.0`
+    pub message_version: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct RefundsResponseData {
     pub connector_refund_id: String,
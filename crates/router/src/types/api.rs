@@ -1,11 +1,17 @@
 pub mod admin;
 pub mod api_keys;
+pub mod authentication;
+pub mod bank_accounts;
+pub mod capabilities;
+pub mod cards;
 pub mod configs;
 pub mod customers;
 pub mod enums;
+pub mod fraud_check;
 pub mod mandates;
 pub mod payment_methods;
 pub mod payments;
+pub mod process_tracker;
 pub mod refunds;
 pub mod webhooks;
 
@@ -14,8 +20,9 @@ use std::{fmt::Debug, str::FromStr};
 use error_stack::{report, IntoReport, ResultExt};
 
 pub use self::{
-    admin::*, api_keys::*, configs::*, customers::*, payment_methods::*, payments::*, refunds::*,
-    webhooks::*,
+    admin::*, api_keys::*, authentication::*, bank_accounts::*, capabilities::*, cards::*,
+    configs::*, customers::*, fraud_check::*, payment_methods::*, payments::*, process_tracker::*,
+    refunds::*, webhooks::*,
 };
 use super::ErrorResponse;
 use crate::{
@@ -70,6 +77,13 @@ pub trait ConnectorCommon {
             reason: None,
         })
     }
+
+    /// The payment methods, currencies, countries, capture methods and mandates supported by the
+    /// connector. Connectors processing payments declare all of them, in place of the default
+    /// capabilities given, which only have the connector set to be kept.
+    fn get_capabilities(&self, capabilities: ConnectorCapabilities) -> ConnectorCapabilities {
+        capabilities
+    }
 }
 
 /// Extended trait for connector common to allow functions with generic type
@@ -167,9 +181,12 @@ impl ConnectorData {
             "braintree" => Ok(Box::new(&connector::Braintree)),
             "checkout" => Ok(Box::new(&connector::Checkout)),
             "cybersource" => Ok(Box::new(&connector::Cybersource)),
+            #[cfg(feature = "dummy_connector")]
+            "dummy_connector" => Ok(Box::new(&connector::DummyConnector)),
             "fiserv" => Ok(Box::new(&connector::Fiserv)),
             "globalpay" => Ok(Box::new(&connector::Globalpay)),
             "klarna" => Ok(Box::new(&connector::Klarna)),
+            "paypal" => Ok(Box::new(&connector::Paypal)),
             "payu" => Ok(Box::new(&connector::Payu)),
             "rapyd" => Ok(Box::new(&connector::Rapyd)),
             "shift4" => Ok(Box::new(&connector::Shift4)),
@@ -0,0 +1,58 @@
+use std::{fmt::Debug, str::FromStr};
+
+use error_stack::{IntoReport, ResultExt};
+
+use super::ConnectorCommon;
+#[cfg(feature = "dummy_connector")]
+use crate::connector;
+use crate::{
+    core::errors::{self, CustomResult},
+    services::api,
+    types::{self, api::enums as api_enums},
+};
+
+#[derive(Debug, Clone)]
+pub struct Authenticate;
+
+pub trait ConnectorAuthenticate:
+    api::ConnectorIntegration<
+    Authenticate,
+    types::ConnectorAuthenticationData,
+    types::AuthenticationResponseData,
+>
+{
+}
+
+/// Authentication connectors run 3DS for a payment before it is authorized with the processor,
+/// so that the payment can be authorized with any processor using the result of the
+/// authentication.
+pub trait ExternalAuthentication: ConnectorCommon + ConnectorAuthenticate + Debug + Send {}
+
+type BoxedAuthenticationConnector = Box<&'static (dyn ExternalAuthentication + Sync)>;
+
+pub struct AuthenticationConnectorData {
+    pub connector: BoxedAuthenticationConnector,
+    pub connector_name: api_enums::AuthenticationConnectors,
+}
+
+impl AuthenticationConnectorData {
+    pub fn get_connector_by_name(name: &str) -> CustomResult<Self, errors::ApiErrorResponse> {
+        let connector_name = api_enums::AuthenticationConnectors::from_str(name)
+            .into_report()
+            .change_context(errors::ConnectorError::InvalidConnectorName)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("unable to parse authentication connector name {name}")
+            })?;
+        let connector: BoxedAuthenticationConnector = match connector_name {
+            #[cfg(feature = "dummy_connector")]
+            api_enums::AuthenticationConnectors::DummyConnector => {
+                Box::new(&connector::DummyConnector)
+            }
+        };
+        Ok(Self {
+            connector,
+            connector_name,
+        })
+    }
+}
@@ -0,0 +1 @@
+pub use api_models::bank_accounts::{BankAccountResponse, BankDebitBilling, BankDebitData};
@@ -0,0 +1 @@
+pub use api_models::capabilities::ConnectorCapabilities;
@@ -1,5 +1,7 @@
 use api_models::customers;
-pub use api_models::customers::{CustomerDeleteResponse, CustomerId, CustomerRequest};
+pub use api_models::customers::{
+    CustomerDeleteResponse, CustomerId, CustomerPaymentMethodId, CustomerRequest,
+};
 use error_stack::ResultExt;
 use serde::Serialize;
 
@@ -46,6 +48,7 @@ impl From<storage::Customer> for CustomerResponse {
             created_at: cust.created_at,
             metadata: cust.metadata,
             address: None,
+            default_payment_method_id: cust.default_payment_method_id,
         }
         .into()
     }
@@ -0,0 +1,61 @@
+use std::{fmt::Debug, str::FromStr};
+
+pub use api_models::fraud_check::{
+    FraudCheckResponse, FraudCheckReviewDecision, FraudCheckReviewRequest,
+    ListFraudCheckConstraints,
+};
+use error_stack::{IntoReport, ResultExt};
+
+use super::ConnectorCommon;
+use crate::{
+    connector,
+    core::errors::{self, CustomResult},
+    services::api,
+    types::{self, api::enums as api_enums},
+};
+
+#[derive(Debug, Clone)]
+pub struct PreAuth;
+#[derive(Debug, Clone)]
+pub struct PostAuth;
+
+pub trait FraudCheckPreAuth:
+    api::ConnectorIntegration<PreAuth, types::FraudCheckPreAuthData, types::FraudCheckResponseData>
+{
+}
+
+pub trait FraudCheckPostAuth:
+    api::ConnectorIntegration<PostAuth, types::FraudCheckPostAuthData, types::FraudCheckResponseData>
+{
+}
+
+/// Fraud and risk management (FRM) connectors assess the risk of a payment, before it is
+/// authorized with the processor (pre-auth) or before it is captured (post-auth).
+pub trait FraudCheck:
+    ConnectorCommon + FraudCheckPreAuth + FraudCheckPostAuth + Debug + Send
+{
+}
+
+type BoxedFrmConnector = Box<&'static (dyn FraudCheck + Sync)>;
+
+pub struct FrmConnectorData {
+    pub connector: BoxedFrmConnector,
+    pub connector_name: api_enums::FrmConnectors,
+}
+
+impl FrmConnectorData {
+    pub fn get_connector_by_name(name: &str) -> CustomResult<Self, errors::ApiErrorResponse> {
+        let connector_name = api_enums::FrmConnectors::from_str(name)
+            .into_report()
+            .change_context(errors::ConnectorError::InvalidConnectorName)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("unable to parse FRM connector name {name}"))?;
+        let connector: BoxedFrmConnector = match connector_name {
+            api_enums::FrmConnectors::Signifyd => Box::new(&connector::Signifyd),
+        };
+        Ok(Self {
+            connector,
+            connector_name,
+        })
+    }
+}
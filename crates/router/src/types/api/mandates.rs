@@ -15,7 +15,6 @@ use crate::{
         storage::{self, enums as storage_enums},
         transformers::ForeignInto,
     },
-    utils::OptionExt,
 };
 
 newtype!(
@@ -48,13 +47,9 @@ impl MandateResponseExt for MandateResponse {
             })?;
 
         let card = if payment_method.payment_method == storage_enums::PaymentMethodType::Card {
-            let locker_id = merchant_account
-                .locker_id
-                .to_owned()
-                .get_required_value("locker_id")?;
             let get_card_resp = payment_methods::cards::get_card_from_legacy_locker(
                 state,
-                &locker_id,
+                merchant_account,
                 &payment_method.payment_method_id,
             )
             .await?;
@@ -15,6 +15,7 @@ use once_cell::sync::Lazy;
 use crate::{
     core::errors::{self, RouterResult},
     types::api::enums as api_enums,
+    utils::OptionExt,
 };
 
 /// Static collection that contains valid Payment Method Type and Payment Method SubType
@@ -29,7 +30,23 @@ static PAYMENT_METHOD_TYPE_SET: Lazy<
             ST::Credit,
             ST::Debit
         ],
-        T::BankTransfer => vec![],
+        T::BankTransfer => vec![
+            ST::Pix,
+            ST::Boleto
+        ],
+        T::BankDebit => vec![
+            ST::Ach,
+            ST::Sepa,
+            ST::Bacs
+        ],
+        T::BankRedirect => vec![
+            ST::Ideal,
+            ST::Sofort,
+            ST::Giropay,
+            ST::Eps,
+            ST::BancontactCard,
+            ST::Przelewy24
+        ],
         T::Netbanking => vec![],
         T::Upi => vec![
             ST::UpiIntent,
@@ -113,6 +130,23 @@ impl CreatePaymentMethodExt for CreatePaymentMethod {
             .attach_printable("Invalid payment method issuer code"));
         }
 
+        if self.payment_method == api_enums::PaymentMethodType::BankDebit {
+            let bank_account = self
+                .bank_account
+                .as_ref()
+                .get_required_value("bank_account")?;
+            if self.payment_method_type.map_or(false, |pm_type| {
+                pm_type != bank_account.get_bank_debit_type()
+            }) {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "'payment_method_type' does not match the debit scheme of \
+                              'bank_account'."
+                        .to_string()
+                })
+                .attach_printable("Mismatched payment method type and bank account"));
+            }
+        }
+
         Ok(())
     }
 
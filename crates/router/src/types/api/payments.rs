@@ -1,13 +1,15 @@
 pub use api_models::payments::{
-    AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse, Card,
-    CustomerAcceptance, MandateData, MandateTxnType, MandateType, MandateValidationFields,
-    NextAction, NextActionType, OnlineMandate, PayLaterData, PaymentIdType, PaymentListConstraints,
-    PaymentListResponse, PaymentMethod, PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody,
-    PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsRedirectRequest,
-    PaymentsRedirectionResponse, PaymentsRequest, PaymentsResponse, PaymentsResponseForm,
-    PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse, PaymentsStartRequest,
-    PgRedirectResponse, PhoneDetails, RedirectionResponse, SessionToken, UrlDetails, VerifyRequest,
-    VerifyResponse, WalletData,
+    AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse,
+    BankRedirectBilling, BankRedirectData, BankTransferBilling, BankTransferData,
+    BankTransferInstructions, Card, CustomerAcceptance, MandateData, MandateTxnType, MandateType,
+    MandateValidationFields, NextAction, NextActionType, OnlineMandate, PayLaterData,
+    PaymentIdType, PaymentListConstraints, PaymentListResponse, PaymentMethod,
+    PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody, PaymentsCancelRequest,
+    PaymentsCaptureRequest, PaymentsRedirectRequest, PaymentsRedirectionResponse, PaymentsRequest,
+    PaymentsResponse, PaymentsResponseForm, PaymentsRetrieveRequest, PaymentsSessionRequest,
+    PaymentsSessionResponse, PaymentsStartRequest, PgRedirectResponse, PhoneDetails, PollConfig,
+    RedirectionResponse, SessionToken, UpiData, UrlDetails, VerifyRequest, VerifyResponse,
+    WalletData,
 };
 use error_stack::{IntoReport, ResultExt};
 use masking::PeekInterface;
@@ -61,6 +63,23 @@ impl CustomerAcceptanceExt for CustomerAcceptance {
 
 impl super::Router for PaymentsRequest {}
 
+/// Connector metadata of a payment the customer completes by following bank transfer
+/// instructions, used to build the next action of the payment
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BankTransferNextSteps {
+    pub bank_transfer_instructions: BankTransferInstructions,
+}
+
+/// Connector metadata of a UPI payment the customer approves in their UPI app, used to build the
+/// next action of the payment
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct UpiNextSteps {
+    pub upi_flow: api_types::enums::PaymentMethodSubType,
+    pub upi_intent_uri: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+}
+
 // Core related api layer.
 #[derive(Debug, Clone)]
 pub struct Authorize;
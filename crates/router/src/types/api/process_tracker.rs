@@ -0,0 +1,109 @@
+use time::PrimitiveDateTime;
+
+use crate::types::storage::{self, enums};
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerListConstraints {
+    pub runner: Option<String>,
+    pub status: Option<enums::ProcessTrackerStatus>,
+    pub merchant_id: Option<String>,
+    #[serde(default = "default_limit")]
+    pub limit: i64,
+    pub skip: Option<i64>,
+}
+
+fn default_limit() -> i64 {
+    100
+}
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerSummaryConstraints {
+    pub runner: Option<String>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ProcessTrackerResponse {
+    pub id: String,
+    pub name: Option<String>,
+    pub tag: Vec<String>,
+    pub runner: Option<String>,
+    pub retry_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+    pub tracking_data: serde_json::Value,
+    pub business_status: String,
+    pub status: enums::ProcessTrackerStatus,
+    pub event: Vec<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+impl From<storage::ProcessTracker> for ProcessTrackerResponse {
+    fn from(process: storage::ProcessTracker) -> Self {
+        Self {
+            id: process.id,
+            name: process.name,
+            tag: process.tag,
+            runner: process.runner,
+            retry_count: process.retry_count,
+            schedule_time: process.schedule_time,
+            tracking_data: process.tracking_data,
+            business_status: process.business_status,
+            status: process.status,
+            event: process.event,
+            created_at: process.created_at,
+            updated_at: process.updated_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ProcessTrackerStatusCount {
+    pub status: enums::ProcessTrackerStatus,
+    pub count: i64,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ProcessTrackerSummaryResponse {
+    pub runner: Option<String>,
+    pub counts: Vec<ProcessTrackerStatusCount>,
+}
+
+/// Delay before a retry of the task, according to the current retry schedule of its connector.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ProcessTrackerRetry {
+    pub retry_count: i32,
+    pub delay_in_seconds: Option<i32>,
+}
+
+/// Retry history of a task, with its retry schedule projected from the current retry schedule of its
+/// connector.
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ProcessTrackerRetryScheduleResponse {
+    pub id: String,
+    pub runner: Option<String>,
+    pub connector: Option<String>,
+    pub retry_count: i32,
+    pub max_retries_count: i32,
+    /// Attempts which did not complete the task, with their time and error, as they were made.
+    /// Attempts made before they were recorded are only reflected in `projected_retries`.
+    pub attempts: Vec<storage::ProcessTrackerAttempt>,
+    /// Delays of the retries already made, as the current retry schedule would have applied them
+    pub projected_retries: Vec<ProcessTrackerRetry>,
+    pub next_retry: Option<ProcessTrackerRetry>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerRescheduleRequest {
+    /// The time at which the task should be picked up next. If not provided, the next retry time
+    /// is computed from the retry schedule of the task's connector.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+}
@@ -6,6 +6,7 @@ pub mod customers;
 pub mod enums;
 pub mod ephemeral_key;
 pub mod events;
+pub mod fraud_check;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -13,8 +14,11 @@ pub mod merchant_connector_account;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payment_token;
 pub mod process_tracker;
+pub mod recurring_task;
 pub mod reverse_lookup;
+pub mod vault;
 
 mod query;
 pub mod refund;
@@ -24,7 +28,8 @@ pub mod kv;
 
 pub use self::{
     address::*, api_keys::*, configs::*, connector_response::*, customers::*, events::*,
-    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
-    payment_attempt::*, payment_intent::*, payment_method::*, process_tracker::*, refund::*,
-    reverse_lookup::*,
+    fraud_check::*, locker_mock_up::*, mandate::*, merchant_account::*,
+    merchant_connector_account::*, payment_attempt::*, payment_intent::*, payment_method::*,
+    payment_token::*, process_tracker::*, recurring_task::*, refund::*, reverse_lookup::*,
+    vault::*,
 };
@@ -1,13 +1,21 @@
-use error_stack::ResultExt;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
 use serde::Serialize;
 pub use storage_models::process_tracker::{
     ProcessData, ProcessTracker, ProcessTrackerNew, ProcessTrackerUpdate,
     ProcessTrackerUpdateInternal, SchedulerOptions,
 };
+use storage_models::schema::process_tracker::dsl;
 use time::PrimitiveDateTime;
 
 use crate::{
-    core::errors, db::StorageInterface, scheduler::metrics, types::storage::enums as storage_enums,
+    connection::PgPooledConn,
+    core::errors::{self, CustomResult},
+    db::StorageInterface,
+    scheduler::metrics,
+    types::{api, storage::enums as storage_enums},
 };
 
 #[async_trait::async_trait]
@@ -24,10 +32,12 @@ pub trait ProcessTrackerExt {
     where
         T: Serialize;
 
+    /// Schedule the task to be run again, recording the attempt which did not complete it.
     async fn retry(
         self,
         db: &dyn StorageInterface,
         schedule_time: PrimitiveDateTime,
+        error: Option<String>,
     ) -> Result<(), errors::ProcessTrackerError>;
 
     async fn finish_with_status(
@@ -35,6 +45,51 @@ pub trait ProcessTrackerExt {
         db: &dyn StorageInterface,
         status: String,
     ) -> Result<(), errors::ProcessTrackerError>;
+
+    /// Finish the task, recording the attempt which failed it.
+    async fn finish_with_error(
+        self,
+        db: &dyn StorageInterface,
+        status: String,
+        error: Option<String>,
+    ) -> Result<(), errors::ProcessTrackerError>;
+}
+
+/// An attempt of a task which did not complete it, recorded in the events of the task as it is
+/// made.
+#[derive(Clone, Debug, Serialize, serde::Deserialize)]
+pub struct ProcessTrackerAttempt {
+    pub retry_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub attempted_at: PrimitiveDateTime,
+    pub error: Option<String>,
+}
+
+impl ProcessTrackerAttempt {
+    /// Attempts recorded in the events of the task, oldest first.
+    pub fn from_events(events: &[String]) -> Vec<Self> {
+        events
+            .iter()
+            .filter_map(|event| serde_json::from_str(event).ok())
+            .collect()
+    }
+}
+
+fn record_attempt(
+    process: &ProcessTracker,
+    error: Option<String>,
+) -> Result<Vec<String>, errors::ProcessTrackerError> {
+    let attempt = ProcessTrackerAttempt {
+        retry_count: process.retry_count,
+        attempted_at: common_utils::date_time::now(),
+        error,
+    };
+    let mut events = process.event.clone();
+    events.push(
+        serde_json::to_string(&attempt)
+            .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?,
+    );
+    Ok(events)
 }
 
 #[async_trait::async_trait]
@@ -76,14 +131,17 @@ impl ProcessTrackerExt for ProcessTracker {
         self,
         db: &dyn StorageInterface,
         schedule_time: PrimitiveDateTime,
+        error: Option<String>,
     ) -> Result<(), errors::ProcessTrackerError> {
         metrics::TASK_RETRIED.add(&metrics::CONTEXT, 1, &[]);
+        let event = record_attempt(&self, error)?;
         db.update_process_tracker(
             self.clone(),
             ProcessTrackerUpdate::StatusRetryUpdate {
                 status: storage_enums::ProcessTrackerStatus::Pending,
                 retry_count: self.retry_count + 1,
                 schedule_time,
+                event,
             },
         )
         .await?;
@@ -107,4 +165,109 @@ impl ProcessTrackerExt for ProcessTracker {
         metrics::TASK_FINISHED.add(&metrics::CONTEXT, 1, &[]);
         Ok(())
     }
+
+    async fn finish_with_error(
+        self,
+        db: &dyn StorageInterface,
+        status: String,
+        error: Option<String>,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let event = record_attempt(&self, error)?;
+        db.update_process(
+            self,
+            ProcessTrackerUpdate::StatusEventUpdate {
+                status: storage_enums::ProcessTrackerStatus::Finish,
+                business_status: Some(status),
+                event,
+            },
+        )
+        .await
+        .attach_printable("Failed while updating status of the process")?;
+        metrics::TASK_FINISHED.add(&metrics::CONTEXT, 1, &[]);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+pub trait ProcessTrackerDbExt: Sized {
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        constraints: &api::ProcessTrackerListConstraints,
+    ) -> CustomResult<Vec<Self>, storage_models::errors::DatabaseError>;
+
+    async fn count_by_status(
+        conn: &PgPooledConn,
+        runner: Option<&str>,
+    ) -> CustomResult<
+        Vec<(storage_enums::ProcessTrackerStatus, i64)>,
+        storage_models::errors::DatabaseError,
+    >;
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerDbExt for ProcessTracker {
+    #[instrument(skip(conn))]
+    async fn filter_by_constraints(
+        conn: &PgPooledConn,
+        constraints: &api::ProcessTrackerListConstraints,
+    ) -> CustomResult<Vec<Self>, storage_models::errors::DatabaseError> {
+        let mut filter = <Self as HasTable>::table()
+            .order(dsl::schedule_time.desc())
+            .into_boxed();
+
+        if let Some(runner) = &constraints.runner {
+            filter = filter.filter(dsl::runner.eq(runner.to_owned()));
+        }
+        if let Some(status) = constraints.status {
+            filter = filter.filter(dsl::status.eq(status));
+        }
+        if let Some(merchant_id) = &constraints.merchant_id {
+            // Tasks do not store the merchant ID in a column of their own, it is only available
+            // as part of the tracking data
+            filter = filter.filter(
+                diesel::dsl::sql::<diesel::sql_types::Bool>("tracking_data ->> 'merchant_id' = ")
+                    .bind::<diesel::sql_types::Text, _>(merchant_id.to_owned()),
+            );
+        }
+        if let Some(skip) = constraints.skip {
+            filter = filter.offset(skip);
+        }
+        filter = filter.limit(constraints.limit);
+
+        crate::logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(storage_models::errors::DatabaseError::NotFound)
+            .attach_printable_lazy(|| "Error filtering records by predicate")
+    }
+
+    #[instrument(skip(conn))]
+    async fn count_by_status(
+        conn: &PgPooledConn,
+        runner: Option<&str>,
+    ) -> CustomResult<
+        Vec<(storage_enums::ProcessTrackerStatus, i64)>,
+        storage_models::errors::DatabaseError,
+    > {
+        let mut filter = <Self as HasTable>::table()
+            .group_by(dsl::status)
+            .select((dsl::status, diesel::dsl::count_star()))
+            .into_boxed();
+
+        if let Some(runner) = runner {
+            filter = filter.filter(dsl::runner.eq(runner.to_owned()));
+        }
+
+        crate::logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&filter).to_string());
+
+        filter
+            .get_results_async(conn)
+            .await
+            .into_report()
+            .change_context(storage_models::errors::DatabaseError::Others)
+            .attach_printable_lazy(|| "Error counting process tracker tasks by status")
+    }
 }
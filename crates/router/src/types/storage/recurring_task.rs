@@ -0,0 +1,3 @@
+pub use storage_models::recurring_task::{
+    RecurringTask, RecurringTaskNew, RecurringTaskUpdate, RecurringTaskUpdateInternal,
+};
@@ -0,0 +1,3 @@
+pub use storage_models::vault::{
+    VaultCard, VaultCardNew, VaultCardUpdate, VaultDataKey, VaultDataKeyNew,
+};
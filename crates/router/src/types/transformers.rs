@@ -285,6 +285,36 @@ impl From<F<storage_enums::Currency>> for F<api_enums::Currency> {
     }
 }
 
+impl From<F<api_enums::FraudCheckStage>> for F<storage_enums::FraudCheckStage> {
+    fn from(frm_stage: F<api_enums::FraudCheckStage>) -> Self {
+        Self(frunk::labelled_convert_from(frm_stage.0))
+    }
+}
+
+impl From<F<storage_enums::FraudCheckStage>> for F<api_enums::FraudCheckStage> {
+    fn from(frm_stage: F<storage_enums::FraudCheckStage>) -> Self {
+        Self(frunk::labelled_convert_from(frm_stage.0))
+    }
+}
+
+impl From<F<api_enums::FraudCheckStatus>> for F<storage_enums::FraudCheckStatus> {
+    fn from(frm_status: F<api_enums::FraudCheckStatus>) -> Self {
+        Self(frunk::labelled_convert_from(frm_status.0))
+    }
+}
+
+impl From<F<storage_enums::FraudCheckStatus>> for F<api_enums::FraudCheckStatus> {
+    fn from(frm_status: F<storage_enums::FraudCheckStatus>) -> Self {
+        Self(frunk::labelled_convert_from(frm_status.0))
+    }
+}
+
+impl From<F<storage_enums::DeclineCode>> for F<api_enums::DeclineCode> {
+    fn from(decline_code: F<storage_enums::DeclineCode>) -> Self {
+        Self(frunk::labelled_convert_from(decline_code.0))
+    }
+}
+
 impl<'a> From<F<&'a api_types::Address>> for F<storage::AddressUpdate> {
     fn from(address: F<&api_types::Address>) -> Self {
         let address = address.0;
@@ -463,3 +493,23 @@ impl From<F<api_models::api_keys::UpdateApiKeyRequest>>
         .into()
     }
 }
+
+impl From<F<storage::FraudCheck>> for F<api_models::fraud_check::FraudCheckResponse> {
+    fn from(item: F<storage::FraudCheck>) -> Self {
+        let fraud_check = item.0;
+        api_models::fraud_check::FraudCheckResponse {
+            frm_id: fraud_check.frm_id,
+            payment_id: fraud_check.payment_id,
+            attempt_id: fraud_check.attempt_id,
+            frm_name: fraud_check.frm_name,
+            frm_transaction_id: fraud_check.frm_transaction_id,
+            frm_stage: fraud_check.frm_stage.foreign_into(),
+            frm_status: fraud_check.frm_status.foreign_into(),
+            frm_score: fraud_check.frm_score,
+            frm_reason: fraud_check.frm_reason,
+            reviewed_at: fraud_check.reviewed_at,
+            created_at: fraud_check.created_at,
+        }
+        .into()
+    }
+}
@@ -13,6 +13,9 @@ pub(crate) enum PartitionKey<'a> {
         merchant_id: &'a str,
         payment_id: &'a str,
     },
+    LookupId {
+        lookup_id: &'a str,
+    },
 }
 
 impl<'a> std::fmt::Display for PartitionKey<'a> {
@@ -22,6 +25,7 @@ impl<'a> std::fmt::Display for PartitionKey<'a> {
                 merchant_id,
                 payment_id,
             } => f.write_str(&format!("mid_{merchant_id}_pid_{payment_id}")),
+            PartitionKey::LookupId { lookup_id } => f.write_str(&format!("lid_{lookup_id}")),
         }
     }
 }
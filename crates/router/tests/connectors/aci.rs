@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
 use masking::Secret;
 use router::{
     configs::settings::Settings,
@@ -7,10 +8,15 @@ use router::{
     core::payments,
     db::StorageImpl,
     routes, services,
-    types::{self, storage::enums, PaymentAddress},
+    types::{self, api, storage::enums, PaymentAddress},
+    utils::crypto::{self, EncodeMessage},
 };
+use serde_json::json;
 
-use crate::connector_auth::ConnectorAuthentication;
+use crate::{
+    connector_auth::ConnectorAuthentication,
+    utils::{self, Connector, ConnectorActions},
+};
 
 fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
     let auth = ConnectorAuthentication::new()
@@ -49,6 +55,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             capture_method: None,
             browser_info: None,
             order_details: None,
+            network_token: None,
+            authentication_data: None,
             email: None,
         },
         response: Err(types::ErrorResponse::default()),
@@ -259,3 +267,111 @@ async fn refunds_create_failure() {
     println!("{response:?}");
     assert!(response, "The refund was intended to fail but it passed");
 }
+
+struct Aci;
+impl ConnectorActions for Aci {}
+impl Connector for Aci {
+    fn get_data(&self) -> types::api::ConnectorData {
+        types::api::ConnectorData {
+            connector: Box::new(&aci::Aci),
+            connector_name: types::Connector::Aci,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            ConnectorAuthentication::new()
+                .aci
+                .expect("Missing ACI connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "aci".to_string()
+    }
+}
+
+fn get_webhook_body(result_code: &str) -> Vec<u8> {
+    json!({
+        "type": "PAYMENT",
+        "payload": {
+            "id": "8ac7a4a1845f7e4d01846095baa04cd2",
+            "paymentType": "DB",
+            "result": {
+                "code": result_code,
+                "description": "Request successfully processed"
+            },
+            "ndc": "8ac7a4c9845f7e4a01846095b9e5_0a40c0ba4e8c4b2ba3b2cb2d8d1d2a6c",
+            "timestamp": "2022-11-14 10:22:45+0000",
+            "buildNumber": "9e30a25d2d3f8ff5e7c4e8a1e2b8d3c1f0b0a6c9"
+        }
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[test]
+fn should_decrypt_webhook_body() {
+    let secret = [7u8; 32];
+    let nonce = [3u8; 12];
+    let body = get_webhook_body("000.100.110");
+    let (encrypted_body, authentication_tag) = crypto::GcmAes256::new(nonce.to_vec())
+        .encode_message(&secret, &body)
+        .unwrap();
+    let encrypted_body = hex::encode(encrypted_body).into_bytes();
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("x-initialization-vector"),
+        HeaderValue::from_str(&hex::encode(nonce)).unwrap(),
+    );
+    headers.insert(
+        HeaderName::from_static("x-authentication-tag"),
+        HeaderValue::from_str(&hex::encode(authentication_tag)).unwrap(),
+    );
+
+    let connector = Aci.get_data().connector;
+    let algorithm = connector
+        .get_webhook_body_decoding_algorithm(&headers, &encrypted_body)
+        .unwrap();
+    let message = connector
+        .get_webhook_body_decoding_message(&headers, &encrypted_body)
+        .unwrap();
+    let decoded_body = algorithm.decode_message(&secret, &message).unwrap();
+    assert_eq!(decoded_body, body);
+
+    let wrong_secret = [8u8; 32];
+    assert!(algorithm.decode_message(&wrong_secret, &message).is_err());
+}
+
+#[test]
+fn should_get_webhook_details() {
+    let connector = Aci.get_data().connector;
+    let body = get_webhook_body("000.100.110");
+    assert_eq!(
+        connector.get_webhook_event_type(&body).unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentSuccess
+    );
+    assert_eq!(
+        connector.get_webhook_object_reference_id(&body).unwrap(),
+        "8ac7a4a1845f7e4d01846095baa04cd2"
+    );
+    let failed_body = get_webhook_body("100.370.100");
+    assert_eq!(
+        connector.get_webhook_event_type(&failed_body).unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentFailure
+    );
+}
+
+#[actix_web::test]
+async fn should_sync_payment_from_webhook() {
+    let response = Aci
+        .sync_payment_from_webhook(&get_webhook_body("000.100.110"), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some("8ac7a4a1845f7e4d01846095baa04cd2".to_string())
+    );
+}
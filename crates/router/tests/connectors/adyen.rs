@@ -77,6 +77,8 @@ impl AdyenTest {
             capture_method: Some(capture_method),
             browser_info: None,
             order_details: None,
+            network_token: None,
+            authentication_data: None,
             email: None,
         })
     }
@@ -162,6 +164,7 @@ async fn should_void_authorized_payment() {
             Some(types::PaymentsCancelData {
                 connector_transaction_id: String::from(""),
                 cancellation_reason: Some("requested_by_customer".to_string()),
+                connector_meta: None,
             }),
             AdyenTest::get_payment_info(),
         )
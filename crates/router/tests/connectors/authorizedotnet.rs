@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
 use masking::Secret;
 use router::{
     configs::settings::Settings,
@@ -7,10 +8,15 @@ use router::{
     core::payments,
     db::StorageImpl,
     routes, services,
-    types::{self, storage::enums, PaymentAddress},
+    types::{self, api, storage::enums, PaymentAddress},
+    utils::crypto::{self, SignMessage},
 };
+use serde_json::json;
 
-use crate::connector_auth::ConnectorAuthentication;
+use crate::{
+    connector_auth::ConnectorAuthentication,
+    utils::{self, Connector, ConnectorActions},
+};
 
 fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
     let auth = ConnectorAuthentication::new()
@@ -49,6 +55,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             capture_method: None,
             browser_info: None,
             order_details: None,
+            network_token: None,
+            authentication_data: None,
             email: None,
         },
         payment_method_id: None,
@@ -253,3 +261,102 @@ async fn refunds_create_failure() {
         "The test was intended to fail but it passed"
     );
 }
+
+struct AuthorizedotnetTest;
+impl ConnectorActions for AuthorizedotnetTest {}
+impl Connector for AuthorizedotnetTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        types::api::ConnectorData {
+            connector: Box::new(&Authorizedotnet),
+            connector_name: types::Connector::Authorizedotnet,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            ConnectorAuthentication::new()
+                .authorizedotnet
+                .expect("Missing Authorize.net connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "authorizedotnet".to_string()
+    }
+}
+
+fn get_webhook_body(event_type: &str, response_code: u8) -> Vec<u8> {
+    json!({
+        "notificationId": "d0e8e7fe-c3e7-4add-a480-27bc5ce28a9e",
+        "eventType": event_type,
+        "eventDate": "2022-11-14T08:18:20.5637219Z",
+        "webhookId": "63d6fea2-aa13-4b1d-a204-f5fbc15942b7",
+        "payload": {
+            "responseCode": response_code,
+            "authCode": "LZ6I19",
+            "avsResponse": "Y",
+            "authAmount": 1.00,
+            "entityName": "transaction",
+            "id": "60190000010"
+        }
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[test]
+fn should_verify_webhook_signature() {
+    let secret = b"5B3F0C21A4E1E66B0F4EDE8D5F8E4B5E";
+    let body = get_webhook_body("net.authorize.payment.authcapture.created", 1);
+    let signature = crypto::HmacSha512.sign_message(secret, &body).unwrap();
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("x-anet-signature"),
+        HeaderValue::from_str(&format!("sha512={}", hex::encode_upper(signature))).unwrap(),
+    );
+
+    assert!(AuthorizedotnetTest
+        .verify_webhook_signature(&headers, &body, secret)
+        .unwrap());
+    assert!(!AuthorizedotnetTest
+        .verify_webhook_signature(&headers, &body, b"wrong_secret")
+        .unwrap());
+}
+
+#[test]
+fn should_get_webhook_details() {
+    let connector = AuthorizedotnetTest.get_data().connector;
+    let body = get_webhook_body("net.authorize.payment.authcapture.created", 1);
+    assert_eq!(
+        connector.get_webhook_event_type(&body).unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentSuccess
+    );
+    assert_eq!(
+        connector.get_webhook_object_reference_id(&body).unwrap(),
+        "60190000010"
+    );
+    let declined_body = get_webhook_body("net.authorize.payment.authcapture.created", 2);
+    assert_eq!(
+        connector.get_webhook_event_type(&declined_body).unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentFailure
+    );
+    let refund_body = get_webhook_body("net.authorize.payment.refund.created", 1);
+    assert!(connector.get_webhook_event_type(&refund_body).is_err());
+}
+
+#[actix_web::test]
+async fn should_sync_payment_from_webhook() {
+    let response = AuthorizedotnetTest
+        .sync_payment_from_webhook(
+            &get_webhook_body("net.authorize.payment.authcapture.created", 1),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some("60190000010".to_string())
+    );
+}
@@ -0,0 +1,407 @@
+use actix_web::http::header::HeaderMap;
+use base64::Engine;
+use masking::Secret;
+use router::types::{self, api, storage::enums};
+use serde_json::json;
+use serial_test::serial;
+use wiremock::{
+    matchers::{body_partial_json, method, path_regex},
+    Mock, ResponseTemplate,
+};
+
+use crate::{
+    connector_auth,
+    utils::{self, Connector, ConnectorActions, LocalMock, MockConfig},
+};
+
+struct Braintree;
+
+impl LocalMock for Braintree {}
+impl ConnectorActions for Braintree {}
+impl Connector for Braintree {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Braintree;
+        types::api::ConnectorData {
+            connector: Box::new(&Braintree),
+            connector_name: types::Connector::Braintree,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .braintree
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "braintree".to_string()
+    }
+}
+
+fn get_transaction(id: &str, status: &str) -> serde_json::Value {
+    json!({
+        "transaction": {
+            "id": id,
+            "currencyIsoCode": "USD",
+            "amount": "1.00",
+            "status": status,
+            "creditCard": { "token": "8x3bq4z" }
+        }
+    })
+}
+
+fn get_mock_config() -> MockConfig {
+    let declined_transaction = json!({
+        "apiErrorResponse": {
+            "message": "Do Not Honor",
+            "transaction": {
+                "id": "kx8mm6ah",
+                "currencyIsoCode": "USD",
+                "amount": "1.00",
+                "status": "processor_declined",
+                "processorResponseCode": "2000",
+                "processorResponseText": "Do Not Honor"
+            }
+        }
+    });
+
+    MockConfig {
+        address: Some("127.0.0.1:9093".to_string()),
+        mocks: vec![
+            Mock::given(method("POST"))
+                .and(path_regex(r"^/merchants/[^/]+/transactions$"))
+                .and(body_partial_json(json!({
+                    "transaction": { "creditCard": { "number": "4000111111111115" } }
+                })))
+                .respond_with(ResponseTemplate::new(422).set_body_json(declined_transaction)),
+            Mock::given(method("POST"))
+                .and(path_regex(r"^/merchants/[^/]+/transactions$"))
+                .and(body_partial_json(json!({
+                    "transaction": { "paymentMethodToken": "8x3bq4z" }
+                })))
+                .respond_with(
+                    ResponseTemplate::new(201)
+                        .set_body_json(get_transaction("9yb2k7cr", "submitted_for_settlement")),
+                ),
+            Mock::given(method("POST"))
+                .and(path_regex(r"^/merchants/[^/]+/transactions$"))
+                .and(body_partial_json(json!({
+                    "transaction": {
+                        "amount": "1.00",
+                        "type": "sale",
+                        "options": { "submitForSettlement": false }
+                    }
+                })))
+                .respond_with(
+                    ResponseTemplate::new(201)
+                        .set_body_json(get_transaction("f5r6kd1x", "authorized")),
+                ),
+            Mock::given(method("POST"))
+                .and(path_regex(r"^/merchants/[^/]+/transactions$"))
+                .and(body_partial_json(json!({
+                    "transaction": { "options": { "submitForSettlement": true } }
+                })))
+                .respond_with(
+                    ResponseTemplate::new(201)
+                        .set_body_json(get_transaction("f5r6kd1x", "submitted_for_settlement")),
+                ),
+            Mock::given(method("PUT"))
+                .and(path_regex(
+                    r"^/merchants/[^/]+/transactions/f5r6kd1x/submit_for_settlement$",
+                ))
+                .and(body_partial_json(
+                    json!({ "transaction": { "amount": "1.00" } }),
+                ))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(get_transaction("f5r6kd1x", "submitted_for_settlement")),
+                ),
+            Mock::given(method("PUT"))
+                .and(path_regex(r"^/merchants/[^/]+/transactions/f5r6kd1x/void$"))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(get_transaction("f5r6kd1x", "voided")),
+                ),
+            Mock::given(method("POST"))
+                .and(path_regex(
+                    r"^/merchants/[^/]+/transactions/f5r6kd1x/refund$",
+                ))
+                .and(body_partial_json(
+                    json!({ "transaction": { "amount": "1.00" } }),
+                ))
+                .respond_with(
+                    ResponseTemplate::new(201)
+                        .set_body_json(get_transaction("3m8dqr2b", "submitted_for_settlement")),
+                ),
+            Mock::given(method("GET"))
+                .and(path_regex(r"^/merchants/[^/]+/transactions/3m8dqr2b$"))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(get_transaction("3m8dqr2b", "settled")),
+                ),
+        ],
+    }
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_only_authorize_payment() {
+    let conn = Braintree {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn.authorize_payment(None, None).await.unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some("f5r6kd1x".to_string())
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_make_payment() {
+    let conn = Braintree {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn.make_payment(None, None).await.unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    match response.response.unwrap() {
+        types::PaymentsResponseData::TransactionResponse {
+            mandate_reference, ..
+        } => assert_eq!(mandate_reference, Some("8x3bq4z".to_string())),
+        types::PaymentsResponseData::SessionResponse { .. } => panic!("Unexpected response"),
+    }
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_make_payment_with_paypal_nonce() {
+    let conn = Braintree {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: api::PaymentMethod::Wallet(api::WalletData {
+                    issuer_name: api_models::enums::WalletIssuer::Paypal,
+                    token: Some("fake-paypal-one-time-nonce".to_string()),
+                }),
+                ..utils::PaymentAuthorizeType::default().0
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_fail_paypal_payment_without_nonce() {
+    let conn = Braintree {};
+    let response = conn
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: api::PaymentMethod::Paypal,
+                ..utils::PaymentAuthorizeType::default().0
+            }),
+            None,
+        )
+        .await;
+    assert!(response.is_err());
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_make_payment_with_vaulted_payment_method() {
+    let conn = Braintree {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: api::PaymentMethod::MandatePayment,
+                mandate_id: Some(api_models::payments::MandateIds {
+                    mandate_id: "man_3JsIQNKVu0F7HQHbpSrJ".to_string(),
+                    connector_mandate_id: Some("8x3bq4z".to_string()),
+                    payment_method: None,
+                }),
+                off_session: Some(true),
+                ..utils::PaymentAuthorizeType::default().0
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some("9yb2k7cr".to_string())
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_return_processor_decline() {
+    let conn = Braintree {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: api::PaymentMethod::Card(api::Card {
+                    card_number: Secret::new("4000111111111115".to_string()),
+                    ..utils::CCardType::default().0
+                }),
+                ..utils::PaymentAuthorizeType::default().0
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    let error = response.response.unwrap_err();
+    assert_eq!(error.status_code, 422);
+    assert_eq!(error.code, "2000");
+    assert_eq!(error.message, "Do Not Honor");
+    assert_eq!(error.reason, Some("Do Not Honor".to_string()));
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_capture_authorized_payment() {
+    let conn = Braintree {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .capture_payment("f5r6kd1x".to_string(), None, None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_void_authorized_payment() {
+    let conn = Braintree {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .void_payment("f5r6kd1x".to_string(), None, None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Voided);
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_refund_settled_payment() {
+    let conn = Braintree {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .refund_payment("f5r6kd1x".to_string(), None, None)
+        .await
+        .unwrap();
+    let refund = response.response.unwrap();
+    assert_eq!(refund.connector_refund_id, "3m8dqr2b");
+    assert_eq!(refund.refund_status, enums::RefundStatus::Pending);
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_sync_refund() {
+    let conn = Braintree {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .sync_refund("3m8dqr2b".to_string(), None, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        response.response.unwrap().refund_status,
+        enums::RefundStatus::Success
+    );
+}
+
+fn get_webhook_notification(kind: &str) -> Vec<u8> {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<notification>
+  <timestamp type="datetime">2023-03-08T10:47:21Z</timestamp>
+  <kind>{kind}</kind>
+  <subject>
+    <transaction>
+      <id>f5r6kd1x</id>
+      <status>settled</status>
+      <type>sale</type>
+      <currency-iso-code>USD</currency-iso-code>
+      <amount>1.00</amount>
+    </transaction>
+  </subject>
+</notification>"#
+    )
+    .into_bytes()
+}
+
+#[test]
+fn should_decode_webhook_payload() {
+    let connector = Braintree {}.get_data().connector;
+    let notification = get_webhook_notification("transaction_settled");
+    let payload = base64::engine::general_purpose::STANDARD.encode(&notification);
+    // Braintree breaks the payload into lines of 60 characters
+    let payload = payload
+        .as_bytes()
+        .chunks(60)
+        .map(|line| std::str::from_utf8(line).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let body = serde_urlencoded::to_string([
+        ("bt_signature", "public_key|signature"),
+        ("bt_payload", payload.as_str()),
+    ])
+    .unwrap();
+
+    let headers = HeaderMap::new();
+    assert_eq!(
+        connector
+            .get_webhook_body_decoding_message(&headers, body.as_bytes())
+            .unwrap(),
+        notification
+    );
+    assert_eq!(
+        connector
+            .get_webhook_source_verification_message(&headers, body.as_bytes(), "braintree", &[])
+            .unwrap(),
+        payload.into_bytes()
+    );
+}
+
+#[test]
+fn should_get_webhook_details() {
+    let connector = Braintree {}.get_data().connector;
+    let body = get_webhook_notification("transaction_settled");
+    assert_eq!(
+        connector.get_webhook_event_type(&body).unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentSuccess
+    );
+    assert_eq!(
+        connector.get_webhook_object_reference_id(&body).unwrap(),
+        "f5r6kd1x"
+    );
+    assert_eq!(
+        connector
+            .get_webhook_event_type(&get_webhook_notification("transaction_settlement_declined"))
+            .unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentFailure
+    );
+    assert!(connector
+        .get_webhook_event_type(&get_webhook_notification("transaction_disbursed"))
+        .is_err());
+}
+
+#[actix_web::test]
+async fn should_sync_payment_from_webhook() {
+    let connector = Braintree {};
+    let response = connector
+        .sync_payment_from_webhook(&get_webhook_notification("transaction_settled"), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some("f5r6kd1x".to_string())
+    );
+}
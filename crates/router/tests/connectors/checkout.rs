@@ -1,13 +1,20 @@
 use std::marker::PhantomData;
 
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
 use router::{
+    connector::Checkout,
     core::payments,
     db::StorageImpl,
     routes,
     types::{self, api, storage::enums, PaymentAddress},
+    utils::crypto::{self, SignMessage},
 };
+use serde_json::json;
 
-use crate::connector_auth::ConnectorAuthentication;
+use crate::{
+    connector_auth::ConnectorAuthentication,
+    utils::{self, Connector, ConnectorActions},
+};
 
 fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
     let auth = ConnectorAuthentication::new()
@@ -46,6 +53,8 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             capture_method: None,
             browser_info: None,
             order_details: None,
+            network_token: None,
+            authentication_data: None,
             email: None,
         },
         response: Err(types::ErrorResponse::default()),
@@ -300,3 +309,107 @@ async fn test_checkout_refund_failure() {
     let code = response.response.unwrap_err().code;
     assert_eq!(code, "refund_amount_exceeds_balance");
 }
+
+struct CheckoutTest;
+impl ConnectorActions for CheckoutTest {}
+impl Connector for CheckoutTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        types::api::ConnectorData {
+            connector: Box::new(&Checkout),
+            connector_name: types::Connector::Checkout,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            ConnectorAuthentication::new()
+                .checkout
+                .expect("Missing Checkout connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "checkout".to_string()
+    }
+}
+
+fn get_webhook_body(event_type: &str) -> Vec<u8> {
+    json!({
+        "id": "evt_az5sblvku4ge3dwpztvyizgcau",
+        "type": event_type,
+        "version": "1.0.24",
+        "created_on": "2022-11-14T08:18:20Z",
+        "data": {
+            "id": "pay_mbabizu24mvu3mela5njyhpit4",
+            "action_id": "act_y3oqhf46pyzuxjbcn2giaqnb44",
+            "amount": 100,
+            "currency": "USD",
+            "response_code": "10000",
+            "response_summary": "Approved"
+        }
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[test]
+fn should_verify_webhook_signature() {
+    let secret = b"0fb33fc8-a05c-4c63-9df1-e3e1b8d9b1e6";
+    let body = get_webhook_body("payment_captured");
+    let signature = crypto::HmacSha256.sign_message(secret, &body).unwrap();
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("cko-signature"),
+        HeaderValue::from_str(&hex::encode(signature)).unwrap(),
+    );
+
+    assert!(CheckoutTest
+        .verify_webhook_signature(&headers, &body, secret)
+        .unwrap());
+    assert!(!CheckoutTest
+        .verify_webhook_signature(&headers, &body, b"wrong_secret")
+        .unwrap());
+}
+
+#[test]
+fn should_get_webhook_details() {
+    let connector = CheckoutTest.get_data().connector;
+    let body = get_webhook_body("payment_captured");
+    assert_eq!(
+        connector.get_webhook_event_type(&body).unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentSuccess
+    );
+    assert_eq!(
+        connector.get_webhook_object_reference_id(&body).unwrap(),
+        "pay_mbabizu24mvu3mela5njyhpit4"
+    );
+    assert_eq!(
+        connector
+            .get_webhook_event_type(&get_webhook_body("payment_declined"))
+            .unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentFailure
+    );
+    assert!(connector
+        .get_webhook_event_type(&get_webhook_body("payment_refunded"))
+        .is_err());
+}
+
+#[actix_web::test]
+async fn should_sync_payment_from_webhook() {
+    let approved = CheckoutTest
+        .sync_payment_from_webhook(&get_webhook_body("payment_approved"), None)
+        .await
+        .unwrap();
+    assert_eq!(approved.status, enums::AttemptStatus::Authorized);
+
+    let captured = CheckoutTest
+        .sync_payment_from_webhook(&get_webhook_body("payment_captured"), None)
+        .await
+        .unwrap();
+    assert_eq!(captured.status, enums::AttemptStatus::Charged);
+    assert_eq!(
+        utils::get_connector_transaction_id(captured.response),
+        Some("pay_mbabizu24mvu3mela5njyhpit4".to_string())
+    );
+}
@@ -0,0 +1,437 @@
+use std::sync::Once;
+
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
+use masking::Secret;
+use router::{
+    connector::DummyConnector,
+    services,
+    types::{self, api, storage::enums},
+    utils::crypto::{self, SignMessage},
+};
+use serde_json::json;
+
+use crate::utils::{self, Connector, ConnectorActions};
+
+static SIMULATOR: Once = Once::new();
+
+#[derive(Clone, Copy)]
+struct DummyConnectorTest;
+impl ConnectorActions for DummyConnectorTest {}
+impl utils::Connector for DummyConnectorTest {
+    fn get_data(&self) -> types::api::ConnectorData {
+        start_simulator();
+        types::api::ConnectorData {
+            connector: Box::new(&DummyConnector),
+            connector_name: types::Connector::DummyConnector,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        // The simulator accepts any API key, so no sandbox credentials are needed
+        types::ConnectorAuthType::HeaderKey {
+            api_key: "simulator_api_key".to_string(),
+        }
+    }
+
+    fn get_name(&self) -> String {
+        "dummy_connector".to_string()
+    }
+
+    fn get_request_interval(&self) -> u64 {
+        3
+    }
+}
+
+static CONNECTOR: DummyConnectorTest = DummyConnectorTest {};
+
+/// Runs the connector simulator on the port configured for the dummy connector, in a thread of
+/// its own so that it outlives the runtime of the test starting it
+fn start_simulator() {
+    SIMULATOR.call_once(|| {
+        let (started, wait_for_start) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            actix_web::rt::System::new().block_on(async move {
+                let server = connector_simulator::start_server(
+                    connector_simulator::settings::SimulatorSettings {
+                        async_outcome_delay: 1,
+                        timeout_delay: 1,
+                        ..Default::default()
+                    },
+                )
+                .expect("Failed to start the connector simulator");
+                started.send(()).expect("Failed to notify the test");
+                server.await
+            })
+        });
+        wait_for_start
+            .recv()
+            .expect("Connector simulator did not start");
+    });
+}
+
+fn get_payment_data(card_number: &str) -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data: types::api::PaymentMethod::Card(api::Card {
+            card_number: Secret::new(card_number.to_string()),
+            ..utils::CCardType::default().0
+        }),
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+#[actix_web::test]
+async fn should_only_authorize_payment() {
+    let response = CONNECTOR.authorize_payment(None, None).await.unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+}
+
+#[actix_web::test]
+async fn should_make_payment() {
+    let response = CONNECTOR.make_payment(None, None).await.unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
+
+#[actix_web::test]
+async fn should_partially_capture_authorized_payment() {
+    let response = CONNECTOR
+        .authorize_and_capture_payment(
+            None,
+            Some(types::PaymentsCaptureData {
+                amount_to_capture: Some(50),
+                ..utils::PaymentCaptureType::default().0
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
+
+#[actix_web::test]
+async fn should_sync_authorized_payment() {
+    let authorize_response = CONNECTOR.authorize_payment(None, None).await.unwrap();
+    let txn_id = utils::get_connector_transaction_id(authorize_response.response);
+    let response = CONNECTOR
+        .sync_payment(
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: router::types::ResponseId::ConnectorTransactionId(
+                    txn_id.unwrap(),
+                ),
+                ..utils::PaymentSyncType::default().0
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+}
+
+#[actix_web::test]
+async fn should_void_authorized_payment() {
+    let response = CONNECTOR
+        .authorize_and_void_payment(None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Voided);
+}
+
+#[actix_web::test]
+async fn should_fail_void_for_captured_payment() {
+    let authorize_response = CONNECTOR.make_payment(None, None).await.unwrap();
+    let txn_id = utils::get_connector_transaction_id(authorize_response.response).unwrap();
+    let response = CONNECTOR.void_payment(txn_id, None, None).await.unwrap();
+    assert_eq!(
+        response.response.unwrap_err().code,
+        "invalid_payment_state".to_string()
+    );
+}
+
+#[actix_web::test]
+async fn should_refund_and_sync_refund() {
+    let refund_response = CONNECTOR
+        .make_payment_and_refund(None, None, None)
+        .await
+        .unwrap();
+    let refund_id = refund_response.response.unwrap().connector_refund_id;
+    let response = CONNECTOR.sync_refund(refund_id, None, None).await.unwrap();
+    assert_eq!(
+        response.response.unwrap().refund_status,
+        enums::RefundStatus::Success
+    );
+}
+
+#[actix_web::test]
+async fn should_fail_refund_for_refund_failure_card() {
+    let response = CONNECTOR
+        .make_payment_and_refund(get_payment_data("4000000000005126"), None, None)
+        .await
+        .unwrap();
+    assert_eq!(
+        response.response.unwrap().refund_status,
+        enums::RefundStatus::Failure
+    );
+}
+
+#[actix_web::test]
+async fn should_decline_payment_for_decline_cards() {
+    for (card_number, code) in [
+        ("4000000000000002", "card_declined"),
+        ("4000000000009995", "insufficient_funds"),
+        ("4000000000000069", "expired_card"),
+    ] {
+        let response = CONNECTOR
+            .make_payment(get_payment_data(card_number), None)
+            .await
+            .unwrap();
+        assert_eq!(response.status, enums::AttemptStatus::Failure);
+        assert_eq!(response.response.unwrap_err().code, code.to_string());
+    }
+}
+
+// Server errors of the connector are reported as failures to call the connector
+#[actix_web::test]
+async fn should_fail_payment_for_processing_error_card() {
+    let response = CONNECTOR
+        .make_payment(get_payment_data("4000000000000119"), None)
+        .await;
+    assert!(response.is_err());
+}
+
+#[actix_web::test]
+async fn should_complete_payment_after_3ds_challenge() {
+    let response = CONNECTOR
+        .authorize_payment(get_payment_data("4000000000003220"), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    let redirection_data = match response.response.as_ref().unwrap() {
+        types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        } => redirection_data.clone().unwrap(),
+        _ => panic!("Expected a transaction response"),
+    };
+
+    let challenge = reqwest::Client::new()
+        .post(&redirection_data.url)
+        .form(&[("result", "success")])
+        .send()
+        .await
+        .unwrap();
+    assert!(challenge.status().is_success());
+
+    let txn_id = utils::get_connector_transaction_id(response.response).unwrap();
+    let response = CONNECTOR
+        .sync_payment(
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: router::types::ResponseId::ConnectorTransactionId(txn_id),
+                ..utils::PaymentSyncType::default().0
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+}
+
+#[actix_web::test]
+async fn should_complete_processing_payment_asynchronously() {
+    let response = CONNECTOR
+        .authorize_payment(get_payment_data("4000000000000077"), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Pending);
+    let txn_id = utils::get_connector_transaction_id(response.response).unwrap();
+    let response = CONNECTOR
+        .psync_retry_till_status_matches(
+            enums::AttemptStatus::Authorized,
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: router::types::ResponseId::ConnectorTransactionId(txn_id),
+                ..utils::PaymentSyncType::default().0
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+}
+
+async fn authenticate_payment(
+    card_number: &str,
+    authentication_id: Option<String>,
+) -> types::RouterData<
+    api::Authenticate,
+    types::ConnectorAuthenticationData,
+    types::AuthenticationResponseData,
+> {
+    start_simulator();
+    let payment_data = get_payment_data(card_number).unwrap();
+    let integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Authenticate,
+        types::ConnectorAuthenticationData,
+        types::AuthenticationResponseData,
+    > = Box::new(&DummyConnector);
+    let request = CONNECTOR.generate_data(
+        types::ConnectorAuthenticationData {
+            amount: payment_data.amount,
+            currency: payment_data.currency,
+            payment_method_data: payment_data.payment_method_data,
+            email: None,
+            browser_info: payment_data.browser_info,
+            authentication_id,
+        },
+        None,
+    );
+    utils::call_connector(request, integration).await.unwrap()
+}
+
+#[actix_web::test]
+async fn should_authorize_challenge_card_with_external_authentication() {
+    let authentication = authenticate_payment("4242424242424242", None)
+        .await
+        .response
+        .unwrap();
+    assert_eq!(
+        authentication.status,
+        types::AuthenticationStatus::Authenticated
+    );
+    assert!(authentication.authentication_data.is_some());
+
+    // A card requiring a challenge is authorized without one with the result of the authentication
+    let response = CONNECTOR
+        .authorize_payment(
+            get_payment_data("4000000000003220").map(|payment_data| types::PaymentsAuthorizeData {
+                authentication_data: authentication.authentication_data,
+                ..payment_data
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+}
+
+#[actix_web::test]
+async fn should_get_authentication_status_for_test_cards() {
+    for (card_number, status) in [
+        (
+            "4000000000003220",
+            types::AuthenticationStatus::ChallengeRequired,
+        ),
+        ("4000000000003063", types::AuthenticationStatus::Failed),
+        ("4000000000003279", types::AuthenticationStatus::Rejected),
+        ("4000000000003238", types::AuthenticationStatus::Attempted),
+    ] {
+        let response = authenticate_payment(card_number, None)
+            .await
+            .response
+            .unwrap();
+        assert_eq!(response.status, status);
+    }
+}
+
+#[actix_web::test]
+async fn should_retrieve_authentication_after_challenge() {
+    let authentication = authenticate_payment("4000000000003220", None)
+        .await
+        .response
+        .unwrap();
+    assert_eq!(
+        authentication.status,
+        types::AuthenticationStatus::ChallengeRequired
+    );
+    let redirection_data = authentication.redirection_data.unwrap();
+
+    let challenge = reqwest::Client::new()
+        .post(&redirection_data.url)
+        .form(&[("result", "success")])
+        .send()
+        .await
+        .unwrap();
+    assert!(challenge.status().is_success());
+
+    let response = authenticate_payment(
+        "4000000000003220",
+        Some(authentication.authentication_id.clone()),
+    )
+    .await
+    .response
+    .unwrap();
+    assert_eq!(response.authentication_id, authentication.authentication_id);
+    assert_eq!(response.status, types::AuthenticationStatus::Authenticated);
+    assert!(response.authentication_data.is_some());
+    assert!(response.redirection_data.is_none());
+}
+
+fn get_webhook_body(event_type: &str, status: &str) -> Vec<u8> {
+    json!({
+        "event_type": event_type,
+        "data": {
+            "id": "pay_Zb8C1wGQX6hM4rYtLpA0kEsF",
+            "status": status,
+            "amount": 100,
+            "amount_captured": 100,
+            "amount_refunded": 0,
+            "currency": "USD",
+            "reference": "pay_mbabizu24mvu3mela5njyhpit4",
+            "created": "2023-03-01T10:00:00Z",
+            "next_action": null,
+            "error": null
+        }
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[test]
+fn should_verify_webhook_signature() {
+    let secret = b"simulator_webhook_secret";
+    let body = get_webhook_body("payment_succeeded", "succeeded");
+    let signature = crypto::HmacSha256.sign_message(secret, &body).unwrap();
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static(connector_simulator::SIGNATURE_HEADER),
+        HeaderValue::from_str(&hex::encode(signature)).unwrap(),
+    );
+
+    assert!(CONNECTOR
+        .verify_webhook_signature(&headers, &body, secret)
+        .unwrap());
+    assert!(!CONNECTOR
+        .verify_webhook_signature(&headers, &body, b"wrong_secret")
+        .unwrap());
+}
+
+#[test]
+fn should_get_webhook_details() {
+    let connector = CONNECTOR.get_data().connector;
+    let body = get_webhook_body("payment_succeeded", "succeeded");
+    assert_eq!(
+        connector.get_webhook_event_type(&body).unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentSuccess
+    );
+    assert_eq!(
+        connector.get_webhook_object_reference_id(&body).unwrap(),
+        "pay_Zb8C1wGQX6hM4rYtLpA0kEsF"
+    );
+    assert_eq!(
+        connector
+            .get_webhook_event_type(&get_webhook_body("payment_failed", "failed"))
+            .unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentFailure
+    );
+}
+
+#[actix_web::test]
+async fn should_sync_payment_from_webhook() {
+    let response = CONNECTOR
+        .sync_payment_from_webhook(&get_webhook_body("payment_succeeded", "succeeded"), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some("pay_Zb8C1wGQX6hM4rYtLpA0kEsF".to_string())
+    );
+}
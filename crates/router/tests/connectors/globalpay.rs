@@ -1,16 +1,20 @@
 use std::{thread::sleep, time::Duration};
 
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
 use masking::Secret;
-use router::types::{
-    self,
-    api::{self},
-    storage::enums,
+use router::{
+    types::{
+        self,
+        api::{self},
+        storage::enums,
+    },
+    utils::crypto::{self, GenerateDigest},
 };
 use serde_json::json;
 
 use crate::{
     connector_auth,
-    utils::{self, ConnectorActions, PaymentInfo},
+    utils::{self, Connector, ConnectorActions, PaymentInfo},
 };
 
 struct Globalpay;
@@ -180,3 +184,83 @@ async fn should_sync_refund() {
         enums::RefundStatus::Success,
     );
 }
+
+fn get_webhook_body(status: &str) -> Vec<u8> {
+    json!({
+        "id": "TRN_BHZ1whvNJnMvB6dPwf3znwWTsPjCn0",
+        "time_created": "2022-11-14T08:18:20.563Z",
+        "type": "SALE",
+        "status": status,
+        "channel": "CNP",
+        "capture_mode": "AUTO",
+        "amount": "100",
+        "currency": "USD",
+        "country": "US",
+        "merchant_id": "MER_7e3e2c7df34f42819b3edee31022ee3f",
+        "merchant_name": "Sandbox_merchant_3",
+        "account_id": "TRA_c9967ad7d8ec4b46b6dd44a61cde9a91",
+        "account_name": "transaction_processing",
+        "reference": "e8c3f5d0-1cd8-4d09-9f0b-8f0a3b6e0a4b"
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[test]
+fn should_verify_webhook_signature() {
+    let connector = Globalpay.get_data().connector;
+    let secret = b"4NpIQJDCIDzfTKhA";
+    let body = get_webhook_body("CAPTURED");
+    let digest = crypto::Sha512
+        .generate_digest(&[body.as_slice(), secret].concat())
+        .unwrap();
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("x-gp-signature"),
+        HeaderValue::from_str(&hex::encode(&digest)).unwrap(),
+    );
+
+    let signature = connector
+        .get_webhook_source_verification_signature(&headers, &body)
+        .unwrap();
+    let message = connector
+        .get_webhook_source_verification_message(&headers, &body, "globalpay", secret)
+        .unwrap();
+    assert_eq!(crypto::Sha512.generate_digest(&message).unwrap(), signature);
+}
+
+#[test]
+fn should_get_webhook_details() {
+    let connector = Globalpay.get_data().connector;
+    let body = get_webhook_body("CAPTURED");
+    assert_eq!(
+        connector.get_webhook_event_type(&body).unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentSuccess
+    );
+    assert_eq!(
+        connector.get_webhook_object_reference_id(&body).unwrap(),
+        "TRN_BHZ1whvNJnMvB6dPwf3znwWTsPjCn0"
+    );
+    assert_eq!(
+        connector
+            .get_webhook_event_type(&get_webhook_body("DECLINED"))
+            .unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentFailure
+    );
+    assert!(connector
+        .get_webhook_event_type(&get_webhook_body("PENDING"))
+        .is_err());
+}
+
+#[actix_web::test]
+async fn should_sync_payment_from_webhook() {
+    let response = Globalpay
+        .sync_payment_from_webhook(&get_webhook_body("CAPTURED"), get_default_payment_info())
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some("TRN_BHZ1whvNJnMvB6dPwf3znwWTsPjCn0".to_string())
+    );
+}
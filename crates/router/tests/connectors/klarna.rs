@@ -0,0 +1,411 @@
+use actix_web::http::header::HeaderMap;
+use router::{
+    configs::settings::Settings,
+    connector,
+    db::StorageImpl,
+    routes,
+    types::{
+        self,
+        api::{self, IncomingWebhook},
+        storage::enums,
+    },
+};
+use serde_json::json;
+use serial_test::serial;
+use wiremock::{
+    matchers::{body_json, body_partial_json, method, path},
+    Mock, ResponseTemplate,
+};
+
+use crate::{
+    connector_auth,
+    utils::{self, Connector, ConnectorActions, LocalMock, MockConfig},
+};
+
+struct Klarna;
+
+impl LocalMock for Klarna {}
+impl ConnectorActions for Klarna {}
+impl Connector for Klarna {
+    fn get_data(&self) -> types::api::ConnectorData {
+        types::api::ConnectorData {
+            connector: Box::new(&connector::Klarna),
+            connector_name: types::Connector::Klarna,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .klarna
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "klarna".to_string()
+    }
+}
+
+const ORDER_ID: &str = "0b1d9815-165e-42e2-8867-35bc03789e00";
+
+fn get_order_path(action: &str) -> String {
+    format!("/ordermanagement/v1/orders/{ORDER_ID}{action}")
+}
+
+fn get_mock_config() -> MockConfig {
+    let order = json!({
+        "order_id": ORDER_ID,
+        "status": "PART_CAPTURED",
+        "fraud_status": "ACCEPTED",
+        "order_amount": 100,
+        "captured_amount": 50,
+        "refunded_amount": 50,
+        "remaining_authorized_amount": 50,
+        "refunds": [{
+            "refund_id": "b2cf5f1e-3c83-4d8f-a7b6-3b7e9c4f1d2a",
+            "refunded_amount": 50,
+            "reference": "ref_1ac5b6ea"
+        }]
+    });
+
+    MockConfig {
+        address: Some("127.0.0.1:9094".to_string()),
+        mocks: vec![
+            Mock::given(method("POST"))
+                .and(path("/payments/v1/authorizations/klarna_token/order"))
+                .and(body_partial_json(json!({
+                    "order_amount": 100,
+                    "order_lines": [{
+                        "name": "shirt",
+                        "quantity": 2,
+                        "unit_price": 50,
+                        "total_amount": 100
+                    }]
+                })))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "order_id": ORDER_ID,
+                    "fraud_status": "ACCEPTED",
+                    "redirect_url": "https://payments.klarna.com/redirect/confirm"
+                }))),
+            Mock::given(method("POST"))
+                .and(path(get_order_path("/captures")))
+                .and(body_json(json!({
+                    "captured_amount": 50,
+                    "order_lines": [{
+                        "name": "shirt",
+                        "quantity": 1,
+                        "unit_price": 50,
+                        "total_amount": 50
+                    }]
+                })))
+                .respond_with(ResponseTemplate::new(201)),
+            Mock::given(method("POST"))
+                .and(path(get_order_path("/captures")))
+                .and(body_json(json!({ "captured_amount": 100 })))
+                .respond_with(ResponseTemplate::new(201)),
+            Mock::given(method("POST"))
+                .and(path(get_order_path("/cancel")))
+                .respond_with(ResponseTemplate::new(204)),
+            Mock::given(method("POST"))
+                .and(path(get_order_path("/release-remaining-authorization")))
+                .respond_with(ResponseTemplate::new(204)),
+            Mock::given(method("POST"))
+                .and(path(get_order_path("/refunds")))
+                .and(body_partial_json(json!({ "refunded_amount": 100 })))
+                .respond_with(ResponseTemplate::new(201)),
+            Mock::given(method("GET"))
+                .and(path(get_order_path("")))
+                .respond_with(ResponseTemplate::new(200).set_body_json(order)),
+        ],
+    }
+}
+
+fn get_payment_authorize_data() -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data: api::PaymentMethod::PayLater(api::PayLaterData::KlarnaSdk {
+            issuer_name: api_models::payments::KlarnaIssuer::Klarna,
+            token: "klarna_token".to_string(),
+        }),
+        order_details: Some(api_models::payments::OrderDetails {
+            product_name: "shirt".to_string(),
+            quantity: 2,
+        }),
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+fn get_connector_meta() -> Option<serde_json::Value> {
+    Some(json!({
+        "order_line": {
+            "name": "shirt",
+            "quantity": 2,
+            "unit_price": 50,
+            "total_amount": 100
+        }
+    }))
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_only_authorize_payment() {
+    let conn = Klarna {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .authorize_payment(get_payment_authorize_data(), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+    match response.response.unwrap() {
+        types::PaymentsResponseData::TransactionResponse {
+            resource_id,
+            connector_metadata,
+            ..
+        } => {
+            assert_eq!(
+                resource_id.get_connector_transaction_id().unwrap(),
+                ORDER_ID
+            );
+            assert_eq!(connector_metadata, get_connector_meta());
+        }
+        types::PaymentsResponseData::SessionResponse { .. } => panic!("Unexpected response"),
+    }
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_make_payment() {
+    let conn = Klarna {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .make_payment(get_payment_authorize_data(), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_capture_authorized_payment() {
+    let conn = Klarna {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .capture_payment(ORDER_ID.to_string(), None, None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    assert_eq!(response.amount_captured, Some(100));
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_partially_capture_authorized_payment_with_order_lines() {
+    let conn = Klarna {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .capture_payment(
+            ORDER_ID.to_string(),
+            Some(types::PaymentsCaptureData {
+                amount_to_capture: Some(50),
+                connector_meta: get_connector_meta(),
+                ..utils::PaymentCaptureType::default().0
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::PartialCharged);
+    assert_eq!(response.amount_captured, Some(50));
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_void_authorized_payment() {
+    let conn = Klarna {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .void_payment(ORDER_ID.to_string(), None, None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Voided);
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_release_remaining_authorization_of_partially_captured_payment() {
+    let conn = Klarna {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let request = types::PaymentsCancelRouterData {
+        amount_captured: Some(50),
+        ..conn.generate_data(
+            types::PaymentsCancelData {
+                connector_transaction_id: ORDER_ID.to_string(),
+                ..utils::PaymentCancelType::default().0
+            },
+            None,
+        )
+    };
+    let response = utils::call_connector(
+        request,
+        conn.get_data().connector.get_connector_integration(),
+    )
+    .await
+    .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_sync_partially_captured_payment() {
+    let conn = Klarna {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .sync_payment(
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::ConnectorTransactionId(
+                    ORDER_ID.to_string(),
+                ),
+                ..utils::PaymentSyncType::default().0
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::PartialCharged);
+    assert_eq!(response.amount_captured, Some(50));
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_refund_captured_payment() {
+    let conn = Klarna {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .refund_payment(
+            ORDER_ID.to_string(),
+            Some(types::RefundsData {
+                refund_id: "ref_5d1e8a3c".to_string(),
+                ..utils::PaymentRefundType::default().0
+            }),
+            None,
+        )
+        .await
+        .unwrap();
+    let refund = response.response.unwrap();
+    assert_eq!(refund.connector_refund_id, "ref_5d1e8a3c");
+    assert_eq!(refund.refund_status, enums::RefundStatus::Success);
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_sync_refund() {
+    let conn = Klarna {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let get_refund_data = |refund_id: &str| types::RefundsData {
+        connector_transaction_id: ORDER_ID.to_string(),
+        connector_refund_id: Some(refund_id.to_string()),
+        ..utils::PaymentRefundType::default().0
+    };
+    let response = conn
+        .sync_refund(
+            "ref_1ac5b6ea".to_string(),
+            Some(get_refund_data("ref_1ac5b6ea")),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        response.response.unwrap().refund_status,
+        enums::RefundStatus::Success
+    );
+
+    let response = conn
+        .sync_refund(
+            "ref_5d1e8a3c".to_string(),
+            Some(get_refund_data("ref_5d1e8a3c")),
+            None,
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        response.response.unwrap().refund_status,
+        enums::RefundStatus::Pending
+    );
+}
+
+fn get_webhook_body(event_type: &str) -> Vec<u8> {
+    json!({
+        "order_id": ORDER_ID,
+        "event_type": event_type
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[test]
+fn should_get_webhook_details() {
+    let body = get_webhook_body("FRAUD_RISK_ACCEPTED");
+    assert_eq!(
+        connector::Klarna.get_webhook_event_type(&body).unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentSuccess
+    );
+    assert_eq!(
+        connector::Klarna
+            .get_webhook_object_reference_id(&body)
+            .unwrap(),
+        "0b1d9815-165e-42e2-8867-35bc03789e00"
+    );
+    assert_eq!(
+        connector::Klarna
+            .get_webhook_event_type(&get_webhook_body("FRAUD_RISK_STOPPED"))
+            .unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentFailure
+    );
+}
+
+#[test]
+fn should_get_webhook_resource_object() {
+    let resource_object = connector::Klarna
+        .get_webhook_resource_object(&get_webhook_body("FRAUD_RISK_REJECTED"))
+        .unwrap();
+    assert_eq!(
+        resource_object,
+        json!({
+            "order_id": "0b1d9815-165e-42e2-8867-35bc03789e00",
+            "fraud_status": "REJECTED"
+        })
+    );
+}
+
+#[actix_web::test]
+async fn should_not_verify_unsigned_webhook() {
+    let conf = Settings::new().unwrap();
+    let state = routes::AppState::with_storage(conf, StorageImpl::Mock).await;
+    let verified = connector::Klarna
+        .verify_webhook_source(
+            &*state.store,
+            &HeaderMap::new(),
+            &get_webhook_body("FRAUD_RISK_ACCEPTED"),
+            "klarna",
+        )
+        .await
+        .unwrap();
+    assert!(!verified);
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_sync_payment_from_unverified_webhook() {
+    let conn = Klarna {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .sync_payment_from_unverified_webhook(&get_webhook_body("FRAUD_RISK_ACCEPTED"), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::PartialCharged);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some(ORDER_ID.to_string())
+    );
+}
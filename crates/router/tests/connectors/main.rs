@@ -3,14 +3,20 @@
 mod aci;
 mod adyen;
 mod authorizedotnet;
+mod braintree;
 mod checkout;
 mod connector_auth;
 mod cybersource;
+#[cfg(feature = "dummy_connector")]
+mod dummy_connector;
 mod fiserv;
 mod globalpay;
+mod klarna;
+mod paypal;
 mod payu;
 mod rapyd;
 mod shift4;
+mod signifyd;
 mod stripe;
 mod utils;
 mod worldline;
@@ -0,0 +1,617 @@
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
+use router::types::{self, api, storage::enums, AccessToken};
+use serde_json::json;
+use serial_test::serial;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, ResponseTemplate,
+};
+
+use crate::{
+    connector_auth,
+    utils::{self, Connector, ConnectorActions, LocalMock, MockConfig},
+};
+
+struct Paypal;
+
+impl LocalMock for Paypal {}
+impl ConnectorActions for Paypal {}
+impl Connector for Paypal {
+    fn get_data(&self) -> types::api::ConnectorData {
+        use router::connector::Paypal;
+        types::api::ConnectorData {
+            connector: Box::new(&Paypal),
+            connector_name: types::Connector::Paypal,
+            get_token: types::api::GetToken::Connector,
+        }
+    }
+
+    fn get_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .paypal
+                .expect("Missing connector authentication configuration"),
+        )
+    }
+
+    fn get_name(&self) -> String {
+        "paypal".to_string()
+    }
+}
+
+fn get_default_payment_info() -> Option<utils::PaymentInfo> {
+    Some(utils::PaymentInfo {
+        access_token: Some(AccessToken {
+            token: "A21AAFEpH4PsADK7qSS7pSRsgzfENtu".to_string(),
+            expires: 32400,
+        }),
+        router_return_url: Some("http://localhost:8080/payments/return".to_string()),
+        ..Default::default()
+    })
+}
+
+fn get_paypal_payment_data() -> Option<types::PaymentsAuthorizeData> {
+    Some(types::PaymentsAuthorizeData {
+        payment_method_data: api::PaymentMethod::Paypal,
+        ..utils::PaymentAuthorizeType::default().0
+    })
+}
+
+fn get_paypal_meta(authorize_id: Option<&str>, capture_id: Option<&str>) -> serde_json::Value {
+    json!({
+        "authorize_id": authorize_id,
+        "capture_id": capture_id,
+    })
+}
+
+fn get_mock_config() -> MockConfig {
+    let payer_action_required = json!({
+        "id": "5O190127TN364715T",
+        "status": "PAYER_ACTION_REQUIRED",
+        "links": [
+            {
+                "href": "https://api-m.sandbox.paypal.com/v2/checkout/orders/5O190127TN364715T",
+                "rel": "self",
+                "method": "GET"
+            },
+            {
+                "href": "https://www.sandbox.paypal.com/checkoutnow?token=5O190127TN364715T",
+                "rel": "payer-action",
+                "method": "GET"
+            }
+        ]
+    });
+    let vaulted_payment = json!({
+        "id": "9AB12345CD678901E",
+        "status": "COMPLETED",
+        "purchase_units": [{
+            "payments": {
+                "captures": [{ "id": "3C679366HH908993F", "status": "COMPLETED" }]
+            }
+        }]
+    });
+    let captured_order = json!({
+        "id": "5O190127TN364715T",
+        "status": "COMPLETED",
+        "payment_source": {
+            "paypal": {
+                "attributes": {
+                    "vault": { "id": "nkq2y9g", "status": "VAULTED" }
+                }
+            }
+        },
+        "purchase_units": [{
+            "payments": {
+                "captures": [{ "id": "3C679366HH908993F", "status": "COMPLETED" }]
+            }
+        }]
+    });
+    let authorized_order = json!({
+        "id": "5O190127TN364715T",
+        "status": "COMPLETED",
+        "purchase_units": [{
+            "payments": {
+                "authorizations": [{ "id": "0VF52814937998046", "status": "CREATED" }]
+            }
+        }]
+    });
+    let error_resp = json!({
+        "name": "UNPROCESSABLE_ENTITY",
+        "message": "The requested action could not be performed, semantically incorrect, or failed business validation.",
+        "debug_id": "f3f2d2a8b0e4c",
+        "details": [{
+            "issue": "AUTHORIZATION_VOIDED",
+            "description": "A voided authorization cannot be captured or reauthorized."
+        }]
+    });
+
+    MockConfig {
+        address: Some("127.0.0.1:9092".to_string()),
+        mocks: vec![
+            Mock::given(method("POST"))
+                .and(path("/v2/checkout/orders"))
+                .and(body_partial_json(json!({
+                    "payment_source": { "paypal": { "vault_id": "nkq2y9g" } }
+                })))
+                .respond_with(ResponseTemplate::new(201).set_body_json(vaulted_payment.clone())),
+            Mock::given(method("POST"))
+                .and(path("/v2/checkout/orders"))
+                .and(body_partial_json(json!({
+                    "payment_source": {
+                        "token": { "id": "B-7TX81530T8384432T", "type": "BILLING_AGREEMENT" }
+                    }
+                })))
+                .respond_with(ResponseTemplate::new(201).set_body_json(vaulted_payment)),
+            Mock::given(method("POST"))
+                .and(path("/v2/checkout/orders"))
+                .respond_with(
+                    ResponseTemplate::new(200).set_body_json(payer_action_required.clone()),
+                ),
+            Mock::given(method("GET"))
+                .and(path("/v2/checkout/orders/5O190127TN364715T"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(payer_action_required)),
+            Mock::given(method("POST"))
+                .and(path("/v2/checkout/orders/5O190127TN364715T/capture"))
+                .respond_with(ResponseTemplate::new(201).set_body_json(captured_order)),
+            Mock::given(method("POST"))
+                .and(path("/v2/checkout/orders/5O190127TN364715T/authorize"))
+                .respond_with(ResponseTemplate::new(201).set_body_json(authorized_order)),
+            Mock::given(method("POST"))
+                .and(path(
+                    "/v2/payments/authorizations/0VF52814937998046/capture",
+                ))
+                .and(body_partial_json(json!({
+                    "amount": { "currency_code": "USD", "value": "1.00" }
+                })))
+                .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                    "id": "7TK53561YB803214S",
+                    "status": "COMPLETED"
+                }))),
+            Mock::given(method("POST"))
+                .and(path("/v2/payments/authorizations/0VF52814937998046/void"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "id": "0VF52814937998046",
+                    "status": "VOIDED"
+                }))),
+            Mock::given(method("POST"))
+                .and(path(
+                    "/v2/payments/authorizations/8AA831015G517922L/capture",
+                ))
+                .respond_with(ResponseTemplate::new(422).set_body_json(error_resp)),
+            Mock::given(method("POST"))
+                .and(path("/v2/payments/captures/3C679366HH908993F/refund"))
+                .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+                    "id": "1JU08902781691411",
+                    "status": "COMPLETED"
+                }))),
+            Mock::given(method("GET"))
+                .and(path("/v2/payments/refunds/1JU08902781691411"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                    "id": "1JU08902781691411",
+                    "status": "COMPLETED"
+                }))),
+        ],
+    }
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_redirect_customer_to_approve_order() {
+    let conn = Paypal {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .make_payment(get_paypal_payment_data(), get_default_payment_info())
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+    match response.response.unwrap() {
+        types::PaymentsResponseData::TransactionResponse {
+            resource_id,
+            redirect,
+            redirection_data,
+            ..
+        } => {
+            assert!(redirect);
+            assert_eq!(
+                resource_id.get_connector_transaction_id().ok(),
+                Some("5O190127TN364715T".to_string())
+            );
+            let redirection_data = redirection_data.unwrap();
+            assert_eq!(
+                redirection_data.url,
+                "https://www.sandbox.paypal.com/checkoutnow"
+            );
+            assert_eq!(
+                redirection_data.form_fields.get("token"),
+                Some(&"5O190127TN364715T".to_string())
+            );
+        }
+        types::PaymentsResponseData::SessionResponse { .. } => panic!("Unexpected response"),
+    }
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_fail_payment_for_unsupported_payment_method() {
+    let conn = Paypal {};
+    let response = conn.make_payment(None, get_default_payment_info()).await;
+    assert!(response.is_err());
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_sync_order_awaiting_approval() {
+    let conn = Paypal {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .sync_payment(
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::ConnectorTransactionId(
+                    "5O190127TN364715T".to_string(),
+                ),
+                ..utils::PaymentSyncType::default().0
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::AuthenticationPending);
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_capture_approved_order_on_return() {
+    let conn = Paypal {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .sync_payment(
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::ConnectorTransactionId(
+                    "5O190127TN364715T".to_string(),
+                ),
+                encoded_data: Some("token=5O190127TN364715T&PayerID=FSMVU44LF3YUS".to_string()),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    match response.response.unwrap() {
+        types::PaymentsResponseData::TransactionResponse {
+            mandate_reference,
+            connector_metadata,
+            ..
+        } => {
+            assert_eq!(
+                mandate_reference,
+                Some(r#"{"type":"vault_id","id":"nkq2y9g"}"#.to_string())
+            );
+            assert_eq!(
+                connector_metadata,
+                Some(get_paypal_meta(None, Some("3C679366HH908993F")))
+            );
+        }
+        types::PaymentsResponseData::SessionResponse { .. } => panic!("Unexpected response"),
+    }
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_authorize_approved_order_on_return() {
+    let conn = Paypal {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .sync_payment(
+            Some(types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::ConnectorTransactionId(
+                    "5O190127TN364715T".to_string(),
+                ),
+                encoded_data: Some("token=5O190127TN364715T&PayerID=FSMVU44LF3YUS".to_string()),
+                capture_method: Some(enums::CaptureMethod::Manual),
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_capture_authorized_payment() {
+    let conn = Paypal {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .capture_payment(
+            "5O190127TN364715T".to_string(),
+            Some(types::PaymentsCaptureData {
+                connector_meta: Some(get_paypal_meta(Some("0VF52814937998046"), None)),
+                ..utils::PaymentCaptureType::default().0
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    match response.response.unwrap() {
+        types::PaymentsResponseData::TransactionResponse {
+            resource_id,
+            connector_metadata,
+            ..
+        } => {
+            assert_eq!(
+                resource_id.get_connector_transaction_id().ok(),
+                Some("5O190127TN364715T".to_string())
+            );
+            assert_eq!(
+                connector_metadata,
+                Some(get_paypal_meta(
+                    Some("0VF52814937998046"),
+                    Some("7TK53561YB803214S")
+                ))
+            );
+        }
+        types::PaymentsResponseData::SessionResponse { .. } => panic!("Unexpected response"),
+    }
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_fail_capture_for_voided_authorization() {
+    let conn = Paypal {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .capture_payment(
+            "5O190127TN364715T".to_string(),
+            Some(types::PaymentsCaptureData {
+                connector_meta: Some(get_paypal_meta(Some("8AA831015G517922L"), None)),
+                ..utils::PaymentCaptureType::default().0
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    let error = response.response.unwrap_err();
+    assert_eq!(error.status_code, 422);
+    assert_eq!(error.code, "UNPROCESSABLE_ENTITY");
+    assert_eq!(
+        error.reason,
+        Some("A voided authorization cannot be captured or reauthorized.".to_string())
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_void_authorized_payment() {
+    let conn = Paypal {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .void_payment(
+            "5O190127TN364715T".to_string(),
+            Some(types::PaymentsCancelData {
+                connector_meta: Some(get_paypal_meta(Some("0VF52814937998046"), None)),
+                ..utils::PaymentCancelType::default().0
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Voided);
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_refund_captured_payment() {
+    let conn = Paypal {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .refund_payment(
+            "5O190127TN364715T".to_string(),
+            Some(types::RefundsData {
+                connector_metadata: Some(get_paypal_meta(None, Some("3C679366HH908993F"))),
+                ..utils::PaymentRefundType::default().0
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        response.response.unwrap().refund_status,
+        enums::RefundStatus::Success
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_sync_refund() {
+    let conn = Paypal {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .sync_refund(
+            "1JU08902781691411".to_string(),
+            None,
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        response.response.unwrap().refund_status,
+        enums::RefundStatus::Success
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_make_payment_with_vaulted_paypal_account() {
+    let conn = Paypal {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: api::PaymentMethod::MandatePayment,
+                mandate_id: Some(api_models::payments::MandateIds {
+                    mandate_id: "man_3JsIQNKVu0F7HQHbpSrJ".to_string(),
+                    connector_mandate_id: Some(r#"{"type":"vault_id","id":"nkq2y9g"}"#.to_string()),
+                    payment_method: None,
+                }),
+                off_session: Some(true),
+                ..utils::PaymentAuthorizeType::default().0
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some("9AB12345CD678901E".to_string())
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_make_payment_with_billing_agreement() {
+    let conn = Paypal {};
+    let _mock = conn.start_server(get_mock_config()).await;
+    let response = conn
+        .make_payment(
+            Some(types::PaymentsAuthorizeData {
+                payment_method_data: api::PaymentMethod::MandatePayment,
+                mandate_id: Some(api_models::payments::MandateIds {
+                    mandate_id: "man_3JsIQNKVu0F7HQHbpSrJ".to_string(),
+                    connector_mandate_id: Some(
+                        r#"{"type":"billing_agreement","id":"B-7TX81530T8384432T"}"#.to_string(),
+                    ),
+                    payment_method: None,
+                }),
+                off_session: Some(true),
+                ..utils::PaymentAuthorizeType::default().0
+            }),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
+
+fn get_webhook_body(event_type: &str, status: &str) -> Vec<u8> {
+    json!({
+        "id": "WH-58D329510W468432D-8HN650336L201105X",
+        "event_version": "1.0",
+        "resource_type": "capture",
+        "event_type": event_type,
+        "summary": "Payment completed for $ 1.0 USD",
+        "resource": {
+            "id": "3C679366HH908993F",
+            "status": status,
+            "amount": { "currency_code": "USD", "value": "1.00" },
+            "final_capture": true,
+            "supplementary_data": {
+                "related_ids": {
+                    "order_id": "5O190127TN364715T",
+                    "authorization_id": "0VF52814937998046"
+                }
+            }
+        }
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[test]
+fn should_build_webhook_verification_message() {
+    let connector = Paypal {}.get_data().connector;
+    let body = get_webhook_body("PAYMENT.CAPTURE.COMPLETED", "COMPLETED");
+    let secret = json!({
+        "webhook_id": "1JE4291016473214C",
+        "public_key": "MIIBCgKCAQEAnne51IvcKjq0M4SMqznu2m7ydgAWeyX1"
+    })
+    .to_string();
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("paypal-transmission-id"),
+        HeaderValue::from_static("69cd13f0-d67a-11e5-baa3-778b53f4ae55"),
+    );
+    headers.insert(
+        HeaderName::from_static("paypal-transmission-time"),
+        HeaderValue::from_static("2016-02-18T20:01:35Z"),
+    );
+    headers.insert(
+        HeaderName::from_static("paypal-transmission-sig"),
+        HeaderValue::from_static("c2lnbmF0dXJl"),
+    );
+
+    let message = connector
+        .get_webhook_source_verification_message(&headers, &body, "paypal", secret.as_bytes())
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(message).unwrap(),
+        format!(
+            "69cd13f0-d67a-11e5-baa3-778b53f4ae55|2016-02-18T20:01:35Z|1JE4291016473214C|{}",
+            crc32fast::hash(&body)
+        )
+    );
+    assert_eq!(
+        connector
+            .get_webhook_source_verification_signature(&headers, &body)
+            .unwrap(),
+        b"signature".to_vec()
+    );
+}
+
+#[test]
+fn should_get_webhook_details() {
+    let connector = Paypal {}.get_data().connector;
+    let body = get_webhook_body("PAYMENT.CAPTURE.COMPLETED", "COMPLETED");
+    assert_eq!(
+        connector.get_webhook_event_type(&body).unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentSuccess
+    );
+    assert_eq!(
+        connector.get_webhook_object_reference_id(&body).unwrap(),
+        "5O190127TN364715T"
+    );
+    assert_eq!(
+        connector
+            .get_webhook_event_type(&get_webhook_body("PAYMENT.CAPTURE.DENIED", "DECLINED"))
+            .unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentFailure
+    );
+    assert!(connector
+        .get_webhook_event_type(&get_webhook_body("CHECKOUT.ORDER.APPROVED", "APPROVED"))
+        .is_err());
+}
+
+#[actix_web::test]
+async fn should_sync_payment_from_webhook() {
+    let connector = Paypal {};
+    let response = connector
+        .sync_payment_from_webhook(
+            &get_webhook_body("PAYMENT.CAPTURE.COMPLETED", "COMPLETED"),
+            get_default_payment_info(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    match response.response.unwrap() {
+        types::PaymentsResponseData::TransactionResponse {
+            resource_id,
+            connector_metadata,
+            ..
+        } => {
+            assert_eq!(
+                resource_id.get_connector_transaction_id().ok(),
+                Some("5O190127TN364715T".to_string())
+            );
+            assert_eq!(
+                connector_metadata,
+                Some(get_paypal_meta(
+                    Some("0VF52814937998046"),
+                    Some("3C679366HH908993F")
+                ))
+            );
+        }
+        types::PaymentsResponseData::SessionResponse { .. } => panic!("Unexpected response"),
+    }
+}
@@ -1,4 +1,6 @@
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
 use router::types::{self, api, storage::enums, AccessToken, ConnectorAuthType};
+use serde_json::json;
 
 use crate::{
     connector_auth,
@@ -347,3 +349,94 @@ async fn should_fail_already_refunded_payment() {
     let x = response.response.unwrap_err();
     assert_eq!(x.reason.unwrap(), "PAID".to_string());
 }
+
+fn get_webhook_body(status: &str) -> Vec<u8> {
+    json!({
+        "order": {
+            "orderId": "LDLW5N7MF4140324GUEST000P01",
+            "extOrderId": "b7e5c8bb-6c1f-4e2b-a42f-4f1b9a8e3d2c",
+            "orderCreateDate": "2022-11-14T08:18:20.563+01:00",
+            "notifyUrl": "http://localhost:8080/webhooks/merchant_1668273825/payu",
+            "customerIp": "127.0.0.1",
+            "merchantPosId": "300746",
+            "description": "Order description",
+            "currencyCode": "PLN",
+            "totalAmount": "200",
+            "status": status
+        },
+        "localReceiptDateTime": "2022-11-14T08:19:02.123+01:00",
+        "properties": [
+            {
+                "name": "PAYMENT_ID",
+                "value": "5000009987"
+            }
+        ]
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[test]
+fn should_verify_webhook_signature() {
+    let connector = Payu {}.get_data().connector;
+    let secret = b"b6ca15b0d1020e8094d9b5f8d163db54";
+    let body = get_webhook_body("COMPLETED");
+    let digest = ring::digest::digest(&ring::digest::SHA256, &[body.as_slice(), secret].concat());
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("openpayu-signature"),
+        HeaderValue::from_str(&format!(
+            "sender=checkout;signature={};algorithm=SHA-256;content=DOCUMENT",
+            hex::encode(digest)
+        ))
+        .unwrap(),
+    );
+
+    let signature = connector
+        .get_webhook_source_verification_signature(&headers, &body)
+        .unwrap();
+    let message = connector
+        .get_webhook_source_verification_message(&headers, &body, "payu", secret)
+        .unwrap();
+    assert_eq!(
+        ring::digest::digest(&ring::digest::SHA256, &message).as_ref(),
+        signature.as_slice()
+    );
+}
+
+#[test]
+fn should_get_webhook_details() {
+    let connector = Payu {}.get_data().connector;
+    let body = get_webhook_body("COMPLETED");
+    assert_eq!(
+        connector.get_webhook_event_type(&body).unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentSuccess
+    );
+    assert_eq!(
+        connector.get_webhook_object_reference_id(&body).unwrap(),
+        "LDLW5N7MF4140324GUEST000P01"
+    );
+    assert_eq!(
+        connector
+            .get_webhook_event_type(&get_webhook_body("CANCELED"))
+            .unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentFailure
+    );
+    assert!(connector
+        .get_webhook_event_type(&get_webhook_body("PENDING"))
+        .is_err());
+}
+
+#[actix_web::test]
+async fn should_sync_payment_from_webhook() {
+    let connector = Payu {};
+    let response = connector
+        .sync_payment_from_webhook(&get_webhook_body("COMPLETED"), get_default_payment_info())
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some("LDLW5N7MF4140324GUEST000P01".to_string())
+    );
+}
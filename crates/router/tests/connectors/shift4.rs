@@ -134,6 +134,7 @@ async fn should_void_authorized_payment() {
             Some(types::PaymentsCancelData {
                 connector_transaction_id: "".to_string(),
                 cancellation_reason: Some("requested_by_customer".to_string()),
+                connector_meta: None,
             }),
             None,
         )
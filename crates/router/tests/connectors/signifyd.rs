@@ -0,0 +1,192 @@
+use std::marker::PhantomData;
+
+use router::{
+    connector::Signifyd,
+    services,
+    types::{self, api, storage::enums, PaymentAddress},
+};
+use serde_json::json;
+use serial_test::serial;
+use wiremock::{
+    matchers::{method, path},
+    Mock, ResponseTemplate,
+};
+
+use crate::{
+    connector_auth,
+    utils::{self, LocalMock, MockConfig},
+};
+
+struct SignifydMock;
+
+impl LocalMock for SignifydMock {}
+
+fn construct_frm_router_data<F, Req>(
+    request: Req,
+) -> types::RouterData<F, Req, types::FraudCheckResponseData> {
+    types::RouterData {
+        flow: PhantomData,
+        merchant_id: "signifyd".to_string(),
+        connector: "signifyd".to_string(),
+        payment_id: uuid::Uuid::new_v4().to_string(),
+        attempt_id: Some(uuid::Uuid::new_v4().to_string()),
+        status: enums::AttemptStatus::Authorized,
+        payment_method: enums::PaymentMethodType::Card,
+        connector_auth_type: types::ConnectorAuthType::from(
+            connector_auth::ConnectorAuthentication::new()
+                .signifyd
+                .expect("Missing connector authentication configuration"),
+        ),
+        description: Some("This is a test".to_string()),
+        return_url: None,
+        router_return_url: None,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::NoThreeDs,
+        connector_meta_data: None,
+        amount_captured: None,
+        access_token: None,
+        request,
+        response: Err(types::ErrorResponse::default()),
+        payment_method_id: None,
+    }
+}
+
+fn get_pre_auth_data() -> types::FraudCheckPreAuthData {
+    let authorize_data = utils::PaymentAuthorizeType::default().0;
+    types::FraudCheckPreAuthData {
+        amount: authorize_data.amount,
+        currency: authorize_data.currency,
+        payment_method_data: authorize_data.payment_method_data,
+        email: None,
+        browser_info: authorize_data.browser_info,
+        order_details: None,
+    }
+}
+
+fn get_post_auth_data() -> types::FraudCheckPostAuthData {
+    types::FraudCheckPostAuthData {
+        amount: 100,
+        currency: enums::Currency::USD,
+        email: None,
+        connector: "stripe".to_string(),
+        connector_transaction_id: Some("ch_123456".to_string()),
+        attempt_status: enums::AttemptStatus::Authorized,
+    }
+}
+
+fn get_decision(checkpoint_action: &str) -> serde_json::Value {
+    json!({
+        "signifydId": 6_024_576_612_u64,
+        "decision": {
+            "checkpointAction": checkpoint_action,
+            "checkpointActionReason": "Signifyd Decision",
+            "score": 998.2
+        }
+    })
+}
+
+fn get_mock_config(endpoint: &str, response: ResponseTemplate) -> MockConfig {
+    MockConfig {
+        address: Some("127.0.0.1:9091".to_string()),
+        mocks: vec![Mock::given(method("POST"))
+            .and(path(endpoint))
+            .respond_with(response)],
+    }
+}
+
+async fn call_pre_auth(
+    mock_config: MockConfig,
+) -> types::RouterData<api::PreAuth, types::FraudCheckPreAuthData, types::FraudCheckResponseData> {
+    let _mock = SignifydMock.start_server(mock_config).await;
+    let integration: services::BoxedConnectorIntegration<
+        '_,
+        api::PreAuth,
+        types::FraudCheckPreAuthData,
+        types::FraudCheckResponseData,
+    > = Box::new(&Signifyd);
+    utils::call_connector(construct_frm_router_data(get_pre_auth_data()), integration)
+        .await
+        .unwrap()
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_accept_checkout_in_pre_auth() {
+    let response = call_pre_auth(get_mock_config(
+        "/v3/orders/events/checkouts",
+        ResponseTemplate::new(200).set_body_json(get_decision("ACCEPT")),
+    ))
+    .await
+    .response
+    .unwrap();
+    assert_eq!(response.frm_status, enums::FraudCheckStatus::Accept);
+    assert_eq!(response.frm_transaction_id, Some("6024576612".to_string()));
+    assert_eq!(response.score, Some(998));
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_reject_checkout_in_pre_auth() {
+    let response = call_pre_auth(get_mock_config(
+        "/v3/orders/events/checkouts",
+        ResponseTemplate::new(200).set_body_json(get_decision("REJECT")),
+    ))
+    .await
+    .response
+    .unwrap();
+    assert_eq!(response.frm_status, enums::FraudCheckStatus::Reject);
+    assert_eq!(response.reason, Some("Signifyd Decision".to_string()));
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_hold_checkout_for_manual_review_in_pre_auth() {
+    let response = call_pre_auth(get_mock_config(
+        "/v3/orders/events/checkouts",
+        ResponseTemplate::new(200).set_body_json(get_decision("HOLD")),
+    ))
+    .await
+    .response
+    .unwrap();
+    assert_eq!(response.frm_status, enums::FraudCheckStatus::ManualReview);
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_fail_checkout_for_invalid_request() {
+    let response = call_pre_auth(get_mock_config(
+        "/v3/orders/events/checkouts",
+        ResponseTemplate::new(400).set_body_json(json!({
+            "traceId": "trace_123",
+            "errors": {"checkoutId": ["must not be blank"]}
+        })),
+    ))
+    .await;
+    let error = response.response.unwrap_err();
+    assert_eq!(error.status_code, 400);
+    assert_eq!(error.reason, Some("trace_123".to_string()));
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_accept_transaction_in_post_auth() {
+    let _mock = SignifydMock
+        .start_server(get_mock_config(
+            "/v3/orders/events/transactions",
+            ResponseTemplate::new(200).set_body_json(get_decision("ACCEPT")),
+        ))
+        .await;
+    let integration: services::BoxedConnectorIntegration<
+        '_,
+        api::PostAuth,
+        types::FraudCheckPostAuthData,
+        types::FraudCheckResponseData,
+    > = Box::new(&Signifyd);
+    let response =
+        utils::call_connector(construct_frm_router_data(get_post_auth_data()), integration)
+            .await
+            .unwrap()
+            .response
+            .unwrap();
+    assert_eq!(response.frm_status, enums::FraudCheckStatus::Accept);
+}
@@ -1,7 +1,8 @@
 use std::{fmt::Debug, marker::PhantomData, time::Duration};
 
+use actix_web::http::header::HeaderMap;
 use async_trait::async_trait;
-use error_stack::Report;
+use error_stack::{IntoReport, Report, ResultExt};
 use masking::Secret;
 use router::{
     core::{errors, errors::ConnectorError, payments},
@@ -14,6 +15,13 @@ use wiremock::{Mock, MockServer};
 pub trait Connector {
     fn get_data(&self) -> types::api::ConnectorData;
     fn get_auth_token(&self) -> types::ConnectorAuthType;
+    /// Authentication used where the connector is not called or is served by a local mock, so
+    /// that these tests do not need the credentials of a connector account
+    fn get_mock_auth_token(&self) -> types::ConnectorAuthType {
+        types::ConnectorAuthType::HeaderKey {
+            api_key: "test_api_key".to_string(),
+        }
+    }
     fn get_name(&self) -> String;
     fn get_connector_meta(&self) -> Option<serde_json::Value> {
         None
@@ -338,10 +346,94 @@ pub trait ConnectorActions: Connector {
         Err(errors::ConnectorError::ProcessingStepFailed(None).into())
     }
 
+    /// Handles a webhook the way the webhooks core does for a verified source, by processing
+    /// its resource object as the connector's response to a payment sync.
+    async fn sync_payment_from_webhook(
+        &self,
+        body: &[u8],
+        payment_info: Option<PaymentInfo>,
+    ) -> Result<types::PaymentsSyncRouterData, Report<ConnectorError>> {
+        let connector = self.get_data().connector;
+        let connector_transaction_id = connector.get_webhook_object_reference_id(body)?;
+        let resource_object = connector.get_webhook_resource_object(body)?;
+        let response = serde_json::to_vec(&resource_object)
+            .into_report()
+            .change_context(ConnectorError::WebhookResourceObjectNotFound)?;
+        let request = self.generate_data_with_auth(
+            types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::ConnectorTransactionId(
+                    connector_transaction_id,
+                ),
+                ..PaymentSyncType::default().0
+            },
+            payment_info,
+            self.get_mock_auth_token(),
+        );
+        call_connector_with_action(
+            request,
+            connector.get_connector_integration(),
+            payments::CallConnectorAction::HandleResponse(response),
+        )
+        .await
+    }
+
+    /// Handles a webhook the way the webhooks core does for an unverified source, by syncing the
+    /// payment it refers to with the connector, which has to be served by a local mock.
+    async fn sync_payment_from_unverified_webhook(
+        &self,
+        body: &[u8],
+        payment_info: Option<PaymentInfo>,
+    ) -> Result<types::PaymentsSyncRouterData, Report<ConnectorError>> {
+        let connector = self.get_data().connector;
+        let connector_transaction_id = connector.get_webhook_object_reference_id(body)?;
+        let request = self.generate_data_with_auth(
+            types::PaymentsSyncData {
+                connector_transaction_id: types::ResponseId::ConnectorTransactionId(
+                    connector_transaction_id,
+                ),
+                ..PaymentSyncType::default().0
+            },
+            payment_info,
+            self.get_mock_auth_token(),
+        );
+        call_connector(request, connector.get_connector_integration()).await
+    }
+
+    /// Verifies a webhook signature with the given merchant secret instead of the one
+    /// configured for the merchant.
+    fn verify_webhook_signature(
+        &self,
+        headers: &HeaderMap,
+        body: &[u8],
+        secret: &[u8],
+    ) -> Result<bool, Report<ConnectorError>> {
+        let connector = self.get_data().connector;
+        let algorithm = connector.get_webhook_source_verification_algorithm(headers, body)?;
+        let signature = connector.get_webhook_source_verification_signature(headers, body)?;
+        let message = connector.get_webhook_source_verification_message(
+            headers,
+            body,
+            &self.get_name(),
+            secret,
+        )?;
+        algorithm
+            .verify_signature(secret, &signature, &message)
+            .change_context(ConnectorError::WebhookSourceVerificationFailed)
+    }
+
     fn generate_data<Flow, Req: From<Req>, Res>(
         &self,
         req: Req,
         info: Option<PaymentInfo>,
+    ) -> types::RouterData<Flow, Req, Res> {
+        self.generate_data_with_auth(req, info, self.get_auth_token())
+    }
+
+    fn generate_data_with_auth<Flow, Req: From<Req>, Res>(
+        &self,
+        req: Req,
+        info: Option<PaymentInfo>,
+        connector_auth_type: types::ConnectorAuthType,
     ) -> types::RouterData<Flow, Req, Res> {
         types::RouterData {
             flow: PhantomData,
@@ -358,7 +450,7 @@ pub trait ConnectorActions: Connector {
                         .map_or(enums::AuthenticationType::NoThreeDs, |a| a)
                 }),
             payment_method: enums::PaymentMethodType::Card,
-            connector_auth_type: self.get_auth_token(),
+            connector_auth_type,
             description: Some("This is a test".to_string()),
             return_url: None,
             request: req,
@@ -389,13 +481,25 @@ pub trait ConnectorActions: Connector {
     }
 }
 
-async fn call_connector<
+pub async fn call_connector<
+    T: Debug + Clone + 'static,
+    Req: Debug + Clone + 'static,
+    Resp: Debug + Clone + 'static,
+>(
+    request: types::RouterData<T, Req, Resp>,
+    integration: services::BoxedConnectorIntegration<'_, T, Req, Resp>,
+) -> Result<types::RouterData<T, Req, Resp>, Report<ConnectorError>> {
+    call_connector_with_action(request, integration, payments::CallConnectorAction::Trigger).await
+}
+
+async fn call_connector_with_action<
     T: Debug + Clone + 'static,
     Req: Debug + Clone + 'static,
     Resp: Debug + Clone + 'static,
 >(
     request: types::RouterData<T, Req, Resp>,
     integration: services::BoxedConnectorIntegration<'_, T, Req, Resp>,
+    call_connector_action: payments::CallConnectorAction,
 ) -> Result<types::RouterData<T, Req, Resp>, Report<ConnectorError>> {
     use router::configs::settings::Settings;
     let conf = Settings::new().unwrap();
@@ -404,7 +508,7 @@ async fn call_connector<
         &state,
         integration,
         &request,
-        payments::CallConnectorAction::Trigger,
+        call_connector_action,
     )
     .await
 }
@@ -468,6 +572,8 @@ impl Default for PaymentAuthorizeType {
             setup_mandate_details: None,
             browser_info: Some(BrowserInfoType::default().0),
             order_details: None,
+            network_token: None,
+            authentication_data: None,
             email: None,
         };
         Self(data)
@@ -481,6 +587,7 @@ impl Default for PaymentCaptureType {
             currency: enums::Currency::USD,
             connector_transaction_id: "".to_string(),
             amount: 100,
+            connector_meta: None,
         })
     }
 }
@@ -490,6 +597,7 @@ impl Default for PaymentCancelType {
         Self(types::PaymentsCancelData {
             cancellation_reason: Some("requested_by_customer".to_string()),
             connector_transaction_id: "".to_string(),
+            connector_meta: None,
         })
     }
 }
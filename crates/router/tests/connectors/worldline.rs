@@ -1,14 +1,18 @@
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
 use api_models::payments::{Address, AddressDetails};
+use base64::Engine;
 use masking::Secret;
 use router::{
     connector::Worldline,
     core::errors,
-    types::{self, storage::enums, PaymentAddress},
+    types::{self, api, storage::enums, PaymentAddress},
+    utils::crypto::{self, SignMessage},
 };
+use serde_json::json;
 
 use crate::{
     connector_auth::ConnectorAuthentication,
-    utils::{self, ConnectorActions, PaymentInfo},
+    utils::{self, Connector, ConnectorActions, PaymentInfo},
 };
 
 struct WorldlineTest;
@@ -79,6 +83,8 @@ impl WorldlineTest {
             capture_method: Some(capture_method),
             browser_info: None,
             order_details: None,
+            network_token: None,
+            authentication_data: None,
             email: None,
         })
     }
@@ -371,3 +377,100 @@ async fn should_fail_refund_with_invalid_payment_status() {
         "ORDER WITHOUT REFUNDABLE PAYMENTS".to_string(),
     );
 }
+
+fn get_webhook_body(status: &str) -> Vec<u8> {
+    json!({
+        "apiVersion": "v1",
+        "created": "2022-11-14T08:18:20.563+0100",
+        "id": "34b8a607-1fce-4003-b3ae-a4d29e92b232",
+        "merchantId": "1859",
+        "payment": {
+            "id": "000000185900000000340000100001",
+            "paymentOutput": {
+                "amountOfMoney": {
+                    "amount": 100,
+                    "currencyCode": "USD"
+                },
+                "references": {
+                    "paymentReference": "0"
+                },
+                "paymentMethod": "card"
+            },
+            "status": status,
+            "statusOutput": {
+                "isCancellable": false,
+                "statusCategory": "COMPLETED",
+                "statusCode": 9,
+                "isAuthorized": false,
+                "isRefundable": true
+            }
+        },
+        "type": "payment.paid"
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[test]
+fn should_verify_webhook_signature() {
+    let secret = b"kjF3DbS2ydAMu2K3cEzZUnR5MdL9Tuw6";
+    let body = get_webhook_body("PAID");
+    let signature = crypto::HmacSha256.sign_message(secret, &body).unwrap();
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("x-gcs-signature"),
+        HeaderValue::from_str(&base64::engine::general_purpose::STANDARD.encode(signature))
+            .unwrap(),
+    );
+
+    assert!(WorldlineTest
+        .verify_webhook_signature(&headers, &body, secret)
+        .unwrap());
+    assert!(!WorldlineTest
+        .verify_webhook_signature(&headers, &body, b"wrong_secret")
+        .unwrap());
+}
+
+#[test]
+fn should_get_webhook_details() {
+    let connector = WorldlineTest.get_data().connector;
+    let body = get_webhook_body("PAID");
+    assert_eq!(
+        connector.get_webhook_event_type(&body).unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentSuccess
+    );
+    assert_eq!(
+        connector.get_webhook_object_reference_id(&body).unwrap(),
+        "000000185900000000340000100001"
+    );
+    assert_eq!(
+        connector
+            .get_webhook_event_type(&get_webhook_body("REJECTED"))
+            .unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentFailure
+    );
+    let refund_body = json!({
+        "id": "4f0f4e2e-2c3a-4e0b-bd8f-ff4f1d2b5d10",
+        "refund": {
+            "id": "000000185900000000340000100001",
+            "status": "REFUNDED"
+        },
+        "type": "refund.refunded"
+    })
+    .to_string()
+    .into_bytes();
+    assert!(connector.get_webhook_event_type(&refund_body).is_err());
+}
+
+#[actix_web::test]
+async fn should_sync_payment_from_webhook() {
+    let response = WorldlineTest
+        .sync_payment_from_webhook(&get_webhook_body("PAID"), WorldlineTest::get_payment_info())
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some("000000185900000000340000100001".to_string())
+    );
+}
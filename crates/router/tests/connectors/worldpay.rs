@@ -1,8 +1,12 @@
+use actix_web::http::header::{HeaderMap, HeaderName, HeaderValue};
 use futures::future::OptionFuture;
-use router::types::{
-    self,
-    api::{self, enums as api_enums},
-    storage::enums,
+use router::{
+    types::{
+        self,
+        api::{self, enums as api_enums},
+        storage::enums,
+    },
+    utils::crypto::{self, SignMessage},
 };
 use serde_json::json;
 use serial_test::serial;
@@ -13,7 +17,7 @@ use wiremock::{
 
 use crate::{
     connector_auth,
-    utils::{self, ConnectorActions, LocalMock, MockConfig},
+    utils::{self, Connector, ConnectorActions, LocalMock, MockConfig},
 };
 
 struct Worldpay;
@@ -221,6 +225,93 @@ async fn should_sync_refund() {
     );
 }
 
+fn get_webhook_body(event_type: &str) -> Vec<u8> {
+    json!({
+        "eventId": "a7b1b5c3-0b1d-4b3c-9c1b-3b5b1b4c9a11",
+        "eventTimestamp": "2022-11-14T08:18:20.563Z",
+        "eventDetails": {
+            "classification": "payment",
+            "transactionReference": "e8c3f5d0-1cd8-4d09-9f0b-8f0a3b6e0a4b",
+            "type": event_type,
+            "_links": {
+                "payments:events": {
+                    "href": "/payments/events/112233"
+                }
+            }
+        }
+    })
+    .to_string()
+    .into_bytes()
+}
+
+#[test]
+fn should_verify_webhook_signature() {
+    let connector = Worldpay {};
+    let secret = b"7c1f3e7d-8b3a-4c2a-9d5e-0f6a8b9c1d2e";
+    let body = get_webhook_body("authorized");
+    let signature = crypto::HmacSha256.sign_message(secret, &body).unwrap();
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("event-signature"),
+        HeaderValue::from_str(&format!("1/SHA256/{}", hex::encode(signature))).unwrap(),
+    );
+
+    assert!(connector
+        .verify_webhook_signature(&headers, &body, secret)
+        .unwrap());
+    assert!(!connector
+        .verify_webhook_signature(&headers, &body, b"wrong_secret")
+        .unwrap());
+}
+
+#[test]
+fn should_get_webhook_details() {
+    let connector = Worldpay {}.get_data().connector;
+    let body = get_webhook_body("authorized");
+    assert_eq!(
+        connector.get_webhook_event_type(&body).unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentSuccess
+    );
+    assert_eq!(
+        connector.get_webhook_object_reference_id(&body).unwrap(),
+        "112233"
+    );
+    assert_eq!(
+        connector
+            .get_webhook_event_type(&get_webhook_body("refused"))
+            .unwrap(),
+        api::IncomingWebhookEvent::PaymentIntentFailure
+    );
+    assert!(connector
+        .get_webhook_event_type(&get_webhook_body("refunded"))
+        .is_err());
+}
+
+#[actix_web::test]
+async fn should_sync_payment_from_webhook() {
+    let response = Worldpay {}
+        .sync_payment_from_webhook(&get_webhook_body("settled"), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some("112233".to_string())
+    );
+}
+
+#[actix_web::test]
+#[serial]
+async fn should_sync_payment_for_unverified_webhook() {
+    let connector = Worldpay {};
+    let _mock = connector.start_server(get_mock_config()).await;
+    let response = connector
+        .sync_payment_from_unverified_webhook(&get_webhook_body("authorized"), None)
+        .await
+        .unwrap();
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+}
+
 fn get_mock_config() -> MockConfig {
     let authorized = json!({
         "outcome": "authorized",
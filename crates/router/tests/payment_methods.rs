@@ -0,0 +1,97 @@
+#![allow(clippy::unwrap_used)]
+
+mod utils;
+
+fn mk_payment_method(
+    customer_id: &str,
+    card_number: &str,
+    card_exp_year: &str,
+) -> serde_json::Value {
+    serde_json::json!({
+        "payment_method": "card",
+        "customer_id": customer_id,
+        "card": {
+            "card_number": card_number,
+            "card_exp_month": "10",
+            "card_exp_year": card_exp_year,
+            "card_holder_name": "John Doe"
+        }
+    })
+}
+
+#[actix_web::test]
+#[ignore]
+// verify the API-KEY/merchant id has a locker configured
+async fn saving_a_card_twice_returns_the_same_payment_method() {
+    utils::setup().await;
+
+    let customer_id = format!("customer_{}", uuid::Uuid::new_v4());
+    let api_key = ("API-KEY", "MySecretApiKey");
+    let client = awc::Client::default();
+
+    let response = client
+        .post("http://127.0.0.1:8080/customers")
+        .insert_header(api_key)
+        .send_json(&serde_json::json!({ "customer_id": customer_id }))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+
+    let mut saved_cards = Vec::new();
+    for request in [
+        mk_payment_method(&customer_id, "4111111111111111", "2030"),
+        mk_payment_method(&customer_id, "4111111111111111", "2031"),
+        mk_payment_method(&customer_id, "4242424242424242", "2030"),
+    ] {
+        let mut response = client
+            .post("http://127.0.0.1:8080/payment_methods")
+            .insert_header(api_key)
+            .send_json(&request)
+            .await
+            .unwrap();
+        assert_eq!(response.status(), awc::http::StatusCode::OK);
+        let response_body: serde_json::Value = response.json().await.unwrap();
+        println!("payment-method-create: {response_body:?}");
+        saved_cards.push(response_body);
+    }
+
+    // The same card number is saved once per customer
+    assert_eq!(
+        saved_cards[0]["payment_method_id"],
+        saved_cards[1]["payment_method_id"]
+    );
+    assert_eq!(
+        saved_cards[0]["card_fingerprint"],
+        saved_cards[1]["card_fingerprint"]
+    );
+    assert_ne!(
+        saved_cards[0]["payment_method_id"],
+        saved_cards[2]["payment_method_id"]
+    );
+    assert_ne!(
+        saved_cards[0]["card_fingerprint"],
+        saved_cards[2]["card_fingerprint"]
+    );
+
+    // The expiry returned when saving a card again is the one stored
+    let mut response = client
+        .get(format!(
+            "http://127.0.0.1:8080/payment_methods/{}",
+            saved_cards[1]["payment_method_id"].as_str().unwrap()
+        ))
+        .insert_header(api_key)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), awc::http::StatusCode::OK);
+    let stored_card: serde_json::Value = response.json().await.unwrap();
+    println!("payment-method-retrieve: {stored_card:?}");
+    assert_eq!(
+        stored_card["card"]["expiry_month"],
+        saved_cards[1]["card"]["expiry_month"]
+    );
+    assert_eq!(
+        stored_card["card"]["expiry_year"],
+        saved_cards[1]["card"]["expiry_year"]
+    );
+}
@@ -269,3 +269,54 @@ async fn payments_create_core_adyen_no_redirect() {
         .unwrap();
     assert_eq!(expected_response, actual_response);
 }
+
+#[ignore]
+#[actix_rt::test]
+async fn payments_create_and_confirm_with_unsupported_payment_method() {
+    use router::configs::settings::Settings;
+    let conf = Settings::new().expect("invalid settings");
+
+    let state = routes::AppState::with_storage(conf, StorageImpl::PostgresqlTest).await;
+
+    let merchant_account = services::authenticate_by_api_key(&*state.store, "MySecretApiKey")
+        .await
+        .unwrap();
+
+    // Klarna does not accept cards, the payment is rejected before it is sent to it
+    let req = api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(format!(
+            "pay_{}",
+            Uuid::new_v4().simple()
+        ))),
+        amount: Some(6540.into()),
+        currency: Some(api_enums::Currency::USD),
+        connector: Some(api_enums::Connector::Klarna),
+        confirm: Some(true),
+        authentication_type: Some(api_enums::AuthenticationType::NoThreeDs),
+        payment_method_data: Some(api::PaymentMethod::Card(api::Card {
+            card_number: "4242424242424242".to_string().into(),
+            card_exp_month: "10".to_string().into(),
+            card_exp_year: "35".to_string().into(),
+            card_holder_name: "Arun Raj".to_string().into(),
+            card_cvc: "123".to_string().into(),
+        })),
+        payment_method: Some(api_enums::PaymentMethodType::Card),
+        ..<_>::default()
+    };
+
+    let error =
+        router::core::payments::payments_core::<api::Authorize, api::PaymentsResponse, _, _, _>(
+            &state,
+            merchant_account,
+            payments::PaymentCreate,
+            req,
+            services::AuthFlow::Merchant,
+            payments::CallConnectorAction::Trigger,
+        )
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        error.current_context(),
+        router::core::errors::ApiErrorResponse::NotSupportedByConnector { .. }
+    ));
+}
@@ -88,6 +88,8 @@ pub enum Flow {
     CustomersDelete,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customers set default payment method flow.
+    CustomersSetDefaultPaymentMethod,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -148,6 +150,8 @@ pub enum Flow {
     RefundsList,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
+    /// Network token lifecycle webhook receive
+    NetworkTokenWebhookReceive,
     /// Validate payment method flow
     ValidatePaymentMethod,
     /// API Key create flow
@@ -160,6 +164,28 @@ pub enum Flow {
     ApiKeyRevoke,
     /// API Key list flow
     ApiKeyList,
+    /// Process tracker tasks list flow
+    ProcessTrackerList,
+    /// Process tracker task retrieve flow
+    ProcessTrackerRetrieve,
+    /// Process tracker task retry schedule flow
+    ProcessTrackerRetrySchedule,
+    /// Process tracker task force retry flow
+    ProcessTrackerRetry,
+    /// Process tracker task reschedule flow
+    ProcessTrackerReschedule,
+    /// Process tracker task cancel flow
+    ProcessTrackerCancel,
+    /// Process tracker tasks summary flow
+    ProcessTrackerSummary,
+    /// Fraud check review queue list flow
+    FraudCheckReviewQueue,
+    /// Fraud check retrieve flow
+    FraudCheckRetrieve,
+    /// Fraud check review flow
+    FraudCheckReview,
+    /// Connector capabilities list flow
+    ConnectorCapabilitiesList,
 }
 
 /// Category of log event.
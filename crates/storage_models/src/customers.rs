@@ -31,6 +31,7 @@ pub struct Customer {
     pub description: Option<String>,
     pub created_at: PrimitiveDateTime,
     pub metadata: Option<serde_json::Value>,
+    pub default_payment_method_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -43,6 +44,9 @@ pub enum CustomerUpdate {
         phone_country_code: Option<String>,
         metadata: Option<serde_json::Value>,
     },
+    DefaultPaymentMethodUpdate {
+        default_payment_method_id: Option<String>,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -54,6 +58,7 @@ pub struct CustomerUpdateInternal {
     description: Option<String>,
     phone_country_code: Option<String>,
     metadata: Option<serde_json::Value>,
+    default_payment_method_id: Option<Option<String>>,
 }
 
 impl From<CustomerUpdate> for CustomerUpdateInternal {
@@ -73,6 +78,13 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 description,
                 phone_country_code,
                 metadata,
+                ..Default::default()
+            },
+            CustomerUpdate::DefaultPaymentMethodUpdate {
+                default_payment_method_id,
+            } => Self {
+                default_payment_method_id: Some(default_payment_method_id),
+                ..Default::default()
             },
         }
     }
@@ -3,10 +3,13 @@ pub mod diesel_exports {
     pub use super::{
         DbAttemptStatus as AttemptStatus, DbAuthenticationType as AuthenticationType,
         DbCaptureMethod as CaptureMethod, DbConnectorType as ConnectorType, DbCurrency as Currency,
-        DbEventClass as EventClass, DbEventObjectType as EventObjectType, DbEventType as EventType,
+        DbDeclineCode as DeclineCode, DbEventClass as EventClass,
+        DbEventObjectType as EventObjectType, DbEventType as EventType,
+        DbFraudCheckStage as FraudCheckStage, DbFraudCheckStatus as FraudCheckStatus,
         DbFutureUsage as FutureUsage, DbIntentStatus as IntentStatus,
         DbMandateStatus as MandateStatus, DbMandateType as MandateType,
-        DbMerchantStorageScheme as MerchantStorageScheme, DbPaymentFlow as PaymentFlow,
+        DbMerchantStorageScheme as MerchantStorageScheme,
+        DbNetworkTokenStatus as NetworkTokenStatus, DbPaymentFlow as PaymentFlow,
         DbPaymentMethodIssuerCode as PaymentMethodIssuerCode,
         DbPaymentMethodSubType as PaymentMethodSubType, DbPaymentMethodType as PaymentMethodType,
         DbProcessTrackerStatus as ProcessTrackerStatus, DbRefundStatus as RefundStatus,
@@ -124,6 +127,8 @@ pub enum ConnectorType {
     PaymentProcessor,
     /// Fraud, Currency Conversion, Crypto etc
     PaymentVas,
+    /// 3DS servers and other providers authenticating payments before they are authorized
+    AuthenticationProcessor,
     /// Accounting, Billing, Invoicing, Tax etc
     FinOperations,
     /// Inventory, ERP, CRM, KYC etc
@@ -277,6 +282,7 @@ pub enum Currency {
 #[strum(serialize_all = "snake_case")]
 pub enum EventClass {
     Payments,
+    PaymentMethods,
 }
 
 #[derive(
@@ -296,6 +302,7 @@ pub enum EventClass {
 #[strum(serialize_all = "snake_case")]
 pub enum EventObjectType {
     PaymentDetails,
+    PaymentMethodDetails,
 }
 
 #[derive(
@@ -316,6 +323,8 @@ pub enum EventObjectType {
 #[strum(serialize_all = "snake_case")]
 pub enum EventType {
     PaymentSucceeded,
+    PaymentMethodUpdated,
+    PaymentMethodClosed,
 }
 
 #[derive(
@@ -470,6 +479,17 @@ pub enum PaymentMethodSubType {
     UpiCollect,
     CreditCardInstallments,
     PayLaterInstallments,
+    Ach,
+    Sepa,
+    Bacs,
+    Ideal,
+    Sofort,
+    Giropay,
+    Eps,
+    BancontactCard,
+    Przelewy24,
+    Pix,
+    Boleto,
 }
 
 #[derive(
@@ -496,6 +516,7 @@ pub enum PaymentMethodType {
     #[default]
     BankTransfer,
     BankDebit,
+    BankRedirect,
     PayLater,
     Netbanking,
     Upi,
@@ -672,3 +693,111 @@ pub enum MandateStatus {
     Pending,
     Revoked,
 }
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum NetworkTokenStatus {
+    Active,
+    Suspended,
+    Deleted,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudCheckStage {
+    /// The check is made before the payment is authorized
+    #[default]
+    PreAuth,
+    /// The check is made once the payment is authorized, before it is captured
+    PostAuth,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudCheckStatus {
+    /// A post-auth check waiting for the payment to be authorized
+    Pending,
+    Accept,
+    Reject,
+    /// The payment is held until the merchant reviews it
+    ManualReview,
+}
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    router_derive::DieselEnum,
+    frunk::LabelledGeneric,
+)]
+#[router_derive::diesel_enum]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DeclineCode {
+    InsufficientFunds,
+    DoNotHonor,
+    ExpiredCard,
+    InvalidExpiryDate,
+    IncorrectCvc,
+    IncorrectNumber,
+    InvalidAmount,
+    CardNotSupported,
+    LostCard,
+    StolenCard,
+    RestrictedCard,
+    FraudSuspected,
+    TransactionNotAllowed,
+    AuthenticationRequired,
+    CardVelocityExceeded,
+    IssuerUnavailable,
+    ProcessingError,
+    GenericDecline,
+}
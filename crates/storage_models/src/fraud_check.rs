@@ -0,0 +1,108 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::fraud_check};
+
+/// Outcome of a check made with a fraud and risk management (FRM) connector for a payment attempt.
+/// Checks with the `manual_review` status make up the review queue of the merchant.
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Deserialize,
+    Identifiable,
+    Queryable,
+    Serialize,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = fraud_check)]
+#[diesel(primary_key(frm_id))]
+pub struct FraudCheck {
+    pub frm_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub frm_name: String,
+    pub frm_transaction_id: Option<String>,
+    pub frm_stage: storage_enums::FraudCheckStage,
+    pub frm_status: storage_enums::FraudCheckStatus,
+    pub frm_score: Option<i32>,
+    pub frm_reason: Option<String>,
+    /// The capture method requested for the payment, before capture was held for the check
+    pub payment_capture_method: Option<storage_enums::CaptureMethod>,
+    pub reviewed_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = fraud_check)]
+pub struct FraudCheckNew {
+    pub frm_id: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub frm_name: String,
+    pub frm_transaction_id: Option<String>,
+    pub frm_stage: storage_enums::FraudCheckStage,
+    pub frm_status: storage_enums::FraudCheckStatus,
+    pub frm_score: Option<i32>,
+    pub frm_reason: Option<String>,
+    pub payment_capture_method: Option<storage_enums::CaptureMethod>,
+}
+
+#[derive(Debug)]
+pub enum FraudCheckUpdate {
+    ResponseUpdate {
+        frm_transaction_id: Option<String>,
+        frm_status: storage_enums::FraudCheckStatus,
+        frm_score: Option<i32>,
+        frm_reason: Option<String>,
+    },
+    ReviewUpdate {
+        frm_status: storage_enums::FraudCheckStatus,
+        reviewed_at: PrimitiveDateTime,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = fraud_check)]
+pub(crate) struct FraudCheckUpdateInternal {
+    frm_transaction_id: Option<String>,
+    frm_status: Option<storage_enums::FraudCheckStatus>,
+    frm_score: Option<i32>,
+    frm_reason: Option<String>,
+    reviewed_at: Option<PrimitiveDateTime>,
+    modified_at: Option<PrimitiveDateTime>,
+}
+
+impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
+    fn from(fraud_check_update: FraudCheckUpdate) -> Self {
+        match fraud_check_update {
+            FraudCheckUpdate::ResponseUpdate {
+                frm_transaction_id,
+                frm_status,
+                frm_score,
+                frm_reason,
+            } => Self {
+                frm_transaction_id,
+                frm_status: Some(frm_status),
+                frm_score,
+                frm_reason,
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            FraudCheckUpdate::ReviewUpdate {
+                frm_status,
+                reviewed_at,
+            } => Self {
+                frm_status: Some(frm_status),
+                reviewed_at: Some(reviewed_at),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        }
+    }
+}
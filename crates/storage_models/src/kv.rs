@@ -6,6 +6,8 @@ use crate::{
     payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
     payment_intent::{PaymentIntent, PaymentIntentNew, PaymentIntentUpdate},
     refund::{Refund, RefundNew, RefundUpdate},
+    reverse_lookup::{ReverseLookup, ReverseLookupNew},
+    PgPooledConn, StorageResult,
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -13,7 +15,80 @@ use crate::{
 pub enum DBOperation {
     Insert { insertable: Insertable },
     Update { updatable: Updateable },
-    Delete,
+    Delete { deletable: Deletable },
+}
+
+impl DBOperation {
+    pub fn operation(&self) -> &'static str {
+        match self {
+            Self::Insert { .. } => "insert",
+            Self::Update { .. } => "update",
+            Self::Delete { .. } => "delete",
+        }
+    }
+
+    pub fn table(&self) -> &'static str {
+        match self {
+            Self::Insert { insertable } => match insertable {
+                Insertable::PaymentIntent(_) => "payment_intent",
+                Insertable::PaymentAttempt(_) => "payment_attempt",
+                Insertable::Refund(_) => "refund",
+                Insertable::ReverseLookUp(_) => "reverse_lookup",
+            },
+            Self::Update { updatable } => match updatable {
+                Updateable::PaymentIntentUpdate(_) => "payment_intent",
+                Updateable::PaymentAttemptUpdate(_) => "payment_attempt",
+                Updateable::RefundUpdate(_) => "refund",
+            },
+            Self::Delete { deletable } => match deletable {
+                Deletable::ReverseLookUp { .. } => "reverse_lookup",
+            },
+        }
+    }
+
+    /// Run the operation against the database. Tables written in KV mode only need to be handled
+    /// here for the drainer to pick them up.
+    pub async fn execute(self, conn: &PgPooledConn) -> StorageResult<DBResult> {
+        Ok(match self {
+            Self::Insert { insertable } => match insertable {
+                Insertable::PaymentIntent(a) => {
+                    DBResult::PaymentIntent(Box::new(a.insert(conn).await?))
+                }
+                Insertable::PaymentAttempt(a) => {
+                    DBResult::PaymentAttempt(Box::new(a.insert(conn).await?))
+                }
+                Insertable::Refund(a) => DBResult::Refund(Box::new(a.insert(conn).await?)),
+                Insertable::ReverseLookUp(a) => {
+                    DBResult::ReverseLookUp(Box::new(a.insert(conn).await?))
+                }
+            },
+            Self::Update { updatable } => match updatable {
+                Updateable::PaymentIntentUpdate(a) => {
+                    DBResult::PaymentIntent(Box::new(a.orig.update(conn, a.update_data).await?))
+                }
+                Updateable::PaymentAttemptUpdate(a) => {
+                    DBResult::PaymentAttempt(Box::new(a.orig.update(conn, a.update_data).await?))
+                }
+                Updateable::RefundUpdate(a) => {
+                    DBResult::Refund(Box::new(a.orig.update(conn, a.update_data).await?))
+                }
+            },
+            Self::Delete { deletable } => match deletable {
+                Deletable::ReverseLookUp { lookup_id } => {
+                    DBResult::Deleted(ReverseLookup::delete_by_lookup_id(conn, &lookup_id).await?)
+                }
+            },
+        })
+    }
+}
+
+#[derive(Debug)]
+pub enum DBResult {
+    PaymentIntent(Box<PaymentIntent>),
+    PaymentAttempt(Box<PaymentAttempt>),
+    Refund(Box<Refund>),
+    ReverseLookUp(Box<ReverseLookup>),
+    Deleted(bool),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -39,6 +114,7 @@ pub enum Insertable {
     PaymentIntent(PaymentIntentNew),
     PaymentAttempt(PaymentAttemptNew),
     Refund(RefundNew),
+    ReverseLookUp(ReverseLookupNew),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -49,6 +125,12 @@ pub enum Updateable {
     RefundUpdate(RefundUpdateMems),
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "table", content = "data")]
+pub enum Deletable {
+    ReverseLookUp { lookup_id: String },
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PaymentIntentUpdateMems {
     pub orig: PaymentIntent,
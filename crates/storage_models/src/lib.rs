@@ -8,6 +8,7 @@ pub mod enums;
 pub mod ephemeral_key;
 pub mod errors;
 pub mod events;
+pub mod fraud_check;
 #[cfg(feature = "kv_store")]
 pub mod kv;
 pub mod locker_mock_up;
@@ -17,11 +18,15 @@ pub mod merchant_connector_account;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payment_token;
 pub mod process_tracker;
 pub mod query;
+pub mod recurring_task;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod schema;
+pub mod vault;
+pub mod vault_schema;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
@@ -39,6 +39,10 @@ pub struct PaymentAttempt {
     pub error_code: Option<String>,
     pub payment_token: Option<String>,
     pub connector_metadata: Option<serde_json::Value>,
+    pub card_info: Option<serde_json::Value>,
+    pub decline_code: Option<storage_enums::DeclineCode>,
+    /// The authentication of the payment with an external 3DS authentication connector
+    pub authentication_id: Option<String>,
 }
 
 #[derive(
@@ -78,6 +82,10 @@ pub struct PaymentAttemptNew {
     pub payment_token: Option<String>,
     pub error_code: Option<String>,
     pub connector_metadata: Option<serde_json::Value>,
+    pub card_info: Option<serde_json::Value>,
+    pub decline_code: Option<storage_enums::DeclineCode>,
+    /// The authentication of the payment with an external 3DS authentication connector
+    pub authentication_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,10 +97,12 @@ pub enum PaymentAttemptUpdate {
         authentication_type: Option<storage_enums::AuthenticationType>,
         payment_method: Option<storage_enums::PaymentMethodType>,
         payment_token: Option<String>,
+        card_info: Option<serde_json::Value>,
     },
     UpdateTrackers {
         payment_token: Option<String>,
         connector: Option<String>,
+        card_info: Option<serde_json::Value>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -106,6 +116,7 @@ pub enum PaymentAttemptUpdate {
         browser_info: Option<serde_json::Value>,
         connector: Option<String>,
         payment_token: Option<String>,
+        card_info: Option<serde_json::Value>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -124,11 +135,19 @@ pub enum PaymentAttemptUpdate {
     StatusUpdate {
         status: storage_enums::AttemptStatus,
     },
+    CaptureMethodUpdate {
+        capture_method: storage_enums::CaptureMethod,
+    },
     ErrorUpdate {
         connector: Option<String>,
         status: storage_enums::AttemptStatus,
         error_code: Option<String>,
         error_message: Option<String>,
+        decline_code: Option<storage_enums::DeclineCode>,
+    },
+    AuthenticationUpdate {
+        status: storage_enums::AttemptStatus,
+        authentication_id: String,
     },
 }
 
@@ -152,6 +171,10 @@ pub struct PaymentAttemptUpdateInternal {
     payment_token: Option<String>,
     error_code: Option<String>,
     connector_metadata: Option<serde_json::Value>,
+    card_info: Option<serde_json::Value>,
+    capture_method: Option<storage_enums::CaptureMethod>,
+    decline_code: Option<storage_enums::DeclineCode>,
+    authentication_id: Option<String>,
 }
 
 impl PaymentAttemptUpdate {
@@ -174,6 +197,11 @@ impl PaymentAttemptUpdate {
             browser_info: pa_update.browser_info.or(source.browser_info),
             modified_at: common_utils::date_time::now(),
             payment_token: pa_update.payment_token.or(source.payment_token),
+            card_info: pa_update.card_info.or(source.card_info),
+            capture_method: pa_update.capture_method.or(source.capture_method),
+            error_code: pa_update.error_code.or(source.error_code),
+            decline_code: pa_update.decline_code.or(source.decline_code),
+            authentication_id: pa_update.authentication_id.or(source.authentication_id),
             ..source
         }
     }
@@ -190,6 +218,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 authentication_type,
                 payment_method,
                 payment_token,
+                card_info,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -198,6 +227,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 authentication_type,
                 payment_method,
                 payment_token,
+                card_info,
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
@@ -217,6 +247,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 browser_info,
                 connector,
                 payment_token,
+                card_info,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -227,6 +258,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 browser_info,
                 connector,
                 payment_token,
+                card_info,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -263,11 +295,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 status,
                 error_code,
                 error_message,
+                decline_code,
             } => Self {
                 connector,
                 status: Some(status),
                 error_message,
                 error_code,
+                decline_code,
                 modified_at: Some(common_utils::date_time::now()),
                 ..Default::default()
             },
@@ -275,12 +309,28 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 status: Some(status),
                 ..Default::default()
             },
+            PaymentAttemptUpdate::AuthenticationUpdate {
+                status,
+                authentication_id,
+            } => Self {
+                status: Some(status),
+                authentication_id: Some(authentication_id),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+            PaymentAttemptUpdate::CaptureMethodUpdate { capture_method } => Self {
+                capture_method: Some(capture_method),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
                 connector,
+                card_info,
             } => Self {
                 payment_token,
                 connector,
+                card_info,
                 ..Default::default()
             },
         }
@@ -1,4 +1,4 @@
-use diesel::{Identifiable, Insertable, Queryable};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use masking::Secret;
 use time::PrimitiveDateTime;
 
@@ -30,6 +30,17 @@ pub struct PaymentMethod {
     pub payment_method_issuer: Option<String>,
     pub payment_method_issuer_code: Option<storage_enums::PaymentMethodIssuerCode>,
     pub metadata: Option<serde_json::Value>,
+    pub card_fingerprint: Option<String>,
+    pub network_token_reference_id: Option<String>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    pub network_token_last4: Option<String>,
+    pub network_token_exp_month: Option<String>,
+    pub network_token_exp_year: Option<String>,
+    pub card_exp_month: Option<String>,
+    pub card_exp_year: Option<String>,
+    pub last_used_at: Option<PrimitiveDateTime>,
+    pub usage_count: i32,
+    pub bank_account_last4: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable, Queryable, router_derive::DebugAsDisplay)]
@@ -55,6 +66,15 @@ pub struct PaymentMethodNew {
     pub created_at: PrimitiveDateTime,
     pub last_modified: PrimitiveDateTime,
     pub metadata: Option<serde_json::Value>,
+    pub card_fingerprint: Option<String>,
+    pub network_token_reference_id: Option<String>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    pub network_token_last4: Option<String>,
+    pub network_token_exp_month: Option<String>,
+    pub network_token_exp_year: Option<String>,
+    pub card_exp_month: Option<String>,
+    pub card_exp_year: Option<String>,
+    pub bank_account_last4: Option<String>,
 }
 
 impl Default for PaymentMethodNew {
@@ -82,6 +102,89 @@ impl Default for PaymentMethodNew {
             created_at: now,
             last_modified: now,
             metadata: Option::default(),
+            card_fingerprint: Option::default(),
+            network_token_reference_id: Option::default(),
+            network_token_status: Option::default(),
+            network_token_last4: Option::default(),
+            network_token_exp_month: Option::default(),
+            network_token_exp_year: Option::default(),
+            card_exp_month: Option::default(),
+            card_exp_year: Option::default(),
+            bank_account_last4: Option::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum PaymentMethodUpdate {
+    NetworkTokenUpdate {
+        network_token_reference_id: Option<String>,
+        network_token_status: Option<storage_enums::NetworkTokenStatus>,
+        network_token_last4: Option<String>,
+        network_token_exp_month: Option<String>,
+        network_token_exp_year: Option<String>,
+    },
+    NetworkTokenStatusUpdate {
+        network_token_status: storage_enums::NetworkTokenStatus,
+    },
+    CardUpdate {
+        card_fingerprint: Option<String>,
+        card_exp_month: Option<String>,
+        card_exp_year: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_methods)]
+pub struct PaymentMethodUpdateInternal {
+    network_token_reference_id: Option<String>,
+    network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    network_token_last4: Option<String>,
+    network_token_exp_month: Option<String>,
+    network_token_exp_year: Option<String>,
+    card_fingerprint: Option<String>,
+    card_exp_month: Option<String>,
+    card_exp_year: Option<String>,
+    last_modified: Option<PrimitiveDateTime>,
+}
+
+impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
+    fn from(payment_method_update: PaymentMethodUpdate) -> Self {
+        let last_modified = Some(common_utils::date_time::now());
+        match payment_method_update {
+            PaymentMethodUpdate::NetworkTokenUpdate {
+                network_token_reference_id,
+                network_token_status,
+                network_token_last4,
+                network_token_exp_month,
+                network_token_exp_year,
+            } => Self {
+                network_token_reference_id,
+                network_token_status,
+                network_token_last4,
+                network_token_exp_month,
+                network_token_exp_year,
+                last_modified,
+                ..Default::default()
+            },
+            PaymentMethodUpdate::NetworkTokenStatusUpdate {
+                network_token_status,
+            } => Self {
+                network_token_status: Some(network_token_status),
+                last_modified,
+                ..Default::default()
+            },
+            PaymentMethodUpdate::CardUpdate {
+                card_fingerprint,
+                card_exp_month,
+                card_exp_year,
+            } => Self {
+                card_fingerprint,
+                card_exp_month,
+                card_exp_year,
+                last_modified,
+                ..Default::default()
+            },
         }
     }
 }
@@ -0,0 +1,35 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::payment_token;
+
+/// Usage of a payment token issued for payment method data stored in the temporary locker. The
+/// expiry and the number of uses allowed are decided by the merchant using the token, when the
+/// token is used to confirm a payment.
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Deserialize,
+    Identifiable,
+    Queryable,
+    Serialize,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = payment_token)]
+#[diesel(primary_key(token))]
+pub struct PaymentToken {
+    pub token: String,
+    pub use_count: i32,
+    pub created_at: PrimitiveDateTime,
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_token)]
+pub struct PaymentTokenNew {
+    pub token: String,
+    pub created_at: PrimitiveDateTime,
+}
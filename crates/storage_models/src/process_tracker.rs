@@ -71,6 +71,12 @@ pub enum ProcessTrackerUpdate {
         status: storage_enums::ProcessTrackerStatus,
         retry_count: i32,
         schedule_time: PrimitiveDateTime,
+        event: Vec<String>,
+    },
+    StatusEventUpdate {
+        status: storage_enums::ProcessTrackerStatus,
+        business_status: Option<String>,
+        event: Vec<String>,
     },
 }
 
@@ -83,6 +89,7 @@ pub struct ProcessTrackerUpdateInternal {
     tracking_data: Option<serde_json::Value>,
     business_status: Option<String>,
     status: Option<storage_enums::ProcessTrackerStatus>,
+    event: Option<Vec<String>>,
     updated_at: Option<PrimitiveDateTime>,
 }
 
@@ -95,6 +102,7 @@ impl Default for ProcessTrackerUpdateInternal {
             tracking_data: Option::default(),
             business_status: Option::default(),
             status: Option::default(),
+            event: Option::default(),
             updated_at: Some(common_utils::date_time::now()),
         }
     }
@@ -119,6 +127,7 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
                 business_status,
                 status,
                 updated_at,
+                ..Default::default()
             },
             ProcessTrackerUpdate::StatusUpdate {
                 status,
@@ -132,10 +141,22 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
                 status,
                 retry_count,
                 schedule_time,
+                event,
             } => Self {
                 status: Some(status),
                 retry_count: Some(retry_count),
                 schedule_time: Some(schedule_time),
+                event: Some(event),
+                ..Default::default()
+            },
+            ProcessTrackerUpdate::StatusEventUpdate {
+                status,
+                business_status,
+                event,
+            } => Self {
+                status: Some(status),
+                business_status,
+                event: Some(event),
                 ..Default::default()
             },
         }
@@ -4,6 +4,7 @@ pub mod configs;
 pub mod connector_response;
 pub mod customers;
 pub mod events;
+pub mod fraud_check;
 pub mod generics;
 pub mod locker_mock_up;
 pub mod mandate;
@@ -12,6 +13,9 @@ pub mod merchant_connector_account;
 pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_method;
+pub mod payment_token;
 pub mod process_tracker;
+pub mod recurring_task;
 pub mod refund;
 pub mod reverse_lookup;
+pub mod vault;
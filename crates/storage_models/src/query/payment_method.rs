@@ -1,9 +1,13 @@
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
 use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
-    payment_method::{PaymentMethod, PaymentMethodNew},
+    enums, errors,
+    payment_method::{
+        PaymentMethod, PaymentMethodNew, PaymentMethodUpdate, PaymentMethodUpdateInternal,
+    },
     schema::payment_methods::dsl,
     PgPooledConn, StorageResult,
 };
@@ -97,4 +101,141 @@ impl PaymentMethod {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_optional_by_merchant_id_customer_id_card_fingerprint(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::card_fingerprint.eq(card_fingerprint.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_network_token_reference_id(
+        conn: &PgPooledConn,
+        network_token_reference_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::network_token_reference_id.eq(network_token_reference_id.to_owned()),
+        )
+        .await
+    }
+
+    /// Cards expiring between the two `(year, month)` pairs, both inclusive, ordered by ID. Only
+    /// payment methods with an ID greater than `after_id` are returned, for paginating through
+    /// the results.
+    #[instrument(skip(conn))]
+    pub async fn find_cards_by_expiry_range(
+        conn: &PgPooledConn,
+        from: (&str, &str),
+        to: (&str, &str),
+        after_id: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let ((from_year, from_month), (to_year, to_month)) = (from, to);
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::id
+                .gt(after_id)
+                .and(dsl::payment_method.eq(enums::PaymentMethodType::Card))
+                .and(
+                    dsl::card_exp_year
+                        .gt(from_year.to_owned())
+                        .or(dsl::card_exp_year
+                            .eq(from_year.to_owned())
+                            .and(dsl::card_exp_month.ge(from_month.to_owned()))),
+                )
+                .and(
+                    dsl::card_exp_year
+                        .lt(to_year.to_owned())
+                        .or(dsl::card_exp_year
+                            .eq(to_year.to_owned())
+                            .and(dsl::card_exp_month.le(to_month.to_owned()))),
+                ),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+
+    /// Cards saved before their expiry was stored on the payment method, ordered by ID. Only
+    /// payment methods with an ID greater than `after_id` are returned, for paginating through
+    /// the results.
+    #[instrument(skip(conn))]
+    pub async fn find_cards_without_expiry(
+        conn: &PgPooledConn,
+        after_id: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::id
+                .gt(after_id)
+                .and(dsl::payment_method.eq(enums::PaymentMethodType::Card))
+                .and(
+                    dsl::card_exp_year
+                        .is_null()
+                        .or(dsl::card_exp_month.is_null()),
+                ),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+
+    /// Record a successful payment made with the payment method.
+    #[instrument(skip(conn))]
+    pub async fn record_usage(
+        conn: &PgPooledConn,
+        payment_method_id: &str,
+        used_at: PrimitiveDateTime,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::payment_method_id.eq(payment_method_id.to_owned()),
+            (
+                dsl::usage_count.eq(dsl::usage_count + 1),
+                dsl::last_used_at.eq(used_at),
+            ),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payment_method: PaymentMethodUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            self.id,
+            PaymentMethodUpdateInternal::from(payment_method),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
 }
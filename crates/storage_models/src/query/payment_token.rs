@@ -0,0 +1,91 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::generics;
+use crate::{
+    payment_token::{PaymentToken, PaymentTokenNew},
+    schema::payment_token::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentTokenNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentToken> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentToken {
+    #[instrument(skip(conn))]
+    pub async fn find_optional_by_token(
+        conn: &PgPooledConn,
+        token: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::token.eq(token.to_owned()),
+        )
+        .await
+    }
+
+    /// Record a use of the token, provided it was created after `created_after` and was used
+    /// fewer than `max_uses` times. Returns `None` if the token cannot be used.
+    #[instrument(skip(conn))]
+    pub async fn record_use(
+        conn: &PgPooledConn,
+        token: &str,
+        created_after: PrimitiveDateTime,
+        max_uses: i32,
+        used_at: PrimitiveDateTime,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::token
+                .eq(token.to_owned())
+                .and(dsl::created_at.gt(created_after))
+                .and(dsl::use_count.lt(max_uses)),
+            (
+                dsl::use_count.eq(dsl::use_count + 1),
+                dsl::last_used_at.eq(used_at),
+            ),
+        )
+        .await
+        .map(|mut tokens| tokens.pop())
+    }
+
+    /// Release a use of the token recorded with [`Self::record_use`]. Returns whether a use was
+    /// released.
+    #[instrument(skip(conn))]
+    pub async fn release_use(conn: &PgPooledConn, token: &str) -> StorageResult<bool> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::token.eq(token.to_owned()).and(dsl::use_count.gt(0)),
+            dsl::use_count.eq(dsl::use_count - 1),
+        )
+        .await
+        .map(|updated| updated > 0)
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_created_before(
+        conn: &PgPooledConn,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::created_at.lt(created_before),
+            Some(limit),
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_tokens(conn: &PgPooledConn, tokens: Vec<String>) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(conn, dsl::token.eq_any(tokens))
+            .await
+    }
+}
@@ -0,0 +1,66 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use super::generics;
+use crate::{
+    recurring_task::{
+        RecurringTask, RecurringTaskNew, RecurringTaskUpdate, RecurringTaskUpdateInternal,
+    },
+    schema::recurring_task::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl RecurringTaskNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RecurringTask> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RecurringTask {
+    #[instrument(skip(conn))]
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        recurring_task: RecurringTaskUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_by_id::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            self.id.clone(),
+            RecurringTaskUpdateInternal::from(recurring_task),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_by_id(conn: &PgPooledConn, id: &str) -> StorageResult<Option<Self>> {
+        generics::generic_find_by_id_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            id.to_owned(),
+        )
+        .await
+    }
+
+    /// Enabled recurring tasks whose next run is due at or before `time`.
+    #[instrument(skip(conn))]
+    pub async fn find_due(
+        conn: &PgPooledConn,
+        time: PrimitiveDateTime,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::enabled.eq(true).and(dsl::next_run_at.le(time)),
+            limit,
+            None,
+            None,
+        )
+        .await
+    }
+}
@@ -0,0 +1,108 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use router_env::{instrument, tracing};
+
+use super::generics;
+use crate::{
+    vault::{VaultCard, VaultCardNew, VaultCardUpdate, VaultDataKey, VaultDataKeyNew},
+    vault_schema::vault_card::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl VaultDataKeyNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultDataKey> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultDataKey {
+    #[instrument(skip(conn))]
+    pub async fn find_optional_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_by_id_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            merchant_id.to_owned(),
+        )
+        .await
+    }
+}
+
+impl VaultCardNew {
+    #[instrument(skip(conn))]
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultCard> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultCard {
+    #[instrument(skip(conn))]
+    pub async fn find_by_merchant_id_card_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::card_id.eq(card_id.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn find_optional_by_fingerprint(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        customer_id: &str,
+        card_fingerprint: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::card_fingerprint.eq(card_fingerprint.to_owned())),
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn update_by_merchant_id_card_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        card_id: &str,
+        card: VaultCardUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::card_id.eq(card_id.to_owned())),
+            card,
+        )
+        .await
+    }
+
+    #[instrument(skip(conn))]
+    pub async fn delete_by_merchant_id_card_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        card_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::card_id.eq(card_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -0,0 +1,115 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::recurring_task;
+
+/// Definition of a task that is run on a cron schedule. The producer materializes a process
+/// tracker task for every occurrence of the schedule.
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Deserialize,
+    Identifiable,
+    Queryable,
+    Serialize,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = recurring_task)]
+pub struct RecurringTask {
+    pub id: String,
+    pub name: String,
+    pub runner: String,
+    #[diesel(deserialize_as = super::DieselArray<String>)]
+    pub tag: Vec<String>,
+    pub schedule: String,
+    pub tracking_data: serde_json::Value,
+    pub enabled: bool,
+    pub next_run_at: PrimitiveDateTime,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub updated_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recurring_task)]
+pub struct RecurringTaskNew {
+    pub id: String,
+    pub name: String,
+    pub runner: String,
+    pub tag: Vec<String>,
+    pub schedule: String,
+    pub tracking_data: serde_json::Value,
+    pub enabled: bool,
+    pub next_run_at: PrimitiveDateTime,
+    pub created_at: PrimitiveDateTime,
+    pub updated_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum RecurringTaskUpdate {
+    Update {
+        schedule: Option<String>,
+        tracking_data: Option<serde_json::Value>,
+        enabled: Option<bool>,
+        next_run_at: Option<PrimitiveDateTime>,
+    },
+    RunUpdate {
+        next_run_at: PrimitiveDateTime,
+        last_run_at: PrimitiveDateTime,
+    },
+    Disable,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = recurring_task)]
+pub struct RecurringTaskUpdateInternal {
+    schedule: Option<String>,
+    tracking_data: Option<serde_json::Value>,
+    enabled: Option<bool>,
+    next_run_at: Option<PrimitiveDateTime>,
+    last_run_at: Option<PrimitiveDateTime>,
+    updated_at: PrimitiveDateTime,
+}
+
+impl From<RecurringTaskUpdate> for RecurringTaskUpdateInternal {
+    fn from(recurring_task_update: RecurringTaskUpdate) -> Self {
+        let updated_at = common_utils::date_time::now();
+        match recurring_task_update {
+            RecurringTaskUpdate::Update {
+                schedule,
+                tracking_data,
+                enabled,
+                next_run_at,
+            } => Self {
+                schedule,
+                tracking_data,
+                enabled,
+                next_run_at,
+                last_run_at: None,
+                updated_at,
+            },
+            RecurringTaskUpdate::RunUpdate {
+                next_run_at,
+                last_run_at,
+            } => Self {
+                schedule: None,
+                tracking_data: None,
+                enabled: None,
+                next_run_at: Some(next_run_at),
+                last_run_at: Some(last_run_at),
+                updated_at,
+            },
+            RecurringTaskUpdate::Disable => Self {
+                schedule: None,
+                tracking_data: None,
+                enabled: Some(false),
+                next_run_at: None,
+                last_run_at: None,
+                updated_at,
+            },
+        }
+    }
+}
@@ -180,4 +180,5 @@ pub struct RefundCoreWorkflow {
     pub connector_transaction_id: String,
     pub merchant_id: String,
     pub payment_id: String,
+    pub connector: Option<String>,
 }
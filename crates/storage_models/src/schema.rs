@@ -87,6 +87,7 @@ diesel::table! {
         description -> Nullable<Varchar>,
         created_at -> Timestamp,
         metadata -> Nullable<Json>,
+        default_payment_method_id -> Nullable<Varchar>,
     }
 }
 
@@ -107,6 +108,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    fraud_check (frm_id) {
+        frm_id -> Varchar,
+        merchant_id -> Varchar,
+        payment_id -> Varchar,
+        attempt_id -> Varchar,
+        frm_name -> Varchar,
+        frm_transaction_id -> Nullable<Varchar>,
+        frm_stage -> FraudCheckStage,
+        frm_status -> FraudCheckStatus,
+        frm_score -> Nullable<Int4>,
+        frm_reason -> Nullable<Text>,
+        payment_capture_method -> Nullable<CaptureMethod>,
+        reviewed_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -153,6 +176,7 @@ diesel::table! {
         amount_captured -> Nullable<Int8>,
         connector -> Varchar,
         connector_mandate_id -> Nullable<Varchar>,
+        payment_method -> Nullable<PaymentMethodType>,
     }
 }
 
@@ -178,6 +202,7 @@ diesel::table! {
         locker_id -> Nullable<Varchar>,
         metadata -> Nullable<Jsonb>,
         routing_algorithm -> Nullable<Json>,
+        payment_token_config -> Nullable<Json>,
     }
 }
 
@@ -236,6 +261,9 @@ diesel::table! {
         error_code -> Nullable<Varchar>,
         payment_token -> Nullable<Varchar>,
         connector_metadata -> Nullable<Jsonb>,
+        card_info -> Nullable<Jsonb>,
+        decline_code -> Nullable<DeclineCode>,
+        authentication_id -> Nullable<Varchar>,
     }
 }
 
@@ -295,6 +323,29 @@ diesel::table! {
         payment_method_issuer -> Nullable<Varchar>,
         payment_method_issuer_code -> Nullable<PaymentMethodIssuerCode>,
         metadata -> Nullable<Json>,
+        card_fingerprint -> Nullable<Varchar>,
+        network_token_reference_id -> Nullable<Varchar>,
+        network_token_status -> Nullable<NetworkTokenStatus>,
+        network_token_last4 -> Nullable<Varchar>,
+        network_token_exp_month -> Nullable<Varchar>,
+        network_token_exp_year -> Nullable<Varchar>,
+        card_exp_month -> Nullable<Varchar>,
+        card_exp_year -> Nullable<Varchar>,
+        last_used_at -> Nullable<Timestamp>,
+        usage_count -> Int4,
+        bank_account_last4 -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_token (token) {
+        token -> Varchar,
+        use_count -> Int4,
+        created_at -> Timestamp,
+        last_used_at -> Nullable<Timestamp>,
     }
 }
 
@@ -319,6 +370,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    recurring_task (id) {
+        id -> Varchar,
+        name -> Varchar,
+        runner -> Varchar,
+        tag -> Array<Nullable<Text>>,
+        schedule -> Varchar,
+        tracking_data -> Json,
+        enabled -> Bool,
+        next_run_at -> Timestamp,
+        last_run_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -370,6 +440,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     connector_response,
     customers,
     events,
+    fraud_check,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -377,7 +448,9 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_attempt,
     payment_intent,
     payment_methods,
+    payment_token,
     process_tracker,
+    recurring_task,
     refund,
     reverse_lookup,
 );
@@ -0,0 +1,54 @@
+//! Models for the card vault, which lives in a database separate from the main database.
+
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::vault_schema::{vault_card, vault_data_key};
+
+/// Per-merchant data encryption key, itself encrypted with the vault's key encryption key.
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
+#[diesel(table_name = vault_data_key, primary_key(merchant_id))]
+pub struct VaultDataKey {
+    pub merchant_id: String,
+    pub encrypted_key: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = vault_data_key)]
+pub struct VaultDataKeyNew {
+    pub merchant_id: String,
+    pub encrypted_key: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Card data encrypted with the data key of the merchant it belongs to.
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable)]
+#[diesel(table_name = vault_card, primary_key(card_id))]
+pub struct VaultCard {
+    pub card_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub card_fingerprint: String,
+    pub encrypted_card: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = vault_card)]
+pub struct VaultCardNew {
+    pub card_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub card_fingerprint: String,
+    pub encrypted_card: Vec<u8>,
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Replacement of the card data, such as when the card is reissued with a new number or expiry.
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = vault_card)]
+pub struct VaultCardUpdate {
+    pub card_fingerprint: String,
+    pub encrypted_card: Vec<u8>,
+}
@@ -0,0 +1,15 @@
+CREATE TABLE recurring_task (
+    id VARCHAR(64) NOT NULL PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    runner VARCHAR(64) NOT NULL,
+    tag TEXT [] NOT NULL DEFAULT '{}'::TEXT [],
+    schedule VARCHAR(255) NOT NULL,
+    tracking_data JSON NOT NULL DEFAULT '{}'::JSON,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    next_run_at TIMESTAMP NOT NULL,
+    last_run_at TIMESTAMP DEFAULT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX recurring_task_enabled_next_run_at_index ON recurring_task (enabled, next_run_at);
@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS payment_methods_merchant_id_customer_id_card_fingerprint_index;
+
+ALTER TABLE payment_methods DROP COLUMN IF EXISTS card_fingerprint;
@@ -0,0 +1,10 @@
+DROP INDEX IF EXISTS payment_methods_network_token_reference_id_index;
+
+ALTER TABLE payment_methods
+DROP COLUMN IF EXISTS network_token_reference_id,
+DROP COLUMN IF EXISTS network_token_status,
+DROP COLUMN IF EXISTS network_token_last4,
+DROP COLUMN IF EXISTS network_token_exp_month,
+DROP COLUMN IF EXISTS network_token_exp_year;
+
+DROP TYPE IF EXISTS "NetworkTokenStatus";
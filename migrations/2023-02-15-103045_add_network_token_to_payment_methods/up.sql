@@ -0,0 +1,10 @@
+CREATE TYPE "NetworkTokenStatus" AS ENUM ('active', 'suspended', 'deleted');
+
+ALTER TABLE payment_methods
+ADD COLUMN network_token_reference_id VARCHAR(128),
+ADD COLUMN network_token_status "NetworkTokenStatus",
+ADD COLUMN network_token_last4 VARCHAR(4),
+ADD COLUMN network_token_exp_month VARCHAR(2),
+ADD COLUMN network_token_exp_year VARCHAR(4);
+
+CREATE UNIQUE INDEX payment_methods_network_token_reference_id_index ON payment_methods (network_token_reference_id);
@@ -0,0 +1,25 @@
+DELETE FROM recurring_task WHERE id = 'card_account_updater';
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'payment_method_updated'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventType'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'payment_method_details'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventObjectType'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel = 'payment_methods'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventClass'
+);
+
+DROP INDEX IF EXISTS payment_methods_card_exp_year_card_exp_month_index;
+
+ALTER TABLE payment_methods
+DROP COLUMN IF EXISTS card_exp_month,
+DROP COLUMN IF EXISTS card_exp_year;
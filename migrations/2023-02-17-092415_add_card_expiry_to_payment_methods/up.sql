@@ -0,0 +1,21 @@
+ALTER TABLE payment_methods
+ADD COLUMN card_exp_month VARCHAR(2),
+ADD COLUMN card_exp_year VARCHAR(4);
+
+CREATE INDEX payment_methods_card_exp_year_card_exp_month_index ON payment_methods (card_exp_year, card_exp_month);
+
+ALTER TYPE "EventClass" ADD VALUE 'payment_methods';
+ALTER TYPE "EventObjectType" ADD VALUE 'payment_method_details';
+ALTER TYPE "EventType" ADD VALUE 'payment_method_updated';
+
+-- Disabled by default, enable once an account updater provider is configured
+INSERT INTO recurring_task (id, name, runner, tag, schedule, enabled, next_run_at)
+VALUES (
+    'card_account_updater',
+    'CARD_ACCOUNT_UPDATER',
+    'CARD_ACCOUNT_UPDATER_WORKFLOW',
+    '{"PAYMENT_METHOD", "ACCOUNT_UPDATER"}',
+    '0 3 * * *',
+    FALSE,
+    now()::TIMESTAMP
+);
@@ -0,0 +1 @@
+ALTER TABLE payment_attempt DROP COLUMN card_info;
@@ -0,0 +1 @@
+ALTER TABLE payment_attempt ADD COLUMN card_info JSONB;
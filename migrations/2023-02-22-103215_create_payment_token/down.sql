@@ -0,0 +1,8 @@
+DELETE FROM recurring_task WHERE id = 'payment_token_purge';
+
+ALTER TABLE merchant_account
+DROP COLUMN IF EXISTS payment_token_config;
+
+DROP INDEX IF EXISTS payment_token_created_at_index;
+
+DROP TABLE IF EXISTS payment_token;
@@ -0,0 +1,22 @@
+CREATE TABLE payment_token (
+    token VARCHAR(64) NOT NULL PRIMARY KEY,
+    use_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    last_used_at TIMESTAMP
+);
+
+CREATE INDEX payment_token_created_at_index ON payment_token (created_at);
+
+ALTER TABLE merchant_account
+ADD COLUMN payment_token_config JSON;
+
+INSERT INTO recurring_task (id, name, runner, tag, schedule, enabled, next_run_at)
+VALUES (
+    'payment_token_purge',
+    'PAYMENT_TOKEN_PURGE',
+    'PAYMENT_TOKEN_PURGE_WORKFLOW',
+    '{"PAYMENT_METHOD", "PAYMENT_TOKEN"}',
+    '*/15 * * * *',
+    TRUE,
+    now()::TIMESTAMP
+);
@@ -0,0 +1,6 @@
+ALTER TABLE payment_methods
+DROP COLUMN IF EXISTS last_used_at,
+DROP COLUMN IF EXISTS usage_count;
+
+ALTER TABLE customers
+DROP COLUMN IF EXISTS default_payment_method_id;
@@ -0,0 +1,6 @@
+ALTER TABLE customers
+ADD COLUMN default_payment_method_id VARCHAR(64);
+
+ALTER TABLE payment_methods
+ADD COLUMN last_used_at TIMESTAMP,
+ADD COLUMN usage_count INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,8 @@
+ALTER TABLE payment_methods
+DROP COLUMN IF EXISTS bank_account_last4;
+
+DELETE FROM pg_enum
+WHERE enumlabel IN ('ach', 'sepa', 'bacs')
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'PaymentMethodSubType'
+);
@@ -0,0 +1,6 @@
+ALTER TYPE "PaymentMethodSubType" ADD VALUE 'ach';
+ALTER TYPE "PaymentMethodSubType" ADD VALUE 'sepa';
+ALTER TYPE "PaymentMethodSubType" ADD VALUE 'bacs';
+
+ALTER TABLE payment_methods
+ADD COLUMN bank_account_last4 VARCHAR(4);
@@ -0,0 +1,11 @@
+DELETE FROM pg_enum
+WHERE enumlabel = 'bank_redirect'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'PaymentMethodType'
+);
+
+DELETE FROM pg_enum
+WHERE enumlabel IN ('ideal', 'sofort', 'giropay', 'eps', 'bancontact_card', 'przelewy24', 'pix', 'boleto')
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'PaymentMethodSubType'
+);
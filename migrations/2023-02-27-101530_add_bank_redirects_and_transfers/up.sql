@@ -0,0 +1,10 @@
+ALTER TYPE "PaymentMethodType" ADD VALUE 'bank_redirect' AFTER 'bank_debit';
+
+ALTER TYPE "PaymentMethodSubType" ADD VALUE 'ideal';
+ALTER TYPE "PaymentMethodSubType" ADD VALUE 'sofort';
+ALTER TYPE "PaymentMethodSubType" ADD VALUE 'giropay';
+ALTER TYPE "PaymentMethodSubType" ADD VALUE 'eps';
+ALTER TYPE "PaymentMethodSubType" ADD VALUE 'bancontact_card';
+ALTER TYPE "PaymentMethodSubType" ADD VALUE 'przelewy24';
+ALTER TYPE "PaymentMethodSubType" ADD VALUE 'pix';
+ALTER TYPE "PaymentMethodSubType" ADD VALUE 'boleto';
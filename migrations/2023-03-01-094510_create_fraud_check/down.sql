@@ -0,0 +1,9 @@
+DROP INDEX IF EXISTS fraud_check_attempt_id_index;
+
+DROP INDEX IF EXISTS fraud_check_merchant_id_frm_status_index;
+
+DROP TABLE IF EXISTS fraud_check;
+
+DROP TYPE IF EXISTS "FraudCheckStatus";
+
+DROP TYPE IF EXISTS "FraudCheckStage";
@@ -0,0 +1,29 @@
+CREATE TYPE "FraudCheckStage" AS ENUM ('pre_auth', 'post_auth');
+
+CREATE TYPE "FraudCheckStatus" AS ENUM (
+    'pending',
+    'accept',
+    'reject',
+    'manual_review'
+);
+
+CREATE TABLE fraud_check (
+    frm_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    attempt_id VARCHAR(64) NOT NULL,
+    frm_name VARCHAR(64) NOT NULL,
+    frm_transaction_id VARCHAR(255),
+    frm_stage "FraudCheckStage" NOT NULL,
+    frm_status "FraudCheckStatus" NOT NULL,
+    frm_score INTEGER,
+    frm_reason TEXT,
+    payment_capture_method "CaptureMethod",
+    reviewed_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX fraud_check_merchant_id_frm_status_index ON fraud_check (merchant_id, frm_status);
+
+CREATE INDEX fraud_check_attempt_id_index ON fraud_check (attempt_id);
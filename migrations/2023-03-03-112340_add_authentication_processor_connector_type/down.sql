@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DELETE FROM pg_enum
+WHERE enumlabel = 'authentication_processor'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'ConnectorType'
+)
@@ -0,0 +1,3 @@
+ALTER TABLE payment_attempt DROP COLUMN decline_code;
+
+DROP TYPE IF EXISTS "DeclineCode";
@@ -0,0 +1,22 @@
+CREATE TYPE "DeclineCode" AS ENUM (
+    'insufficient_funds',
+    'do_not_honor',
+    'expired_card',
+    'invalid_expiry_date',
+    'incorrect_cvc',
+    'incorrect_number',
+    'invalid_amount',
+    'card_not_supported',
+    'lost_card',
+    'stolen_card',
+    'restricted_card',
+    'fraud_suspected',
+    'transaction_not_allowed',
+    'authentication_required',
+    'card_velocity_exceeded',
+    'issuer_unavailable',
+    'processing_error',
+    'generic_decline'
+);
+
+ALTER TABLE payment_attempt ADD COLUMN decline_code "DeclineCode";
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS payment_methods_merchant_id_customer_id_card_fingerprint_index;
+CREATE INDEX payment_methods_merchant_id_customer_id_card_fingerprint_index ON payment_methods (merchant_id, customer_id, card_fingerprint);
@@ -0,0 +1,18 @@
+-- Keep the fingerprint only on the oldest payment method of cards saved more than once by the same
+-- customer, so that the unique index can be created
+UPDATE payment_methods
+SET card_fingerprint = NULL
+WHERE id IN (
+    SELECT id
+    FROM (
+        SELECT id, row_number() OVER (
+            PARTITION BY merchant_id, customer_id, card_fingerprint ORDER BY id
+        ) AS row_number
+        FROM payment_methods
+        WHERE card_fingerprint IS NOT NULL
+    ) AS saved_cards
+    WHERE row_number > 1
+);
+
+DROP INDEX IF EXISTS payment_methods_merchant_id_customer_id_card_fingerprint_index;
+CREATE UNIQUE INDEX payment_methods_merchant_id_customer_id_card_fingerprint_index ON payment_methods (merchant_id, customer_id, card_fingerprint);
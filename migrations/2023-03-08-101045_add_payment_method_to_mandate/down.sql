@@ -0,0 +1 @@
+ALTER TABLE mandate DROP COLUMN IF EXISTS payment_method;
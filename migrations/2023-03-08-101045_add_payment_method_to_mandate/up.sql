@@ -0,0 +1,6 @@
+ALTER TABLE mandate ADD COLUMN payment_method "PaymentMethodType";
+
+UPDATE mandate
+SET payment_method = payment_methods.payment_method
+FROM payment_methods
+WHERE payment_methods.payment_method_id = mandate.payment_method_id;
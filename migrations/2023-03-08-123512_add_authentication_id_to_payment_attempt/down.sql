@@ -0,0 +1 @@
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS authentication_id;
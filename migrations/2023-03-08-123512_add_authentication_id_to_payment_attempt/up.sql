@@ -0,0 +1 @@
+ALTER TABLE payment_attempt ADD COLUMN authentication_id VARCHAR(64);
@@ -0,0 +1,5 @@
+DELETE FROM pg_enum
+WHERE enumlabel = 'payment_method_closed'
+AND enumtypid = (
+  SELECT oid FROM pg_type WHERE typname = 'EventType'
+);
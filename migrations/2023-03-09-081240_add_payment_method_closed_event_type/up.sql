@@ -0,0 +1 @@
+ALTER TYPE "EventType" ADD VALUE 'payment_method_closed';
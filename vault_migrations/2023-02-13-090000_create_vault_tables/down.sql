@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS vault_card;
+DROP TABLE IF EXISTS vault_data_key;
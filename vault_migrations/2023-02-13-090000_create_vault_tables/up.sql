@@ -0,0 +1,16 @@
+CREATE TABLE vault_data_key (
+    merchant_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    encrypted_key BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE TABLE vault_card (
+    card_id VARCHAR(64) NOT NULL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    card_fingerprint VARCHAR(128) NOT NULL,
+    encrypted_card BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX vault_card_merchant_id_customer_id_card_fingerprint_index ON vault_card (merchant_id, customer_id, card_fingerprint);